@@ -1,7 +1,9 @@
+use futures::Stream;
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use uuid::Uuid;
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, pagination::{ paginate, Page }, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct AccountApi {
@@ -36,8 +38,8 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/block").await?;
+        self.handle_response(response).await
     }
 
     pub async fn add_email(
@@ -58,8 +60,8 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/emails").await?;
+        self.handle_response(response).await
     }
 
     pub async fn close_account(
@@ -95,7 +97,7 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -113,8 +115,8 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_account_custom_fields(
@@ -135,8 +137,8 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_account_tags(
@@ -153,8 +155,8 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_payment_method(
@@ -200,8 +202,8 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/paymentMethods").await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_account_custom_fields(
@@ -228,7 +230,7 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -252,13 +254,13 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_account(
         &self,
-        request: GetAccountRequest<'_>
+        request: GetAccountRequest
     ) -> Result<models::Account, AccountApiError> {
         let url = format!("{}/1.0/kb/accounts/{}", self.config.base_path, request.account_id);
 
@@ -272,17 +274,17 @@ impl AccountApi {
                         "accountWithBalanceAndCBA",
                         request.account_with_balance_and_cba.map(|b| b.to_string()),
                     ),
-                    ("audit", request.audit.map(|a| a.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_audit_logs(
         &self,
-        request: GetAccountAuditLogsRequest<'_>
+        request: GetAccountAuditLogsRequest
     ) -> Result<Vec<models::AuditLog>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/auditLogs",
@@ -294,13 +296,13 @@ impl AccountApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/auditLogs").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_audit_logs_with_history(
         &self,
-        request: GetAccountAuditLogsWithHistoryRequest<'_>
+        request: GetAccountAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/auditLogsWithHistory",
@@ -312,8 +314,10 @@ impl AccountApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/{accountId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_bundles(
@@ -333,18 +337,18 @@ impl AccountApi {
                 &[
                     ("externalKey", request.external_key),
                     ("bundlesFilter", request.bundles_filter),
-                    ("audit", request.audit),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/bundles").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_bundles_paginated(
         &self,
-        request: GetAccountBundlesPaginatedRequest<'_>
-    ) -> Result<Vec<models::Bundle>, AccountApiError> {
+        request: GetAccountBundlesPaginatedRequest
+    ) -> Result<Page<models::Bundle>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/bundles/pagination",
             self.config.base_path,
@@ -358,12 +362,26 @@ impl AccountApi {
                 &[
                     ("offset", request.offset.map(|o| o.to_string())),
                     ("limit", request.limit.map(|l| l.to_string())),
-                    ("audit", request.audit.map(|a| a.to_string())),
                 ]
-            );
-
-        let response = req.send().await?;
-        Self::handle_response(response).await
+            )
+            .audit(request.audit);
+
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/{accountId}/bundles/pagination")
+            .await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_account_bundles_paginated_stream<'a>(
+        &'a self,
+        request: GetAccountBundlesPaginatedRequest
+    ) -> impl Stream<Item = Result<models::Bundle, AccountApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_account_bundles_paginated(request)
+        })
     }
 
     pub async fn get_account_by_key(
@@ -383,17 +401,17 @@ impl AccountApi {
                         "accountWithBalanceAndCBA",
                         request.account_with_balance_and_cba.map(|b| if b { "true" } else { "false" }).unwrap_or("false"),
                     ),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_custom_fields(
         &self,
-        request: GetAccountCustomFieldsRequest<'_>
+        request: GetAccountCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/customFields",
@@ -404,15 +422,15 @@ impl AccountApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_email_audit_logs_with_history(
         &self,
-        request: GetAccountEmailAuditLogsWithHistoryRequest<'_>
+        request: GetAccountEmailAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/emails/{}/auditLogsWithHistory",
@@ -425,13 +443,15 @@ impl AccountApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/{accountId}/emails/{accountEmailId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_tags(
         &self,
-        request: GetAccountTagsRequest<'_>
+        request: GetAccountTagsRequest
     ) -> Result<Vec<models::Tag>, AccountApiError> {
         let url = format!("{}/1.0/kb/accounts/{}/tags", self.config.base_path, request.account_id);
 
@@ -446,17 +466,17 @@ impl AccountApi {
                             .map(|b| if b { "true" } else { "false" })
                             .unwrap_or("false"),
                     ),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_account_timeline(
         &self,
-        request: GetAccountTimelineRequest<'_>
+        request: GetAccountTimelineRequest
     ) -> Result<models::AccountTimeline, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/timeline",
@@ -470,18 +490,18 @@ impl AccountApi {
             .query(
                 &[
                     ("parallel", request.parallel.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/timeline").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_accounts(
         &self,
-        request: GetAccountsRequest<'_>
-    ) -> Result<Vec<models::Account>, AccountApiError> {
+        request: GetAccountsRequest
+    ) -> Result<Page<models::Account>, AccountApiError> {
         let url = format!("{}/1.0/kb/accounts/pagination", self.config.base_path);
 
         let req = self.config.client
@@ -496,12 +516,24 @@ impl AccountApi {
                         "accountWithBalanceAndCBA",
                         request.account_with_balance_and_cba.map(|b| b.to_string()),
                     ),
-                    ("audit", request.audit.map(|l| l.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
+
+        let response = self.config.send(req, "/1.0/kb/accounts/pagination").await?;
+        self.handle_page_response(response).await
+    }
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+    pub fn get_accounts_stream<'a>(
+        &'a self,
+        request: GetAccountsRequest
+    ) -> impl Stream<Item = Result<models::Account, AccountApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_accounts(request)
+        })
     }
 
     pub async fn get_all_custom_fields(
@@ -520,12 +552,12 @@ impl AccountApi {
             .query(
                 &[
                     ("objectType", request.object_type),
-                    ("audit", request.audit),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/allCustomFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_all_tags(
@@ -550,17 +582,17 @@ impl AccountApi {
                             .map(|b| if b { "true" } else { "false" })
                             ,
                     ),
-                    ("audit", request.audit),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/allTags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_blocking_state_audit_logs_with_history(
         &self,
-        request: GetBlockingStateAuditLogsWithHistoryRequest<'_>
+        request: GetBlockingStateAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/block/{}/auditLogsWithHistory",
@@ -572,13 +604,15 @@ impl AccountApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/block/{blockingId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_blocking_states(
         &self,
-        request: GetBlockingStatesRequest<'_>
+        request: GetBlockingStatesRequest
     ) -> Result<Vec<models::BlockingState>, AccountApiError> {
         let url = format!("{}/1.0/kb/accounts/{}/block", self.config.base_path, request.account_id);
 
@@ -600,15 +634,15 @@ impl AccountApi {
                         .collect()
                 })
             )
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/block").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_children_accounts(
         &self,
-        request: GetChildrenAccountsRequest<'_>
+        request: GetChildrenAccountsRequest
     ) -> Result<Vec<models::Account>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/children",
@@ -626,17 +660,17 @@ impl AccountApi {
                         "accountWithBalanceAndCBA",
                         request.account_with_balance_and_cba.map(|b| b.to_string()),
                     ),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/children").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_emails(
         &self,
-        request: GetEmailsRequest<'_>
+        request: GetEmailsRequest
     ) -> Result<Vec<models::AccountEmail>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/emails",
@@ -648,13 +682,13 @@ impl AccountApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/emails").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_payments(
         &self,
-        request: GetInvoicePaymentsRequest<'_>
+        request: GetInvoicePaymentsRequest
     ) -> Result<Vec<models::InvoicePayment>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/invoicePayments",
@@ -669,12 +703,12 @@ impl AccountApi {
                 &[
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/invoicePayments").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoices_for_account(
@@ -708,18 +742,18 @@ impl AccountApi {
                         request.include_invoice_components.map(|b| b.to_string()),
                     ),
                     ("invoicesFilter", request.invoices_filter.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/invoices").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoices_for_account_paginated(
         &self,
-        request: GetInvoicesForAccountPaginatedRequest<'_>
-    ) -> Result<Vec<models::Invoice>, AccountApiError> {
+        request: GetInvoicesForAccountPaginatedRequest
+    ) -> Result<Page<models::Invoice>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/invoices/pagination",
             self.config.base_path,
@@ -733,17 +767,31 @@ impl AccountApi {
                 &[
                     ("offset", request.offset.map(|o| o.to_string())),
                     ("limit", request.limit.map(|l| l.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
-
-        let response = req.send().await?;
-        Self::handle_response(response).await
+            )
+            .audit(request.audit);
+
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/{accountId}/invoices/pagination")
+            .await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_invoices_for_account_paginated_stream<'a>(
+        &'a self,
+        request: GetInvoicesForAccountPaginatedRequest
+    ) -> impl Stream<Item = Result<models::Invoice, AccountApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_invoices_for_account_paginated(request)
+        })
     }
 
     pub async fn get_overdue_account(
         &self,
-        request: GetOverdueAccountRequest<'_>
+        request: GetOverdueAccountRequest
     ) -> Result<models::OverdueState, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/overdue",
@@ -755,13 +803,13 @@ impl AccountApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/overdue").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_methods_for_account(
         &self,
-        request: GetPaymentMethodsForAccountRequest<'_>
+        request: GetPaymentMethodsForAccountRequest
     ) -> Result<Vec<models::PaymentMethod>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/paymentMethods",
@@ -776,9 +824,9 @@ impl AccountApi {
                 &[
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("includedDeleted", request.included_deleted.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -788,13 +836,13 @@ impl AccountApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/paymentMethods").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payments_for_account(
         &self,
-        request: GetPaymentsForAccountRequest<'_>
+        request: GetPaymentsForAccountRequest
     ) -> Result<Vec<models::Payment>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/{}/payments",
@@ -809,9 +857,9 @@ impl AccountApi {
                 &[
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -821,8 +869,8 @@ impl AccountApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/payments").await?;
+        self.handle_response(response).await
     }
 
     pub async fn modify_account_custom_fields(
@@ -843,7 +891,7 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -862,7 +910,7 @@ impl AccountApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("paymentMethodId", request.payment_method_id),
+                    ("paymentMethodId", request.payment_method_id.map(|id| id.to_string()).as_deref()),
                     ("externalPayment", request.external_payment.map(|b| b.to_string()).as_deref()),
                     ("paymentAmount", request.payment_amount.map(|a| a.to_string()).as_deref()),
                     ("targetDate", request.target_date.as_deref()),
@@ -880,8 +928,8 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/invoicePayments").await?;
+        self.handle_response(response).await
     }
 
     pub async fn process_payment(
@@ -919,8 +967,15 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(
+                req,
+                idempotency_key,
+                "/1.0/kb/accounts/{accountId}/payments"
+            )
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn process_payment_by_external_key(
@@ -934,8 +989,8 @@ impl AccountApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("externalKey", request.external_key),
-                    ("paymentMethodId", request.payment_method_id.unwrap()),
+                    ("externalKey", Some(request.external_key)),
+                    ("paymentMethodId", request.payment_method_id.map(|id| id.to_string()).as_deref()),
                 ]
             )
             .query(
@@ -959,8 +1014,11 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/accounts/payments")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn rebalance_existing_cbaon_account(
@@ -980,7 +1038,7 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/cbaRebalancing").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -1010,7 +1068,9 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/{accountId}/paymentMethods/refresh")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -1032,14 +1092,14 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}/emails/{email}").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn search_accounts(
         &self,
         request: SearchAccountsRequest<'_>
-    ) -> Result<Vec<models::Account>, AccountApiError> {
+    ) -> Result<Page<models::Account>, AccountApiError> {
         let url = format!(
             "{}/1.0/kb/accounts/search/{}",
             self.config.base_path,
@@ -1058,12 +1118,24 @@ impl AccountApi {
                         "accountWithBalanceAndCBA",
                         request.account_with_balance_and_cba.map(|b| b.to_string()),
                     ),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/accounts/search/{searchKey}").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_accounts_stream<'a>(
+        &'a self,
+        request: SearchAccountsRequest<'a>
+    ) -> impl Stream<Item = Result<models::Account, AccountApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_accounts(request)
+        })
     }
 
     pub async fn set_default_payment_method(
@@ -1095,7 +1167,9 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/{accountId}/paymentMethods/{paymentMethodId}/setDefault")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -1116,7 +1190,9 @@ impl AccountApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/accounts/{childAccountId}/transferCredit")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -1135,13 +1211,16 @@ impl AccountApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/accounts/{accountId}").await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, AccountApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(AccountApiError::from)
@@ -1153,6 +1232,15 @@ impl AccountApi {
         }
     }
 
+    async fn handle_page_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<Page<T>, AccountApiError> {
+        let headers = response.headers().clone();
+        let items = self.handle_response(response).await?;
+        Ok(Page::new(items, &headers))
+    }
+
     async fn handle_empty_response(response: Response) -> Result<(), AccountApiError> {
         match response.status() {
             StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
@@ -1166,7 +1254,7 @@ impl AccountApi {
 
 #[derive(Debug, Clone)]
 pub struct AddAccountBlockingStateRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::BlockingState,
     pub(crate) requested_date: Option<String>,
@@ -1183,7 +1271,7 @@ impl<'a> AddAccountBlockingStateRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct AddAccountBlockingStateRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::BlockingState>,
     requested_date: Option<String>,
@@ -1193,8 +1281,8 @@ pub struct AddAccountBlockingStateRequestBuilder<'a> {
 }
 
 impl<'a> AddAccountBlockingStateRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1208,8 +1296,8 @@ impl<'a> AddAccountBlockingStateRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -1228,13 +1316,15 @@ impl<'a> AddAccountBlockingStateRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AddAccountBlockingStateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AddAccountBlockingStateRequest<'a>, AccountApiError> {
         Ok(AddAccountBlockingStateRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             requested_date: self.requested_date,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1245,7 +1335,7 @@ impl<'a> AddAccountBlockingStateRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct AddEmailRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::AccountEmail,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1260,7 +1350,7 @@ impl<'a> AddEmailRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct AddEmailRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::AccountEmail>,
     x_killbill_reason: Option<&'a str>,
@@ -1268,8 +1358,8 @@ pub struct AddEmailRequestBuilder<'a> {
 }
 
 impl<'a> AddEmailRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1293,13 +1383,15 @@ impl<'a> AddEmailRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AddEmailRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AddEmailRequest<'a>, AccountApiError> {
         Ok(AddEmailRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1308,7 +1400,7 @@ impl<'a> AddEmailRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CloseAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) cancel_all_subscriptions: Option<bool>,
     pub(crate) write_off_unpaid_invoices: Option<bool>,
@@ -1326,7 +1418,7 @@ impl<'a> CloseAccountRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CloseAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     cancel_all_subscriptions: Option<bool>,
     write_off_unpaid_invoices: Option<bool>,
@@ -1337,8 +1429,8 @@ pub struct CloseAccountRequestBuilder<'a> {
 }
 
 impl<'a> CloseAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1377,11 +1469,13 @@ impl<'a> CloseAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CloseAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CloseAccountRequest<'a>, AccountApiError> {
         Ok(CloseAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             cancel_all_subscriptions: self.cancel_all_subscriptions,
             write_off_unpaid_invoices: self.write_off_unpaid_invoices,
@@ -1436,12 +1530,12 @@ impl<'a> CreateAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateAccountRequest<'a>, AccountApiError> {
         Ok(CreateAccountRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1450,7 +1544,7 @@ impl<'a> CreateAccountRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateAccountCustomFieldsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1465,7 +1559,7 @@ impl<'a> CreateAccountCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateAccountCustomFieldsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -1473,8 +1567,8 @@ pub struct CreateAccountCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreateAccountCustomFieldsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1498,13 +1592,15 @@ impl<'a> CreateAccountCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateAccountCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateAccountCustomFieldsRequest<'a>, AccountApiError> {
         Ok(CreateAccountCustomFieldsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1513,7 +1609,7 @@ impl<'a> CreateAccountCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateAccountTagsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1528,7 +1624,7 @@ impl<'a> CreateAccountTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateAccountTagsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1536,8 +1632,8 @@ pub struct CreateAccountTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreateAccountTagsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1561,13 +1657,15 @@ impl<'a> CreateAccountTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateAccountTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateAccountTagsRequest<'a>, AccountApiError> {
         Ok(CreateAccountTagsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1576,7 +1674,7 @@ impl<'a> CreateAccountTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreatePaymentMethodRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentMethod,
     pub(crate) is_default: Option<bool>,
@@ -1595,7 +1693,7 @@ impl<'a> CreatePaymentMethodRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreatePaymentMethodRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentMethod>,
     is_default: Option<bool>,
@@ -1607,8 +1705,8 @@ pub struct CreatePaymentMethodRequestBuilder<'a> {
 }
 
 impl<'a> CreatePaymentMethodRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1652,13 +1750,15 @@ impl<'a> CreatePaymentMethodRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreatePaymentMethodRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreatePaymentMethodRequest<'a>, AccountApiError> {
         Ok(CreatePaymentMethodRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             is_default: self.is_default,
             pay_all_unpaid_invoices: self.pay_all_unpaid_invoices,
             control_plugin_name: self.control_plugin_name,
@@ -1671,7 +1771,7 @@ impl<'a> CreatePaymentMethodRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteAccountCustomFieldsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1686,7 +1786,7 @@ impl<'a> DeleteAccountCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteAccountCustomFieldsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1694,8 +1794,8 @@ pub struct DeleteAccountCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteAccountCustomFieldsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1719,11 +1819,13 @@ impl<'a> DeleteAccountCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteAccountCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteAccountCustomFieldsRequest<'a>, AccountApiError> {
         Ok(DeleteAccountCustomFieldsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -1734,7 +1836,7 @@ impl<'a> DeleteAccountCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteAccountTagsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1749,7 +1851,7 @@ impl<'a> DeleteAccountTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteAccountTagsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1757,8 +1859,8 @@ pub struct DeleteAccountTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteAccountTagsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1782,11 +1884,13 @@ impl<'a> DeleteAccountTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteAccountTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteAccountTagsRequest<'a>, AccountApiError> {
         Ok(DeleteAccountTagsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -1796,30 +1900,30 @@ impl<'a> DeleteAccountTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetAccountRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) account_with_balance: Option<bool>,
     pub(crate) account_with_balance_and_cba: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountRequest<'a> {
-    pub fn builder() -> GetAccountRequestBuilder<'a> {
+impl GetAccountRequest {
+    pub fn builder() -> GetAccountRequestBuilder {
         GetAccountRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetAccountRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     account_with_balance: Option<bool>,
     account_with_balance_and_cba: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1833,14 +1937,16 @@ impl<'a> GetAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountRequest, AccountApiError> {
         Ok(GetAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             account_with_balance: self.account_with_balance,
             account_with_balance_and_cba: self.account_with_balance_and_cba,
             audit: self.audit,
@@ -1849,69 +1955,73 @@ impl<'a> GetAccountRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountAuditLogsRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetAccountAuditLogsRequest {
+    pub(crate) account_id: uuid::Uuid,
 }
 
-impl<'a> GetAccountAuditLogsRequest<'a> {
-    pub fn builder() -> GetAccountAuditLogsRequestBuilder<'a> {
+impl GetAccountAuditLogsRequest {
+    pub fn builder() -> GetAccountAuditLogsRequestBuilder {
         GetAccountAuditLogsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountAuditLogsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetAccountAuditLogsRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetAccountAuditLogsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountAuditLogsRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetAccountAuditLogsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountAuditLogsRequest, AccountApiError> {
         Ok(GetAccountAuditLogsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountAuditLogsWithHistoryRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetAccountAuditLogsWithHistoryRequest {
+    pub(crate) account_id: uuid::Uuid,
 }
 
-impl<'a> GetAccountAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetAccountAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetAccountAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetAccountAuditLogsWithHistoryRequestBuilder {
         GetAccountAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountAuditLogsWithHistoryRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetAccountAuditLogsWithHistoryRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetAccountAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountAuditLogsWithHistoryRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetAccountAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountAuditLogsWithHistoryRequest, AccountApiError> {
         Ok(GetAccountAuditLogsWithHistoryRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct GetAccountBundlesRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) external_key: Option<&'a str>,
     pub(crate) bundles_filter: Option<&'a str>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAccountBundlesRequest<'a> {
@@ -1922,15 +2032,15 @@ impl<'a> GetAccountBundlesRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct GetAccountBundlesRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     external_key: Option<&'a str>,
     bundles_filter: Option<&'a str>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAccountBundlesRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1944,14 +2054,16 @@ impl<'a> GetAccountBundlesRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountBundlesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountBundlesRequest<'a>, AccountApiError> {
         Ok(GetAccountBundlesRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             external_key: self.external_key,
             bundles_filter: self.bundles_filter,
             audit: self.audit,
@@ -1960,30 +2072,30 @@ impl<'a> GetAccountBundlesRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountBundlesPaginatedRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetAccountBundlesPaginatedRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountBundlesPaginatedRequest<'a> {
-    pub fn builder() -> GetAccountBundlesPaginatedRequestBuilder<'a> {
+impl GetAccountBundlesPaginatedRequest {
+    pub fn builder() -> GetAccountBundlesPaginatedRequestBuilder {
         GetAccountBundlesPaginatedRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountBundlesPaginatedRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetAccountBundlesPaginatedRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountBundlesPaginatedRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountBundlesPaginatedRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1997,14 +2109,16 @@ impl<'a> GetAccountBundlesPaginatedRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountBundlesPaginatedRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountBundlesPaginatedRequest, AccountApiError> {
         Ok(GetAccountBundlesPaginatedRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             offset: self.offset,
             limit: self.limit,
             audit: self.audit,
@@ -2017,7 +2131,7 @@ pub struct GetAccountByKeyRequest<'a> {
     pub(crate) external_key: &'a str,
     pub(crate) account_with_balance: Option<bool>,
     pub(crate) account_with_balance_and_cba: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAccountByKeyRequest<'a> {
@@ -2031,7 +2145,7 @@ pub struct GetAccountByKeyRequestBuilder<'a> {
     external_key: Option<&'a str>,
     account_with_balance: Option<bool>,
     account_with_balance_and_cba: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAccountByKeyRequestBuilder<'a> {
@@ -2050,14 +2164,14 @@ impl<'a> GetAccountByKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountByKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountByKeyRequest<'a>, AccountApiError> {
         Ok(GetAccountByKeyRequest {
-            external_key: self.external_key.ok_or("external_key is required")?,
+            external_key: self.external_key.ok_or(ParamError::missing("external_key"))?,
             account_with_balance: self.account_with_balance,
             account_with_balance_and_cba: self.account_with_balance_and_cba,
             audit: self.audit,
@@ -2066,102 +2180,108 @@ impl<'a> GetAccountByKeyRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountCustomFieldsRequest<'a> {
-    pub(crate) account_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetAccountCustomFieldsRequest {
+    pub(crate) account_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountCustomFieldsRequest<'a> {
-    pub fn builder() -> GetAccountCustomFieldsRequestBuilder<'a> {
+impl GetAccountCustomFieldsRequest {
+    pub fn builder() -> GetAccountCustomFieldsRequestBuilder {
         GetAccountCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountCustomFieldsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetAccountCustomFieldsRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountCustomFieldsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountCustomFieldsRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountCustomFieldsRequest, AccountApiError> {
         Ok(GetAccountCustomFieldsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountEmailAuditLogsWithHistoryRequest<'a> {
-    pub(crate) account_id: &'a str,
-    pub(crate) account_email_id: &'a str,
+pub struct GetAccountEmailAuditLogsWithHistoryRequest {
+    pub(crate) account_id: uuid::Uuid,
+    pub(crate) account_email_id: uuid::Uuid,
 }
 
-impl<'a> GetAccountEmailAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetAccountEmailAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetAccountEmailAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetAccountEmailAuditLogsWithHistoryRequestBuilder {
         GetAccountEmailAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountEmailAuditLogsWithHistoryRequestBuilder<'a> {
-    account_id: Option<&'a str>,
-    account_email_id: Option<&'a str>,
+pub struct GetAccountEmailAuditLogsWithHistoryRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    account_email_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetAccountEmailAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountEmailAuditLogsWithHistoryRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn account_email_id(mut self, account_email_id: &'a str) -> Self {
-        self.account_email_id = Some(account_email_id);
+    pub fn account_email_id(mut self, account_email_id: impl models::IdParam) -> Self {
+        self.account_email_id = Some(account_email_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetAccountEmailAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountEmailAuditLogsWithHistoryRequest, AccountApiError> {
         Ok(GetAccountEmailAuditLogsWithHistoryRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            account_email_id: self.account_email_id.ok_or("account_email_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            account_email_id: self.account_email_id
+                .ok_or(ParamError::missing("account_email_id"))?
+                .map_err(|e| ParamError::invalid_id("account_email_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountTagsRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetAccountTagsRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountTagsRequest<'a> {
-    pub fn builder() -> GetAccountTagsRequestBuilder<'a> {
+impl GetAccountTagsRequest {
+    pub fn builder() -> GetAccountTagsRequestBuilder {
         GetAccountTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountTagsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetAccountTagsRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountTagsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountTagsRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2170,14 +2290,16 @@ impl<'a> GetAccountTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountTagsRequest, AccountApiError> {
         Ok(GetAccountTagsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             included_deleted: self.included_deleted,
             audit: self.audit,
         })
@@ -2185,28 +2307,28 @@ impl<'a> GetAccountTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountTimelineRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetAccountTimelineRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) parallel: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountTimelineRequest<'a> {
-    pub fn builder() -> GetAccountTimelineRequestBuilder<'a> {
+impl GetAccountTimelineRequest {
+    pub fn builder() -> GetAccountTimelineRequestBuilder {
         GetAccountTimelineRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountTimelineRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetAccountTimelineRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     parallel: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountTimelineRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAccountTimelineRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2215,14 +2337,16 @@ impl<'a> GetAccountTimelineRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountTimelineRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountTimelineRequest, AccountApiError> {
         Ok(GetAccountTimelineRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             parallel: self.parallel,
             audit: self.audit,
         })
@@ -2230,30 +2354,30 @@ impl<'a> GetAccountTimelineRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAccountsRequest<'a> {
+pub struct GetAccountsRequest {
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
     pub(crate) account_with_balance: Option<bool>,
     pub(crate) account_with_balance_and_cba: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountsRequest<'a> {
-    pub fn builder() -> GetAccountsRequestBuilder<'a> {
+impl GetAccountsRequest {
+    pub fn builder() -> GetAccountsRequestBuilder {
         GetAccountsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAccountsRequestBuilder<'a> {
+pub struct GetAccountsRequestBuilder {
     offset: Option<i64>,
     limit: Option<i64>,
     account_with_balance: Option<bool>,
     account_with_balance_and_cba: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetAccountsRequestBuilder<'a> {
+impl GetAccountsRequestBuilder {
     pub fn offset(mut self, offset: i64) -> Self {
         self.offset = Some(offset);
         self
@@ -2274,12 +2398,12 @@ impl<'a> GetAccountsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAccountsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAccountsRequest, AccountApiError> {
         Ok(GetAccountsRequest {
             offset: self.offset,
             limit: self.limit,
@@ -2292,9 +2416,9 @@ impl<'a> GetAccountsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct GetAllCustomFieldsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) object_type: Option<&'a str>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAllCustomFieldsRequest<'a> {
@@ -2305,14 +2429,14 @@ impl<'a> GetAllCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct GetAllCustomFieldsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     object_type: Option<&'a str>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAllCustomFieldsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2321,14 +2445,16 @@ impl<'a> GetAllCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAllCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAllCustomFieldsRequest<'a>, AccountApiError> {
         Ok(GetAllCustomFieldsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             object_type: self.object_type,
             audit: self.audit,
         })
@@ -2337,10 +2463,10 @@ impl<'a> GetAllCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct GetAllTagsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) object_type: Option<&'a str>,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAllTagsRequest<'a> {
@@ -2351,15 +2477,15 @@ impl<'a> GetAllTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct GetAllTagsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     object_type: Option<&'a str>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetAllTagsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2373,14 +2499,16 @@ impl<'a> GetAllTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetAllTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAllTagsRequest<'a>, AccountApiError> {
         Ok(GetAllTagsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             object_type: self.object_type,
             included_deleted: self.included_deleted,
             audit: self.audit,
@@ -2389,59 +2517,61 @@ impl<'a> GetAllTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetBlockingStateAuditLogsWithHistoryRequest<'a> {
-    pub(crate) blocking_id: &'a str,
+pub struct GetBlockingStateAuditLogsWithHistoryRequest {
+    pub(crate) blocking_id: uuid::Uuid,
 }
 
-impl<'a> GetBlockingStateAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetBlockingStateAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetBlockingStateAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetBlockingStateAuditLogsWithHistoryRequestBuilder {
         GetBlockingStateAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetBlockingStateAuditLogsWithHistoryRequestBuilder<'a> {
-    blocking_id: Option<&'a str>,
+pub struct GetBlockingStateAuditLogsWithHistoryRequestBuilder {
+    blocking_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetBlockingStateAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn blocking_id(mut self, blocking_id: &'a str) -> Self {
-        self.blocking_id = Some(blocking_id);
+impl GetBlockingStateAuditLogsWithHistoryRequestBuilder {
+    pub fn blocking_id(mut self, blocking_id: impl models::IdParam) -> Self {
+        self.blocking_id = Some(blocking_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetBlockingStateAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBlockingStateAuditLogsWithHistoryRequest, AccountApiError> {
         Ok(GetBlockingStateAuditLogsWithHistoryRequest {
-            blocking_id: self.blocking_id.ok_or("blocking_id is required")?,
+            blocking_id: self.blocking_id
+                .ok_or(ParamError::missing("blocking_id"))?
+                .map_err(|e| ParamError::invalid_id("blocking_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetBlockingStatesRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetBlockingStatesRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) blocking_state_types: Option<Vec<String>>,
     pub(crate) blocking_state_svcs: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBlockingStatesRequest<'a> {
-    pub fn builder() -> GetBlockingStatesRequestBuilder<'a> {
+impl GetBlockingStatesRequest {
+    pub fn builder() -> GetBlockingStatesRequestBuilder {
         GetBlockingStatesRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetBlockingStatesRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetBlockingStatesRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     blocking_state_types: Option<Vec<String>>,
     blocking_state_svcs: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBlockingStatesRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetBlockingStatesRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2455,14 +2585,16 @@ impl<'a> GetBlockingStatesRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetBlockingStatesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBlockingStatesRequest, AccountApiError> {
         Ok(GetBlockingStatesRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             blocking_state_types: self.blocking_state_types,
             blocking_state_svcs: self.blocking_state_svcs,
             audit: self.audit,
@@ -2471,30 +2603,30 @@ impl<'a> GetBlockingStatesRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetChildrenAccountsRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetChildrenAccountsRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) account_with_balance: Option<bool>,
     pub(crate) account_with_balance_and_cba: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetChildrenAccountsRequest<'a> {
-    pub fn builder() -> GetChildrenAccountsRequestBuilder<'a> {
+impl GetChildrenAccountsRequest {
+    pub fn builder() -> GetChildrenAccountsRequestBuilder {
         GetChildrenAccountsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetChildrenAccountsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetChildrenAccountsRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     account_with_balance: Option<bool>,
     account_with_balance_and_cba: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetChildrenAccountsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetChildrenAccountsRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2508,14 +2640,16 @@ impl<'a> GetChildrenAccountsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetChildrenAccountsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetChildrenAccountsRequest, AccountApiError> {
         Ok(GetChildrenAccountsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             account_with_balance: self.account_with_balance,
             account_with_balance_and_cba: self.account_with_balance_and_cba,
             audit: self.audit,
@@ -2524,61 +2658,63 @@ impl<'a> GetChildrenAccountsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetEmailsRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetEmailsRequest {
+    pub(crate) account_id: uuid::Uuid,
 }
 
-impl<'a> GetEmailsRequest<'a> {
-    pub fn builder() -> GetEmailsRequestBuilder<'a> {
+impl GetEmailsRequest {
+    pub fn builder() -> GetEmailsRequestBuilder {
         GetEmailsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetEmailsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetEmailsRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetEmailsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetEmailsRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetEmailsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetEmailsRequest, AccountApiError> {
         Ok(GetEmailsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicePaymentsRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetInvoicePaymentsRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentsRequest<'a> {
-    pub fn builder() -> GetInvoicePaymentsRequestBuilder<'a> {
+impl GetInvoicePaymentsRequest {
+    pub fn builder() -> GetInvoicePaymentsRequestBuilder {
         GetInvoicePaymentsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicePaymentsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetInvoicePaymentsRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetInvoicePaymentsRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2597,14 +2733,16 @@ impl<'a> GetInvoicePaymentsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicePaymentsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicePaymentsRequest, AccountApiError> {
         Ok(GetInvoicePaymentsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             with_plugin_info: self.with_plugin_info,
             with_attempts: self.with_attempts,
             plugin_property: self.plugin_property,
@@ -2615,7 +2753,7 @@ impl<'a> GetInvoicePaymentsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct GetInvoicesForAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) start_date: Option<String>,
     pub(crate) end_date: Option<String>,
     pub(crate) with_migration_invoices: Option<bool>,
@@ -2623,7 +2761,7 @@ pub struct GetInvoicesForAccountRequest<'a> {
     pub(crate) include_voided_invoices: Option<bool>,
     pub(crate) include_invoice_components: Option<bool>,
     pub(crate) invoices_filter: Option<&'a str>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetInvoicesForAccountRequest<'a> {
@@ -2634,7 +2772,7 @@ impl<'a> GetInvoicesForAccountRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct GetInvoicesForAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     start_date: Option<String>,
     end_date: Option<String>,
     with_migration_invoices: Option<bool>,
@@ -2642,22 +2780,22 @@ pub struct GetInvoicesForAccountRequestBuilder<'a> {
     include_voided_invoices: Option<bool>,
     include_invoice_components: Option<bool>,
     invoices_filter: Option<&'a str>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetInvoicesForAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn start_date(mut self, start_date: impl Into<String>) -> Self {
-        self.start_date = Some(start_date.into());
+    pub fn start_date(mut self, start_date: impl models::DateParam) -> Self {
+        self.start_date = Some(start_date.into_param());
         self
     }
 
-    pub fn end_date(mut self, end_date: impl Into<String>) -> Self {
-        self.end_date = Some(end_date.into());
+    pub fn end_date(mut self, end_date: impl models::DateParam) -> Self {
+        self.end_date = Some(end_date.into_param());
         self
     }
 
@@ -2686,14 +2824,16 @@ impl<'a> GetInvoicesForAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicesForAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicesForAccountRequest<'a>, AccountApiError> {
         Ok(GetInvoicesForAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             start_date: self.start_date,
             end_date: self.end_date,
             with_migration_invoices: self.with_migration_invoices,
@@ -2707,30 +2847,30 @@ impl<'a> GetInvoicesForAccountRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicesForAccountPaginatedRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetInvoicesForAccountPaginatedRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicesForAccountPaginatedRequest<'a> {
-    pub fn builder() -> GetInvoicesForAccountPaginatedRequestBuilder<'a> {
+impl GetInvoicesForAccountPaginatedRequest {
+    pub fn builder() -> GetInvoicesForAccountPaginatedRequestBuilder {
         GetInvoicesForAccountPaginatedRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicesForAccountPaginatedRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetInvoicesForAccountPaginatedRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicesForAccountPaginatedRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetInvoicesForAccountPaginatedRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2744,14 +2884,16 @@ impl<'a> GetInvoicesForAccountPaginatedRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicesForAccountPaginatedRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicesForAccountPaginatedRequest, AccountApiError> {
         Ok(GetInvoicesForAccountPaginatedRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             offset: self.offset,
             limit: self.limit,
             audit: self.audit,
@@ -2760,32 +2902,32 @@ impl<'a> GetInvoicesForAccountPaginatedRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentsForAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetPaymentsForAccountRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentsForAccountRequest<'a> {
-    pub fn builder() -> GetPaymentsForAccountRequestBuilder<'a> {
+impl GetPaymentsForAccountRequest {
+    pub fn builder() -> GetPaymentsForAccountRequestBuilder {
         GetPaymentsForAccountRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentsForAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetPaymentsForAccountRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_attempts: Option<bool>,
     with_plugin_info: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentsForAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetPaymentsForAccountRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2804,14 +2946,16 @@ impl<'a> GetPaymentsForAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentsForAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentsForAccountRequest, AccountApiError> {
         Ok(GetPaymentsForAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             with_attempts: self.with_attempts,
             with_plugin_info: self.with_plugin_info,
             plugin_property: self.plugin_property,
@@ -2822,7 +2966,7 @@ impl<'a> GetPaymentsForAccountRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyAccountCustomFieldsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -2837,7 +2981,7 @@ impl<'a> ModifyAccountCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyAccountCustomFieldsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -2845,8 +2989,8 @@ pub struct ModifyAccountCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyAccountCustomFieldsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2870,11 +3014,15 @@ impl<'a> ModifyAccountCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyAccountCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyAccountCustomFieldsRequest<'a>, AccountApiError> {
         Ok(ModifyAccountCustomFieldsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -2883,7 +3031,7 @@ impl<'a> ModifyAccountCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct RemoveEmailRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) email: &'a str,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -2898,7 +3046,7 @@ impl<'a> RemoveEmailRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct RemoveEmailRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     email: Option<&'a str>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
@@ -2906,8 +3054,8 @@ pub struct RemoveEmailRequestBuilder<'a> {
 }
 
 impl<'a> RemoveEmailRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2931,11 +3079,15 @@ impl<'a> RemoveEmailRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<RemoveEmailRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<RemoveEmailRequest<'a>, AccountApiError> {
         Ok(RemoveEmailRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            email: self.email.ok_or("email is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            email: self.email.ok_or(ParamError::missing("email"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -2949,7 +3101,7 @@ pub struct SearchAccountsRequest<'a> {
     pub(crate) limit: Option<i64>,
     pub(crate) account_with_balance: Option<bool>,
     pub(crate) account_with_balance_and_cba: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchAccountsRequest<'a> {
@@ -2965,7 +3117,7 @@ pub struct SearchAccountsRequestBuilder<'a> {
     limit: Option<i64>,
     account_with_balance: Option<bool>,
     account_with_balance_and_cba: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchAccountsRequestBuilder<'a> {
@@ -2994,14 +3146,14 @@ impl<'a> SearchAccountsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<SearchAccountsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SearchAccountsRequest<'a>, AccountApiError> {
         Ok(SearchAccountsRequest {
-            search_key: self.search_key.ok_or("search_key is required")?,
+            search_key: self.search_key.ok_or(ParamError::missing("search_key"))?,
             offset: self.offset,
             limit: self.limit,
             account_with_balance: self.account_with_balance,
@@ -3012,8 +3164,8 @@ impl<'a> SearchAccountsRequestBuilder<'a> {
 }
 #[derive(Debug, Clone)]
 pub struct SetDefaultPaymentMethodRequest<'a> {
-    pub(crate) account_id: &'a str,
-    pub(crate) payment_method_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
+    pub(crate) payment_method_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) pay_all_unpaid_invoices: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -3029,8 +3181,8 @@ impl<'a> SetDefaultPaymentMethodRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct SetDefaultPaymentMethodRequestBuilder<'a> {
-    account_id: Option<&'a str>,
-    payment_method_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     pay_all_unpaid_invoices: Option<bool>,
     plugin_property: Option<Vec<String>>,
@@ -3039,13 +3191,13 @@ pub struct SetDefaultPaymentMethodRequestBuilder<'a> {
 }
 
 impl<'a> SetDefaultPaymentMethodRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -3074,11 +3226,17 @@ impl<'a> SetDefaultPaymentMethodRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<SetDefaultPaymentMethodRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SetDefaultPaymentMethodRequest<'a>, AccountApiError> {
         Ok(SetDefaultPaymentMethodRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
             pay_all_unpaid_invoices: self.pay_all_unpaid_invoices,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -3090,7 +3248,7 @@ impl<'a> SetDefaultPaymentMethodRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct TransferChildCreditToParentRequest<'a> {
-    pub(crate) child_account_id: &'a str,
+    pub(crate) child_account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -3104,15 +3262,15 @@ impl<'a> TransferChildCreditToParentRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct TransferChildCreditToParentRequestBuilder<'a> {
-    child_account_id: Option<&'a str>,
+    child_account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> TransferChildCreditToParentRequestBuilder<'a> {
-    pub fn child_account_id(mut self, child_account_id: &'a str) -> Self {
-        self.child_account_id = Some(child_account_id);
+    pub fn child_account_id(mut self, child_account_id: impl models::IdParam) -> Self {
+        self.child_account_id = Some(child_account_id.into_id());
         self
     }
 
@@ -3131,10 +3289,14 @@ impl<'a> TransferChildCreditToParentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<TransferChildCreditToParentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<TransferChildCreditToParentRequest<'a>, AccountApiError> {
         Ok(TransferChildCreditToParentRequest {
-            child_account_id: self.child_account_id.ok_or("child_account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
+            child_account_id: self.child_account_id
+                .ok_or(ParamError::missing("child_account_id"))?
+                .map_err(|e| ParamError::invalid_id("child_account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -3143,7 +3305,7 @@ impl<'a> TransferChildCreditToParentRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct UpdateAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::Account,
     pub(crate) treat_null_as_reset: Option<bool>,
@@ -3159,7 +3321,7 @@ impl<'a> UpdateAccountRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct UpdateAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::Account>,
     treat_null_as_reset: Option<bool>,
@@ -3168,8 +3330,8 @@ pub struct UpdateAccountRequestBuilder<'a> {
 }
 
 impl<'a> UpdateAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -3198,11 +3360,15 @@ impl<'a> UpdateAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UpdateAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UpdateAccountRequest<'a>, AccountApiError> {
         Ok(UpdateAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             treat_null_as_reset: self.treat_null_as_reset,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -3212,10 +3378,10 @@ impl<'a> UpdateAccountRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ProcessPaymentRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
-    pub(crate) payment_method_id: Option<&'a str>,
+    pub(crate) payment_method_id: Option<uuid::Uuid>,
     pub(crate) control_plugin_name: Option<Vec<String>>,
     pub(crate) plugin_property: Option<Vec<String>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -3230,10 +3396,10 @@ impl<'a> ProcessPaymentRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ProcessPaymentRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     control_plugin_name: Option<Vec<String>>,
     plugin_property: Option<Vec<String>>,
     x_killbill_reason: Option<&'a str>,
@@ -3241,8 +3407,8 @@ pub struct ProcessPaymentRequestBuilder<'a> {
 }
 
 impl<'a> ProcessPaymentRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -3256,8 +3422,8 @@ impl<'a> ProcessPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -3281,12 +3447,18 @@ impl<'a> ProcessPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ProcessPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ProcessPaymentRequest<'a>, AccountApiError> {
         Ok(ProcessPaymentRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
-            payment_method_id: self.payment_method_id,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            payment_method_id: self.payment_method_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -3300,7 +3472,7 @@ pub struct ProcessPaymentByExternalKeyRequest<'a> {
     pub(crate) external_key: &'a str,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
-    pub(crate) payment_method_id: Option<&'a str>,
+    pub(crate) payment_method_id: Option<uuid::Uuid>,
     pub(crate) control_plugin_name: Option<Vec<String>>,
     pub(crate) plugin_property: Option<Vec<String>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -3318,7 +3490,7 @@ pub struct ProcessPaymentByExternalKeyRequestBuilder<'a> {
     external_key: Option<&'a str>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     control_plugin_name: Option<Vec<String>>,
     plugin_property: Option<Vec<String>>,
     x_killbill_reason: Option<&'a str>,
@@ -3341,8 +3513,8 @@ impl<'a> ProcessPaymentByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -3366,12 +3538,16 @@ impl<'a> ProcessPaymentByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ProcessPaymentByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ProcessPaymentByExternalKeyRequest<'a>, AccountApiError> {
         Ok(ProcessPaymentByExternalKeyRequest {
-            external_key: self.external_key.ok_or("external_key is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
-            payment_method_id: self.payment_method_id,
+            external_key: self.external_key.ok_or(ParamError::missing("external_key"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            payment_method_id: self.payment_method_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -3382,7 +3558,7 @@ impl<'a> ProcessPaymentByExternalKeyRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct RebalanceExistingCbaonAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -3396,15 +3572,15 @@ impl<'a> RebalanceExistingCbaonAccountRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct RebalanceExistingCbaonAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> RebalanceExistingCbaonAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -3423,10 +3599,14 @@ impl<'a> RebalanceExistingCbaonAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<RebalanceExistingCbaonAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<RebalanceExistingCbaonAccountRequest<'a>, AccountApiError> {
         Ok(RebalanceExistingCbaonAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -3435,7 +3615,7 @@ impl<'a> RebalanceExistingCbaonAccountRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct RefreshPaymentMethodsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) plugin_name: Option<&'a str>,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -3451,7 +3631,7 @@ impl<'a> RefreshPaymentMethodsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct RefreshPaymentMethodsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     plugin_name: Option<&'a str>,
     plugin_property: Option<Vec<String>>,
@@ -3460,8 +3640,8 @@ pub struct RefreshPaymentMethodsRequestBuilder<'a> {
 }
 
 impl<'a> RefreshPaymentMethodsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -3490,10 +3670,14 @@ impl<'a> RefreshPaymentMethodsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<RefreshPaymentMethodsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<RefreshPaymentMethodsRequest<'a>, AccountApiError> {
         Ok(RefreshPaymentMethodsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
             plugin_name: self.plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -3503,61 +3687,63 @@ impl<'a> RefreshPaymentMethodsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetOverdueAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetOverdueAccountRequest {
+    pub(crate) account_id: uuid::Uuid,
 }
 
-impl<'a> GetOverdueAccountRequest<'a> {
-    pub fn builder() -> GetOverdueAccountRequestBuilder<'a> {
+impl GetOverdueAccountRequest {
+    pub fn builder() -> GetOverdueAccountRequestBuilder {
         GetOverdueAccountRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetOverdueAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetOverdueAccountRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetOverdueAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetOverdueAccountRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetOverdueAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetOverdueAccountRequest, AccountApiError> {
         Ok(GetOverdueAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentMethodsForAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct GetPaymentMethodsForAccountRequest {
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) included_deleted: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentMethodsForAccountRequest<'a> {
-    pub fn builder() -> GetPaymentMethodsForAccountRequestBuilder<'a> {
+impl GetPaymentMethodsForAccountRequest {
+    pub fn builder() -> GetPaymentMethodsForAccountRequestBuilder {
         GetPaymentMethodsForAccountRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentMethodsForAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetPaymentMethodsForAccountRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_plugin_info: Option<bool>,
     included_deleted: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentMethodsForAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetPaymentMethodsForAccountRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -3576,14 +3762,16 @@ impl<'a> GetPaymentMethodsForAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentMethodsForAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentMethodsForAccountRequest, AccountApiError> {
         Ok(GetPaymentMethodsForAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             with_plugin_info: self.with_plugin_info,
             included_deleted: self.included_deleted,
             plugin_property: self.plugin_property,
@@ -3595,11 +3783,11 @@ impl<'a> GetPaymentMethodsForAccountRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct PayAllInvoicesRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
-    pub(crate) payment_method_id: Option<&'a str>,
+    pub(crate) payment_method_id: Option<uuid::Uuid>,
     pub(crate) external_payment: Option<bool>,
-    pub(crate) payment_amount: Option<f64>,
+    pub(crate) payment_amount: Option<models::Decimal>,
     pub(crate) target_date: Option<String>,
     pub(crate) plugin_property: Option<Vec<String>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -3614,11 +3802,11 @@ impl<'a> PayAllInvoicesRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct PayAllInvoicesRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     external_payment: Option<bool>,
-    payment_amount: Option<f64>,
+    payment_amount: Option<models::Decimal>,
     target_date: Option<String>,
     plugin_property: Option<Vec<String>>,
     x_killbill_reason: Option<&'a str>,
@@ -3626,8 +3814,8 @@ pub struct PayAllInvoicesRequestBuilder<'a> {
 }
 
 impl<'a> PayAllInvoicesRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -3636,8 +3824,8 @@ impl<'a> PayAllInvoicesRequestBuilder<'a> {
         self
     }
 
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -3646,13 +3834,13 @@ impl<'a> PayAllInvoicesRequestBuilder<'a> {
         self
     }
 
-    pub fn payment_amount(mut self, payment_amount: f64) -> Self {
+    pub fn payment_amount(mut self, payment_amount: models::Decimal) -> Self {
         self.payment_amount = Some(payment_amount);
         self
     }
 
-    pub fn target_date(mut self, target_date: impl Into<String>) -> Self {
-        self.target_date = Some(target_date.into());
+    pub fn target_date(mut self, target_date: impl models::DateParam) -> Self {
+        self.target_date = Some(target_date.into_param());
         self
     }
 
@@ -3671,11 +3859,17 @@ impl<'a> PayAllInvoicesRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<PayAllInvoicesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<PayAllInvoicesRequest<'a>, AccountApiError> {
         Ok(PayAllInvoicesRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            payment_method_id: self.payment_method_id,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            payment_method_id: self.payment_method_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             external_payment: self.external_payment,
             payment_amount: self.payment_amount,
             target_date: self.target_date,
@@ -3701,7 +3895,10 @@ pub enum AccountApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl AccountApiError {
@@ -3734,7 +3931,7 @@ mod tests {
     async fn test_add_account_blocking_state() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/test-account-id/block")
+            .mock("POST", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/block")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"state_name": "test-state"}]"#)
@@ -3744,7 +3941,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = AddAccountBlockingStateRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(BlockingState {
                 ..Default::default()
@@ -3762,7 +3959,7 @@ mod tests {
     async fn test_add_email() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/test-account-id/emails")
+            .mock("POST", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/emails")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"email": "test@test.com"}]"#)
@@ -3772,7 +3969,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = AddEmailRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(AccountEmail {
                 ..Default::default()
@@ -3790,7 +3987,7 @@ mod tests {
     async fn test_close_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/accounts/test-account-id")
+            .mock("DELETE", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(204)
             .create_async().await;
 
@@ -3798,7 +3995,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = CloseAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3816,7 +4013,7 @@ mod tests {
             .mock("POST", "/1.0/kb/accounts")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"account_id": "test-account-id"}"#)
+            .with_body(r#"{"account_id": "527a22fb-a40b-548b-ad48-290cf05ddc8c"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3836,11 +4033,124 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_create_account_follows_location() {
+        let mut server = Server::new_async().await;
+        let account_id = "4b3c5a0e-1d8f-4b3a-9a51-5a0b2ea3b9d1";
+        let create_mock = server
+            .mock("POST", "/1.0/kb/accounts")
+            .with_status(201)
+            .with_header("location", &format!("{}/1.0/kb/accounts/{}", server.url(), account_id))
+            .create_async().await;
+        let get_mock = server
+            .mock("GET", format!("/1.0/kb/accounts/{}", account_id).as_str())
+            .match_header("X-Killbill-ApiKey", "bob")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"accountId": "{}", "name": "John Doe"}}"#, account_id))
+            .create_async().await;
+
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .tenant("bob", "lazar")
+            .build()
+            .unwrap();
+
+        let api = AccountApi::new(config);
+        let request = CreateAccountRequest::builder()
+            .x_killbill_created_by("test")
+            .body(Account {
+                name: Some("John Doe".to_owned()),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let account = api.create_account(request).await.unwrap();
+        assert_eq!(account.account_id, Some(Uuid::parse_str(account_id).unwrap()));
+        assert_eq!(account.name.as_deref(), Some("John Doe"));
+
+        create_mock.assert_async().await;
+        get_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_account_follows_location_under_prefixed_base_path() {
+        let mut server = Server::new_async().await;
+        let account_id = "4b3c5a0e-1d8f-4b3a-9a51-5a0b2ea3b9d1";
+        let create_mock = server
+            .mock("POST", "/killbill/1.0/kb/accounts")
+            .with_status(201)
+            .with_header("location", &format!("/1.0/kb/accounts/{}", account_id))
+            .with_chunked_body(|_| Ok(()))
+            .create_async().await;
+        let get_mock = server
+            .mock("GET", format!("/killbill/1.0/kb/accounts/{}", account_id).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"accountId": "{}", "name": "John Doe"}}"#, account_id))
+            .create_async().await;
+
+        let config = Configuration::builder()
+            .base_path(format!("{}/killbill", server.url()))
+            .build()
+            .unwrap();
+
+        let api = AccountApi::new(config);
+        let request = CreateAccountRequest::builder()
+            .x_killbill_created_by("test")
+            .body(Account {
+                name: Some("John Doe".to_owned()),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let account = api.create_account(request).await.unwrap();
+        assert_eq!(account.account_id, Some(Uuid::parse_str(account_id).unwrap()));
+
+        create_mock.assert_async().await;
+        get_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_account_without_follow_location() {
+        let mut server = Server::new_async().await;
+        let create_mock = server
+            .mock("POST", "/1.0/kb/accounts")
+            .with_status(201)
+            .with_header("location", "/1.0/kb/accounts/4b3c5a0e-1d8f-4b3a-9a51-5a0b2ea3b9d1")
+            .create_async().await;
+        let get_mock = server
+            .mock("GET", mockito::Matcher::Any)
+            .expect(0)
+            .create_async().await;
+
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .follow_location(false)
+            .build()
+            .unwrap();
+
+        let api = AccountApi::new(config);
+        let request = CreateAccountRequest::builder()
+            .x_killbill_created_by("test")
+            .body(Account::default())
+            .build()
+            .unwrap();
+
+        let result = api.create_account(request).await;
+        assert!(matches!(result, Err(AccountApiError::RequestFailed(_))));
+
+        create_mock.assert_async().await;
+        get_mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_create_account_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/test-account-id/customFields")
+            .mock("POST", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -3850,7 +4160,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = CreateAccountCustomFieldsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -3870,7 +4180,7 @@ mod tests {
     async fn test_create_account_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/test-account-id/tags")
+            .mock("POST", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -3880,7 +4190,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = CreateAccountTagsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -3896,17 +4206,17 @@ mod tests {
     async fn test_create_payment_method() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/test-account-id/paymentMethods")
+            .mock("POST", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/paymentMethods")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"payment_method_id": "test-payment-method-id"}"#)
+            .with_body(r#"{"payment_method_id": "b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = AccountApi::new(config);
         let request = CreatePaymentMethodRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(PaymentMethod {
                 ..Default::default()
@@ -3924,7 +4234,7 @@ mod tests {
     async fn test_delete_account_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/accounts/test-account-id/customFields")
+            .mock("DELETE", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -3932,7 +4242,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = DeleteAccountCustomFieldsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3947,7 +4257,7 @@ mod tests {
     async fn test_delete_account_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/accounts/test-account-id/tags")
+            .mock("DELETE", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/tags")
             .with_status(204)
             .create_async().await;
 
@@ -3955,7 +4265,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = DeleteAccountTagsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3970,16 +4280,66 @@ mod tests {
     async fn test_get_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"account_id": "527a22fb-a40b-548b-ad48-290cf05ddc8c"}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = AccountApi::new(config);
+        let request = GetAccountRequest::builder().account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c").build().unwrap();
+
+        let result = api.get_account(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_account_id_is_validated() {
+        let result = GetAccountRequest::builder().account_id("not-a-uuid").build();
+        assert!(
+            matches!(
+                result,
+                Err(AccountApiError::ValidationError(ParamError::InvalidId { field: "account_id", ref value }))
+                    if value == "not-a-uuid"
+            )
+        );
+
+        let error = crate::apis::KillBillError::from(result.unwrap_err());
+        assert!(
+            matches!(
+                error,
+                crate::apis::KillBillError::ValidationError(ParamError::InvalidId { field: "account_id", .. })
+            )
+        );
+
+        let account_id = uuid::Uuid::new_v4();
+        let request = GetAccountRequest::builder().account_id(account_id).build().unwrap();
+        assert_eq!(request.account_id, account_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_account_with_audit_level() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c")
+            .match_query(mockito::Matcher::UrlEncoded("audit".into(), "FULL".into()))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"account_id": "test-account-id"}"#)
+            .with_body(serde_json::to_string(&Account::default()).unwrap())
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = AccountApi::new(config);
-        let request = GetAccountRequest::builder().account_id("test-account-id").build().unwrap();
+        let request = GetAccountRequest::builder()
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
+            .audit(models::AuditLevel::Full)
+            .build()
+            .unwrap();
 
         let result = api.get_account(request).await;
         assert!(result.is_ok());
@@ -3987,11 +4347,40 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_get_account_by_key_without_audit_level() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/accounts")
+            .match_query(
+                mockito::Matcher::Exact(
+                    "externalKey=test-external-key&accountWithBalance=false&accountWithBalanceAndCBA=false".into()
+                )
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&Account::default()).unwrap())
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = AccountApi::new(config);
+        let request = GetAccountByKeyRequest::builder()
+            .external_key("test-external-key")
+            .build()
+            .unwrap();
+
+        let result = api.get_account_by_key(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_get_account_audit_logs() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/auditLogs")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/auditLogs")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -4001,7 +4390,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAccountAuditLogsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4015,7 +4404,7 @@ mod tests {
     async fn test_get_account_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -4025,7 +4414,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAccountAuditLogsWithHistoryRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4039,7 +4428,7 @@ mod tests {
     async fn test_get_account_bundles() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/bundles")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/bundles")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"bundle_id": "test-bundle-id"}]"#)
@@ -4049,7 +4438,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAccountBundlesRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4063,7 +4452,7 @@ mod tests {
     async fn test_get_account_bundles_paginated() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/bundles/pagination")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/bundles/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"bundle_id": "test-bundle-id"}]"#)
@@ -4073,7 +4462,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAccountBundlesPaginatedRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4090,7 +4479,7 @@ mod tests {
             .mock("GET", "/1.0/kb/accounts?externalKey=test-external-key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"account_id": "test-account-id"}"#)
+            .with_body(r#"{"account_id": "527a22fb-a40b-548b-ad48-290cf05ddc8c"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -4111,7 +4500,7 @@ mod tests {
     async fn test_get_account_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/customFields")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -4121,7 +4510,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAccountCustomFieldsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4137,7 +4526,7 @@ mod tests {
         let mock = server
             .mock(
                 "GET",
-                "/1.0/kb/accounts/test-account-id/emails/test-account-email-id/auditLogsWithHistory"
+                "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/emails/14306a2b-dd04-5d95-82c6-420066b31e9e/auditLogsWithHistory"
             )
             .with_status(200)
             .with_header("content-type", "application/json")
@@ -4148,8 +4537,8 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAccountEmailAuditLogsWithHistoryRequest::builder()
-            .account_id("test-account-id")
-            .account_email_id("test-account-email-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
+            .account_email_id("14306a2b-dd04-5d95-82c6-420066b31e9e")
             .build()
             .unwrap();
 
@@ -4163,7 +4552,7 @@ mod tests {
     async fn test_get_account_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/tags")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -4173,7 +4562,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAccountTagsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4187,17 +4576,17 @@ mod tests {
     async fn test_get_account_timeline() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/timeline")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/timeline")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"account_id": "test-account-id"}"#)
+            .with_body(r#"{"account_id": "527a22fb-a40b-548b-ad48-290cf05ddc8c"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = AccountApi::new(config);
         let request = GetAccountTimelineRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4214,7 +4603,7 @@ mod tests {
             .mock("GET", "/1.0/kb/accounts/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"account_id": "test-account-id"}]"#)
+            .with_body(r#"[{"account_id": "527a22fb-a40b-548b-ad48-290cf05ddc8c"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -4228,11 +4617,97 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_get_accounts_pagination_headers() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/accounts/pagination")
+            .match_query(mockito::Matcher::UrlEncoded("limit".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("X-Killbill-Pagination-CurrentOffset", "0")
+            .with_header("X-Killbill-Pagination-NextOffset", "1")
+            .with_header("X-Killbill-Pagination-TotalNbRecords", "2")
+            .with_header("X-Killbill-Pagination-MaxNbRecords", "2")
+            .with_header(
+                "X-Killbill-Pagination-NextPageUri",
+                "/1.0/kb/accounts/pagination?offset=1&limit=1"
+            )
+            .with_body(r#"[{"name": "first"}]"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = AccountApi::new(config);
+        let request = GetAccountsRequest::builder().limit(1).build().unwrap();
+
+        let page = api.get_accounts(request).await.unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.current_offset, Some(0));
+        assert_eq!(page.next_offset, Some(1));
+        assert_eq!(page.total_nb_records, Some(2));
+        assert_eq!(page.max_nb_records, Some(2));
+        assert!(page.has_next_page());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_accounts_stream() {
+        use futures::TryStreamExt;
+
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/1.0/kb/accounts/pagination")
+            .match_query(
+                mockito::Matcher::AllOf(
+                    vec![
+                        mockito::Matcher::UrlEncoded("offset".into(), "0".into()),
+                        mockito::Matcher::UrlEncoded("limit".into(), "2".into())
+                    ]
+                )
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("X-Killbill-Pagination-NextOffset", "2")
+            .with_body(r#"[{"name": "a"}, {"name": "b"}]"#)
+            .create_async().await;
+        let second = server
+            .mock("GET", "/1.0/kb/accounts/pagination")
+            .match_query(
+                mockito::Matcher::AllOf(
+                    vec![
+                        mockito::Matcher::UrlEncoded("offset".into(), "2".into()),
+                        mockito::Matcher::UrlEncoded("limit".into(), "2".into())
+                    ]
+                )
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"name": "c"}]"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = AccountApi::new(config);
+        let request = GetAccountsRequest::builder().limit(2).build().unwrap();
+
+        let accounts: Vec<Account> = api.get_accounts_stream(request).try_collect().await.unwrap();
+        let names: Vec<_> = accounts
+            .iter()
+            .filter_map(|a| a.name.as_deref())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_get_all_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/allCustomFields")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/allCustomFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -4242,7 +4717,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetAllCustomFieldsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4256,7 +4731,7 @@ mod tests {
     async fn test_get_all_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/allTags")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/allTags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -4265,7 +4740,7 @@ mod tests {
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = AccountApi::new(config);
-        let request = GetAllTagsRequest::builder().account_id("test-account-id").build().unwrap();
+        let request = GetAllTagsRequest::builder().account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c").build().unwrap();
 
         let result = api.get_all_tags(request).await;
         assert!(result.is_ok());
@@ -4277,7 +4752,7 @@ mod tests {
     async fn test_get_blocking_state_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/block/test-blocking-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/accounts/block/bd107b7e-51f5-5362-adcd-330346897ff4/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -4287,7 +4762,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetBlockingStateAuditLogsWithHistoryRequest::builder()
-            .blocking_id("test-blocking-id")
+            .blocking_id("bd107b7e-51f5-5362-adcd-330346897ff4")
             .build()
             .unwrap();
 
@@ -4301,7 +4776,7 @@ mod tests {
     async fn test_get_blocking_states() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/block")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/block")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"state_name": "test-state"}]"#)
@@ -4311,7 +4786,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetBlockingStatesRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4325,17 +4800,17 @@ mod tests {
     async fn test_get_children_accounts() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/children")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/children")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"account_id": "test-account-id"}]"#)
+            .with_body(r#"[{"account_id": "527a22fb-a40b-548b-ad48-290cf05ddc8c"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = AccountApi::new(config);
         let request = GetChildrenAccountsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4349,7 +4824,7 @@ mod tests {
     async fn test_get_emails() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/emails")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/emails")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"email": "test@test.com"}]"#)
@@ -4358,7 +4833,7 @@ mod tests {
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = AccountApi::new(config);
-        let request = GetEmailsRequest::builder().account_id("test-account-id").build().unwrap();
+        let request = GetEmailsRequest::builder().account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c").build().unwrap();
 
         let result = api.get_emails(request).await;
         assert!(result.is_ok());
@@ -4370,7 +4845,7 @@ mod tests {
     async fn test_get_invoice_payments() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/invoicePayments")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/invoicePayments")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"payment_id": "test-payment-id"}]"#)
@@ -4380,7 +4855,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetInvoicePaymentsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4394,7 +4869,7 @@ mod tests {
     async fn test_get_invoices_for_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/invoices")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/invoices")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"invoice_id": "test-invoice-id"}]"#)
@@ -4404,7 +4879,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetInvoicesForAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4418,7 +4893,7 @@ mod tests {
     async fn test_get_invoices_for_account_paginated() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/invoices/pagination")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/invoices/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"invoice_id": "test-invoice-id"}]"#)
@@ -4428,7 +4903,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetInvoicesForAccountPaginatedRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4442,7 +4917,7 @@ mod tests {
     async fn test_get_overdue_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/overdue")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/overdue")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"state_name": "test-state"}"#)
@@ -4452,7 +4927,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetOverdueAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4466,17 +4941,17 @@ mod tests {
     async fn test_get_payment_methods_for_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/paymentMethods")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/paymentMethods")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"payment_method_id": "test-payment-method-id"}]"#)
+            .with_body(r#"[{"payment_method_id": "b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = AccountApi::new(config);
         let request = GetPaymentMethodsForAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4490,7 +4965,7 @@ mod tests {
     async fn test_get_payments_for_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/accounts/test-account-id/payments")
+            .mock("GET", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/payments")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"payment_id": "test-payment-id"}]"#)
@@ -4500,7 +4975,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = GetPaymentsForAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -4514,7 +4989,7 @@ mod tests {
     async fn test_modify_account_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/accounts/test-account-id/customFields")
+            .mock("PUT", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -4522,7 +4997,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = ModifyAccountCustomFieldsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -4542,7 +5017,7 @@ mod tests {
     async fn test_pay_all_invoices() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/test-account-id/invoicePayments")
+            .mock("POST", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/invoicePayments")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"invoice_id": "test-invoice-id"}]"#)
@@ -4552,7 +5027,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = PayAllInvoicesRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -4567,7 +5042,7 @@ mod tests {
     async fn test_process_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/test-account-id/payments")
+            .mock("POST", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/payments")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"payment_id": "test-payment-id"}"#)
@@ -4577,7 +5052,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = ProcessPaymentRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(models::PaymentTransaction {
                 ..Default::default()
@@ -4595,7 +5070,19 @@ mod tests {
     async fn test_process_payment_by_external_key() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/accounts/payments?externalKey=test-external-key")
+            .mock("POST", "/1.0/kb/accounts/payments")
+            .match_query(mockito::Matcher::Exact("externalKey=test-external-key".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"payment_id": "test-payment-id"}"#)
+            .create_async().await;
+        let with_payment_method = server
+            .mock("POST", "/1.0/kb/accounts/payments")
+            .match_query(
+                mockito::Matcher::Exact(
+                    "externalKey=test-external-key&paymentMethodId=b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7".into()
+                )
+            )
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"payment_id": "test-payment-id"}"#)
@@ -4616,14 +5103,28 @@ mod tests {
         let result = api.process_payment_by_external_key(request).await;
         assert!(result.is_ok());
 
+        let request = ProcessPaymentByExternalKeyRequest::builder()
+            .external_key("test-external-key")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
+            .x_killbill_created_by("test")
+            .body(models::PaymentTransaction {
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let result = api.process_payment_by_external_key(request).await;
+        assert!(result.is_ok());
+
         mock.assert_async().await;
+        with_payment_method.assert_async().await;
     }
 
     #[tokio::test]
     async fn test_rebalance_existing_cbaon_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/accounts/test-account-id/cbaRebalancing")
+            .mock("PUT", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/cbaRebalancing")
             .with_status(204)
             .create_async().await;
 
@@ -4631,7 +5132,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = RebalanceExistingCbaonAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -4646,7 +5147,7 @@ mod tests {
     async fn test_refresh_payment_methods() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/accounts/test-account-id/paymentMethods/refresh")
+            .mock("PUT", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/paymentMethods/refresh")
             .with_status(204)
             .create_async().await;
 
@@ -4654,7 +5155,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = RefreshPaymentMethodsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -4669,7 +5170,7 @@ mod tests {
     async fn test_remove_email() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/accounts/test-account-id/emails/test@test.com")
+            .mock("DELETE", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/emails/test@test.com")
             .with_status(204)
             .create_async().await;
 
@@ -4677,7 +5178,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = RemoveEmailRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .email("test@test.com")
             .x_killbill_created_by("test")
             .build()
@@ -4696,7 +5197,7 @@ mod tests {
             .mock("GET", "/1.0/kb/accounts/search/test-search-key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"account_id": "test-account-id"}]"#)
+            .with_body(r#"[{"account_id": "527a22fb-a40b-548b-ad48-290cf05ddc8c"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -4719,7 +5220,7 @@ mod tests {
         let mock = server
             .mock(
                 "PUT",
-                "/1.0/kb/accounts/test-account-id/paymentMethods/test-payment-method-id/setDefault"
+                "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7/setDefault"
             )
             .with_status(204)
             .create_async().await;
@@ -4728,8 +5229,8 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = SetDefaultPaymentMethodRequest::builder()
-            .payment_method_id("test-payment-method-id")
-            .account_id("test-account-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -4744,7 +5245,7 @@ mod tests {
     async fn test_transfer_child_credit_to_parent() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/accounts/test-child-account-id/transferCredit")
+            .mock("PUT", "/1.0/kb/accounts/19c3eb6c-b140-523e-ab51-68422bc2f6a7/transferCredit")
             .with_status(204)
             .create_async().await;
 
@@ -4752,7 +5253,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = TransferChildCreditToParentRequest::builder()
-            .child_account_id("test-child-account-id")
+            .child_account_id("19c3eb6c-b140-523e-ab51-68422bc2f6a7")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -4767,7 +5268,7 @@ mod tests {
     async fn test_update_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/accounts/test-account-id")
+            .mock("PUT", "/1.0/kb/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(204)
             .create_async().await;
 
@@ -4775,7 +5276,7 @@ mod tests {
 
         let api = AccountApi::new(config);
         let request = UpdateAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(Account {
                 ..Default::default()
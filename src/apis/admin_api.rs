@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct AdminApi {
@@ -23,19 +24,19 @@ impl AdminApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("accountId", request.account_id),
+                    ("accountId", request.account_id.map(|id| id.to_string()).as_deref()),
                     ("queueName", request.queue_name),
                     ("serviceName", request.service_name),
                     ("withHistory", request.with_history.map(|b| if b { "true" } else { "false" })),
-                    ("minDate", request.min_date),
-                    ("maxDate", request.max_date),
+                    ("minDate", request.min_date.as_deref()),
+                    ("maxDate", request.max_date.as_deref()),
                     ("withInProcessing", request.with_in_processing.map(|b| if b { "true" } else { "false" })),
                     ("withBusEvents", request.with_bus_events.map(|b| b.to_string()).as_deref()),
                     ("withNotifications", request.with_notifications.map(|b| if b { "true" } else { "false" })),
                 ]
             );
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/admin/queues").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -50,13 +51,13 @@ impl AdminApi {
             .headers(self.config.get_auth_headers())
             .query(&[("cacheName", request.cache_name)]);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/admin/cache").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn invalidates_cache_by_account(
         &self,
-        request: InvalidatesCacheByAccountRequest<'_>
+        request: InvalidatesCacheByAccountRequest
     ) -> Result<(), AdminApiError> {
         let url = format!(
             "{}/1.0/kb/admin/cache/accounts/{}",
@@ -68,7 +69,7 @@ impl AdminApi {
             .request(Method::DELETE, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/admin/cache/accounts/{accountId}").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -79,7 +80,7 @@ impl AdminApi {
             .request(Method::DELETE, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/admin/cache/tenants").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -90,7 +91,7 @@ impl AdminApi {
             .request(Method::PUT, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/admin/healthcheck").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -101,7 +102,7 @@ impl AdminApi {
             .request(Method::DELETE, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/admin/healthcheck").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -132,7 +133,7 @@ impl AdminApi {
                 })
             );
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/admin/invoices").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -155,11 +156,18 @@ impl AdminApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/admin/payments/{paymentId}/transactions/{paymentTransactionId}")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
-    async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T, AdminApiError> {
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, AdminApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(AdminApiError::from)
@@ -184,12 +192,12 @@ impl AdminApi {
 
 #[derive(Debug, Clone)]
 pub struct GetQueueEntriesRequest<'a> {
-    pub(crate) account_id: Option<&'a str>,
+    pub(crate) account_id: Option<uuid::Uuid>,
     pub(crate) queue_name: Option<&'a str>,
     pub(crate) service_name: Option<&'a str>,
     pub(crate) with_history: Option<bool>,
-    pub(crate) min_date: Option<&'a str>,
-    pub(crate) max_date: Option<&'a str>,
+    pub(crate) min_date: Option<String>,
+    pub(crate) max_date: Option<String>,
     pub(crate) with_in_processing: Option<bool>,
     pub(crate) with_bus_events: Option<bool>,
     pub(crate) with_notifications: Option<bool>,
@@ -203,20 +211,20 @@ impl<'a> GetQueueEntriesRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct GetQueueEntriesRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     queue_name: Option<&'a str>,
     service_name: Option<&'a str>,
     with_history: Option<bool>,
-    min_date: Option<&'a str>,
-    max_date: Option<&'a str>,
+    min_date: Option<String>,
+    max_date: Option<String>,
     with_in_processing: Option<bool>,
     with_bus_events: Option<bool>,
     with_notifications: Option<bool>,
 }
 
 impl<'a> GetQueueEntriesRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -235,13 +243,13 @@ impl<'a> GetQueueEntriesRequestBuilder<'a> {
         self
     }
 
-    pub fn min_date(mut self, min_date: &'a str) -> Self {
-        self.min_date = Some(min_date);
+    pub fn min_date(mut self, min_date: impl models::DateParam) -> Self {
+        self.min_date = Some(min_date.into_param());
         self
     }
 
-    pub fn max_date(mut self, max_date: &'a str) -> Self {
-        self.max_date = Some(max_date);
+    pub fn max_date(mut self, max_date: impl models::DateParam) -> Self {
+        self.max_date = Some(max_date.into_param());
         self
     }
 
@@ -260,9 +268,11 @@ impl<'a> GetQueueEntriesRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetQueueEntriesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetQueueEntriesRequest<'a>, AdminApiError> {
         Ok(GetQueueEntriesRequest {
-            account_id: self.account_id,
+            account_id: self.account_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             queue_name: self.queue_name,
             service_name: self.service_name,
             with_history: self.with_history,
@@ -297,7 +307,7 @@ impl<'a> InvalidatesCacheRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<InvalidatesCacheRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<InvalidatesCacheRequest<'a>, AdminApiError> {
         Ok(InvalidatesCacheRequest {
             cache_name: self.cache_name,
         })
@@ -305,30 +315,32 @@ impl<'a> InvalidatesCacheRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct InvalidatesCacheByAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+pub struct InvalidatesCacheByAccountRequest {
+    pub(crate) account_id: uuid::Uuid,
 }
 
-impl<'a> InvalidatesCacheByAccountRequest<'a> {
-    pub fn builder() -> InvalidatesCacheByAccountRequestBuilder<'a> {
+impl InvalidatesCacheByAccountRequest {
+    pub fn builder() -> InvalidatesCacheByAccountRequestBuilder {
         InvalidatesCacheByAccountRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct InvalidatesCacheByAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct InvalidatesCacheByAccountRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> InvalidatesCacheByAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl InvalidatesCacheByAccountRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<InvalidatesCacheByAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<InvalidatesCacheByAccountRequest, AdminApiError> {
         Ok(InvalidatesCacheByAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
@@ -392,10 +404,10 @@ impl<'a> TriggerInvoiceGenerationForParkedAccountsRequestBuilder<'a> {
 
     pub fn build(
         self
-    ) -> Result<TriggerInvoiceGenerationForParkedAccountsRequest<'a>, &'static str> {
+    ) -> Result<TriggerInvoiceGenerationForParkedAccountsRequest<'a>, AdminApiError> {
         Ok(TriggerInvoiceGenerationForParkedAccountsRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             offset: self.offset,
             limit: self.limit,
@@ -408,8 +420,8 @@ impl<'a> TriggerInvoiceGenerationForParkedAccountsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct UpdatePaymentTransactionStateRequest<'a> {
-    pub(crate) payment_id: &'a str,
-    pub(crate) payment_transaction_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
+    pub(crate) payment_transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::AdminPayment,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -424,8 +436,8 @@ impl<'a> UpdatePaymentTransactionStateRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct UpdatePaymentTransactionStateRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
-    payment_transaction_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    payment_transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::AdminPayment>,
     x_killbill_reason: Option<&'a str>,
@@ -433,13 +445,13 @@ pub struct UpdatePaymentTransactionStateRequestBuilder<'a> {
 }
 
 impl<'a> UpdatePaymentTransactionStateRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
-    pub fn payment_transaction_id(mut self, payment_transaction_id: &'a str) -> Self {
-        self.payment_transaction_id = Some(payment_transaction_id);
+    pub fn payment_transaction_id(mut self, payment_transaction_id: impl models::IdParam) -> Self {
+        self.payment_transaction_id = Some(payment_transaction_id.into_id());
         self
     }
 
@@ -463,16 +475,18 @@ impl<'a> UpdatePaymentTransactionStateRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UpdatePaymentTransactionStateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UpdatePaymentTransactionStateRequest<'a>, AdminApiError> {
         Ok(UpdatePaymentTransactionStateRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
-            payment_transaction_id: self.payment_transaction_id.ok_or(
-                "payment_transaction_id is required"
-            )?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
+            payment_transaction_id: self.payment_transaction_id
+                .ok_or(ParamError::missing("payment_transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -492,7 +506,10 @@ pub enum AdminApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl AdminApiError {
@@ -546,7 +563,7 @@ mod tests {
     async fn test_invalidates_cache_by_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/admin/cache/accounts/test-account-id")
+            .mock("DELETE", "/1.0/kb/admin/cache/accounts/527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(204)
             .create_async().await;
 
@@ -554,7 +571,7 @@ mod tests {
 
         let api = AdminApi::new(config);
         let request = InvalidatesCacheByAccountRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -643,7 +660,7 @@ mod tests {
         let mock = server
             .mock(
                 "PUT",
-                "/1.0/kb/admin/payments/test-payment-id/transactions/test-payment-transaction-id"
+                "/1.0/kb/admin/payments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/transactions/755e62eb-6b9c-5e10-8b6b-e81f602f98c9"
             )
             .with_status(204)
             .create_async().await;
@@ -652,8 +669,8 @@ mod tests {
 
         let api = AdminApi::new(config);
         let request = UpdatePaymentTransactionStateRequest::builder()
-            .payment_id("test-payment-id")
-            .payment_transaction_id("test-payment-transaction-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
+            .payment_transaction_id("755e62eb-6b9c-5e10-8b6b-e81f602f98c9")
             .x_killbill_created_by("test")
             .body(AdminPayment {
                 ..Default::default()
@@ -0,0 +1,485 @@
+use reqwest::{ Method, Response, StatusCode };
+use serde::{ Deserialize, de::DeserializeOwned };
+use crate::{ apis::{ configuration::Configuration, urlencode }, models };
+use crate::apis::error::ParamError;
+use thiserror::Error;
+
+/// Client for the routes of the killbill-analytics plugin
+pub struct AnalyticsApi {
+    config: Configuration,
+}
+
+impl AnalyticsApi {
+    pub fn new(config: Configuration) -> Self {
+        Self { config }
+    }
+
+    pub async fn get_business_snapshot(
+        &self,
+        request: GetBusinessSnapshotRequest
+    ) -> Result<models::BusinessSnapshot, AnalyticsApiError> {
+        let url = format!(
+            "{}/plugins/killbill-analytics/{}",
+            self.config.base_path,
+            request.account_id
+        );
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config.send(req, "/plugins/killbill-analytics/{accountId}").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn refresh_business_snapshot(
+        &self,
+        request: RefreshBusinessSnapshotRequest<'_>
+    ) -> Result<(), AnalyticsApiError> {
+        let url = format!(
+            "{}/plugins/killbill-analytics/{}",
+            self.config.base_path,
+            request.account_id
+        );
+
+        let req = self.config.client
+            .request(Method::PUT, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+
+        let response = self.config.send(req, "/plugins/killbill-analytics/{accountId}").await?;
+        Self::handle_empty_response(response).await
+    }
+
+    pub async fn get_report_configurations(
+        &self
+    ) -> Result<Vec<models::ReportConfiguration>, AnalyticsApiError> {
+        let url = format!("{}/plugins/killbill-analytics/reports", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config.send(req, "/plugins/killbill-analytics/reports").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_reports(
+        &self,
+        request: GetReportsRequest<'_>
+    ) -> Result<Vec<models::Report>, AnalyticsApiError> {
+        let url = format!("{}/plugins/killbill-analytics/reports", self.config.base_path);
+
+        let mut query: Vec<(&str, &str)> = request.names
+            .iter()
+            .map(|name| ("name", *name))
+            .collect();
+        query.push(("format", "json"));
+        if let Some(start_date) = &request.start_date {
+            query.push(("startDate", start_date));
+        }
+        if let Some(end_date) = &request.end_date {
+            query.push(("endDate", end_date));
+        }
+        if let Some(smooth) = request.smooth {
+            query.push(("smooth", smooth));
+        }
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers())
+            .query(&query);
+
+        let response = self.config.send(req, "/plugins/killbill-analytics/reports").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn refresh_report(
+        &self,
+        request: RefreshReportRequest<'_>
+    ) -> Result<(), AnalyticsApiError> {
+        let url = format!(
+            "{}/plugins/killbill-analytics/reports/{}",
+            self.config.base_path,
+            urlencode(request.report_name)
+        );
+
+        let req = self.config.client
+            .request(Method::PUT, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
+            .query(&[("shouldRefresh", "true")]);
+
+        let response = self.config
+            .send(req, "/plugins/killbill-analytics/reports/{reportName}")
+            .await?;
+        Self::handle_empty_response(response).await
+    }
+
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, AnalyticsApiError> {
+        let response = self.config.follow_location(response).await?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                response.json().await.map_err(AnalyticsApiError::from)
+            }
+            status => {
+                let text = response.text().await?;
+                Err(AnalyticsApiError::from_response(status, text))
+            }
+        }
+    }
+
+    async fn handle_empty_response(response: Response) -> Result<(), AnalyticsApiError> {
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
+            status => {
+                let text = response.text().await?;
+                Err(AnalyticsApiError::from_response(status, text))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetBusinessSnapshotRequest {
+    pub(crate) account_id: uuid::Uuid,
+}
+
+impl GetBusinessSnapshotRequest {
+    pub fn builder() -> GetBusinessSnapshotRequestBuilder {
+        GetBusinessSnapshotRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetBusinessSnapshotRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+}
+
+impl GetBusinessSnapshotRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
+        self
+    }
+
+    pub fn build(self) -> Result<GetBusinessSnapshotRequest, AnalyticsApiError> {
+        Ok(GetBusinessSnapshotRequest {
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RefreshBusinessSnapshotRequest<'a> {
+    pub(crate) account_id: uuid::Uuid,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> RefreshBusinessSnapshotRequest<'a> {
+    pub fn builder() -> RefreshBusinessSnapshotRequestBuilder<'a> {
+        RefreshBusinessSnapshotRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RefreshBusinessSnapshotRequestBuilder<'a> {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    x_killbill_created_by: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> RefreshBusinessSnapshotRequestBuilder<'a> {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<RefreshBusinessSnapshotRequest<'a>, AnalyticsApiError> {
+        Ok(RefreshBusinessSnapshotRequest {
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetReportsRequest<'a> {
+    pub(crate) names: Vec<&'a str>,
+    pub(crate) start_date: Option<String>,
+    pub(crate) end_date: Option<String>,
+    pub(crate) smooth: Option<&'a str>,
+}
+
+impl<'a> GetReportsRequest<'a> {
+    pub fn builder() -> GetReportsRequestBuilder<'a> {
+        GetReportsRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetReportsRequestBuilder<'a> {
+    names: Vec<&'a str>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    smooth: Option<&'a str>,
+}
+
+impl<'a> GetReportsRequestBuilder<'a> {
+    /// Add a report to fetch; repeat the call to fetch several reports at once
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.names.push(name);
+        self
+    }
+
+    pub fn start_date(mut self, start_date: impl models::DateParam) -> Self {
+        self.start_date = Some(start_date.into_param());
+        self
+    }
+
+    pub fn end_date(mut self, end_date: impl models::DateParam) -> Self {
+        self.end_date = Some(end_date.into_param());
+        self
+    }
+
+    /// Smoothing of timeline reports: `AVERAGE_WEEKLY`, `AVERAGE_MONTHLY`,
+    /// `SUM_WEEKLY` or `SUM_MONTHLY`
+    pub fn smooth(mut self, smooth: &'a str) -> Self {
+        self.smooth = Some(smooth);
+        self
+    }
+
+    pub fn build(self) -> Result<GetReportsRequest<'a>, AnalyticsApiError> {
+        if self.names.is_empty() {
+            return Err(ParamError::missing("name").into());
+        }
+
+        Ok(GetReportsRequest {
+            names: self.names,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            smooth: self.smooth,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RefreshReportRequest<'a> {
+    pub(crate) report_name: &'a str,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> RefreshReportRequest<'a> {
+    pub fn builder() -> RefreshReportRequestBuilder<'a> {
+        RefreshReportRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RefreshReportRequestBuilder<'a> {
+    report_name: Option<&'a str>,
+    x_killbill_created_by: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> RefreshReportRequestBuilder<'a> {
+    pub fn report_name(mut self, report_name: &'a str) -> Self {
+        self.report_name = Some(report_name);
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<RefreshReportRequest<'a>, AnalyticsApiError> {
+        Ok(RefreshReportRequest {
+            report_name: self.report_name.ok_or(ParamError::missing("report_name"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnalyticsApiError {
+    #[error("Request failed: {0}")] RequestFailed(#[from] reqwest::Error),
+
+    #[error("API error ({status}): {message}")] ApiError {
+        status: StatusCode,
+        message: String,
+    },
+
+    #[error("Configuration error: {0}")] ConfigError(
+        #[from] crate::apis::configuration::ConfigError,
+    ),
+
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
+}
+
+impl AnalyticsApiError {
+    fn from_response(status: StatusCode, message: String) -> Self {
+        Self::ApiError { status, message }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{ Matcher, Server };
+
+    #[tokio::test]
+    async fn test_get_business_snapshot() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/plugins/killbill-analytics/7f5c6a4e-2f4e-5b8a-9c61-3a0d1e6b2c11")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "businessAccount": {"accountId":"7f5c6a4e-2f4e-5b8a-9c61-3a0d1e6b2c11","currency":"USD","balance":10.5,"nbActiveBundles":1},
+                    "businessSubscriptionTransitions": [{"event":"START_ENTITLEMENT_BASE","nextSubscription":{"productName":"Gold","mrr":20.0,"businessActive":true}}],
+                    "businessInvoices": [{"invoiceNumber":12,"amountCharged":20.0,"invoiceItems":[{"itemType":"RECURRING","amount":20.0}]}],
+                    "businessBundles": []
+                }"#
+            )
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = AnalyticsApi::new(config);
+        let request = GetBusinessSnapshotRequest::builder()
+            .account_id("7f5c6a4e-2f4e-5b8a-9c61-3a0d1e6b2c11")
+            .build()
+            .unwrap();
+
+        let snapshot = api.get_business_snapshot(request).await.unwrap();
+        let account = snapshot.business_account.unwrap();
+        assert_eq!(account.currency, Some(models::Currency::Usd));
+        assert_eq!(account.nb_active_bundles, Some(1));
+        let transitions = snapshot.business_subscription_transitions.unwrap();
+        let next = transitions[0].next_subscription.as_ref().unwrap();
+        assert_eq!(next.product_name.as_deref(), Some("Gold"));
+        let invoices = snapshot.business_invoices.unwrap();
+        assert_eq!(invoices[0].invoice_items.as_ref().unwrap().len(), 1);
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_reports_time_series() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/plugins/killbill-analytics/reports")
+            .match_query(
+                Matcher::AllOf(
+                    vec![
+                        Matcher::Regex("name=conversions&name=trial_starts".into()),
+                        Matcher::UrlEncoded("format".into(), "json".into()),
+                        Matcher::UrlEncoded("startDate".into(), "2024-01-01".into())
+                    ]
+                )
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{"title":"Conversions","type":"TIMELINE","data":[{"name":"Gold","values":[{"x":"2024-01-01","y":3.0},{"x":"2024-01-02","y":5.0}]}]}]"#
+            )
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = AnalyticsApi::new(config);
+        let request = GetReportsRequest::builder()
+            .name("conversions")
+            .name("trial_starts")
+            .start_date("2024-01-01")
+            .build()
+            .unwrap();
+
+        let reports = api.get_reports(request).await.unwrap();
+        let series = reports[0].time_series().unwrap();
+        assert_eq!(series[0].name.as_deref(), Some("Gold"));
+        assert_eq!(series[0].values.as_ref().unwrap().len(), 2);
+        assert!(GetReportsRequest::builder().build().is_err());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_refresh_report() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/plugins/killbill-analytics/reports/conversions")
+            .match_header("X-Killbill-CreatedBy", "finance")
+            .match_query(Matcher::UrlEncoded("shouldRefresh".into(), "true".into()))
+            .with_status(200)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = AnalyticsApi::new(config);
+        let request = RefreshReportRequest::builder()
+            .report_name("conversions")
+            .x_killbill_created_by("finance")
+            .build()
+            .unwrap();
+
+        assert!(api.refresh_report(request).await.is_ok());
+
+        mock.assert_async().await;
+    }
+}
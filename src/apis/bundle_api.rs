@@ -1,7 +1,9 @@
+use futures::Stream;
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use uuid::Uuid;
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, pagination::{ paginate, Page }, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct BundleApi {
@@ -36,8 +38,8 @@ impl BundleApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/block").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_bundle_custom_fields(
@@ -58,8 +60,8 @@ impl BundleApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_bundle_tags(
@@ -76,8 +78,8 @@ impl BundleApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_bundle_custom_fields(
@@ -104,7 +106,7 @@ impl BundleApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -128,28 +130,28 @@ impl BundleApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_bundle(
         &self,
-        request: GetBundleRequest<'_>
+        request: GetBundleRequest
     ) -> Result<models::Bundle, BundleApiError> {
         let url = format!("{}/1.0/kb/bundles/{}", self.config.base_path, request.bundle_id);
 
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_bundle_audit_logs_with_history(
         &self,
-        request: GetBundleAuditLogsWithHistoryRequest<'_>
+        request: GetBundleAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, BundleApiError> {
         let url = format!(
             "{}/1.0/kb/bundles/{}/auditLogsWithHistory",
@@ -161,8 +163,10 @@ impl BundleApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/bundles/{bundleId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_bundle_by_key(
@@ -178,17 +182,17 @@ impl BundleApi {
                 &[
                     ("externalKey", request.external_key),
                     ("includedDeleted", request.included_deleted.map(|b| if b { "true" } else { "false" }).unwrap_or("false")),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_bundle_custom_fields(
         &self,
-        request: GetBundleCustomFieldsRequest<'_>
+        request: GetBundleCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, BundleApiError> {
         let url = format!(
             "{}/1.0/kb/bundles/{}/customFields",
@@ -199,15 +203,15 @@ impl BundleApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_bundle_tags(
         &self,
-        request: GetBundleTagsRequest<'_>
+        request: GetBundleTagsRequest
     ) -> Result<Vec<models::Tag>, BundleApiError> {
         let url = format!("{}/1.0/kb/bundles/{}/tags", self.config.base_path, request.bundle_id);
 
@@ -222,18 +226,18 @@ impl BundleApi {
                             .map(|b| if b { "true" } else { "false" })
                             .unwrap_or("false"),
                     ),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_bundles(
         &self,
-        request: GetBundlesRequest<'_>
-    ) -> Result<Vec<models::Bundle>, BundleApiError> {
+        request: GetBundlesRequest
+    ) -> Result<Page<models::Bundle>, BundleApiError> {
         let url = format!("{}/1.0/kb/bundles/pagination", self.config.base_path);
 
         let req = self.config.client
@@ -243,12 +247,24 @@ impl BundleApi {
                 &[
                     ("offset", request.offset.map(|o| o.to_string())),
                     ("limit", request.limit.map(|l| l.to_string())),
-                    ("audit", request.audit.map(|l| l.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/pagination").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_bundles_stream<'a>(
+        &'a self,
+        request: GetBundlesRequest
+    ) -> impl Stream<Item = Result<models::Bundle, BundleApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_bundles(request)
+        })
     }
 
     pub async fn modify_bundle_custom_fields(
@@ -269,7 +285,7 @@ impl BundleApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -295,7 +311,7 @@ impl BundleApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/pause").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -317,7 +333,7 @@ impl BundleApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/renameKey").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -343,14 +359,14 @@ impl BundleApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}/resume").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn search_bundles(
         &self,
         request: SearchBundlesRequest<'_>
-    ) -> Result<Vec<models::Bundle>, BundleApiError> {
+    ) -> Result<Page<models::Bundle>, BundleApiError> {
         let url = format!("{}/1.0/kb/bundles/search/{}", self.config.base_path, request.search_key);
 
         let req = self.config.client
@@ -360,12 +376,24 @@ impl BundleApi {
                 &[
                     ("offset", request.offset.map(|o| o.to_string())),
                     ("limit", request.limit.map(|l| l.to_string())),
-                    ("audit", request.audit.map(|l| l.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/search/{searchKey}").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_bundles_stream<'a>(
+        &'a self,
+        request: SearchBundlesRequest<'a>
+    ) -> impl Stream<Item = Result<models::Bundle, BundleApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_bundles(request)
+        })
     }
 
     pub async fn transfer_bundle(
@@ -397,11 +425,16 @@ impl BundleApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/bundles/{bundleId}").await?;
+        self.handle_response(response).await
     }
 
-    async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T, BundleApiError> {
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, BundleApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(BundleApiError::from)
@@ -413,6 +446,15 @@ impl BundleApi {
         }
     }
 
+    async fn handle_page_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<Page<T>, BundleApiError> {
+        let headers = response.headers().clone();
+        let items = self.handle_response(response).await?;
+        Ok(Page::new(items, &headers))
+    }
+
     async fn handle_empty_response(response: Response) -> Result<(), BundleApiError> {
         match response.status() {
             StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
@@ -426,7 +468,7 @@ impl BundleApi {
 
 #[derive(Debug, Clone)]
 pub struct AddBundleBlockingStateRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::BlockingState,
     pub(crate) requested_date: Option<String>,
@@ -443,7 +485,7 @@ impl<'a> AddBundleBlockingStateRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct AddBundleBlockingStateRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::BlockingState>,
     requested_date: Option<String>,
@@ -453,8 +495,8 @@ pub struct AddBundleBlockingStateRequestBuilder<'a> {
 }
 
 impl<'a> AddBundleBlockingStateRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -468,8 +510,8 @@ impl<'a> AddBundleBlockingStateRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -488,13 +530,15 @@ impl<'a> AddBundleBlockingStateRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AddBundleBlockingStateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AddBundleBlockingStateRequest<'a>, BundleApiError> {
         Ok(AddBundleBlockingStateRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             requested_date: self.requested_date,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -505,7 +549,7 @@ impl<'a> AddBundleBlockingStateRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateBundleCustomFieldsRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -520,7 +564,7 @@ impl<'a> CreateBundleCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateBundleCustomFieldsRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -528,8 +572,8 @@ pub struct CreateBundleCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreateBundleCustomFieldsRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -553,13 +597,15 @@ impl<'a> CreateBundleCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateBundleCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateBundleCustomFieldsRequest<'a>, BundleApiError> {
         Ok(CreateBundleCustomFieldsRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -568,7 +614,7 @@ impl<'a> CreateBundleCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateBundleTagsRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -583,7 +629,7 @@ impl<'a> CreateBundleTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateBundleTagsRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -591,8 +637,8 @@ pub struct CreateBundleTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreateBundleTagsRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -616,13 +662,15 @@ impl<'a> CreateBundleTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateBundleTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateBundleTagsRequest<'a>, BundleApiError> {
         Ok(CreateBundleTagsRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -631,7 +679,7 @@ impl<'a> CreateBundleTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteBundleCustomFieldsRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -646,7 +694,7 @@ impl<'a> DeleteBundleCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteBundleCustomFieldsRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -654,8 +702,8 @@ pub struct DeleteBundleCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteBundleCustomFieldsRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -679,11 +727,13 @@ impl<'a> DeleteBundleCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteBundleCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteBundleCustomFieldsRequest<'a>, BundleApiError> {
         Ok(DeleteBundleCustomFieldsRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -694,7 +744,7 @@ impl<'a> DeleteBundleCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteBundleTagsRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -709,7 +759,7 @@ impl<'a> DeleteBundleTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteBundleTagsRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -717,8 +767,8 @@ pub struct DeleteBundleTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteBundleTagsRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -742,11 +792,13 @@ impl<'a> DeleteBundleTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteBundleTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteBundleTagsRequest<'a>, BundleApiError> {
         Ok(DeleteBundleTagsRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -756,67 +808,71 @@ impl<'a> DeleteBundleTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetBundleRequest<'a> {
-    pub(crate) bundle_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetBundleRequest {
+    pub(crate) bundle_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundleRequest<'a> {
-    pub fn builder() -> GetBundleRequestBuilder<'a> {
+impl GetBundleRequest {
+    pub fn builder() -> GetBundleRequestBuilder {
         GetBundleRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetBundleRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetBundleRequestBuilder {
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundleRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+impl GetBundleRequestBuilder {
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetBundleRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBundleRequest, BundleApiError> {
         Ok(GetBundleRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetBundleAuditLogsWithHistoryRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+pub struct GetBundleAuditLogsWithHistoryRequest {
+    pub(crate) bundle_id: uuid::Uuid,
 }
 
-impl<'a> GetBundleAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetBundleAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetBundleAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetBundleAuditLogsWithHistoryRequestBuilder {
         GetBundleAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetBundleAuditLogsWithHistoryRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+pub struct GetBundleAuditLogsWithHistoryRequestBuilder {
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetBundleAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+impl GetBundleAuditLogsWithHistoryRequestBuilder {
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetBundleAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBundleAuditLogsWithHistoryRequest, BundleApiError> {
         Ok(GetBundleAuditLogsWithHistoryRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
         })
     }
 }
@@ -825,7 +881,7 @@ impl<'a> GetBundleAuditLogsWithHistoryRequestBuilder<'a> {
 pub struct GetBundleByKeyRequest<'a> {
     pub(crate) external_key: &'a str,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetBundleByKeyRequest<'a> {
@@ -838,7 +894,7 @@ impl<'a> GetBundleByKeyRequest<'a> {
 pub struct GetBundleByKeyRequestBuilder<'a> {
     external_key: Option<&'a str>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetBundleByKeyRequestBuilder<'a> {
@@ -852,14 +908,14 @@ impl<'a> GetBundleByKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetBundleByKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBundleByKeyRequest<'a>, BundleApiError> {
         Ok(GetBundleByKeyRequest {
-            external_key: self.external_key.ok_or("external_key is required")?,
+            external_key: self.external_key.ok_or(ParamError::missing("external_key"))?,
             included_deleted: self.included_deleted,
             audit: self.audit,
         })
@@ -867,65 +923,67 @@ impl<'a> GetBundleByKeyRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetBundleCustomFieldsRequest<'a> {
-    pub(crate) bundle_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetBundleCustomFieldsRequest {
+    pub(crate) bundle_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundleCustomFieldsRequest<'a> {
-    pub fn builder() -> GetBundleCustomFieldsRequestBuilder<'a> {
+impl GetBundleCustomFieldsRequest {
+    pub fn builder() -> GetBundleCustomFieldsRequestBuilder {
         GetBundleCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetBundleCustomFieldsRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetBundleCustomFieldsRequestBuilder {
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundleCustomFieldsRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+impl GetBundleCustomFieldsRequestBuilder {
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetBundleCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBundleCustomFieldsRequest, BundleApiError> {
         Ok(GetBundleCustomFieldsRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetBundleTagsRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+pub struct GetBundleTagsRequest {
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundleTagsRequest<'a> {
-    pub fn builder() -> GetBundleTagsRequestBuilder<'a> {
+impl GetBundleTagsRequest {
+    pub fn builder() -> GetBundleTagsRequestBuilder {
         GetBundleTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetBundleTagsRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+pub struct GetBundleTagsRequestBuilder {
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundleTagsRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+impl GetBundleTagsRequestBuilder {
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -934,14 +992,16 @@ impl<'a> GetBundleTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetBundleTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBundleTagsRequest, BundleApiError> {
         Ok(GetBundleTagsRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             included_deleted: self.included_deleted,
             audit: self.audit,
         })
@@ -949,26 +1009,26 @@ impl<'a> GetBundleTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetBundlesRequest<'a> {
+pub struct GetBundlesRequest {
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundlesRequest<'a> {
-    pub fn builder() -> GetBundlesRequestBuilder<'a> {
+impl GetBundlesRequest {
+    pub fn builder() -> GetBundlesRequestBuilder {
         GetBundlesRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetBundlesRequestBuilder<'a> {
+pub struct GetBundlesRequestBuilder {
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetBundlesRequestBuilder<'a> {
+impl GetBundlesRequestBuilder {
     pub fn offset(mut self, offset: i64) -> Self {
         self.offset = Some(offset);
         self
@@ -979,12 +1039,12 @@ impl<'a> GetBundlesRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetBundlesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetBundlesRequest, BundleApiError> {
         Ok(GetBundlesRequest {
             offset: self.offset,
             limit: self.limit,
@@ -995,7 +1055,7 @@ impl<'a> GetBundlesRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyBundleCustomFieldsRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1010,7 +1070,7 @@ impl<'a> ModifyBundleCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyBundleCustomFieldsRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -1018,8 +1078,8 @@ pub struct ModifyBundleCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyBundleCustomFieldsRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
     pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
@@ -1042,13 +1102,15 @@ impl<'a> ModifyBundleCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyBundleCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyBundleCustomFieldsRequest<'a>, BundleApiError> {
         Ok(ModifyBundleCustomFieldsRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1057,7 +1119,7 @@ impl<'a> ModifyBundleCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct PauseBundleRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) requested_date: Option<String>,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -1073,7 +1135,7 @@ impl<'a> PauseBundleRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct PauseBundleRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     requested_date: Option<String>,
     plugin_property: Option<Vec<String>>,
@@ -1082,8 +1144,8 @@ pub struct PauseBundleRequestBuilder<'a> {
 }
 
 impl<'a> PauseBundleRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -1092,8 +1154,8 @@ impl<'a> PauseBundleRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -1112,11 +1174,13 @@ impl<'a> PauseBundleRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<PauseBundleRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<PauseBundleRequest<'a>, BundleApiError> {
         Ok(PauseBundleRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             requested_date: self.requested_date,
             plugin_property: self.plugin_property,
@@ -1128,7 +1192,7 @@ impl<'a> PauseBundleRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct RenameExternalKeyRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::Bundle,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1143,7 +1207,7 @@ impl<'a> RenameExternalKeyRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct RenameExternalKeyRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::Bundle>,
     x_killbill_reason: Option<&'a str>,
@@ -1151,8 +1215,8 @@ pub struct RenameExternalKeyRequestBuilder<'a> {
 }
 
 impl<'a> RenameExternalKeyRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -1176,13 +1240,15 @@ impl<'a> RenameExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<RenameExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<RenameExternalKeyRequest<'a>, BundleApiError> {
         Ok(RenameExternalKeyRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1191,7 +1257,7 @@ impl<'a> RenameExternalKeyRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ResumeBundleRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) requested_date: Option<String>,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -1207,7 +1273,7 @@ impl<'a> ResumeBundleRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ResumeBundleRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     requested_date: Option<String>,
     plugin_property: Option<Vec<String>>,
@@ -1216,8 +1282,8 @@ pub struct ResumeBundleRequestBuilder<'a> {
 }
 
 impl<'a> ResumeBundleRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -1226,8 +1292,8 @@ impl<'a> ResumeBundleRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -1246,11 +1312,13 @@ impl<'a> ResumeBundleRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ResumeBundleRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ResumeBundleRequest<'a>, BundleApiError> {
         Ok(ResumeBundleRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             requested_date: self.requested_date,
             plugin_property: self.plugin_property,
@@ -1265,7 +1333,7 @@ pub struct SearchBundlesRequest<'a> {
     pub(crate) search_key: &'a str,
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchBundlesRequest<'a> {
@@ -1279,7 +1347,7 @@ pub struct SearchBundlesRequestBuilder<'a> {
     search_key: Option<&'a str>,
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchBundlesRequestBuilder<'a> {
@@ -1298,14 +1366,14 @@ impl<'a> SearchBundlesRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<SearchBundlesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SearchBundlesRequest<'a>, BundleApiError> {
         Ok(SearchBundlesRequest {
-            search_key: self.search_key.ok_or("search_key is required")?,
+            search_key: self.search_key.ok_or(ParamError::missing("search_key"))?,
             offset: self.offset,
             limit: self.limit,
             audit: self.audit,
@@ -1315,7 +1383,7 @@ impl<'a> SearchBundlesRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct TransferBundleRequest<'a> {
-    pub(crate) bundle_id: &'a str,
+    pub(crate) bundle_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::Bundle,
     pub(crate) requested_date: Option<String>,
@@ -1334,7 +1402,7 @@ impl<'a> TransferBundleRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct TransferBundleRequestBuilder<'a> {
-    bundle_id: Option<&'a str>,
+    bundle_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::Bundle>,
     requested_date: Option<String>,
@@ -1346,8 +1414,8 @@ pub struct TransferBundleRequestBuilder<'a> {
 }
 
 impl<'a> TransferBundleRequestBuilder<'a> {
-    pub fn bundle_id(mut self, bundle_id: &'a str) -> Self {
-        self.bundle_id = Some(bundle_id);
+    pub fn bundle_id(mut self, bundle_id: impl models::IdParam) -> Self {
+        self.bundle_id = Some(bundle_id.into_id());
         self
     }
 
@@ -1361,8 +1429,8 @@ impl<'a> TransferBundleRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -1391,13 +1459,15 @@ impl<'a> TransferBundleRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<TransferBundleRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<TransferBundleRequest<'a>, BundleApiError> {
         Ok(TransferBundleRequest {
-            bundle_id: self.bundle_id.ok_or("bundle_id is required")?,
+            bundle_id: self.bundle_id
+                .ok_or(ParamError::missing("bundle_id"))?
+                .map_err(|e| ParamError::invalid_id("bundle_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             requested_date: self.requested_date,
             billing_policy: self.billing_policy,
             bcd_transfer: self.bcd_transfer,
@@ -1421,7 +1491,10 @@ pub enum BundleApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl BundleApiError {
@@ -1441,7 +1514,7 @@ mod tests {
     async fn test_add_bundle_blocking_state() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/bundles/test-bundle-id/block")
+            .mock("POST", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/block")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"state_name": "test-state"}]"#)
@@ -1451,7 +1524,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = AddBundleBlockingStateRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .body(BlockingState {
                 ..Default::default()
@@ -1469,7 +1542,7 @@ mod tests {
     async fn test_create_bundle_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/bundles/test-bundle-id/customFields")
+            .mock("POST", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -1479,7 +1552,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = CreateBundleCustomFieldsRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -1499,7 +1572,7 @@ mod tests {
     async fn test_create_bundle_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/bundles/test-bundle-id/tags")
+            .mock("POST", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -1509,7 +1582,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = CreateBundleTagsRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -1525,7 +1598,7 @@ mod tests {
     async fn test_delete_bundle_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/bundles/test-bundle-id/customFields")
+            .mock("DELETE", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1533,7 +1606,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = DeleteBundleCustomFieldsRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1548,7 +1621,7 @@ mod tests {
     async fn test_delete_bundle_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/bundles/test-bundle-id/tags")
+            .mock("DELETE", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/tags")
             .with_status(204)
             .create_async().await;
 
@@ -1556,7 +1629,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = DeleteBundleTagsRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1571,16 +1644,16 @@ mod tests {
     async fn test_get_bundle() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/bundles/test-bundle-id")
+            .mock("GET", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"bundle_id": "test-bundle-id"}"#)
+            .with_body(r#"{"bundle_id": "77ad24a6-d6fb-5e9b-857c-5d832034483d"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = BundleApi::new(config);
-        let request = GetBundleRequest::builder().bundle_id("test-bundle-id").build().unwrap();
+        let request = GetBundleRequest::builder().bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d").build().unwrap();
 
         let result = api.get_bundle(request).await;
         assert!(result.is_ok());
@@ -1592,7 +1665,7 @@ mod tests {
     async fn test_get_bundle_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/bundles/test-bundle-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -1602,7 +1675,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = GetBundleAuditLogsWithHistoryRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .build()
             .unwrap();
 
@@ -1619,7 +1692,7 @@ mod tests {
             .mock("GET", "/1.0/kb/bundles?externalKey=test-external-key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"bundle_id": "test-bundle-id"}]"#)
+            .with_body(r#"[{"bundle_id": "77ad24a6-d6fb-5e9b-857c-5d832034483d"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -1640,7 +1713,7 @@ mod tests {
     async fn test_get_bundle_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/bundles/test-bundle-id/customFields")
+            .mock("GET", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -1650,7 +1723,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = GetBundleCustomFieldsRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .build()
             .unwrap();
 
@@ -1664,7 +1737,7 @@ mod tests {
     async fn test_get_bundle_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/bundles/test-bundle-id/tags")
+            .mock("GET", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -1673,7 +1746,7 @@ mod tests {
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = BundleApi::new(config);
-        let request = GetBundleTagsRequest::builder().bundle_id("test-bundle-id").build().unwrap();
+        let request = GetBundleTagsRequest::builder().bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d").build().unwrap();
 
         let result = api.get_bundle_tags(request).await;
         assert!(result.is_ok());
@@ -1688,7 +1761,7 @@ mod tests {
             .mock("GET", "/1.0/kb/bundles/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"bundle_id": "test-bundle-id"}]"#)
+            .with_body(r#"[{"bundle_id": "77ad24a6-d6fb-5e9b-857c-5d832034483d"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -1706,7 +1779,7 @@ mod tests {
     async fn test_modify_bundle_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/bundles/test-bundle-id/customFields")
+            .mock("PUT", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1714,7 +1787,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = ModifyBundleCustomFieldsRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -1734,7 +1807,7 @@ mod tests {
     async fn test_pause_bundle() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/bundles/test-bundle-id/pause")
+            .mock("PUT", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/pause")
             .with_status(204)
             .create_async().await;
 
@@ -1742,7 +1815,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = PauseBundleRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1757,7 +1830,7 @@ mod tests {
     async fn test_rename_external_key() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/bundles/test-bundle-id/renameKey")
+            .mock("PUT", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/renameKey")
             .with_status(204)
             .create_async().await;
 
@@ -1765,7 +1838,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = RenameExternalKeyRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .body(Bundle {
                 ..Default::default()
@@ -1783,7 +1856,7 @@ mod tests {
     async fn test_resume_bundle() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/bundles/test-bundle-id/resume")
+            .mock("PUT", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d/resume")
             .with_status(204)
             .create_async().await;
 
@@ -1791,7 +1864,7 @@ mod tests {
 
         let api = BundleApi::new(config);
         let request = ResumeBundleRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1809,7 +1882,7 @@ mod tests {
             .mock("GET", "/1.0/kb/bundles/search/test-search-key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"bundle_id": "test-bundle-id"}]"#)
+            .with_body(r#"[{"bundle_id": "77ad24a6-d6fb-5e9b-857c-5d832034483d"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -1830,17 +1903,17 @@ mod tests {
     async fn test_transfer_bundle() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/bundles/test-bundle-id")
+            .mock("POST", "/1.0/kb/bundles/77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"bundle_id": "test-bundle-id"}"#)
+            .with_body(r#"{"bundle_id": "77ad24a6-d6fb-5e9b-857c-5d832034483d"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = BundleApi::new(config);
         let request = TransferBundleRequest::builder()
-            .bundle_id("test-bundle-id")
+            .bundle_id("77ad24a6-d6fb-5e9b-857c-5d832034483d")
             .x_killbill_created_by("test")
             .body(Bundle {
                 ..Default::default()
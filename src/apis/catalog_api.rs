@@ -1,9 +1,10 @@
-use reqwest::{Method, Response, StatusCode};
+use reqwest::{header::{ACCEPT, CONTENT_TYPE}, Method, Response, StatusCode};
 use serde::{Deserialize, de::DeserializeOwned};
 use crate::{
     apis::configuration::Configuration,
     models,
 };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct CatalogApi {
@@ -29,8 +30,8 @@ impl CatalogApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/simplePlan").await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_catalog(
@@ -46,7 +47,7 @@ impl CatalogApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
         
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/catalog").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -62,16 +63,16 @@ impl CatalogApi {
             .query(&[
                 ("baseProductName", request.base_product_name),
                 ("priceListName", request.price_list_name),
-                ("accountId", request.account_id),
+                ("accountId", request.account_id.map(|id| id.to_string()).as_deref()),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/availableAddons").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_available_base_plans(
         &self,
-        request: GetAvailableBasePlansRequest<'_>,
+        request: GetAvailableBasePlansRequest,
     ) -> Result<Vec<models::PlanDetail>, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/availableBasePlans", self.config.base_path);
         
@@ -82,13 +83,13 @@ impl CatalogApi {
                 ("accountId", request.account_id),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/availableBasePlans").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_catalog_json(
         &self,
-        request: GetCatalogJsonRequest<'_>,
+        request: GetCatalogJsonRequest,
     ) -> Result<Vec<models::Catalog>, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog", self.config.base_path);
         
@@ -100,13 +101,13 @@ impl CatalogApi {
                 ("accountId", request.account_id.map(|id| id.to_string())),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_catalog_versions(
         &self,
-        request: GetCatalogVersionsRequest<'_>,
+        request: GetCatalogVersionsRequest,
     ) -> Result<Vec<String>, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/versions", self.config.base_path);
         
@@ -117,31 +118,32 @@ impl CatalogApi {
                 ("accountId", request.account_id),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/versions").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_catalog_xml(
         &self,
-        request: GetCatalogXmlRequest<'_>,
+        request: GetCatalogXmlRequest,
     ) -> Result<String, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/xml", self.config.base_path);
         
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
+            .header(ACCEPT, "text/xml")
             .query(&[
                 ("requestedDate", request.requested_date),
                 ("accountId", request.account_id.map(|id| id.to_string())),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/xml").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn get_phase_for_subscription_and_date(
         &self,
-        request: GetPhaseForSubscriptionAndDateRequest<'_>,
+        request: GetPhaseForSubscriptionAndDateRequest,
     ) -> Result<models::Phase, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/phase", self.config.base_path);
         
@@ -149,17 +151,17 @@ impl CatalogApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
             .query(&[
-                ("subscriptionId", request.subscription_id),
+                ("subscriptionId", request.subscription_id.map(|id| id.to_string()).as_deref()),
                 ("requestedDate", request.requested_date.as_deref()),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/phase").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_plan_for_subscription_and_date(
         &self,
-        request: GetPlanForSubscriptionAndDateRequest<'_>,
+        request: GetPlanForSubscriptionAndDateRequest,
     ) -> Result<models::Plan, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/plan", self.config.base_path);
         
@@ -167,17 +169,17 @@ impl CatalogApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
             .query(&[
-                ("subscriptionId", request.subscription_id),
+                ("subscriptionId", request.subscription_id.map(|id| id.to_string()).as_deref()),
                 ("requestedDate", request.requested_date.as_deref()),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/plan").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_price_list_for_subscription_and_date(
         &self,
-        request: GetPriceListForSubscriptionAndDateRequest<'_>,
+        request: GetPriceListForSubscriptionAndDateRequest,
     ) -> Result<models::PriceList, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/priceList", self.config.base_path);
         
@@ -185,17 +187,17 @@ impl CatalogApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
             .query(&[
-                ("subscriptionId", request.subscription_id),
+                ("subscriptionId", request.subscription_id.map(|id| id.to_string()).as_deref()),
                 ("requestedDate", request.requested_date.as_deref()),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/priceList").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_product_for_subscription_and_date(
         &self,
-        request: GetProductForSubscriptionAndDateRequest<'_>,
+        request: GetProductForSubscriptionAndDateRequest,
     ) -> Result<models::Product, CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/product", self.config.base_path);
         
@@ -203,18 +205,22 @@ impl CatalogApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
             .query(&[
-                ("subscriptionId", request.subscription_id),
+                ("subscriptionId", request.subscription_id.map(|id| id.to_string()).as_deref()),
                 ("requestedDate", request.requested_date.as_deref()),
             ]);
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/product").await?;
+        self.handle_response(response).await
     }
 
+    /// Upload a catalog version
+    ///
+    /// Kill Bill answers with the location of the JSON catalog, which is not
+    /// fetched; use [`Self::get_catalog_xml`] to read the catalog back.
     pub async fn upload_catalog_xml(
         &self,
         request: UploadCatalogXmlRequest<'_>,
-    ) -> Result<String, CatalogApiError> {
+    ) -> Result<(), CatalogApiError> {
         let url = format!("{}/1.0/kb/catalog/xml", self.config.base_path);
         
         let req = self.config.client
@@ -223,10 +229,11 @@ impl CatalogApi {
             .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
-            .json(&request.body);
+            .header(CONTENT_TYPE, "text/xml")
+            .body(request.body.to_owned());
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/xml").await?;
+        Self::handle_empty_response(response).await
     }
 
     pub async fn validate_catalog_xml(
@@ -241,15 +248,19 @@ impl CatalogApi {
             .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
-            .json(&request.body);
+            .header(CONTENT_TYPE, "text/xml")
+            .body(request.body.to_owned());
         
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/catalog/xml/validate").await?;
+        self.handle_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, CatalogApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(CatalogApiError::from)
@@ -261,6 +272,23 @@ impl CatalogApi {
         }
     }
 
+    async fn handle_text_response(
+        &self,
+        response: Response
+    ) -> Result<String, CatalogApiError> {
+        let response = self.config.follow_location(response).await?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                response.text().await.map_err(CatalogApiError::from)
+            }
+            status => {
+                let text = response.text().await?;
+                Err(CatalogApiError::from_response(status, text))
+            }
+        }
+    }
+
     async fn handle_empty_response(
         response: Response
     ) -> Result<(), CatalogApiError> {
@@ -317,10 +345,12 @@ impl<'a> AddSimplePlanRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AddSimplePlanRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AddSimplePlanRequest<'a>, CatalogApiError> {
         Ok(AddSimplePlanRequest {
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -363,9 +393,11 @@ impl<'a> DeleteCatalogRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteCatalogRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteCatalogRequest<'a>, CatalogApiError> {
         Ok(DeleteCatalogRequest {
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -376,7 +408,7 @@ impl<'a> DeleteCatalogRequestBuilder<'a> {
 pub struct GetAvailableAddonsRequest<'a> {
     pub(crate) base_product_name: Option<&'a str>,
     pub(crate) price_list_name: Option<&'a str>,
-    pub(crate) account_id: Option<&'a str>,
+    pub(crate) account_id: Option<uuid::Uuid>,
 }
 
 impl<'a> GetAvailableAddonsRequest<'a> {
@@ -389,7 +421,7 @@ impl<'a> GetAvailableAddonsRequest<'a> {
 pub struct GetAvailableAddonsRequestBuilder<'a> {
     base_product_name: Option<&'a str>,
     price_list_name: Option<&'a str>,
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
 impl<'a> GetAvailableAddonsRequestBuilder<'a> {
@@ -403,295 +435,313 @@ impl<'a> GetAvailableAddonsRequestBuilder<'a> {
         self
     }
 
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetAvailableAddonsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAvailableAddonsRequest<'a>, CatalogApiError> {
         Ok(GetAvailableAddonsRequest {
             base_product_name: self.base_product_name,
             price_list_name: self.price_list_name,
-            account_id: self.account_id,
+            account_id: self.account_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAvailableBasePlansRequest<'a> {
-    pub(crate) account_id: Option<&'a str>,
+pub struct GetAvailableBasePlansRequest {
+    pub(crate) account_id: Option<uuid::Uuid>,
 }
 
-impl<'a> GetAvailableBasePlansRequest<'a> {
-    pub fn builder() -> GetAvailableBasePlansRequestBuilder<'a> {
+impl GetAvailableBasePlansRequest {
+    pub fn builder() -> GetAvailableBasePlansRequestBuilder {
         GetAvailableBasePlansRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAvailableBasePlansRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetAvailableBasePlansRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetAvailableBasePlansRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetAvailableBasePlansRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetAvailableBasePlansRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAvailableBasePlansRequest, CatalogApiError> {
         Ok(GetAvailableBasePlansRequest {
-            account_id: self.account_id,
+            account_id: self.account_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetCatalogJsonRequest<'a> {
+pub struct GetCatalogJsonRequest {
     pub(crate) requested_date: Option<String>,
-    pub(crate) account_id: Option<&'a str>,
+    pub(crate) account_id: Option<uuid::Uuid>,
 }
 
-impl<'a> GetCatalogJsonRequest<'a> {
-    pub fn builder() -> GetCatalogJsonRequestBuilder<'a> {
+impl GetCatalogJsonRequest {
+    pub fn builder() -> GetCatalogJsonRequestBuilder {
         GetCatalogJsonRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetCatalogJsonRequestBuilder<'a> {
+pub struct GetCatalogJsonRequestBuilder {
     requested_date: Option<String>,
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetCatalogJsonRequestBuilder<'a> {
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+impl GetCatalogJsonRequestBuilder {
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetCatalogJsonRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetCatalogJsonRequest, CatalogApiError> {
         Ok(GetCatalogJsonRequest {
             requested_date: self.requested_date,
-            account_id: self.account_id,
+            account_id: self.account_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetCatalogVersionsRequest<'a> {
-    pub(crate) account_id: Option<&'a str>,
+pub struct GetCatalogVersionsRequest {
+    pub(crate) account_id: Option<uuid::Uuid>,
 }
 
-impl<'a> GetCatalogVersionsRequest<'a> {
-    pub fn builder() -> GetCatalogVersionsRequestBuilder<'a> {
+impl GetCatalogVersionsRequest {
+    pub fn builder() -> GetCatalogVersionsRequestBuilder {
         GetCatalogVersionsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetCatalogVersionsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+pub struct GetCatalogVersionsRequestBuilder {
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetCatalogVersionsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+impl GetCatalogVersionsRequestBuilder {
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetCatalogVersionsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetCatalogVersionsRequest, CatalogApiError> {
         Ok(GetCatalogVersionsRequest {
-            account_id: self.account_id,
+            account_id: self.account_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetCatalogXmlRequest<'a> {
+pub struct GetCatalogXmlRequest {
     pub(crate) requested_date: Option<String>,
-    pub(crate) account_id: Option<&'a str>,
+    pub(crate) account_id: Option<uuid::Uuid>,
 }
 
-impl<'a> GetCatalogXmlRequest<'a> {
-    pub fn builder() -> GetCatalogXmlRequestBuilder<'a> {
+impl GetCatalogXmlRequest {
+    pub fn builder() -> GetCatalogXmlRequestBuilder {
         GetCatalogXmlRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetCatalogXmlRequestBuilder<'a> {
+pub struct GetCatalogXmlRequestBuilder {
     requested_date: Option<String>,
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetCatalogXmlRequestBuilder<'a> {
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+impl GetCatalogXmlRequestBuilder {
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetCatalogXmlRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetCatalogXmlRequest, CatalogApiError> {
         Ok(GetCatalogXmlRequest {
             requested_date: self.requested_date,
-            account_id: self.account_id,
+            account_id: self.account_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPhaseForSubscriptionAndDateRequest<'a> {
-    pub(crate) subscription_id: Option<&'a str>,
+pub struct GetPhaseForSubscriptionAndDateRequest {
+    pub(crate) subscription_id: Option<uuid::Uuid>,
     pub(crate) requested_date: Option<String>,
 }
 
-impl<'a> GetPhaseForSubscriptionAndDateRequest<'a> {
-    pub fn builder() -> GetPhaseForSubscriptionAndDateRequestBuilder<'a> {
+impl GetPhaseForSubscriptionAndDateRequest {
+    pub fn builder() -> GetPhaseForSubscriptionAndDateRequestBuilder {
         GetPhaseForSubscriptionAndDateRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPhaseForSubscriptionAndDateRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+pub struct GetPhaseForSubscriptionAndDateRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     requested_date: Option<String>,
 }
 
-impl<'a> GetPhaseForSubscriptionAndDateRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetPhaseForSubscriptionAndDateRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
-    pub fn build(self) -> Result<GetPhaseForSubscriptionAndDateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPhaseForSubscriptionAndDateRequest, CatalogApiError> {
         Ok(GetPhaseForSubscriptionAndDateRequest {
-            subscription_id: self.subscription_id,
+            subscription_id: self.subscription_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             requested_date: self.requested_date,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPlanForSubscriptionAndDateRequest<'a> {
-    pub(crate) subscription_id: Option<&'a str>,
+pub struct GetPlanForSubscriptionAndDateRequest {
+    pub(crate) subscription_id: Option<uuid::Uuid>,
     pub(crate) requested_date: Option<String>,
 }
 
-impl<'a> GetPlanForSubscriptionAndDateRequest<'a> {
-    pub fn builder() -> GetPlanForSubscriptionAndDateRequestBuilder<'a> {
+impl GetPlanForSubscriptionAndDateRequest {
+    pub fn builder() -> GetPlanForSubscriptionAndDateRequestBuilder {
         GetPlanForSubscriptionAndDateRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPlanForSubscriptionAndDateRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+pub struct GetPlanForSubscriptionAndDateRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     requested_date: Option<String>,
 }
 
-impl<'a> GetPlanForSubscriptionAndDateRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetPlanForSubscriptionAndDateRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
-    pub fn build(self) -> Result<GetPlanForSubscriptionAndDateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPlanForSubscriptionAndDateRequest, CatalogApiError> {
         Ok(GetPlanForSubscriptionAndDateRequest {
-            subscription_id: self.subscription_id,
+            subscription_id: self.subscription_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             requested_date: self.requested_date,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPriceListForSubscriptionAndDateRequest<'a> {
-    pub(crate) subscription_id: Option<&'a str>,
+pub struct GetPriceListForSubscriptionAndDateRequest {
+    pub(crate) subscription_id: Option<uuid::Uuid>,
     pub(crate) requested_date: Option<String>,
 }
 
-impl<'a> GetPriceListForSubscriptionAndDateRequest<'a> {
-    pub fn builder() -> GetPriceListForSubscriptionAndDateRequestBuilder<'a> {
+impl GetPriceListForSubscriptionAndDateRequest {
+    pub fn builder() -> GetPriceListForSubscriptionAndDateRequestBuilder {
         GetPriceListForSubscriptionAndDateRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPriceListForSubscriptionAndDateRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+pub struct GetPriceListForSubscriptionAndDateRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     requested_date: Option<String>,
 }
 
-impl<'a> GetPriceListForSubscriptionAndDateRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetPriceListForSubscriptionAndDateRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
-    pub fn build(self) -> Result<GetPriceListForSubscriptionAndDateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPriceListForSubscriptionAndDateRequest, CatalogApiError> {
         Ok(GetPriceListForSubscriptionAndDateRequest {
-            subscription_id: self.subscription_id,
+            subscription_id: self.subscription_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             requested_date: self.requested_date,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetProductForSubscriptionAndDateRequest<'a> {
-    pub(crate) subscription_id: Option<&'a str>,
+pub struct GetProductForSubscriptionAndDateRequest {
+    pub(crate) subscription_id: Option<uuid::Uuid>,
     pub(crate) requested_date: Option<String>,
 }
 
-impl<'a> GetProductForSubscriptionAndDateRequest<'a> {
-    pub fn builder() -> GetProductForSubscriptionAndDateRequestBuilder<'a> {
+impl GetProductForSubscriptionAndDateRequest {
+    pub fn builder() -> GetProductForSubscriptionAndDateRequestBuilder {
         GetProductForSubscriptionAndDateRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetProductForSubscriptionAndDateRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+pub struct GetProductForSubscriptionAndDateRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     requested_date: Option<String>,
 }
 
-impl<'a> GetProductForSubscriptionAndDateRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetProductForSubscriptionAndDateRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
-    pub fn build(self) -> Result<GetProductForSubscriptionAndDateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetProductForSubscriptionAndDateRequest, CatalogApiError> {
         Ok(GetProductForSubscriptionAndDateRequest {
-            subscription_id: self.subscription_id,
+            subscription_id: self.subscription_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             requested_date: self.requested_date,
         })
     }
@@ -740,10 +790,12 @@ impl<'a> UploadCatalogXmlRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UploadCatalogXmlRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UploadCatalogXmlRequest<'a>, CatalogApiError> {
         Ok(UploadCatalogXmlRequest {
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -793,10 +845,12 @@ impl<'a> ValidateCatalogXmlRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ValidateCatalogXmlRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ValidateCatalogXmlRequest<'a>, CatalogApiError> {
         Ok(ValidateCatalogXmlRequest {
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -818,7 +872,11 @@ pub enum CatalogApiError {
     ConfigError(#[from] crate::apis::configuration::ConfigError),
 
     #[error("Validation error: {0}")]
-    ValidationError(String),
+    ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")]
+    InsideAsyncRuntime,
 }
 
 impl CatalogApiError {
@@ -860,7 +918,102 @@ mod tests {
 
         let result = api.add_simple_plan(request).await;
         assert!(result.is_ok());
-        
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_catalog_xml() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/catalog/xml")
+            .match_header("accept", "text/xml")
+            .with_status(200)
+            .with_header("content-type", "text/xml")
+            .with_body("<catalogs><versions/></catalogs>")
+            .create_async()
+            .await;
+
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .build()
+            .unwrap();
+
+        let api = CatalogApi::new(config);
+        let request = GetCatalogXmlRequest::builder().build().unwrap();
+
+        let result = api.get_catalog_xml(request).await;
+        assert_eq!(result.unwrap(), "<catalogs><versions/></catalogs>");
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_catalog_xml() {
+        let catalog = r#"<catalog xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><catalogName>Sports</catalogName></catalog>"#;
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/catalog/xml")
+            .match_header("content-type", "text/xml")
+            .match_body(catalog)
+            .with_status(201)
+            .with_header("location", "/1.0/kb/catalog")
+            .create_async()
+            .await;
+        let get = server
+            .mock("GET", "/1.0/kb/catalog")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .build()
+            .unwrap();
+
+        let api = CatalogApi::new(config);
+        let request = UploadCatalogXmlRequest::builder()
+            .x_killbill_created_by("test")
+            .body(catalog)
+            .build()
+            .unwrap();
+
+        let result = api.upload_catalog_xml(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+        get.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_validate_catalog_xml() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/catalog/xml/validate")
+            .match_header("content-type", "text/xml")
+            .match_body("<catalog/>")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"catalogValidationErrors": []}"#)
+            .create_async()
+            .await;
+
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .build()
+            .unwrap();
+
+        let api = CatalogApi::new(config);
+        let request = ValidateCatalogXmlRequest::builder()
+            .x_killbill_created_by("test")
+            .body("<catalog/>")
+            .build()
+            .unwrap();
+
+        let result = api.validate_catalog_xml(request).await;
+        assert!(result.unwrap().catalog_validation_errors.unwrap().is_empty());
+
         mock.assert_async().await;
     }
 
@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct CreditApi {
@@ -37,13 +38,13 @@ impl CreditApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/credits").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_credit(
         &self,
-        request: GetCreditRequest<'_>
+        request: GetCreditRequest
     ) -> Result<models::InvoiceItem, CreditApiError> {
         let url = format!("{}/1.0/kb/credits/{}", self.config.base_path, request.credit_id);
 
@@ -51,11 +52,16 @@ impl CreditApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/credits/{creditId}").await?;
+        self.handle_response(response).await
     }
 
-    async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T, CreditApiError> {
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, CreditApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(CreditApiError::from)
@@ -125,12 +131,12 @@ impl<'a> CreateCreditsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateCreditsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateCreditsRequest<'a>, CreditApiError> {
         Ok(CreateCreditsRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             auto_commit: self.auto_commit,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -140,30 +146,32 @@ impl<'a> CreateCreditsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetCreditRequest<'a> {
-    pub(crate) credit_id: &'a str,
+pub struct GetCreditRequest {
+    pub(crate) credit_id: uuid::Uuid,
 }
 
-impl<'a> GetCreditRequest<'a> {
-    pub fn builder() -> GetCreditRequestBuilder<'a> {
+impl GetCreditRequest {
+    pub fn builder() -> GetCreditRequestBuilder {
         GetCreditRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetCreditRequestBuilder<'a> {
-    credit_id: Option<&'a str>,
+pub struct GetCreditRequestBuilder {
+    credit_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetCreditRequestBuilder<'a> {
-    pub fn credit_id(mut self, credit_id: &'a str) -> Self {
-        self.credit_id = Some(credit_id);
+impl GetCreditRequestBuilder {
+    pub fn credit_id(mut self, credit_id: impl models::IdParam) -> Self {
+        self.credit_id = Some(credit_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetCreditRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetCreditRequest, CreditApiError> {
         Ok(GetCreditRequest {
-            credit_id: self.credit_id.ok_or("credit_id is required")?,
+            credit_id: self.credit_id
+                .ok_or(ParamError::missing("credit_id"))?
+                .map_err(|e| ParamError::invalid_id("credit_id", e))?,
         })
     }
 }
@@ -181,7 +189,10 @@ pub enum CreditApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl CreditApiError {
@@ -204,7 +215,7 @@ mod tests {
             .mock("POST", "/1.0/kb/credits")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"id": "test-id"}]"#)
+            .with_body(r#"[{"id": "890bb499-1afb-5fd2-b6e8-032068253de2"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -230,16 +241,16 @@ mod tests {
     async fn test_get_credit() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/credits/test-id")
+            .mock("GET", "/1.0/kb/credits/890bb499-1afb-5fd2-b6e8-032068253de2")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"id": "test-id"}"#)
+            .with_body(r#"{"id": "890bb499-1afb-5fd2-b6e8-032068253de2"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = CreditApi::new(config);
-        let request = GetCreditRequest::builder().credit_id("test-id").build().unwrap();
+        let request = GetCreditRequest::builder().credit_id("890bb499-1afb-5fd2-b6e8-032068253de2").build().unwrap();
 
         let result = api.get_credit(request).await;
         assert!(result.is_ok());
@@ -1,9 +1,8 @@
+use futures::Stream;
 use reqwest::{Method, Response, StatusCode};
 use serde::{Deserialize, de::DeserializeOwned};
-use crate::{
-    apis::configuration::Configuration,
-    models,
-};
+use crate::{ apis::{ configuration::Configuration, pagination::{ paginate, Page }, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct CustomFieldApi {
@@ -17,7 +16,7 @@ impl CustomFieldApi {
 
     pub async fn get_custom_field_audit_logs_with_history(
         &self,
-        request: GetCustomFieldAuditLogsWithHistoryRequest<'_>,
+        request: GetCustomFieldAuditLogsWithHistoryRequest,
     ) -> Result<Vec<models::AuditLog>, CustomFieldApiError> {
         let url = format!("{}/1.0/kb/customFields/{}/auditLogsWithHistory", self.config.base_path, request.custom_field_id);
 
@@ -25,14 +24,16 @@ impl CustomFieldApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/customFields/{customFieldId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_custom_fields(
         &self,
-        request: GetCustomFieldsRequest<'_>,
-    ) -> Result<Vec<models::CustomField>, CustomFieldApiError> {
+        request: GetCustomFieldsRequest,
+    ) -> Result<Page<models::CustomField>, CustomFieldApiError> {
         let url = format!("{}/1.0/kb/customFields/pagination", self.config.base_path);
 
         let req = self.config.client
@@ -41,17 +42,29 @@ impl CustomFieldApi {
             .query(&[
                 ("offset", request.offset.map(|o| o.to_string())),
                 ("limit", request.limit.map(|l| l.to_string())),
-                ("audit", request.audit.map(|a| a.to_string())),
-            ]);
-
-        let response = req.send().await?;
-        Self::handle_response(response).await
+            ])
+            .audit(request.audit);
+
+        let response = self.config.send(req, "/1.0/kb/customFields/pagination").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_custom_fields_stream<'a>(
+        &'a self,
+        request: GetCustomFieldsRequest,
+    ) -> impl Stream<Item = Result<models::CustomField, CustomFieldApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_custom_fields(request)
+        })
     }
 
     pub async fn search_custom_fields(
         &self,
         request: SearchCustomFieldsRequest<'_>,
-    ) -> Result<Vec<models::CustomField>, CustomFieldApiError> {
+    ) -> Result<Page<models::CustomField>, CustomFieldApiError> {
         let url = format!("{}/1.0/kb/customFields/search/{}", self.config.base_path, request.search_key);
 
         let req = self.config.client
@@ -60,17 +73,29 @@ impl CustomFieldApi {
             .query(&[
                 ("offset", request.offset.map(|o| o.to_string())),
                 ("limit", request.limit.map(|l| l.to_string())),
-                ("audit", request.audit.map(|a| a.to_string())),
-            ]);
-
-        let response = req.send().await?;
-        Self::handle_response(response).await
+            ])
+            .audit(request.audit);
+
+        let response = self.config.send(req, "/1.0/kb/customFields/search/{searchKey}").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_custom_fields_stream<'a>(
+        &'a self,
+        request: SearchCustomFieldsRequest<'a>,
+    ) -> impl Stream<Item = Result<models::CustomField, CustomFieldApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_custom_fields(request)
+        })
     }
 
     pub async fn search_custom_fields_by_type_name(
         &self,
         request: SearchCustomFieldsByTypeNameRequest<'_>,
-    ) -> Result<Vec<models::CustomField>, CustomFieldApiError> {
+    ) -> Result<Page<models::CustomField>, CustomFieldApiError> {
         let url = format!("{}/1.0/kb/customFields/search", self.config.base_path);
 
         let req = self.config.client
@@ -82,16 +107,31 @@ impl CustomFieldApi {
                 ("fieldValue", request.field_value),
                 ("offset", request.offset.map(|o| o.to_string()).as_deref()),
                 ("limit", request.limit.map(|l| l.to_string()).as_deref()),
-                ("audit", request.audit),
-            ]);
-
-        let response = req.send().await?;
-        Self::handle_response(response).await
+            ])
+            .audit(request.audit);
+
+        let response = self.config.send(req, "/1.0/kb/customFields/search").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_custom_fields_by_type_name_stream<'a>(
+        &'a self,
+        request: SearchCustomFieldsByTypeNameRequest<'a>,
+    ) -> impl Stream<Item = Result<models::CustomField, CustomFieldApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_custom_fields_by_type_name(request)
+        })
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, CustomFieldApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(CustomFieldApiError::from)
@@ -102,58 +142,69 @@ impl CustomFieldApi {
             }
         }
     }
+
+    async fn handle_page_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<Page<T>, CustomFieldApiError> {
+        let headers = response.headers().clone();
+        let items = self.handle_response(response).await?;
+        Ok(Page::new(items, &headers))
+    }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetCustomFieldAuditLogsWithHistoryRequest<'a> {
-    pub(crate) custom_field_id: &'a str,
+pub struct GetCustomFieldAuditLogsWithHistoryRequest {
+    pub(crate) custom_field_id: uuid::Uuid,
 }
 
-impl<'a> GetCustomFieldAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetCustomFieldAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetCustomFieldAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetCustomFieldAuditLogsWithHistoryRequestBuilder {
         GetCustomFieldAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetCustomFieldAuditLogsWithHistoryRequestBuilder<'a> {
-    custom_field_id: Option<&'a str>,
+pub struct GetCustomFieldAuditLogsWithHistoryRequestBuilder {
+    custom_field_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetCustomFieldAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn custom_field_id(mut self, custom_field_id: &'a str) -> Self {
-        self.custom_field_id = Some(custom_field_id);
+impl GetCustomFieldAuditLogsWithHistoryRequestBuilder {
+    pub fn custom_field_id(mut self, custom_field_id: impl models::IdParam) -> Self {
+        self.custom_field_id = Some(custom_field_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetCustomFieldAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetCustomFieldAuditLogsWithHistoryRequest, CustomFieldApiError> {
         Ok(GetCustomFieldAuditLogsWithHistoryRequest {
-            custom_field_id: self.custom_field_id.ok_or("custom_field_id is required")?,
+            custom_field_id: self.custom_field_id
+                .ok_or(ParamError::missing("custom_field_id"))?
+                .map_err(|e| ParamError::invalid_id("custom_field_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetCustomFieldsRequest<'a> {
+pub struct GetCustomFieldsRequest {
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetCustomFieldsRequest<'a> {
-    pub fn builder() -> GetCustomFieldsRequestBuilder<'a> {
+impl GetCustomFieldsRequest {
+    pub fn builder() -> GetCustomFieldsRequestBuilder {
         GetCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetCustomFieldsRequestBuilder<'a> {
+pub struct GetCustomFieldsRequestBuilder {
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetCustomFieldsRequestBuilder<'a> {
+impl GetCustomFieldsRequestBuilder {
     pub fn offset(mut self, offset: i64) -> Self {
         self.offset = Some(offset);
         self
@@ -164,12 +215,12 @@ impl<'a> GetCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetCustomFieldsRequest, CustomFieldApiError> {
         Ok(GetCustomFieldsRequest {
             offset: self.offset,
             limit: self.limit,
@@ -183,7 +234,7 @@ pub struct SearchCustomFieldsRequest<'a> {
     pub(crate) search_key: &'a str,
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchCustomFieldsRequest<'a> {
@@ -197,7 +248,7 @@ pub struct SearchCustomFieldsRequestBuilder<'a> {
     search_key: Option<&'a str>,
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchCustomFieldsRequestBuilder<'a> {
@@ -216,14 +267,14 @@ impl<'a> SearchCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<SearchCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SearchCustomFieldsRequest<'a>, CustomFieldApiError> {
         Ok(SearchCustomFieldsRequest {
-            search_key: self.search_key.ok_or("search_key is required")?,
+            search_key: self.search_key.ok_or(ParamError::missing("search_key"))?,
             offset: self.offset,
             limit: self.limit,
             audit: self.audit,
@@ -238,7 +289,7 @@ pub struct SearchCustomFieldsByTypeNameRequest<'a> {
     pub(crate) field_value: Option<&'a str>,
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchCustomFieldsByTypeNameRequest<'a> {
@@ -254,7 +305,7 @@ pub struct SearchCustomFieldsByTypeNameRequestBuilder<'a> {
     field_value: Option<&'a str>,
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchCustomFieldsByTypeNameRequestBuilder<'a> {
@@ -283,12 +334,12 @@ impl<'a> SearchCustomFieldsByTypeNameRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<SearchCustomFieldsByTypeNameRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SearchCustomFieldsByTypeNameRequest<'a>, CustomFieldApiError> {
         Ok(SearchCustomFieldsByTypeNameRequest {
             object_type: self.object_type,
             field_name: self.field_name,
@@ -315,7 +366,11 @@ pub enum CustomFieldApiError {
     ConfigError(#[from] crate::apis::configuration::ConfigError),
 
     #[error("Validation error: {0}")]
-    ValidationError(String),
+    ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")]
+    InsideAsyncRuntime,
 }
 
 impl CustomFieldApiError {
@@ -334,7 +389,7 @@ mod tests {
     async fn test_get_custom_field_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/customFields/test-custom-field-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/customFields/93b6b65e-c69c-5a6a-ba4d-2d78cef4d58c/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -348,7 +403,7 @@ mod tests {
 
         let api = CustomFieldApi::new(config);
         let request = GetCustomFieldAuditLogsWithHistoryRequest::builder()
-            .custom_field_id("test-custom-field-id")
+            .custom_field_id("93b6b65e-c69c-5a6a-ba4d-2d78cef4d58c")
             .build()
             .unwrap();
 
@@ -0,0 +1,366 @@
+use reqwest::StatusCode;
+use serde::{ Deserialize, Serialize };
+use thiserror::Error;
+
+use crate::apis::configuration::ConfigError;
+use crate::models::InvalidId;
+
+/// Error body returned by Kill Bill when an API exception is raised
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BillingException {
+    #[serde(rename = "className", skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[serde(rename = "code", skip_serializing_if = "Option::is_none")]
+    pub code: Option<i32>,
+    #[serde(rename = "message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(rename = "causeClassName", skip_serializing_if = "Option::is_none")]
+    pub cause_class_name: Option<String>,
+    #[serde(rename = "causeMessage", skip_serializing_if = "Option::is_none")]
+    pub cause_message: Option<String>,
+    #[serde(rename = "stackTrace", alias = "stacktrace", skip_serializing_if = "Option::is_none")]
+    pub stack_trace: Option<Vec<StackTraceElement>>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StackTraceElement {
+    #[serde(rename = "className", skip_serializing_if = "Option::is_none")]
+    pub class_name: Option<String>,
+    #[serde(rename = "fileName", skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(rename = "lineNumber", skip_serializing_if = "Option::is_none")]
+    pub line_number: Option<i32>,
+    #[serde(rename = "methodName", skip_serializing_if = "Option::is_none")]
+    pub method_name: Option<String>,
+    #[serde(rename = "nativeMethod", skip_serializing_if = "Option::is_none")]
+    pub native_method: Option<bool>,
+}
+
+impl BillingException {
+    /// Parse a response body, returning `None` if it is not a Kill Bill error
+    pub fn parse(body: &str) -> Option<Self> {
+        serde_json::from_str::<Self>(body)
+            .ok()
+            .filter(|exception| exception.code.is_some() || exception.class_name.is_some())
+    }
+
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        self.code.map(ErrorCode::from)
+    }
+}
+
+/// Kill Bill API error codes (`org.killbill.billing.ErrorCode`)
+///
+/// Only the codes clients commonly branch on are named; any other code is
+/// kept as [`ErrorCode::Other`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    NotImplemented,
+    SubCreateBadPhase,
+    SubCreateNoBundle,
+    SubCreateNoBp,
+    SubCreateBpExists,
+    SubCreateAoBpNonActive,
+    AccountAlreadyExists,
+    AccountInvalidName,
+    AccountDoesNotExistForId,
+    AccountDoesNotExistForKey,
+    TagDefinitionConflictsWithControlTag,
+    TagDefinitionAlreadyExists,
+    TagDefinitionDoesNotExist,
+    InvoiceAccountIdInvalid,
+    PaymentNoSuchPaymentMethod,
+    PaymentNoPaymentMethods,
+    PaymentPluginTimeout,
+    PaymentPluginException,
+    TenantAlreadyExists,
+    TenantDoesNotExistForId,
+    TenantDoesNotExistForApiKey,
+    Other(i32),
+}
+
+impl ErrorCode {
+    pub fn code(self) -> i32 {
+        match self {
+            Self::NotImplemented => 1,
+            Self::SubCreateBadPhase => 1011,
+            Self::SubCreateNoBundle => 1012,
+            Self::SubCreateNoBp => 1013,
+            Self::SubCreateBpExists => 1015,
+            Self::SubCreateAoBpNonActive => 1017,
+            Self::AccountAlreadyExists => 3000,
+            Self::AccountInvalidName => 3001,
+            Self::AccountDoesNotExistForId => 3002,
+            Self::AccountDoesNotExistForKey => 3003,
+            Self::TagDefinitionConflictsWithControlTag => 3900,
+            Self::TagDefinitionAlreadyExists => 3901,
+            Self::TagDefinitionDoesNotExist => 3902,
+            Self::InvoiceAccountIdInvalid => 4001,
+            Self::PaymentNoSuchPaymentMethod => 7001,
+            Self::PaymentNoPaymentMethods => 7002,
+            Self::PaymentPluginTimeout => 7190,
+            Self::PaymentPluginException => 7199,
+            Self::TenantAlreadyExists => 10000,
+            Self::TenantDoesNotExistForId => 10001,
+            Self::TenantDoesNotExistForApiKey => 10002,
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl From<i32> for ErrorCode {
+    fn from(code: i32) -> Self {
+        match code {
+            1 => Self::NotImplemented,
+            1011 => Self::SubCreateBadPhase,
+            1012 => Self::SubCreateNoBundle,
+            1013 => Self::SubCreateNoBp,
+            1015 => Self::SubCreateBpExists,
+            1017 => Self::SubCreateAoBpNonActive,
+            3000 => Self::AccountAlreadyExists,
+            3001 => Self::AccountInvalidName,
+            3002 => Self::AccountDoesNotExistForId,
+            3003 => Self::AccountDoesNotExistForKey,
+            3900 => Self::TagDefinitionConflictsWithControlTag,
+            3901 => Self::TagDefinitionAlreadyExists,
+            3902 => Self::TagDefinitionDoesNotExist,
+            4001 => Self::InvoiceAccountIdInvalid,
+            7001 => Self::PaymentNoSuchPaymentMethod,
+            7002 => Self::PaymentNoPaymentMethods,
+            7190 => Self::PaymentPluginTimeout,
+            7199 => Self::PaymentPluginException,
+            10000 => Self::TenantAlreadyExists,
+            10001 => Self::TenantDoesNotExistForId,
+            10002 => Self::TenantDoesNotExistForApiKey,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// Request parameter rejected by a request builder, before anything is sent
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParamError {
+    #[error("{field} is required")] Missing {
+        field: &'static str,
+    },
+
+    #[error("one of {} is required", fields.join(", "))] MissingOneOf {
+        fields: &'static [&'static str],
+    },
+
+    #[error("{field} is not a valid UUID: {value:?}")] InvalidId {
+        field: &'static str,
+        value: String,
+    },
+
+    #[error("{field} is invalid: {value:?}")] Invalid {
+        field: &'static str,
+        value: String,
+    },
+}
+
+impl ParamError {
+    pub(crate) fn missing(field: &'static str) -> Self {
+        Self::Missing { field }
+    }
+
+    pub(crate) fn invalid_id(field: &'static str, error: InvalidId) -> Self {
+        Self::InvalidId { field, value: error.value }
+    }
+
+    pub(crate) fn invalid(field: &'static str, value: impl Into<String>) -> Self {
+        Self::Invalid { field, value: value.into() }
+    }
+
+    /// Name of the rejected parameter, if the error is about a single one
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            Self::Missing { field } | Self::InvalidId { field, .. } | Self::Invalid { field, .. } => {
+                Some(field)
+            }
+            Self::MissingOneOf { .. } => None,
+        }
+    }
+}
+
+/// Crate-level error every per-API error converts into
+#[derive(Debug, Error)]
+pub enum KillBillError {
+    #[error("Request failed: {0}")] RequestFailed(#[from] reqwest::Error),
+
+    #[error("API error ({status}): {message}")] ApiError {
+        status: StatusCode,
+        message: String,
+        exception: Option<BillingException>,
+    },
+
+    #[error("Configuration error: {0}")] ConfigError(#[from] ConfigError),
+
+    #[error("Validation error: {0}")] ValidationError(#[from] ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
+}
+
+impl KillBillError {
+    pub(crate) fn from_response(status: StatusCode, body: String) -> Self {
+        let exception = BillingException::parse(&body);
+        let message = exception
+            .as_ref()
+            .and_then(|exception| exception.message.clone())
+            .unwrap_or(body);
+
+        Self::ApiError { status, message, exception }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::ApiError { status, .. } => Some(*status),
+            Self::RequestFailed(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    pub fn exception(&self) -> Option<&BillingException> {
+        match self {
+            Self::ApiError { exception, .. } => exception.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        self.exception().and_then(BillingException::error_code)
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Whether the server reported a failure or timeout of a payment plugin
+    ///
+    /// Kill Bill answers these with `502 Bad Gateway` and `504 Gateway Timeout`
+    /// and a `PaymentApiException` body; the same statuses without one come from
+    /// a proxy or load balancer in front of Kill Bill.
+    pub fn is_payment_plugin_failure(&self) -> bool {
+        let is_payment = self
+            .exception()
+            .and_then(|exception| exception.class_name.as_deref())
+            .is_some_and(|class_name| class_name.ends_with("PaymentApiException"));
+
+        is_payment &&
+            matches!(self.status(), Some(StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT))
+    }
+}
+
+macro_rules! impl_from_api_error {
+    ($($module:ident::$error:ident),* $(,)?) => {
+        $(
+            impl From<crate::apis::$module::$error> for KillBillError {
+                fn from(error: crate::apis::$module::$error) -> Self {
+                    use crate::apis::$module::$error;
+
+                    match error {
+                        $error::RequestFailed(e) => Self::RequestFailed(e),
+                        $error::ApiError { status, message } => Self::from_response(status, message),
+                        $error::ConfigError(e) => Self::ConfigError(e),
+                        $error::ValidationError(e) => Self::ValidationError(e),
+                        #[cfg(feature = "blocking")]
+                        $error::InsideAsyncRuntime => Self::InsideAsyncRuntime,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_api_error!(
+    account_api::AccountApiError,
+    admin_api::AdminApiError,
+    analytics_api::AnalyticsApiError,
+    bundle_api::BundleApiError,
+    catalog_api::CatalogApiError,
+    credit_api::CreditApiError,
+    custom_field_api::CustomFieldApiError,
+    export_api::ExportApiError,
+    invoice_api::InvoiceApiError,
+    invoice_item_api::InvoiceItemApiError,
+    invoice_payment_api::InvoicePaymentApiError,
+    nodes_info_api::NodesApiError,
+    overdue_api::OverdueApiError,
+    payment_api::PaymentApiError,
+    payment_gateway_api::HostedPaymentPageApiError,
+    payment_method_api::PaymentMethodApiError,
+    payment_transaction_api::PaymentTransactionApiError,
+    plugin_api::PluginApiError,
+    plugin_info_api::PluginsApiError,
+    security_api::SecurityApiError,
+    subscription_api::SubscriptionApiError,
+    tag_api::TagApiError,
+    tag_definition_api::TagDefinitionApiError,
+    tenant_api::TenantApiError,
+    test_api::TestApiError,
+    usage_api::UsageApiError,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apis::{ account_api::AccountApiError, payment_api::PaymentApiError };
+
+    #[test]
+    fn test_parses_billing_exception() {
+        let body =
+            r#"{"className":"org.killbill.billing.account.api.AccountApiException","code":3003,"message":"Account does not exist for key foo","causeClassName":null,"causeMessage":null,"stackTrace":[{"className":"org.killbill.billing.account.api.svcs.DefaultAccountInternalApi","fileName":"DefaultAccountInternalApi.java","lineNumber":92,"methodName":"getAccountByKey","nativeMethod":false}]}"#;
+
+        let error = KillBillError::from(AccountApiError::ApiError {
+            status: StatusCode::NOT_FOUND,
+            message: body.to_owned(),
+        });
+
+        assert!(error.is_not_found());
+        assert_eq!(error.error_code(), Some(ErrorCode::AccountDoesNotExistForKey));
+        assert_eq!(error.to_string(), "API error (404 Not Found): Account does not exist for key foo");
+        assert_eq!(error.exception().unwrap().stack_trace.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_keeps_raw_body_when_not_json() {
+        let error = KillBillError::from(AccountApiError::ApiError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "<html>oops</html>".to_owned(),
+        });
+
+        assert!(error.exception().is_none());
+        assert_eq!(error.error_code(), None);
+        assert_eq!(error.to_string(), "API error (500 Internal Server Error): <html>oops</html>");
+    }
+
+    #[test]
+    fn test_unknown_error_code() {
+        assert_eq!(ErrorCode::from(99999), ErrorCode::Other(99999));
+        assert_eq!(ErrorCode::from(7001).code(), 7001);
+    }
+
+    #[test]
+    fn test_payment_plugin_failure() {
+        let body =
+            r#"{"className":"org.killbill.billing.payment.api.PaymentApiException","code":7199,"message":"Plugin failure"}"#;
+
+        let error = KillBillError::from(PaymentApiError::ApiError {
+            status: StatusCode::BAD_GATEWAY,
+            message: body.to_owned(),
+        });
+
+        assert!(error.is_payment_plugin_failure());
+        assert!(!error.is_not_found());
+        assert_eq!(error.error_code(), Some(ErrorCode::PaymentPluginException));
+        assert_eq!(ErrorCode::from(7190), ErrorCode::PaymentPluginTimeout);
+
+        let error = KillBillError::from(PaymentApiError::ApiError {
+            status: StatusCode::BAD_GATEWAY,
+            message: "<html>502 Bad Gateway</html>".to_owned(),
+        });
+        assert!(!error.is_payment_plugin_failure());
+    }
+}
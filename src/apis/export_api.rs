@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct ExportApi {
@@ -25,7 +26,7 @@ impl ExportApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/export/{accountId}").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -42,7 +43,7 @@ impl ExportApi {
 
 #[derive(Debug, Clone)]
 pub struct ExportDataForAccountRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -56,15 +57,15 @@ impl<'a> ExportDataForAccountRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ExportDataForAccountRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> ExportDataForAccountRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -83,11 +84,13 @@ impl<'a> ExportDataForAccountRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ExportDataForAccountRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ExportDataForAccountRequest<'a>, ExportApiError> {
         Ok(ExportDataForAccountRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -108,7 +111,10 @@ pub enum ExportApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl ExportApiError {
@@ -126,7 +132,7 @@ mod tests {
     async fn test_export_data_for_account() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/export/test-account")
+            .mock("GET", "/1.0/kb/export/54adbd1f-9528-5c85-a36d-f512157830ab")
             .with_status(204)
             .create_async().await;
 
@@ -134,7 +140,7 @@ mod tests {
 
         let api = ExportApi::new(config);
         let request = ExportDataForAccountRequest::builder()
-            .account_id("test-account")
+            .account_id("54adbd1f-9528-5c85-a36d-f512157830ab")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
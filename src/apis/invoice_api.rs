@@ -1,7 +1,9 @@
-use reqwest::{ Method, Response, StatusCode };
+use futures::Stream;
+use reqwest::{ header::{ ACCEPT, CONTENT_TYPE }, Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use uuid::Uuid;
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, pagination::{ paginate, Page }, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct InvoiceApi {
@@ -36,8 +38,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn commit_invoice(
@@ -57,7 +59,7 @@ impl InvoiceApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/commitInvoice").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -93,8 +95,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/charges/{accountId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_future_invoice(
@@ -108,7 +110,7 @@ impl InvoiceApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("accountId", request.account_id),
+                    ("accountId", request.account_id.to_string().as_str()),
                     ("targetDate", &request.target_date.unwrap_or_default()),
                 ]
             )
@@ -124,8 +126,8 @@ impl InvoiceApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_future_invoice_group(
@@ -139,7 +141,7 @@ impl InvoiceApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("accountId", request.account_id),
+                    ("accountId", request.account_id.to_string().as_str()),
                     ("targetDate", request.target_date.unwrap_or_default().as_str()),
                 ]
             )
@@ -155,8 +157,8 @@ impl InvoiceApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/group").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_instant_payment(
@@ -194,8 +196,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/payments").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_invoice_custom_fields(
@@ -216,8 +218,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_invoice_tags(
@@ -234,8 +236,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_migration_invoice(
@@ -257,8 +259,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/migration/{accountId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_tax_items(
@@ -289,8 +291,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/taxes/{accountId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_cba(&self, request: DeleteCbaRequest<'_>) -> Result<(), InvoiceApiError> {
@@ -309,7 +311,9 @@ impl InvoiceApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/invoices/{invoiceId}/{invoiceItemId}/cba")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -337,7 +341,7 @@ impl InvoiceApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -361,7 +365,7 @@ impl InvoiceApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -376,7 +380,7 @@ impl InvoiceApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("accountId", request.account_id),
+                    ("accountId", request.account_id.to_string().as_str()),
                     ("targetDate", request.target_date.as_deref().unwrap_or("")),
                 ]
             )
@@ -393,8 +397,8 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/dryRun").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_catalog_translation(
@@ -409,15 +413,16 @@ impl InvoiceApi {
 
         let req = self.config.client
             .request(Method::GET, &url)
-            .headers(self.config.get_auth_headers());
+            .headers(self.config.get_auth_headers())
+            .header(ACCEPT, "text/plain");
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/catalogTranslation/{locale}").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn get_invoice(
         &self,
-        request: GetInvoiceRequest<'_>
+        request: GetInvoiceRequest
     ) -> Result<models::Invoice, InvoiceApiError> {
         let url = format!("{}/1.0/kb/invoices/{}", self.config.base_path, request.invoice_id);
 
@@ -427,31 +432,32 @@ impl InvoiceApi {
             .query(
                 &[
                     ("withChildrenItems", request.with_children_items.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|s| s.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_as_html(
         &self,
-        request: GetInvoiceAsHtmlRequest<'_>
+        request: GetInvoiceAsHtmlRequest
     ) -> Result<String, InvoiceApiError> {
         let url = format!("{}/1.0/kb/invoices/{}/html", self.config.base_path, request.invoice_id);
 
         let req = self.config.client
             .request(Method::GET, &url)
-            .headers(self.config.get_auth_headers());
+            .headers(self.config.get_auth_headers())
+            .header(ACCEPT, "text/html");
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/html").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn get_invoice_audit_logs_with_history(
         &self,
-        request: GetInvoiceAuditLogsWithHistoryRequest<'_>
+        request: GetInvoiceAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, InvoiceApiError> {
         let url = format!(
             "{}/1.0/kb/invoices/{}/auditLogsWithHistory",
@@ -463,13 +469,15 @@ impl InvoiceApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/invoices/{invoiceId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_by_item_id(
         &self,
-        request: GetInvoiceByItemIdRequest<'_>
+        request: GetInvoiceByItemIdRequest
     ) -> Result<models::Invoice, InvoiceApiError> {
         let url = format!("{}/1.0/kb/invoices/byItemId/{}", self.config.base_path, request.item_id);
 
@@ -479,17 +487,17 @@ impl InvoiceApi {
             .query(
                 &[
                     ("withChildrenItems", request.with_children_items.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/byItemId/{itemId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_by_number(
         &self,
-        request: GetInvoiceByNumberRequest<'_>
+        request: GetInvoiceByNumberRequest
     ) -> Result<models::Invoice, InvoiceApiError> {
         let url = format!(
             "{}/1.0/kb/invoices/byNumber/{}",
@@ -503,17 +511,17 @@ impl InvoiceApi {
             .query(
                 &[
                     ("withChildrenItems", request.with_children_items.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/byNumber/{invoiceNumber}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_custom_fields(
         &self,
-        request: GetInvoiceCustomFieldsRequest<'_>
+        request: GetInvoiceCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, InvoiceApiError> {
         let url = format!(
             "{}/1.0/kb/invoices/{}/customFields",
@@ -524,10 +532,10 @@ impl InvoiceApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_mp_template(
@@ -542,15 +550,16 @@ impl InvoiceApi {
 
         let req = self.config.client
             .request(Method::GET, &url)
-            .headers(self.config.get_auth_headers());
+            .headers(self.config.get_auth_headers())
+            .header(ACCEPT, "text/html");
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/manualPayTemplate/{locale}").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn get_invoice_tags(
         &self,
-        request: GetInvoiceTagsRequest<'_>
+        request: GetInvoiceTagsRequest
     ) -> Result<Vec<models::Tag>, InvoiceApiError> {
         let url = format!("{}/1.0/kb/invoices/{}/tags", self.config.base_path, request.invoice_id);
 
@@ -565,9 +574,9 @@ impl InvoiceApi {
                             .map(|b| if b { "true" } else { "false" })
                             .unwrap_or("false"),
                     ),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -577,8 +586,8 @@ impl InvoiceApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_template(&self) -> Result<String, InvoiceApiError> {
@@ -586,10 +595,11 @@ impl InvoiceApi {
 
         let req = self.config.client
             .request(Method::GET, &url)
-            .headers(self.config.get_auth_headers());
+            .headers(self.config.get_auth_headers())
+            .header(ACCEPT, "text/html");
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/template").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn get_invoice_translation(
@@ -604,16 +614,17 @@ impl InvoiceApi {
 
         let req = self.config.client
             .request(Method::GET, &url)
-            .headers(self.config.get_auth_headers());
+            .headers(self.config.get_auth_headers())
+            .header(ACCEPT, "text/plain");
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/translation/{locale}").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn get_invoices(
         &self,
-        request: GetInvoicesRequest<'_>
-    ) -> Result<Vec<models::Invoice>, InvoiceApiError> {
+        request: GetInvoicesRequest
+    ) -> Result<Page<models::Invoice>, InvoiceApiError> {
         let url = format!("{}/1.0/kb/invoices/pagination", self.config.base_path);
 
         let req = self.config.client
@@ -623,17 +634,29 @@ impl InvoiceApi {
                 &[
                     ("offset", request.offset.map(|o| o.to_string())),
                     ("limit", request.limit.map(|l| l.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/pagination").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_invoices_stream<'a>(
+        &'a self,
+        request: GetInvoicesRequest
+    ) -> impl Stream<Item = Result<models::Invoice, InvoiceApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_invoices(request)
+        })
     }
 
     pub async fn get_invoices_group(
         &self,
-        request: GetInvoicesGroupRequest<'_>
+        request: GetInvoicesGroupRequest
     ) -> Result<Vec<models::Invoice>, InvoiceApiError> {
         let url = format!("{}/1.0/kb/invoices/{}/group", self.config.base_path, request.group_id);
 
@@ -642,19 +665,19 @@ impl InvoiceApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("accountId", request.account_id),
+                    ("accountId", request.account_id.to_string().as_str()),
                     ("withChildrenItems", request.with_children_items.map(|b| if b { "true" } else { "false" }).unwrap_or("false")),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{groupId}/group").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payments_for_invoice(
         &self,
-        request: GetPaymentsForInvoiceRequest<'_>
+        request: GetPaymentsForInvoiceRequest
     ) -> Result<Vec<models::InvoicePayment>, InvoiceApiError> {
         let url = format!(
             "{}/1.0/kb/invoices/{}/payments",
@@ -669,12 +692,12 @@ impl InvoiceApi {
                 &[
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|l| l.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/payments").await?;
+        self.handle_response(response).await
     }
 
     pub async fn modify_invoice_custom_fields(
@@ -695,14 +718,14 @@ impl InvoiceApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn search_invoices(
         &self,
         request: SearchInvoicesRequest<'_>
-    ) -> Result<Vec<models::Invoice>, InvoiceApiError> {
+    ) -> Result<Page<models::Invoice>, InvoiceApiError> {
         let url = format!(
             "{}/1.0/kb/invoices/search/{}",
             self.config.base_path,
@@ -716,12 +739,24 @@ impl InvoiceApi {
                 &[
                     ("offset", request.offset.map(|o| o.to_string())),
                     ("limit", request.limit.map(|l| l.to_string())),
-                    ("audit", request.audit.map(|l| l.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/search/{searchKey}").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_invoices_stream<'a>(
+        &'a self,
+        request: SearchInvoicesRequest<'a>
+    ) -> impl Stream<Item = Result<models::Invoice, InvoiceApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_invoices(request)
+        })
     }
 
     pub async fn upload_catalog_translation(
@@ -741,10 +776,11 @@ impl InvoiceApi {
             .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
-            .json(&request.body);
+            .header(CONTENT_TYPE, "text/plain")
+            .body(request.body.to_owned());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/catalogTranslation/{locale}").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn upload_invoice_mp_template(
@@ -760,10 +796,11 @@ impl InvoiceApi {
             .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
-            .json(&request.body);
+            .header(CONTENT_TYPE, "text/html")
+            .body(request.body.to_owned());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/manualPayTemplate").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn upload_invoice_template(
@@ -779,10 +816,11 @@ impl InvoiceApi {
             .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
-            .json(&request.body);
+            .header(CONTENT_TYPE, "text/html")
+            .body(request.body.to_owned());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/template").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn upload_invoice_translation(
@@ -802,10 +840,11 @@ impl InvoiceApi {
             .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
-            .json(&request.body);
+            .header(CONTENT_TYPE, "text/plain")
+            .body(request.body.to_owned());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoices/translation/{locale}").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn void_invoice(
@@ -825,13 +864,16 @@ impl InvoiceApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/invoices/{invoiceId}/voidInvoice").await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, InvoiceApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(InvoiceApiError::from)
@@ -843,6 +885,32 @@ impl InvoiceApi {
         }
     }
 
+    async fn handle_text_response(
+        &self,
+        response: Response
+    ) -> Result<String, InvoiceApiError> {
+        let response = self.config.follow_location(response).await?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                response.text().await.map_err(InvoiceApiError::from)
+            }
+            status => {
+                let text = response.text().await?;
+                Err(InvoiceApiError::from_response(status, text))
+            }
+        }
+    }
+
+    async fn handle_page_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<Page<T>, InvoiceApiError> {
+        let headers = response.headers().clone();
+        let items = self.handle_response(response).await?;
+        Ok(Page::new(items, &headers))
+    }
+
     async fn handle_empty_response(response: Response) -> Result<(), InvoiceApiError> {
         match response.status() {
             StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
@@ -856,7 +924,7 @@ impl InvoiceApi {
 
 #[derive(Debug, Clone)]
 pub struct AdjustInvoiceItemRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::InvoiceItem,
     pub(crate) requested_date: Option<String>,
@@ -873,7 +941,7 @@ impl<'a> AdjustInvoiceItemRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct AdjustInvoiceItemRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::InvoiceItem>,
     requested_date: Option<String>,
@@ -883,8 +951,8 @@ pub struct AdjustInvoiceItemRequestBuilder<'a> {
 }
 
 impl<'a> AdjustInvoiceItemRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -898,8 +966,8 @@ impl<'a> AdjustInvoiceItemRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -918,13 +986,15 @@ impl<'a> AdjustInvoiceItemRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AdjustInvoiceItemRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AdjustInvoiceItemRequest<'a>, InvoiceApiError> {
         Ok(AdjustInvoiceItemRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             requested_date: self.requested_date,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -935,7 +1005,7 @@ impl<'a> AdjustInvoiceItemRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CommitInvoiceRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -949,15 +1019,15 @@ impl<'a> CommitInvoiceRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CommitInvoiceRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> CommitInvoiceRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -976,11 +1046,13 @@ impl<'a> CommitInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CommitInvoiceRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CommitInvoiceRequest<'a>, InvoiceApiError> {
         Ok(CommitInvoiceRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -990,7 +1062,7 @@ impl<'a> CommitInvoiceRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateExternalChargesRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::InvoiceItem>,
     pub(crate) requested_date: Option<String>,
@@ -1008,7 +1080,7 @@ impl<'a> CreateExternalChargesRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateExternalChargesRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::InvoiceItem>>,
     requested_date: Option<String>,
@@ -1019,8 +1091,8 @@ pub struct CreateExternalChargesRequestBuilder<'a> {
 }
 
 impl<'a> CreateExternalChargesRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1034,8 +1106,8 @@ impl<'a> CreateExternalChargesRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -1059,13 +1131,15 @@ impl<'a> CreateExternalChargesRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateExternalChargesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateExternalChargesRequest<'a>, InvoiceApiError> {
         Ok(CreateExternalChargesRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             requested_date: self.requested_date,
             auto_commit: self.auto_commit,
             plugin_property: self.plugin_property,
@@ -1077,7 +1151,7 @@ impl<'a> CreateExternalChargesRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateFutureInvoiceRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) target_date: Option<String>,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -1093,7 +1167,7 @@ impl<'a> CreateFutureInvoiceRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateFutureInvoiceRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     target_date: Option<String>,
     plugin_property: Option<Vec<String>>,
@@ -1102,8 +1176,8 @@ pub struct CreateFutureInvoiceRequestBuilder<'a> {
 }
 
 impl<'a> CreateFutureInvoiceRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1112,8 +1186,8 @@ impl<'a> CreateFutureInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn target_date(mut self, target_date: impl Into<String>) -> Self {
-        self.target_date = Some(target_date.into());
+    pub fn target_date(mut self, target_date: impl models::DateParam) -> Self {
+        self.target_date = Some(target_date.into_param());
         self
     }
 
@@ -1132,11 +1206,13 @@ impl<'a> CreateFutureInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateFutureInvoiceRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateFutureInvoiceRequest<'a>, InvoiceApiError> {
         Ok(CreateFutureInvoiceRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             target_date: self.target_date,
             plugin_property: self.plugin_property,
@@ -1148,7 +1224,7 @@ impl<'a> CreateFutureInvoiceRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateFutureInvoiceGroupRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) target_date: Option<String>,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -1164,7 +1240,7 @@ impl<'a> CreateFutureInvoiceGroupRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateFutureInvoiceGroupRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     target_date: Option<String>,
     plugin_property: Option<Vec<String>>,
@@ -1173,8 +1249,8 @@ pub struct CreateFutureInvoiceGroupRequestBuilder<'a> {
 }
 
 impl<'a> CreateFutureInvoiceGroupRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1183,8 +1259,8 @@ impl<'a> CreateFutureInvoiceGroupRequestBuilder<'a> {
         self
     }
 
-    pub fn target_date(mut self, target_date: impl Into<String>) -> Self {
-        self.target_date = Some(target_date.into());
+    pub fn target_date(mut self, target_date: impl models::DateParam) -> Self {
+        self.target_date = Some(target_date.into_param());
         self
     }
 
@@ -1203,11 +1279,13 @@ impl<'a> CreateFutureInvoiceGroupRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateFutureInvoiceGroupRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateFutureInvoiceGroupRequest<'a>, InvoiceApiError> {
         Ok(CreateFutureInvoiceGroupRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             target_date: self.target_date,
             plugin_property: self.plugin_property,
@@ -1219,7 +1297,7 @@ impl<'a> CreateFutureInvoiceGroupRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateInstantPaymentRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::InvoicePayment,
     pub(crate) external_payment: Option<bool>,
@@ -1237,7 +1315,7 @@ impl<'a> CreateInstantPaymentRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateInstantPaymentRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::InvoicePayment>,
     external_payment: Option<bool>,
@@ -1248,8 +1326,8 @@ pub struct CreateInstantPaymentRequestBuilder<'a> {
 }
 
 impl<'a> CreateInstantPaymentRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -1288,13 +1366,15 @@ impl<'a> CreateInstantPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateInstantPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateInstantPaymentRequest<'a>, InvoiceApiError> {
         Ok(CreateInstantPaymentRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             external_payment: self.external_payment,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
@@ -1306,7 +1386,7 @@ impl<'a> CreateInstantPaymentRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateInvoiceCustomFieldsRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1321,7 +1401,7 @@ impl<'a> CreateInvoiceCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateInvoiceCustomFieldsRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -1329,8 +1409,8 @@ pub struct CreateInvoiceCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreateInvoiceCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -1354,13 +1434,15 @@ impl<'a> CreateInvoiceCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateInvoiceCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateInvoiceCustomFieldsRequest<'a>, InvoiceApiError> {
         Ok(CreateInvoiceCustomFieldsRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1369,7 +1451,7 @@ impl<'a> CreateInvoiceCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateInvoiceTagsRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1384,7 +1466,7 @@ impl<'a> CreateInvoiceTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateInvoiceTagsRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1392,8 +1474,8 @@ pub struct CreateInvoiceTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreateInvoiceTagsRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -1417,13 +1499,15 @@ impl<'a> CreateInvoiceTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateInvoiceTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateInvoiceTagsRequest<'a>, InvoiceApiError> {
         Ok(CreateInvoiceTagsRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1433,7 +1517,7 @@ impl<'a> CreateInvoiceTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateMigrationInvoiceRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::InvoiceItem>,
     pub(crate) target_date: Option<String>,
@@ -1449,7 +1533,7 @@ impl<'a> CreateMigrationInvoiceRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateMigrationInvoiceRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::InvoiceItem>>,
     target_date: Option<String>,
@@ -1458,8 +1542,8 @@ pub struct CreateMigrationInvoiceRequestBuilder<'a> {
 }
 
 impl<'a> CreateMigrationInvoiceRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1473,8 +1557,8 @@ impl<'a> CreateMigrationInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn target_date(mut self, target_date: impl Into<String>) -> Self {
-        self.target_date = Some(target_date.into());
+    pub fn target_date(mut self, target_date: impl models::DateParam) -> Self {
+        self.target_date = Some(target_date.into_param());
         self
     }
 
@@ -1488,11 +1572,15 @@ impl<'a> CreateMigrationInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateMigrationInvoiceRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateMigrationInvoiceRequest<'a>, InvoiceApiError> {
         Ok(CreateMigrationInvoiceRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             target_date: self.target_date,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -1501,7 +1589,7 @@ impl<'a> CreateMigrationInvoiceRequestBuilder<'a> {
 }
 #[derive(Debug, Clone)]
 pub struct DeleteInvoiceCustomFieldsRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1516,7 +1604,7 @@ impl<'a> DeleteInvoiceCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteInvoiceCustomFieldsRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1524,8 +1612,8 @@ pub struct DeleteInvoiceCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteInvoiceCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -1549,11 +1637,13 @@ impl<'a> DeleteInvoiceCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteInvoiceCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteInvoiceCustomFieldsRequest<'a>, InvoiceApiError> {
         Ok(DeleteInvoiceCustomFieldsRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -1564,7 +1654,7 @@ impl<'a> DeleteInvoiceCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteInvoiceTagsRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1579,7 +1669,7 @@ impl<'a> DeleteInvoiceTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteInvoiceTagsRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1587,8 +1677,8 @@ pub struct DeleteInvoiceTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteInvoiceTagsRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -1612,11 +1702,13 @@ impl<'a> DeleteInvoiceTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteInvoiceTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteInvoiceTagsRequest<'a>, InvoiceApiError> {
         Ok(DeleteInvoiceTagsRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -1626,28 +1718,28 @@ impl<'a> DeleteInvoiceTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+pub struct GetInvoiceRequest {
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) with_children_items: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceRequest<'a> {
-    pub fn builder() -> GetInvoiceRequestBuilder<'a> {
+impl GetInvoiceRequest {
+    pub fn builder() -> GetInvoiceRequestBuilder {
         GetInvoiceRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+pub struct GetInvoiceRequestBuilder {
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_children_items: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+impl GetInvoiceRequestBuilder {
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -1656,14 +1748,16 @@ impl<'a> GetInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceRequest, InvoiceApiError> {
         Ok(GetInvoiceRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             with_children_items: self.with_children_items,
             audit: self.audit,
         })
@@ -1672,7 +1766,7 @@ impl<'a> GetInvoiceRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateTaxItemsRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::InvoiceItem>,
     pub(crate) auto_commit: Option<bool>,
@@ -1690,7 +1784,7 @@ impl<'a> CreateTaxItemsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateTaxItemsRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::InvoiceItem>>,
     auto_commit: Option<bool>,
@@ -1701,8 +1795,8 @@ pub struct CreateTaxItemsRequestBuilder<'a> {
 }
 
 impl<'a> CreateTaxItemsRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1721,8 +1815,8 @@ impl<'a> CreateTaxItemsRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -1741,11 +1835,15 @@ impl<'a> CreateTaxItemsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateTaxItemsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateTaxItemsRequest<'a>, InvoiceApiError> {
         Ok(CreateTaxItemsRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             auto_commit: self.auto_commit,
             requested_date: self.requested_date,
             plugin_property: self.plugin_property,
@@ -1757,9 +1855,9 @@ impl<'a> CreateTaxItemsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteCbaRequest<'a> {
-    pub(crate) invoice_id: &'a str,
-    pub(crate) invoice_item_id: &'a str,
-    pub(crate) account_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
+    pub(crate) invoice_item_id: uuid::Uuid,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -1773,27 +1871,27 @@ impl<'a> DeleteCbaRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteCbaRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
-    invoice_item_id: Option<&'a str>,
-    account_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> DeleteCbaRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1812,12 +1910,20 @@ impl<'a> DeleteCbaRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteCbaRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteCbaRequest<'a>, InvoiceApiError> {
         Ok(DeleteCbaRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1826,7 +1932,7 @@ impl<'a> DeleteCbaRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct GenerateDryRunInvoiceRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::InvoiceDryRun,
     pub(crate) target_date: Option<String>,
@@ -1843,7 +1949,7 @@ impl<'a> GenerateDryRunInvoiceRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct GenerateDryRunInvoiceRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::InvoiceDryRun>,
     target_date: Option<String>,
@@ -1853,8 +1959,8 @@ pub struct GenerateDryRunInvoiceRequestBuilder<'a> {
 }
 
 impl<'a> GenerateDryRunInvoiceRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -1868,8 +1974,8 @@ impl<'a> GenerateDryRunInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn target_date(mut self, target_date: impl Into<String>) -> Self {
-        self.target_date = Some(target_date.into());
+    pub fn target_date(mut self, target_date: impl models::DateParam) -> Self {
+        self.target_date = Some(target_date.into_param());
         self
     }
 
@@ -1888,11 +1994,15 @@ impl<'a> GenerateDryRunInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GenerateDryRunInvoiceRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GenerateDryRunInvoiceRequest<'a>, InvoiceApiError> {
         Ok(GenerateDryRunInvoiceRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
-            x_killbill_created_by: self.x_killbill_created_by.ok_or("x_killbill_created_by is required")?,
-            body: self.body.ok_or("body is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             target_date: self.target_date,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1923,94 +2033,98 @@ impl<'a> GetCatalogTranslationRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetCatalogTranslationRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetCatalogTranslationRequest<'a>, InvoiceApiError> {
         Ok(GetCatalogTranslationRequest {
-            locale: self.locale.ok_or("locale is required")?,
+            locale: self.locale.ok_or(ParamError::missing("locale"))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceAsHtmlRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+pub struct GetInvoiceAsHtmlRequest {
+    pub(crate) invoice_id: uuid::Uuid,
 }
 
-impl<'a> GetInvoiceAsHtmlRequest<'a> {
-    pub fn builder() -> GetInvoiceAsHtmlRequestBuilder<'a> {
+impl GetInvoiceAsHtmlRequest {
+    pub fn builder() -> GetInvoiceAsHtmlRequestBuilder {
         GetInvoiceAsHtmlRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceAsHtmlRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+pub struct GetInvoiceAsHtmlRequestBuilder {
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetInvoiceAsHtmlRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+impl GetInvoiceAsHtmlRequestBuilder {
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceAsHtmlRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceAsHtmlRequest, InvoiceApiError> {
         Ok(GetInvoiceAsHtmlRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceAuditLogsWithHistoryRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+pub struct GetInvoiceAuditLogsWithHistoryRequest {
+    pub(crate) invoice_id: uuid::Uuid,
 }
 
-impl<'a> GetInvoiceAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetInvoiceAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetInvoiceAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetInvoiceAuditLogsWithHistoryRequestBuilder {
         GetInvoiceAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceAuditLogsWithHistoryRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+pub struct GetInvoiceAuditLogsWithHistoryRequestBuilder {
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetInvoiceAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+impl GetInvoiceAuditLogsWithHistoryRequestBuilder {
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceAuditLogsWithHistoryRequest, InvoiceApiError> {
         Ok(GetInvoiceAuditLogsWithHistoryRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceByItemIdRequest<'a> {
-    pub(crate) item_id: &'a str,
+pub struct GetInvoiceByItemIdRequest {
+    pub(crate) item_id: uuid::Uuid,
     pub(crate) with_children_items: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceByItemIdRequest<'a> {
-    pub fn builder() -> GetInvoiceByItemIdRequestBuilder<'a> {
+impl GetInvoiceByItemIdRequest {
+    pub fn builder() -> GetInvoiceByItemIdRequestBuilder {
         GetInvoiceByItemIdRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceByItemIdRequestBuilder<'a> {
-    item_id: Option<&'a str>,
+pub struct GetInvoiceByItemIdRequestBuilder {
+    item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_children_items: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceByItemIdRequestBuilder<'a> {
-    pub fn item_id(mut self, item_id: &'a str) -> Self {
-        self.item_id = Some(item_id);
+impl GetInvoiceByItemIdRequestBuilder {
+    pub fn item_id(mut self, item_id: impl models::IdParam) -> Self {
+        self.item_id = Some(item_id.into_id());
         self
     }
 
@@ -2019,14 +2133,16 @@ impl<'a> GetInvoiceByItemIdRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceByItemIdRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceByItemIdRequest, InvoiceApiError> {
         Ok(GetInvoiceByItemIdRequest {
-            item_id: self.item_id.ok_or("item_id is required")?,
+            item_id: self.item_id
+                .ok_or(ParamError::missing("item_id"))?
+                .map_err(|e| ParamError::invalid_id("item_id", e))?,
             with_children_items: self.with_children_items,
             audit: self.audit,
         })
@@ -2034,26 +2150,26 @@ impl<'a> GetInvoiceByItemIdRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceByNumberRequest<'a> {
+pub struct GetInvoiceByNumberRequest {
     pub(crate) invoice_number: i32,
     pub(crate) with_children_items: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceByNumberRequest<'a> {
-    pub fn builder() -> GetInvoiceByNumberRequestBuilder<'a> {
+impl GetInvoiceByNumberRequest {
+    pub fn builder() -> GetInvoiceByNumberRequestBuilder {
         GetInvoiceByNumberRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceByNumberRequestBuilder<'a> {
+pub struct GetInvoiceByNumberRequestBuilder {
     invoice_number: Option<i32>,
     with_children_items: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceByNumberRequestBuilder<'a> {
+impl GetInvoiceByNumberRequestBuilder {
     pub fn invoice_number(mut self, invoice_number: i32) -> Self {
         self.invoice_number = Some(invoice_number);
         self
@@ -2064,14 +2180,14 @@ impl<'a> GetInvoiceByNumberRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceByNumberRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceByNumberRequest, InvoiceApiError> {
         Ok(GetInvoiceByNumberRequest {
-            invoice_number: self.invoice_number.ok_or("invoice_number is required")?,
+            invoice_number: self.invoice_number.ok_or(ParamError::missing("invoice_number"))?,
             with_children_items: self.with_children_items,
             audit: self.audit,
         })
@@ -2079,37 +2195,39 @@ impl<'a> GetInvoiceByNumberRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceCustomFieldsRequest<'a> {
-    pub(crate) invoice_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetInvoiceCustomFieldsRequest {
+    pub(crate) invoice_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceCustomFieldsRequest<'a> {
-    pub fn builder() -> GetInvoiceCustomFieldsRequestBuilder<'a> {
+impl GetInvoiceCustomFieldsRequest {
+    pub fn builder() -> GetInvoiceCustomFieldsRequestBuilder {
         GetInvoiceCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceCustomFieldsRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetInvoiceCustomFieldsRequestBuilder {
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+impl GetInvoiceCustomFieldsRequestBuilder {
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceCustomFieldsRequest, InvoiceApiError> {
         Ok(GetInvoiceCustomFieldsRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             audit: self.audit,
         })
     }
@@ -2137,38 +2255,38 @@ impl<'a> GetInvoiceMpTemplateRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceMpTemplateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceMpTemplateRequest<'a>, InvoiceApiError> {
         Ok(GetInvoiceMpTemplateRequest {
-            locale: self.locale.ok_or("locale is required")?,
+            locale: self.locale.ok_or(ParamError::missing("locale"))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceTagsRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+pub struct GetInvoiceTagsRequest {
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceTagsRequest<'a> {
-    pub fn builder() -> GetInvoiceTagsRequestBuilder<'a> {
+impl GetInvoiceTagsRequest {
+    pub fn builder() -> GetInvoiceTagsRequestBuilder {
         GetInvoiceTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceTagsRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+pub struct GetInvoiceTagsRequestBuilder {
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceTagsRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+impl GetInvoiceTagsRequestBuilder {
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -2182,14 +2300,16 @@ impl<'a> GetInvoiceTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceTagsRequest, InvoiceApiError> {
         Ok(GetInvoiceTagsRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             included_deleted: self.included_deleted,
             plugin_property: self.plugin_property,
             audit: self.audit,
@@ -2219,34 +2339,34 @@ impl<'a> GetInvoiceTranslationRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceTranslationRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceTranslationRequest<'a>, InvoiceApiError> {
         Ok(GetInvoiceTranslationRequest {
-            locale: self.locale.ok_or("locale is required")?,
+            locale: self.locale.ok_or(ParamError::missing("locale"))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicesRequest<'a> {
+pub struct GetInvoicesRequest {
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicesRequest<'a> {
-    pub fn builder() -> GetInvoicesRequestBuilder<'a> {
+impl GetInvoicesRequest {
+    pub fn builder() -> GetInvoicesRequestBuilder {
         GetInvoicesRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicesRequestBuilder<'a> {
+pub struct GetInvoicesRequestBuilder {
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicesRequestBuilder<'a> {
+impl GetInvoicesRequestBuilder {
     pub fn offset(mut self, offset: i64) -> Self {
         self.offset = Some(offset);
         self
@@ -2257,12 +2377,12 @@ impl<'a> GetInvoicesRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicesRequest, InvoiceApiError> {
         Ok(GetInvoicesRequest {
             offset: self.offset,
             limit: self.limit,
@@ -2272,35 +2392,35 @@ impl<'a> GetInvoicesRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicesGroupRequest<'a> {
-    pub(crate) group_id: &'a str,
-    pub(crate) account_id: &'a str,
+pub struct GetInvoicesGroupRequest {
+    pub(crate) group_id: uuid::Uuid,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) with_children_items: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicesGroupRequest<'a> {
-    pub fn builder() -> GetInvoicesGroupRequestBuilder<'a> {
+impl GetInvoicesGroupRequest {
+    pub fn builder() -> GetInvoicesGroupRequestBuilder {
         GetInvoicesGroupRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicesGroupRequestBuilder<'a> {
-    group_id: Option<&'a str>,
-    account_id: Option<&'a str>,
+pub struct GetInvoicesGroupRequestBuilder {
+    group_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_children_items: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicesGroupRequestBuilder<'a> {
-    pub fn group_id(mut self, group_id: &'a str) -> Self {
-        self.group_id = Some(group_id);
+impl GetInvoicesGroupRequestBuilder {
+    pub fn group_id(mut self, group_id: impl models::IdParam) -> Self {
+        self.group_id = Some(group_id.into_id());
         self
     }
 
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -2309,15 +2429,19 @@ impl<'a> GetInvoicesGroupRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicesGroupRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicesGroupRequest, InvoiceApiError> {
         Ok(GetInvoicesGroupRequest {
-            group_id: self.group_id.ok_or("group_id is required")?,
-            account_id: self.account_id.ok_or("account_id is required")?,
+            group_id: self.group_id
+                .ok_or(ParamError::missing("group_id"))?
+                .map_err(|e| ParamError::invalid_id("group_id", e))?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             with_children_items: self.with_children_items,
             audit: self.audit,
         })
@@ -2325,30 +2449,30 @@ impl<'a> GetInvoicesGroupRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentsForInvoiceRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+pub struct GetPaymentsForInvoiceRequest {
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentsForInvoiceRequest<'a> {
-    pub fn builder() -> GetPaymentsForInvoiceRequestBuilder<'a> {
+impl GetPaymentsForInvoiceRequest {
+    pub fn builder() -> GetPaymentsForInvoiceRequestBuilder {
         GetPaymentsForInvoiceRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentsForInvoiceRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+pub struct GetPaymentsForInvoiceRequestBuilder {
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentsForInvoiceRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+impl GetPaymentsForInvoiceRequestBuilder {
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -2362,14 +2486,16 @@ impl<'a> GetPaymentsForInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentsForInvoiceRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentsForInvoiceRequest, InvoiceApiError> {
         Ok(GetPaymentsForInvoiceRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             with_plugin_info: self.with_plugin_info,
             with_attempts: self.with_attempts,
             audit: self.audit,
@@ -2379,7 +2505,7 @@ impl<'a> GetPaymentsForInvoiceRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyInvoiceCustomFieldsRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -2394,7 +2520,7 @@ impl<'a> ModifyInvoiceCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyInvoiceCustomFieldsRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -2402,8 +2528,8 @@ pub struct ModifyInvoiceCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyInvoiceCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -2427,13 +2553,15 @@ impl<'a> ModifyInvoiceCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyInvoiceCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyInvoiceCustomFieldsRequest<'a>, InvoiceApiError> {
         Ok(ModifyInvoiceCustomFieldsRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -2445,7 +2573,7 @@ pub struct SearchInvoicesRequest<'a> {
     pub(crate) search_key: &'a str,
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchInvoicesRequest<'a> {
@@ -2459,7 +2587,7 @@ pub struct SearchInvoicesRequestBuilder<'a> {
     search_key: Option<&'a str>,
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchInvoicesRequestBuilder<'a> {
@@ -2478,14 +2606,14 @@ impl<'a> SearchInvoicesRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<SearchInvoicesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SearchInvoicesRequest<'a>, InvoiceApiError> {
         Ok(SearchInvoicesRequest {
-            search_key: self.search_key.ok_or("search_key is required")?,
+            search_key: self.search_key.ok_or(ParamError::missing("search_key"))?,
             offset: self.offset,
             limit: self.limit,
             audit: self.audit,
@@ -2550,13 +2678,13 @@ impl<'a> UploadCatalogTranslationRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UploadCatalogTranslationRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UploadCatalogTranslationRequest<'a>, InvoiceApiError> {
         Ok(UploadCatalogTranslationRequest {
-            locale: self.locale.ok_or("locale is required")?,
+            locale: self.locale.ok_or(ParamError::missing("locale"))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             delete_if_exists: self.delete_if_exists,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -2614,12 +2742,12 @@ impl<'a> UploadInvoiceMpTemplateRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UploadInvoiceMpTemplateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UploadInvoiceMpTemplateRequest<'a>, InvoiceApiError> {
         Ok(UploadInvoiceMpTemplateRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             delete_if_exists: self.delete_if_exists,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -2677,12 +2805,12 @@ impl<'a> UploadInvoiceTemplateRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UploadInvoiceTemplateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UploadInvoiceTemplateRequest<'a>, InvoiceApiError> {
         Ok(UploadInvoiceTemplateRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             delete_if_exists: self.delete_if_exists,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -2747,13 +2875,13 @@ impl<'a> UploadInvoiceTranslationRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UploadInvoiceTranslationRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UploadInvoiceTranslationRequest<'a>, InvoiceApiError> {
         Ok(UploadInvoiceTranslationRequest {
-            locale: self.locale.ok_or("locale is required")?,
+            locale: self.locale.ok_or(ParamError::missing("locale"))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             delete_if_exists: self.delete_if_exists,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -2763,7 +2891,7 @@ impl<'a> UploadInvoiceTranslationRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct VoidInvoiceRequest<'a> {
-    pub(crate) invoice_id: &'a str,
+    pub(crate) invoice_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -2777,15 +2905,15 @@ impl<'a> VoidInvoiceRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct VoidInvoiceRequestBuilder<'a> {
-    invoice_id: Option<&'a str>,
+    invoice_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> VoidInvoiceRequestBuilder<'a> {
-    pub fn invoice_id(mut self, invoice_id: &'a str) -> Self {
-        self.invoice_id = Some(invoice_id);
+    pub fn invoice_id(mut self, invoice_id: impl models::IdParam) -> Self {
+        self.invoice_id = Some(invoice_id.into_id());
         self
     }
 
@@ -2804,11 +2932,13 @@ impl<'a> VoidInvoiceRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<VoidInvoiceRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<VoidInvoiceRequest<'a>, InvoiceApiError> {
         Ok(VoidInvoiceRequest {
-            invoice_id: self.invoice_id.ok_or("invoice_id is required")?,
+            invoice_id: self.invoice_id
+                .ok_or(ParamError::missing("invoice_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -2829,7 +2959,10 @@ pub enum InvoiceApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl InvoiceApiError {
@@ -2857,17 +2990,17 @@ mod tests {
     async fn test_adjust_invoice_item() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/test-invoice-id")
+            .mock("POST", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"invoice_id": "test-invoice-id"}"#)
+            .with_body(r#"{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = AdjustInvoiceItemRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .body(InvoiceItem {
                 ..Default::default()
@@ -2885,7 +3018,7 @@ mod tests {
     async fn test_commit_invoice() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/invoices/test-invoice-id/commitInvoice")
+            .mock("PUT", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/commitInvoice")
             .with_status(204)
             .create_async().await;
 
@@ -2893,7 +3026,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = CommitInvoiceRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2908,17 +3041,17 @@ mod tests {
     async fn test_create_external_charges() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/charges/test-account-id")
+            .mock("POST", "/1.0/kb/invoices/charges/527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"invoice_item_id": "test-invoice-item-id"}]"#)
+            .with_body(r#"[{"invoice_item_id": "241fa9ac-ed48-53d7-94c3-7dbc46547acb"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = CreateExternalChargesRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(
                 vec![InvoiceItem {
@@ -2938,17 +3071,17 @@ mod tests {
     async fn test_create_future_invoice() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices?accountId=test-account-id")
+            .mock("POST", "/1.0/kb/invoices?accountId=527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"invoice_id": "test-invoice-id"}"#)
+            .with_body(r#"{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = CreateFutureInvoiceRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2963,17 +3096,17 @@ mod tests {
     async fn test_create_future_invoice_group() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/group?accountId=test-account-id")
+            .mock("POST", "/1.0/kb/invoices/group?accountId=527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"invoice_id": "test-invoice-id"}]"#)
+            .with_body(r#"[{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = CreateFutureInvoiceGroupRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2988,7 +3121,7 @@ mod tests {
     async fn test_create_instant_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/test-invoice-id/payments")
+            .mock("POST", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/payments")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"payment_id": "test-payment-id"}"#)
@@ -2998,7 +3131,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = CreateInstantPaymentRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .body(InvoicePayment {
                 ..Default::default()
@@ -3016,7 +3149,7 @@ mod tests {
     async fn test_create_invoice_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/test-invoice-id/customFields")
+            .mock("POST", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -3026,7 +3159,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = CreateInvoiceCustomFieldsRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -3046,7 +3179,7 @@ mod tests {
     async fn test_create_invoice_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/test-invoice-id/tags")
+            .mock("POST", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -3056,7 +3189,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = CreateInvoiceTagsRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -3072,17 +3205,17 @@ mod tests {
     async fn test_create_migration_invoice() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/migration/test-account-id")
+            .mock("POST", "/1.0/kb/invoices/migration/527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"invoice_id": "test-invoice-id"}"#)
+            .with_body(r#"{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = CreateMigrationInvoiceRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(
                 vec![InvoiceItem {
@@ -3102,17 +3235,17 @@ mod tests {
     async fn test_create_tax_items() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/taxes/test-account-id")
+            .mock("POST", "/1.0/kb/invoices/taxes/527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"invoice_item_id": "test-invoice-item-id"}]"#)
+            .with_body(r#"[{"invoice_item_id": "241fa9ac-ed48-53d7-94c3-7dbc46547acb"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = CreateTaxItemsRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(
                 vec![InvoiceItem {
@@ -3134,7 +3267,7 @@ mod tests {
         let mock = server
             .mock(
                 "DELETE",
-                "/1.0/kb/invoices/test-invoice-id/test-invoice-item-id/cba?accountId=test-account-id"
+                "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/241fa9ac-ed48-53d7-94c3-7dbc46547acb/cba?accountId=527a22fb-a40b-548b-ad48-290cf05ddc8c"
             )
             .with_status(204)
             .create_async().await;
@@ -3143,9 +3276,9 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = DeleteCbaRequest::builder()
-            .invoice_id("test-invoice-id")
-            .invoice_item_id("test-invoice-item-id")
-            .account_id("test-account-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3160,7 +3293,7 @@ mod tests {
     async fn test_delete_invoice_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/invoices/test-invoice-id/customFields")
+            .mock("DELETE", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -3168,7 +3301,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = DeleteInvoiceCustomFieldsRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3183,7 +3316,7 @@ mod tests {
     async fn test_delete_invoice_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/invoices/test-invoice-id/tags")
+            .mock("DELETE", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/tags")
             .with_status(204)
             .create_async().await;
 
@@ -3191,7 +3324,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = DeleteInvoiceTagsRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3206,17 +3339,17 @@ mod tests {
     async fn test_generate_dry_run_invoice() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoices/dryRun?accountId=test-account-id")
+            .mock("POST", "/1.0/kb/invoices/dryRun?accountId=527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"invoice_id": "test-invoice-id"}"#)
+            .with_body(r#"{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = GenerateDryRunInvoiceRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(models::InvoiceDryRun {
                 ..Default::default()
@@ -3235,9 +3368,10 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("GET", "/1.0/kb/invoices/catalogTranslation/fr_CA")
+            .match_header("accept", "text/plain")
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(r#""test-translation""#)
+            .with_header("content-type", "text/plain")
+            .with_body("test-translation")
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3246,7 +3380,7 @@ mod tests {
         let request = GetCatalogTranslationRequest::builder().locale("fr_CA").build().unwrap();
 
         let result = api.get_catalog_translation(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-translation");
 
         mock.assert_async().await;
     }
@@ -3255,16 +3389,16 @@ mod tests {
     async fn test_get_invoice() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/test-invoice-id")
+            .mock("GET", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"invoice_id": "test-invoice-id"}"#)
+            .with_body(r#"{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
-        let request = GetInvoiceRequest::builder().invoice_id("test-invoice-id").build().unwrap();
+        let request = GetInvoiceRequest::builder().invoice_id("658e8d48-80d0-5e8a-9742-b97869465715").build().unwrap();
 
         let result = api.get_invoice(request).await;
         assert!(result.is_ok());
@@ -3276,7 +3410,8 @@ mod tests {
     async fn test_get_invoice_as_html() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/test-invoice-id/html")
+            .mock("GET", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/html")
+            .match_header("accept", "text/html")
             .with_status(200)
             .with_header("content-type", "text/html")
             .with_body("<html><body>test</body></html>")
@@ -3286,12 +3421,12 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = GetInvoiceAsHtmlRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .build()
             .unwrap();
 
         let result = api.get_invoice_as_html(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "<html><body>test</body></html>");
 
         mock.assert_async().await;
     }
@@ -3300,7 +3435,7 @@ mod tests {
     async fn test_get_invoice_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/test-invoice-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -3310,7 +3445,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = GetInvoiceAuditLogsWithHistoryRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .build()
             .unwrap();
 
@@ -3324,16 +3459,16 @@ mod tests {
     async fn test_get_invoice_by_item_id() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/byItemId/test-item-id")
+            .mock("GET", "/1.0/kb/invoices/byItemId/53e275ed-d318-5b88-b10f-c08c443d9be1")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"invoice_id": "test-invoice-id"}"#)
+            .with_body(r#"{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
-        let request = GetInvoiceByItemIdRequest::builder().item_id("test-item-id").build().unwrap();
+        let request = GetInvoiceByItemIdRequest::builder().item_id("53e275ed-d318-5b88-b10f-c08c443d9be1").build().unwrap();
 
         let result = api.get_invoice_by_item_id(request).await;
         assert!(result.is_ok());
@@ -3348,7 +3483,7 @@ mod tests {
             .mock("GET", "/1.0/kb/invoices/byNumber/1234")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"invoice_id": "test-invoice-id"}"#)
+            .with_body(r#"{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3366,7 +3501,7 @@ mod tests {
     async fn test_get_invoice_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/test-invoice-id/customFields")
+            .mock("GET", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -3376,7 +3511,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = GetInvoiceCustomFieldsRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .build()
             .unwrap();
 
@@ -3391,6 +3526,7 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("GET", "/1.0/kb/invoices/manualPayTemplate/fr_CA")
+            .match_header("accept", "text/html")
             .with_status(200)
             .with_header("content-type", "text/html")
             .with_body("<html><body>test</body></html>")
@@ -3402,7 +3538,7 @@ mod tests {
         let request = GetInvoiceMpTemplateRequest::builder().locale("fr_CA").build().unwrap();
 
         let result = api.get_invoice_mp_template(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "<html><body>test</body></html>");
 
         mock.assert_async().await;
     }
@@ -3411,7 +3547,7 @@ mod tests {
     async fn test_get_invoice_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/test-invoice-id/tags")
+            .mock("GET", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -3421,7 +3557,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = GetInvoiceTagsRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .build()
             .unwrap();
 
@@ -3436,6 +3572,7 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("GET", "/1.0/kb/invoices/template")
+            .match_header("accept", "text/html")
             .with_status(200)
             .with_header("content-type", "text/html")
             .with_body("<html><body>test</body></html>")
@@ -3445,7 +3582,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let result = api.get_invoice_template().await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "<html><body>test</body></html>");
 
         mock.assert_async().await;
     }
@@ -3455,9 +3592,10 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("GET", "/1.0/kb/invoices/translation/fr_CA")
+            .match_header("accept", "text/plain")
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(r#""test-translation""#)
+            .with_header("content-type", "text/plain")
+            .with_body("test-translation")
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3466,7 +3604,7 @@ mod tests {
         let request = GetInvoiceTranslationRequest::builder().locale("fr_CA").build().unwrap();
 
         let result = api.get_invoice_translation(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-translation");
 
         mock.assert_async().await;
     }
@@ -3478,7 +3616,7 @@ mod tests {
             .mock("GET", "/1.0/kb/invoices/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"invoice_id": "test-invoice-id"}]"#)
+            .with_body(r#"[{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3496,18 +3634,18 @@ mod tests {
     async fn test_get_invoices_group() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/test-group-id/group?accountId=test-account-id")
+            .mock("GET", "/1.0/kb/invoices/cccc044d-2423-5033-bab3-f4870317393b/group?accountId=527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"invoice_id": "test-invoice-id"}]"#)
+            .with_body(r#"[{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoiceApi::new(config);
         let request = GetInvoicesGroupRequest::builder()
-            .group_id("test-group-id")
-            .account_id("test-account-id")
+            .group_id("cccc044d-2423-5033-bab3-f4870317393b")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -3521,7 +3659,7 @@ mod tests {
     async fn test_get_payments_for_invoice() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoices/test-invoice-id/payments")
+            .mock("GET", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/payments")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"payment_id": "test-payment-id"}]"#)
@@ -3531,7 +3669,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = GetPaymentsForInvoiceRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .build()
             .unwrap();
 
@@ -3545,7 +3683,7 @@ mod tests {
     async fn test_modify_invoice_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/invoices/test-invoice-id/customFields")
+            .mock("PUT", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -3553,7 +3691,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = ModifyInvoiceCustomFieldsRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -3576,7 +3714,7 @@ mod tests {
             .mock("GET", "/1.0/kb/invoices/search/test-search-key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"invoice_id": "test-invoice-id"}]"#)
+            .with_body(r#"[{"invoice_id": "658e8d48-80d0-5e8a-9742-b97869465715"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3598,9 +3736,11 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("POST", "/1.0/kb/invoices/catalogTranslation/fr_CA")
+            .match_header("content-type", "text/plain")
+            .match_body("test-translation")
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(r#""test-translation""#)
+            .with_header("content-type", "text/plain")
+            .with_body("test-translation")
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3614,7 +3754,7 @@ mod tests {
             .unwrap();
 
         let result = api.upload_catalog_translation(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-translation");
 
         mock.assert_async().await;
     }
@@ -3624,6 +3764,8 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("POST", "/1.0/kb/invoices/manualPayTemplate")
+            .match_header("content-type", "text/html")
+            .match_body("<html><body>test</body></html>")
             .with_status(200)
             .with_header("content-type", "text/html")
             .with_body("<html><body>test</body></html>")
@@ -3639,7 +3781,7 @@ mod tests {
             .unwrap();
 
         let result = api.upload_invoice_mp_template(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "<html><body>test</body></html>");
 
         mock.assert_async().await;
     }
@@ -3649,6 +3791,8 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("POST", "/1.0/kb/invoices/template")
+            .match_header("content-type", "text/html")
+            .match_body("<html><body>test</body></html>")
             .with_status(200)
             .with_header("content-type", "text/html")
             .with_body("<html><body>test</body></html>")
@@ -3664,7 +3808,7 @@ mod tests {
             .unwrap();
 
         let result = api.upload_invoice_template(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "<html><body>test</body></html>");
 
         mock.assert_async().await;
     }
@@ -3674,9 +3818,11 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("POST", "/1.0/kb/invoices/translation/fr_CA")
+            .match_header("content-type", "text/plain")
+            .match_body("test-translation")
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(r#""test-translation""#)
+            .with_header("content-type", "text/plain")
+            .with_body("test-translation")
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3690,7 +3836,7 @@ mod tests {
             .unwrap();
 
         let result = api.upload_invoice_translation(request).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "test-translation");
 
         mock.assert_async().await;
     }
@@ -3699,7 +3845,7 @@ mod tests {
     async fn test_void_invoice() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/invoices/test-invoice-id/voidInvoice")
+            .mock("PUT", "/1.0/kb/invoices/658e8d48-80d0-5e8a-9742-b97869465715/voidInvoice")
             .with_status(204)
             .create_async().await;
 
@@ -3707,7 +3853,7 @@ mod tests {
 
         let api = InvoiceApi::new(config);
         let request = VoidInvoiceRequest::builder()
-            .invoice_id("test-invoice-id")
+            .invoice_id("658e8d48-80d0-5e8a-9742-b97869465715")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
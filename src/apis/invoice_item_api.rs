@@ -1,7 +1,8 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use uuid::Uuid;
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct InvoiceItemApi {
@@ -31,8 +32,10 @@ impl InvoiceItemApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_invoice_item_tags(
@@ -53,8 +56,8 @@ impl InvoiceItemApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_invoice_item_custom_fields(
@@ -81,7 +84,9 @@ impl InvoiceItemApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -109,13 +114,13 @@ impl InvoiceItemApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_invoice_item_audit_logs_with_history(
         &self,
-        request: GetInvoiceItemAuditLogsWithHistoryRequest<'_>
+        request: GetInvoiceItemAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, InvoiceItemApiError> {
         let url = format!(
             "{}/1.0/kb/invoiceItems/{}/auditLogsWithHistory",
@@ -127,13 +132,15 @@ impl InvoiceItemApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_item_custom_fields(
         &self,
-        request: GetInvoiceItemCustomFieldsRequest<'_>
+        request: GetInvoiceItemCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, InvoiceItemApiError> {
         let url = format!(
             "{}/1.0/kb/invoiceItems/{}/customFields",
@@ -144,15 +151,17 @@ impl InvoiceItemApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_item_tags(
         &self,
-        request: GetInvoiceItemTagsRequest<'_>
+        request: GetInvoiceItemTagsRequest
     ) -> Result<Vec<models::Tag>, InvoiceItemApiError> {
         let url = format!(
             "{}/1.0/kb/invoiceItems/{}/tags",
@@ -165,19 +174,19 @@ impl InvoiceItemApi {
             .headers(self.config.get_auth_headers())
             .query(
                 &[
-                    ("accountId", request.account_id),
+                    ("accountId", request.account_id.to_string().as_str()),
                     (
                         "includedDeleted",
                         request.included_deleted
                             .map(|b| if b { "true" } else { "false" })
                             .unwrap_or("false"),
                     ),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn modify_invoice_item_custom_fields(
@@ -198,13 +207,18 @@ impl InvoiceItemApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/invoiceItems/{invoiceItemId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, InvoiceItemApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(InvoiceItemApiError::from)
@@ -229,7 +243,7 @@ impl InvoiceItemApi {
 
 #[derive(Debug, Clone)]
 pub struct CreateInvoiceItemCustomFieldsRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
+    pub(crate) invoice_item_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -244,7 +258,7 @@ impl<'a> CreateInvoiceItemCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateInvoiceItemCustomFieldsRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -252,8 +266,8 @@ pub struct CreateInvoiceItemCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreateInvoiceItemCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
@@ -277,13 +291,15 @@ impl<'a> CreateInvoiceItemCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateInvoiceItemCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateInvoiceItemCustomFieldsRequest<'a>, InvoiceItemApiError> {
         Ok(CreateInvoiceItemCustomFieldsRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -292,7 +308,7 @@ impl<'a> CreateInvoiceItemCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateInvoiceItemTagsRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
+    pub(crate) invoice_item_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -307,7 +323,7 @@ impl<'a> CreateInvoiceItemTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateInvoiceItemTagsRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -315,8 +331,8 @@ pub struct CreateInvoiceItemTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreateInvoiceItemTagsRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
@@ -340,13 +356,15 @@ impl<'a> CreateInvoiceItemTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateInvoiceItemTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateInvoiceItemTagsRequest<'a>, InvoiceItemApiError> {
         Ok(CreateInvoiceItemTagsRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -355,7 +373,7 @@ impl<'a> CreateInvoiceItemTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteInvoiceItemCustomFieldsRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
+    pub(crate) invoice_item_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -370,7 +388,7 @@ impl<'a> DeleteInvoiceItemCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteInvoiceItemCustomFieldsRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -378,8 +396,8 @@ pub struct DeleteInvoiceItemCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteInvoiceItemCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
@@ -403,11 +421,13 @@ impl<'a> DeleteInvoiceItemCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteInvoiceItemCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteInvoiceItemCustomFieldsRequest<'a>, InvoiceItemApiError> {
         Ok(DeleteInvoiceItemCustomFieldsRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -418,7 +438,7 @@ impl<'a> DeleteInvoiceItemCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteInvoiceItemTagsRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
+    pub(crate) invoice_item_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -433,7 +453,7 @@ impl<'a> DeleteInvoiceItemTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteInvoiceItemTagsRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -441,8 +461,8 @@ pub struct DeleteInvoiceItemTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteInvoiceItemTagsRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
@@ -466,11 +486,13 @@ impl<'a> DeleteInvoiceItemTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteInvoiceItemTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteInvoiceItemTagsRequest<'a>, InvoiceItemApiError> {
         Ok(DeleteInvoiceItemTagsRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -480,101 +502,105 @@ impl<'a> DeleteInvoiceItemTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceItemAuditLogsWithHistoryRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
+pub struct GetInvoiceItemAuditLogsWithHistoryRequest {
+    pub(crate) invoice_item_id: uuid::Uuid,
 }
 
-impl<'a> GetInvoiceItemAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetInvoiceItemAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetInvoiceItemAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetInvoiceItemAuditLogsWithHistoryRequestBuilder {
         GetInvoiceItemAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceItemAuditLogsWithHistoryRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
+pub struct GetInvoiceItemAuditLogsWithHistoryRequestBuilder {
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetInvoiceItemAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+impl GetInvoiceItemAuditLogsWithHistoryRequestBuilder {
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceItemAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceItemAuditLogsWithHistoryRequest, InvoiceItemApiError> {
         Ok(GetInvoiceItemAuditLogsWithHistoryRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceItemCustomFieldsRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetInvoiceItemCustomFieldsRequest {
+    pub(crate) invoice_item_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceItemCustomFieldsRequest<'a> {
-    pub fn builder() -> GetInvoiceItemCustomFieldsRequestBuilder<'a> {
+impl GetInvoiceItemCustomFieldsRequest {
+    pub fn builder() -> GetInvoiceItemCustomFieldsRequestBuilder {
         GetInvoiceItemCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceItemCustomFieldsRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetInvoiceItemCustomFieldsRequestBuilder {
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceItemCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+impl GetInvoiceItemCustomFieldsRequestBuilder {
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceItemCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceItemCustomFieldsRequest, InvoiceItemApiError> {
         Ok(GetInvoiceItemCustomFieldsRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoiceItemTagsRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
-    pub(crate) account_id: &'a str,
+pub struct GetInvoiceItemTagsRequest {
+    pub(crate) invoice_item_id: uuid::Uuid,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceItemTagsRequest<'a> {
-    pub fn builder() -> GetInvoiceItemTagsRequestBuilder<'a> {
+impl GetInvoiceItemTagsRequest {
+    pub fn builder() -> GetInvoiceItemTagsRequestBuilder {
         GetInvoiceItemTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoiceItemTagsRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
-    account_id: Option<&'a str>,
+pub struct GetInvoiceItemTagsRequestBuilder {
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoiceItemTagsRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+impl GetInvoiceItemTagsRequestBuilder {
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -583,15 +609,19 @@ impl<'a> GetInvoiceItemTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoiceItemTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoiceItemTagsRequest, InvoiceItemApiError> {
         Ok(GetInvoiceItemTagsRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
-            account_id: self.account_id.ok_or("account_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             included_deleted: self.included_deleted,
             audit: self.audit,
         })
@@ -600,7 +630,7 @@ impl<'a> GetInvoiceItemTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyInvoiceItemCustomFieldsRequest<'a> {
-    pub(crate) invoice_item_id: &'a str,
+    pub(crate) invoice_item_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -615,7 +645,7 @@ impl<'a> ModifyInvoiceItemCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyInvoiceItemCustomFieldsRequestBuilder<'a> {
-    invoice_item_id: Option<&'a str>,
+    invoice_item_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -623,8 +653,8 @@ pub struct ModifyInvoiceItemCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyInvoiceItemCustomFieldsRequestBuilder<'a> {
-    pub fn invoice_item_id(mut self, invoice_item_id: &'a str) -> Self {
-        self.invoice_item_id = Some(invoice_item_id);
+    pub fn invoice_item_id(mut self, invoice_item_id: impl models::IdParam) -> Self {
+        self.invoice_item_id = Some(invoice_item_id.into_id());
         self
     }
 
@@ -648,13 +678,15 @@ impl<'a> ModifyInvoiceItemCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyInvoiceItemCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyInvoiceItemCustomFieldsRequest<'a>, InvoiceItemApiError> {
         Ok(ModifyInvoiceItemCustomFieldsRequest {
-            invoice_item_id: self.invoice_item_id.ok_or("invoice_item_id is required")?,
+            invoice_item_id: self.invoice_item_id
+                .ok_or(ParamError::missing("invoice_item_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_item_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -674,7 +706,10 @@ pub enum InvoiceItemApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl InvoiceItemApiError {
@@ -694,7 +729,7 @@ mod tests {
     async fn test_create_invoice_item_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoiceItems/test-invoice-item-id/customFields")
+            .mock("POST", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -704,7 +739,7 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = CreateInvoiceItemCustomFieldsRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -724,7 +759,7 @@ mod tests {
     async fn test_create_invoice_item_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoiceItems/test-invoice-item-id/tags")
+            .mock("POST", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -734,7 +769,7 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = CreateInvoiceItemTagsRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -750,7 +785,7 @@ mod tests {
     async fn test_delete_invoice_item_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/invoiceItems/test-invoice-item-id/customFields")
+            .mock("DELETE", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -758,7 +793,7 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = DeleteInvoiceItemCustomFieldsRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -773,7 +808,7 @@ mod tests {
     async fn test_delete_invoice_item_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/invoiceItems/test-invoice-item-id/tags")
+            .mock("DELETE", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/tags")
             .with_status(204)
             .create_async().await;
 
@@ -781,7 +816,7 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = DeleteInvoiceItemTagsRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -796,7 +831,7 @@ mod tests {
     async fn test_get_invoice_item_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoiceItems/test-invoice-item-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -806,7 +841,7 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = GetInvoiceItemAuditLogsWithHistoryRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
             .build()
             .unwrap();
 
@@ -819,7 +854,7 @@ mod tests {
     async fn test_get_invoice_item_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoiceItems/test-invoice-item-id/customFields")
+            .mock("GET", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -829,7 +864,7 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = GetInvoiceItemCustomFieldsRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
             .build()
             .unwrap();
 
@@ -843,7 +878,7 @@ mod tests {
     async fn test_get_invoice_item_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoiceItems/test-invoice-item-id/tags?accountId=test-account-id")
+            .mock("GET", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/tags?accountId=527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -853,8 +888,8 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = GetInvoiceItemTagsRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
-            .account_id("test-account-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .build()
             .unwrap();
 
@@ -868,7 +903,7 @@ mod tests {
     async fn test_modify_invoice_item_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/invoiceItems/test-invoice-item-id/customFields")
+            .mock("PUT", "/1.0/kb/invoiceItems/241fa9ac-ed48-53d7-94c3-7dbc46547acb/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -876,7 +911,7 @@ mod tests {
 
         let api = InvoiceItemApi::new(config);
         let request = ModifyInvoiceItemCustomFieldsRequest::builder()
-            .invoice_item_id("test-invoice-item-id")
+            .invoice_item_id("241fa9ac-ed48-53d7-94c3-7dbc46547acb")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
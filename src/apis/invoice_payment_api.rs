@@ -1,7 +1,8 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use uuid::Uuid;
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct InvoicePaymentApi {
@@ -47,7 +48,10 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/invoicePayments/{paymentId}")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -77,8 +81,15 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(
+                req,
+                idempotency_key,
+                "/1.0/kb/invoicePayments/{paymentId}/chargebacks"
+            )
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_chargeback_reversal(
@@ -107,8 +118,15 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(
+                req,
+                idempotency_key,
+                "/1.0/kb/invoicePayments/{paymentId}/chargebackReversals"
+            )
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_invoice_payment_custom_fields(
@@ -129,8 +147,10 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/invoicePayments/{paymentId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_invoice_payment_tags(
@@ -151,8 +171,8 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoicePayments/{paymentId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_refund_with_adjustments(
@@ -187,8 +207,15 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(
+                req,
+                idempotency_key,
+                "/1.0/kb/invoicePayments/{paymentId}/refunds"
+            )
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_invoice_payment_custom_fields(
@@ -215,7 +242,9 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/invoicePayments/{paymentId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -243,13 +272,13 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/invoicePayments/{paymentId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_invoice_payment(
         &self,
-        request: GetInvoicePaymentRequest<'_>
+        request: GetInvoicePaymentRequest
     ) -> Result<models::InvoicePayment, InvoicePaymentApiError> {
         let url = format!(
             "{}/1.0/kb/invoicePayments/{}",
@@ -264,9 +293,9 @@ impl InvoicePaymentApi {
                 &[
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|b| b.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -276,13 +305,13 @@ impl InvoicePaymentApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoicePayments/{paymentId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_payment_audit_logs_with_history(
         &self,
-        request: GetInvoicePaymentAuditLogsWithHistoryRequest<'_>
+        request: GetInvoicePaymentAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, InvoicePaymentApiError> {
         let url = format!(
             "{}/1.0/kb/invoicePayments/{}/auditLogsWithHistory",
@@ -294,13 +323,15 @@ impl InvoicePaymentApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/invoicePayments/{invoicePaymentId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_payment_custom_fields(
         &self,
-        request: GetInvoicePaymentCustomFieldsRequest<'_>
+        request: GetInvoicePaymentCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, InvoicePaymentApiError> {
         let url = format!(
             "{}/1.0/kb/invoicePayments/{}/customFields",
@@ -311,15 +342,17 @@ impl InvoicePaymentApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/invoicePayments/{paymentId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_invoice_payment_tags(
         &self,
-        request: GetInvoicePaymentTagsRequest<'_>
+        request: GetInvoicePaymentTagsRequest
     ) -> Result<Vec<models::Tag>, InvoicePaymentApiError> {
         let url = format!(
             "{}/1.0/kb/invoicePayments/{}/tags",
@@ -338,9 +371,9 @@ impl InvoicePaymentApi {
                             .map(|b| if b { "true" } else { "false" })
                             .unwrap_or("false"),
                     ),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -350,8 +383,8 @@ impl InvoicePaymentApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/invoicePayments/{paymentId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn modify_invoice_payment_custom_fields(
@@ -372,13 +405,18 @@ impl InvoicePaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/invoicePayments/{paymentId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, InvoicePaymentApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(InvoicePaymentApiError::from)
@@ -403,7 +441,7 @@ impl InvoicePaymentApi {
 
 #[derive(Debug, Clone)]
 pub struct CompleteInvoicePaymentTransactionRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -420,7 +458,7 @@ impl<'a> CompleteInvoicePaymentTransactionRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CompleteInvoicePaymentTransactionRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -430,8 +468,8 @@ pub struct CompleteInvoicePaymentTransactionRequestBuilder<'a> {
 }
 
 impl<'a> CompleteInvoicePaymentTransactionRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -465,13 +503,15 @@ impl<'a> CompleteInvoicePaymentTransactionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CompleteInvoicePaymentTransactionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CompleteInvoicePaymentTransactionRequest<'a>, InvoicePaymentApiError> {
         Ok(CompleteInvoicePaymentTransactionRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -482,7 +522,7 @@ impl<'a> CompleteInvoicePaymentTransactionRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateChargebackRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::InvoicePaymentTransaction,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -498,7 +538,7 @@ impl<'a> CreateChargebackRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateChargebackRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::InvoicePaymentTransaction>,
     plugin_property: Option<Vec<String>>,
@@ -507,8 +547,8 @@ pub struct CreateChargebackRequestBuilder<'a> {
 }
 
 impl<'a> CreateChargebackRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -537,13 +577,15 @@ impl<'a> CreateChargebackRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateChargebackRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateChargebackRequest<'a>, InvoicePaymentApiError> {
         Ok(CreateChargebackRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -553,7 +595,7 @@ impl<'a> CreateChargebackRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateChargebackReversalRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::InvoicePaymentTransaction,
     pub(crate) plugin_property: Option<Vec<String>>,
@@ -569,7 +611,7 @@ impl<'a> CreateChargebackReversalRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateChargebackReversalRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::InvoicePaymentTransaction>,
     plugin_property: Option<Vec<String>>,
@@ -578,8 +620,8 @@ pub struct CreateChargebackReversalRequestBuilder<'a> {
 }
 
 impl<'a> CreateChargebackReversalRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -608,13 +650,15 @@ impl<'a> CreateChargebackReversalRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateChargebackReversalRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateChargebackReversalRequest<'a>, InvoicePaymentApiError> {
         Ok(CreateChargebackReversalRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -624,7 +668,7 @@ impl<'a> CreateChargebackReversalRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateInvoicePaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -639,7 +683,7 @@ impl<'a> CreateInvoicePaymentCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -647,8 +691,8 @@ pub struct CreateInvoicePaymentCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreateInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -672,13 +716,15 @@ impl<'a> CreateInvoicePaymentCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateInvoicePaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateInvoicePaymentCustomFieldsRequest<'a>, InvoicePaymentApiError> {
         Ok(CreateInvoicePaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -687,7 +733,7 @@ impl<'a> CreateInvoicePaymentCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateInvoicePaymentTagsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -702,7 +748,7 @@ impl<'a> CreateInvoicePaymentTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateInvoicePaymentTagsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -710,8 +756,8 @@ pub struct CreateInvoicePaymentTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreateInvoicePaymentTagsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -735,13 +781,15 @@ impl<'a> CreateInvoicePaymentTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateInvoicePaymentTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateInvoicePaymentTagsRequest<'a>, InvoicePaymentApiError> {
         Ok(CreateInvoicePaymentTagsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -750,11 +798,11 @@ impl<'a> CreateInvoicePaymentTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateRefundWithAdjustmentsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::InvoicePaymentTransaction,
     pub(crate) external_payment: Option<bool>,
-    pub(crate) payment_method_id: Option<&'a str>,
+    pub(crate) payment_method_id: Option<uuid::Uuid>,
     pub(crate) plugin_property: Option<Vec<String>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -768,19 +816,19 @@ impl<'a> CreateRefundWithAdjustmentsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateRefundWithAdjustmentsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::InvoicePaymentTransaction>,
     external_payment: Option<bool>,
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     plugin_property: Option<Vec<String>>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> CreateRefundWithAdjustmentsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -799,8 +847,8 @@ impl<'a> CreateRefundWithAdjustmentsRequestBuilder<'a> {
         self
     }
 
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -819,15 +867,19 @@ impl<'a> CreateRefundWithAdjustmentsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateRefundWithAdjustmentsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateRefundWithAdjustmentsRequest<'a>, InvoicePaymentApiError> {
         Ok(CreateRefundWithAdjustmentsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             external_payment: self.external_payment,
-            payment_method_id: self.payment_method_id,
+            payment_method_id: self.payment_method_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -837,7 +889,7 @@ impl<'a> CreateRefundWithAdjustmentsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteInvoicePaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -852,7 +904,7 @@ impl<'a> DeleteInvoicePaymentCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -860,8 +912,8 @@ pub struct DeleteInvoicePaymentCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -885,11 +937,13 @@ impl<'a> DeleteInvoicePaymentCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteInvoicePaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteInvoicePaymentCustomFieldsRequest<'a>, InvoicePaymentApiError> {
         Ok(DeleteInvoicePaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -900,7 +954,7 @@ impl<'a> DeleteInvoicePaymentCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteInvoicePaymentTagsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -915,7 +969,7 @@ impl<'a> DeleteInvoicePaymentTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteInvoicePaymentTagsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -923,8 +977,8 @@ pub struct DeleteInvoicePaymentTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteInvoicePaymentTagsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -948,11 +1002,13 @@ impl<'a> DeleteInvoicePaymentTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteInvoicePaymentTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteInvoicePaymentTagsRequest<'a>, InvoicePaymentApiError> {
         Ok(DeleteInvoicePaymentTagsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -962,32 +1018,32 @@ impl<'a> DeleteInvoicePaymentTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicePaymentRequest<'a> {
-    pub(crate) payment_id: &'a str,
+pub struct GetInvoicePaymentRequest {
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentRequest<'a> {
-    pub fn builder() -> GetInvoicePaymentRequestBuilder<'a> {
+impl GetInvoicePaymentRequest {
+    pub fn builder() -> GetInvoicePaymentRequestBuilder {
         GetInvoicePaymentRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicePaymentRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+pub struct GetInvoicePaymentRequestBuilder {
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+impl GetInvoicePaymentRequestBuilder {
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1006,14 +1062,16 @@ impl<'a> GetInvoicePaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicePaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicePaymentRequest, InvoicePaymentApiError> {
         Ok(GetInvoicePaymentRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             with_plugin_info: self.with_plugin_info,
             with_attempts: self.with_attempts,
             plugin_property: self.plugin_property,
@@ -1023,96 +1081,100 @@ impl<'a> GetInvoicePaymentRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicePaymentAuditLogsWithHistoryRequest<'a> {
-    pub(crate) invoice_payment_id: &'a str,
+pub struct GetInvoicePaymentAuditLogsWithHistoryRequest {
+    pub(crate) invoice_payment_id: uuid::Uuid,
 }
 
-impl<'a> GetInvoicePaymentAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetInvoicePaymentAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetInvoicePaymentAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetInvoicePaymentAuditLogsWithHistoryRequestBuilder {
         GetInvoicePaymentAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicePaymentAuditLogsWithHistoryRequestBuilder<'a> {
-    invoice_payment_id: Option<&'a str>,
+pub struct GetInvoicePaymentAuditLogsWithHistoryRequestBuilder {
+    invoice_payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetInvoicePaymentAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn invoice_payment_id(mut self, invoice_payment_id: &'a str) -> Self {
-        self.invoice_payment_id = Some(invoice_payment_id);
+impl GetInvoicePaymentAuditLogsWithHistoryRequestBuilder {
+    pub fn invoice_payment_id(mut self, invoice_payment_id: impl models::IdParam) -> Self {
+        self.invoice_payment_id = Some(invoice_payment_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicePaymentAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicePaymentAuditLogsWithHistoryRequest, InvoicePaymentApiError> {
         Ok(GetInvoicePaymentAuditLogsWithHistoryRequest {
-            invoice_payment_id: self.invoice_payment_id.ok_or("invoice_payment_id is required")?,
+            invoice_payment_id: self.invoice_payment_id
+                .ok_or(ParamError::missing("invoice_payment_id"))?
+                .map_err(|e| ParamError::invalid_id("invoice_payment_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicePaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetInvoicePaymentCustomFieldsRequest {
+    pub(crate) payment_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentCustomFieldsRequest<'a> {
-    pub fn builder() -> GetInvoicePaymentCustomFieldsRequestBuilder<'a> {
+impl GetInvoicePaymentCustomFieldsRequest {
+    pub fn builder() -> GetInvoicePaymentCustomFieldsRequestBuilder {
         GetInvoicePaymentCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetInvoicePaymentCustomFieldsRequestBuilder {
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+impl GetInvoicePaymentCustomFieldsRequestBuilder {
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicePaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicePaymentCustomFieldsRequest, InvoicePaymentApiError> {
         Ok(GetInvoicePaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetInvoicePaymentTagsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+pub struct GetInvoicePaymentTagsRequest {
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentTagsRequest<'a> {
-    pub fn builder() -> GetInvoicePaymentTagsRequestBuilder<'a> {
+impl GetInvoicePaymentTagsRequest {
+    pub fn builder() -> GetInvoicePaymentTagsRequestBuilder {
         GetInvoicePaymentTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetInvoicePaymentTagsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+pub struct GetInvoicePaymentTagsRequestBuilder {
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetInvoicePaymentTagsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+impl GetInvoicePaymentTagsRequestBuilder {
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1126,14 +1188,16 @@ impl<'a> GetInvoicePaymentTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetInvoicePaymentTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetInvoicePaymentTagsRequest, InvoicePaymentApiError> {
         Ok(GetInvoicePaymentTagsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             included_deleted: self.included_deleted,
             plugin_property: self.plugin_property,
             audit: self.audit,
@@ -1143,7 +1207,7 @@ impl<'a> GetInvoicePaymentTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyInvoicePaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1158,7 +1222,7 @@ impl<'a> ModifyInvoicePaymentCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -1166,8 +1230,8 @@ pub struct ModifyInvoicePaymentCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyInvoicePaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1191,13 +1255,15 @@ impl<'a> ModifyInvoicePaymentCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyInvoicePaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyInvoicePaymentCustomFieldsRequest<'a>, InvoicePaymentApiError> {
         Ok(ModifyInvoicePaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1217,7 +1283,10 @@ pub enum InvoicePaymentApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl InvoicePaymentApiError {
@@ -1238,7 +1307,7 @@ mod tests {
     async fn test_complete_invoice_payment_transaction() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/invoicePayments/test-payment-id")
+            .mock("PUT", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .with_status(204)
             .create_async().await;
 
@@ -1246,7 +1315,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = CompleteInvoicePaymentTransactionRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -1264,17 +1333,17 @@ mod tests {
     async fn test_create_chargeback() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoicePayments/test-payment-id/chargebacks")
+            .mock("POST", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/chargebacks")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"payment_id": "test-payment-id"}"#)
+            .with_body(r#"{"payment_id": "7e6a0345-8350-5bf5-a82c-fbf0372f6e3d"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoicePaymentApi::new(config);
         let request = CreateChargebackRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .body(InvoicePaymentTransaction {
                 ..Default::default()
@@ -1292,17 +1361,17 @@ mod tests {
     async fn test_create_chargeback_reversal() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoicePayments/test-payment-id/chargebackReversals")
+            .mock("POST", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/chargebackReversals")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"payment_id": "test-payment-id"}"#)
+            .with_body(r#"{"payment_id": "7e6a0345-8350-5bf5-a82c-fbf0372f6e3d"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoicePaymentApi::new(config);
         let request = CreateChargebackReversalRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .body(InvoicePaymentTransaction {
                 ..Default::default()
@@ -1320,7 +1389,7 @@ mod tests {
     async fn test_create_invoice_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoicePayments/test-payment-id/customFields")
+            .mock("POST", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -1330,7 +1399,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = CreateInvoicePaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -1350,7 +1419,7 @@ mod tests {
     async fn test_create_invoice_payment_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoicePayments/test-payment-id/tags")
+            .mock("POST", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -1360,7 +1429,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = CreateInvoicePaymentTagsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -1376,17 +1445,17 @@ mod tests {
     async fn test_create_refund_with_adjustments() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/invoicePayments/test-payment-id/refunds")
+            .mock("POST", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/refunds")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"payment_id": "test-payment-id"}"#)
+            .with_body(r#"{"payment_id": "7e6a0345-8350-5bf5-a82c-fbf0372f6e3d"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoicePaymentApi::new(config);
         let request = CreateRefundWithAdjustmentsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .body(InvoicePaymentTransaction {
                 ..Default::default()
@@ -1404,7 +1473,7 @@ mod tests {
     async fn test_delete_invoice_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/invoicePayments/test-payment-id/customFields")
+            .mock("DELETE", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1412,7 +1481,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = DeleteInvoicePaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1427,7 +1496,7 @@ mod tests {
     async fn test_delete_invoice_payment_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/invoicePayments/test-payment-id/tags")
+            .mock("DELETE", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/tags")
             .with_status(204)
             .create_async().await;
 
@@ -1435,7 +1504,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = DeleteInvoicePaymentTagsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1450,17 +1519,17 @@ mod tests {
     async fn test_get_invoice_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoicePayments/test-payment-id")
+            .mock("GET", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"payment_id": "test-payment-id"}"#)
+            .with_body(r#"{"payment_id": "7e6a0345-8350-5bf5-a82c-fbf0372f6e3d"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = InvoicePaymentApi::new(config);
         let request = GetInvoicePaymentRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .build()
             .unwrap();
 
@@ -1474,7 +1543,7 @@ mod tests {
     async fn test_get_invoice_payment_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoicePayments/test-payment-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -1484,7 +1553,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = GetInvoicePaymentAuditLogsWithHistoryRequest::builder()
-            .invoice_payment_id("test-payment-id")
+            .invoice_payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .build()
             .unwrap();
 
@@ -1498,7 +1567,7 @@ mod tests {
     async fn test_get_invoice_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoicePayments/test-payment-id/customFields")
+            .mock("GET", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -1508,7 +1577,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = GetInvoicePaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .build()
             .unwrap();
 
@@ -1522,7 +1591,7 @@ mod tests {
     async fn test_get_invoice_payment_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/invoicePayments/test-payment-id/tags")
+            .mock("GET", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -1532,7 +1601,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = GetInvoicePaymentTagsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .build()
             .unwrap();
 
@@ -1546,7 +1615,7 @@ mod tests {
     async fn test_modify_invoice_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/invoicePayments/test-payment-id/customFields")
+            .mock("PUT", "/1.0/kb/invoicePayments/7e6a0345-8350-5bf5-a82c-fbf0372f6e3d/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1554,7 +1623,7 @@ mod tests {
 
         let api = InvoicePaymentApi::new(config);
         let request = ModifyInvoicePaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment-id")
+            .payment_id("7e6a0345-8350-5bf5-a82c-fbf0372f6e3d")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
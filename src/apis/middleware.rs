@@ -0,0 +1,157 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{ Method, Request, Response, Url };
+
+/// Hook into every request sent by the API structs
+///
+/// Middlewares are registered with [`ConfigBuilder::middleware`] and run in
+/// registration order. `on_request` runs once per call, before the first
+/// attempt; `on_response` and `on_error` run after every attempt, including
+/// retries.
+///
+/// [`ConfigBuilder::middleware`]: crate::apis::configuration::ConfigBuilder::middleware
+pub trait Middleware: Send + Sync {
+    /// Inspect or mutate the outgoing request, e.g. to add headers
+    fn on_request(&self, _request: &mut Request) {}
+
+    /// Observe a response received from the server
+    fn on_response(&self, _context: &ResponseContext<'_>, _response: &Response) {}
+
+    /// Observe a request that failed without a response
+    fn on_error(&self, _context: &ResponseContext<'_>, _error: &reqwest::Error) {}
+}
+
+/// Details about a completed attempt passed to [`Middleware`] hooks
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseContext<'a> {
+    pub method: &'a Method,
+    pub url: &'a Url,
+    /// Attempt number, starting at 1
+    pub attempt: u32,
+    /// Time between sending the request and receiving the response headers
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn Middleware>>);
+
+impl Middlewares {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn on_request(&self, request: &mut Request) {
+        for middleware in &self.0 {
+            middleware.on_request(request);
+        }
+    }
+
+    pub(crate) fn on_result(
+        &self,
+        context: &ResponseContext<'_>,
+        result: &Result<Response, reqwest::Error>
+    ) {
+        for middleware in &self.0 {
+            match result {
+                Ok(response) => middleware.on_response(context, response),
+                Err(error) => middleware.on_error(context, error),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Middlewares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Middlewares({})", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use reqwest::{ header::HeaderValue, StatusCode };
+    use crate::apis::{
+        configuration::Configuration,
+        retry::RetryPolicy,
+        tag_definition_api::{ GetTagDefinitionsRequest, TagDefinitionApi },
+    };
+
+    struct RequestId;
+
+    impl Middleware for RequestId {
+        fn on_request(&self, request: &mut Request) {
+            request.headers_mut().insert("X-Request-Id", HeaderValue::from_static("req-42"));
+        }
+    }
+
+    type Call = (Method, String, u32, StatusCode);
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Call>>>);
+
+    impl Middleware for Recorder {
+        fn on_response(&self, context: &ResponseContext<'_>, response: &Response) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((
+                    context.method.clone(),
+                    context.url.path().to_owned(),
+                    context.attempt,
+                    response.status(),
+                ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middlewares_are_applied_to_api_calls() {
+        let mut server = mockito::Server::new_async().await;
+        let unavailable = server
+            .mock("GET", "/1.0/kb/tagDefinitions")
+            .match_header("X-Request-Id", "req-42")
+            .with_status(503)
+            .expect(1)
+            .create_async().await;
+        let ok = server
+            .mock("GET", "/1.0/kb/tagDefinitions")
+            .match_header("X-Request-Id", "req-42")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .expect(1)
+            .create_async().await;
+
+        let recorder = Recorder::default();
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(1)))
+            .middleware(RequestId)
+            .middleware(recorder.clone())
+            .build()
+            .unwrap();
+
+        let api = TagDefinitionApi::new(config);
+        let request = GetTagDefinitionsRequest::builder().build().unwrap();
+        api.get_tag_definitions(request).await.unwrap();
+
+        let path = "/1.0/kb/tagDefinitions".to_owned();
+        let calls = recorder.0.lock().unwrap().clone();
+        assert_eq!(
+            calls,
+            vec![
+                (Method::GET, path.clone(), 1, StatusCode::SERVICE_UNAVAILABLE),
+                (Method::GET, path, 2, StatusCode::OK)
+            ]
+        );
+
+        unavailable.assert_async().await;
+        ok.assert_async().await;
+    }
+}
@@ -1,66 +1,22 @@
-use std::error;
-use std::fmt;
-
-#[derive(Debug, Clone)]
-pub struct ResponseContent<T> {
-    pub status: reqwest::StatusCode,
-    pub content: String,
-    pub entity: Option<T>,
-}
-
-#[derive(Debug)]
-pub enum Error<T> {
-    Reqwest(reqwest::Error),
-    Serde(serde_json::Error),
-    Io(std::io::Error),
-    ResponseError(ResponseContent<T>),
-}
-
-impl <T> fmt::Display for Error<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (module, e) = match self {
-            Error::Reqwest(e) => ("reqwest", e.to_string()),
-            Error::Serde(e) => ("serde", e.to_string()),
-            Error::Io(e) => ("IO", e.to_string()),
-            Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
-        };
-        write!(f, "error in {}: {}", module, e)
-    }
-}
-
-impl <T: fmt::Debug> error::Error for Error<T> {
-    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
-        Some(match self {
-            Error::Reqwest(e) => e,
-            Error::Serde(e) => e,
-            Error::Io(e) => e,
-            Error::ResponseError(_) => return None,
-        })
-    }
-}
-
-impl <T> From<reqwest::Error> for Error<T> {
-    fn from(e: reqwest::Error) -> Self {
-        Error::Reqwest(e)
-    }
+pub fn urlencode<T: AsRef<str>>(s: T) -> String {
+    ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
 }
 
-impl <T> From<serde_json::Error> for Error<T> {
-    fn from(e: serde_json::Error) -> Self {
-        Error::Serde(e)
-    }
+/// `audit` query parameter of the request builders
+pub(crate) trait AuditQuery {
+    /// Add the `audit` query parameter, only when a level is set
+    fn audit(self, audit: Option<crate::models::AuditLevel>) -> Self;
 }
 
-impl <T> From<std::io::Error> for Error<T> {
-    fn from(e: std::io::Error) -> Self {
-        Error::Io(e)
+impl AuditQuery for reqwest::RequestBuilder {
+    fn audit(self, audit: Option<crate::models::AuditLevel>) -> Self {
+        match audit {
+            Some(audit) => self.query(&[("audit", audit.as_str())]),
+            None => self,
+        }
     }
 }
 
-pub fn urlencode<T: AsRef<str>>(s: T) -> String {
-    ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
-}
-
 pub fn parse_deep_object(prefix: &str, value: &serde_json::Value) -> Vec<(String, String)> {
     if let serde_json::Value::Object(object) = value {
         let mut params = vec![];
@@ -92,6 +48,7 @@ pub fn parse_deep_object(prefix: &str, value: &serde_json::Value) -> Vec<(String
 
 pub mod account_api;
 pub mod admin_api;
+pub mod analytics_api;
 pub mod bundle_api;
 pub mod catalog_api;
 pub mod credit_api;
@@ -106,12 +63,22 @@ pub mod payment_api;
 pub mod payment_gateway_api;
 pub mod payment_method_api;
 pub mod payment_transaction_api;
+pub mod plugin_api;
 pub mod plugin_info_api;
 pub mod security_api;
 pub mod subscription_api;
 pub mod tag_api;
 pub mod tag_definition_api;
 pub mod tenant_api;
+pub mod test_api;
 pub mod usage_api;
 
 pub mod configuration;
+pub mod error;
+pub mod middleware;
+pub mod pagination;
+pub mod retry;
+#[cfg(feature = "tracing")]
+mod telemetry;
+
+pub use error::KillBillError;
@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct NodesApi {
@@ -21,8 +22,8 @@ impl NodesApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/nodesInfo").await?;
+        self.handle_response(response).await
     }
 
     pub async fn trigger_node_command(
@@ -40,11 +41,16 @@ impl NodesApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/nodesInfo").await?;
         Self::handle_empty_response(response).await
     }
 
-    async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T, NodesApiError> {
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, NodesApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(NodesApiError::from)
@@ -118,12 +124,12 @@ impl<'a> TriggerNodeCommandRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<TriggerNodeCommandRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<TriggerNodeCommandRequest<'a>, NodesApiError> {
         Ok(TriggerNodeCommandRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             local_node_only: self.local_node_only,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -144,7 +150,10 @@ pub enum NodesApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl NodesApiError {
@@ -1,6 +1,7 @@
-use reqwest::{ Method, Response, StatusCode };
+use reqwest::{ header::{ ACCEPT, CONTENT_TYPE }, Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct OverdueApi {
@@ -19,8 +20,8 @@ impl OverdueApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/overdue").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_overdue_config_xml(&self) -> Result<String, OverdueApiError> {
@@ -28,10 +29,11 @@ impl OverdueApi {
 
         let req = self.config.client
             .request(Method::GET, &url)
-            .headers(self.config.get_auth_headers());
+            .headers(self.config.get_auth_headers())
+            .header(ACCEPT, "text/xml");
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/overdue/xml").await?;
+        self.handle_text_response(response).await
     }
 
     pub async fn upload_overdue_config_json(
@@ -48,14 +50,18 @@ impl OverdueApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/overdue").await?;
+        self.handle_response(response).await
     }
 
+    /// Upload the overdue configuration
+    ///
+    /// Kill Bill answers with the location of the JSON configuration, which is
+    /// not fetched; use [`Self::get_overdue_config_xml`] to read it back.
     pub async fn upload_overdue_config_xml(
         &self,
         request: UploadOverdueConfigXmlRequest<'_>
-    ) -> Result<String, OverdueApiError> {
+    ) -> Result<(), OverdueApiError> {
         let url = format!("{}/1.0/kb/overdue/xml", self.config.base_path);
 
         let req = self.config.client
@@ -64,15 +70,19 @@ impl OverdueApi {
             .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
-            .json(&request.body);
+            .header(CONTENT_TYPE, "text/xml")
+            .body(request.body.to_owned());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/overdue/xml").await?;
+        Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, OverdueApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(OverdueApiError::from)
@@ -83,6 +93,35 @@ impl OverdueApi {
             }
         }
     }
+
+    async fn handle_text_response(
+        &self,
+        response: Response
+    ) -> Result<String, OverdueApiError> {
+        let response = self.config.follow_location(response).await?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                response.text().await.map_err(OverdueApiError::from)
+            }
+            status => {
+                let text = response.text().await?;
+                Err(OverdueApiError::from_response(status, text))
+            }
+        }
+    }
+
+    async fn handle_empty_response(
+        response: Response
+    ) -> Result<(), OverdueApiError> {
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
+            status => {
+                let text = response.text().await?;
+                Err(OverdueApiError::from_response(status, text))
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -128,12 +167,12 @@ impl<'a> UploadOverdueConfigJsonRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UploadOverdueConfigJsonRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UploadOverdueConfigJsonRequest<'a>, OverdueApiError> {
         Ok(UploadOverdueConfigJsonRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -183,12 +222,12 @@ impl<'a> UploadOverdueConfigXmlRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UploadOverdueConfigXmlRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UploadOverdueConfigXmlRequest<'a>, OverdueApiError> {
         Ok(UploadOverdueConfigXmlRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -208,7 +247,10 @@ pub enum OverdueApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl OverdueApiError {
@@ -247,8 +289,9 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("GET", "/1.0/kb/overdue/xml")
+            .match_header("accept", "text/xml")
             .with_status(200)
-            .with_header("content-type", "application/xml")
+            .with_header("content-type", "text/xml")
             .with_body("<overdue></overdue>")
             .create_async().await;
 
@@ -256,7 +299,7 @@ mod tests {
 
         let api = OverdueApi::new(config);
         let result = api.get_overdue_config_xml().await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "<overdue></overdue>");
 
         mock.assert_async().await;
     }
@@ -293,9 +336,10 @@ mod tests {
         let mut server = Server::new_async().await;
         let mock = server
             .mock("POST", "/1.0/kb/overdue/xml")
-            .with_status(200)
-            .with_header("content-type", "application/xml")
-            .with_body("<overdue></overdue>")
+            .match_header("content-type", "text/xml")
+            .match_body("<overdue></overdue>")
+            .with_status(201)
+            .with_header("location", "/1.0/kb/overdue")
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
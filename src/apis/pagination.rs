@@ -0,0 +1,193 @@
+use std::future::Future;
+
+use futures::{ stream, Stream, StreamExt, TryStreamExt };
+use reqwest::header::HeaderMap;
+
+const CURRENT_OFFSET: &str = "X-Killbill-Pagination-CurrentOffset";
+const NEXT_OFFSET: &str = "X-Killbill-Pagination-NextOffset";
+const TOTAL_NB_RECORDS: &str = "X-Killbill-Pagination-TotalNbRecords";
+const MAX_NB_RECORDS: &str = "X-Killbill-Pagination-MaxNbRecords";
+const NEXT_PAGE_URI: &str = "X-Killbill-Pagination-NextPageUri";
+
+/// One page of results from a Kill Bill `/pagination` or `/search` endpoint
+///
+/// Besides the items, carries the `X-Killbill-Pagination-*` response headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Offset of the first item of this page
+    pub current_offset: Option<i64>,
+    /// Offset of the next page, absent on the last page
+    pub next_offset: Option<i64>,
+    /// Number of records matching the query
+    pub total_nb_records: Option<i64>,
+    /// Number of records in the table, regardless of the query
+    pub max_nb_records: Option<i64>,
+    /// Relative URI of the next page, absent on the last page
+    pub next_page_uri: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub(crate) fn new(items: Vec<T>, headers: &HeaderMap) -> Self {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let number = |name: &str| header(name).and_then(|value| value.parse().ok());
+
+        Self {
+            items,
+            current_offset: number(CURRENT_OFFSET),
+            next_offset: number(NEXT_OFFSET),
+            total_nb_records: number(TOTAL_NB_RECORDS),
+            max_nb_records: number(MAX_NB_RECORDS),
+            next_page_uri: header(NEXT_PAGE_URI).map(str::to_owned),
+        }
+    }
+
+    /// Whether the server advertised another page after this one
+    pub fn has_next_page(&self) -> bool {
+        self.next_offset.is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Offset to request next, guarding against a server that does not advance
+    fn advance(&self, requested_offset: i64) -> Option<i64> {
+        if self.items.is_empty() {
+            return None;
+        }
+        self.next_offset.filter(|next| *next > requested_offset)
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// Walk every page starting at `offset`, yielding items one by one
+///
+/// `fetch` is called with the offset of each page and stops once a page
+/// comes back without a next offset.
+pub(crate) fn paginate<'a, T, E, F, Fut>(
+    offset: i64,
+    mut fetch: F
+) -> impl Stream<Item = Result<T, E>> + 'a
+    where
+        T: 'a,
+        E: 'a,
+        F: FnMut(i64) -> Fut + 'a,
+        Fut: Future<Output = Result<Page<T>, E>> + 'a
+{
+    stream::try_unfold(Some(offset), move |offset| {
+        let page = offset.map(|offset| (offset, fetch(offset)));
+        async move {
+            match page {
+                Some((offset, page)) => {
+                    let page = page.await?;
+                    let next = page.advance(offset);
+                    Ok(Some((page.items, next)))
+                }
+                None => Ok(None),
+            }
+        }
+    })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_page_reads_pagination_headers() {
+        let page = Page::new(
+            vec![1, 2],
+            &headers(
+                &[
+                    (CURRENT_OFFSET, "0"),
+                    (NEXT_OFFSET, "2"),
+                    (TOTAL_NB_RECORDS, "5"),
+                    (MAX_NB_RECORDS, "10"),
+                    (NEXT_PAGE_URI, "/1.0/kb/accounts/pagination?offset=2&limit=2"),
+                ]
+            )
+        );
+
+        assert_eq!(page.current_offset, Some(0));
+        assert_eq!(page.next_offset, Some(2));
+        assert_eq!(page.total_nb_records, Some(5));
+        assert_eq!(page.max_nb_records, Some(10));
+        assert_eq!(
+            page.next_page_uri.as_deref(),
+            Some("/1.0/kb/accounts/pagination?offset=2&limit=2")
+        );
+        assert!(page.has_next_page());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_walks_all_pages() {
+        let items: Vec<Result<i64, ()>> = paginate(0, |offset| async move {
+            let next = if offset < 4 { (offset + 2).to_string() } else { String::new() };
+            let mut pairs = vec![(CURRENT_OFFSET, offset.to_string())];
+            if !next.is_empty() {
+                pairs.push((NEXT_OFFSET, next));
+            }
+            let pairs: Vec<_> = pairs
+                .iter()
+                .map(|(name, value)| (*name, value.as_str()))
+                .collect();
+            Ok(Page::new(vec![offset, offset + 1], &headers(&pairs)))
+        }).collect().await;
+
+        assert_eq!(items, vec![Ok(0), Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error() {
+        let items: Vec<Result<i64, &str>> = paginate(0, |offset| async move {
+            if offset == 0 {
+                Ok(Page::new(vec![0], &headers(&[(NEXT_OFFSET, "1")])))
+            } else {
+                Err("boom")
+            }
+        }).collect().await;
+
+        assert_eq!(items, vec![Ok(0), Err("boom")]);
+    }
+}
@@ -1,6 +1,8 @@
+use futures::Stream;
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, pagination::{ paginate, Page }, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -30,7 +32,9 @@ impl PaymentApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/payments/cancelScheduledPaymentTransaction")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -51,7 +55,9 @@ impl PaymentApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/payments/{paymentTransactionId}/cancelScheduledPaymentTransaction")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -85,8 +91,11 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/{paymentId}")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn capture_authorization_by_external_key(
@@ -119,8 +128,11 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn chargeback_payment(
@@ -157,8 +169,15 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(
+                req,
+                idempotency_key,
+                "/1.0/kb/payments/{paymentId}/chargebacks"
+            )
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn chargeback_payment_by_external_key(
@@ -191,8 +210,11 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/chargebacks")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn chargeback_reversal_payment(
@@ -229,8 +251,15 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(
+                req,
+                idempotency_key,
+                "/1.0/kb/payments/{paymentId}/chargebackReversals"
+            )
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn chargeback_reversal_payment_by_external_key(
@@ -263,8 +292,11 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/chargebackReversals")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn complete_transaction(
@@ -297,7 +329,10 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/{paymentId}")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -331,7 +366,10 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -357,8 +395,13 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction
+            .as_ref()
+            .and_then(|t| t.transaction_external_key.as_deref());
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/combo")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_payment_custom_fields(
@@ -379,8 +422,8 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_payment_tags(
@@ -397,8 +440,8 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_payment_custom_fields(
@@ -426,7 +469,7 @@ impl PaymentApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -451,13 +494,13 @@ impl PaymentApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_payment(
         &self,
-        request: GetPaymentRequest<'_>
+        request: GetPaymentRequest
     ) -> Result<models::Payment, PaymentApiError> {
         let url = format!("{}/1.0/kb/payments/{}", self.config.base_path, request.payment_id);
 
@@ -468,9 +511,9 @@ impl PaymentApi {
                 &[
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|id| id.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -480,13 +523,13 @@ impl PaymentApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_attempt_audit_logs_with_history(
         &self,
-        request: GetPaymentAttemptAuditLogsWithHistoryRequest<'_>
+        request: GetPaymentAttemptAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, PaymentApiError> {
         let url = format!(
             "{}/1.0/kb/payments/attempts/{}/auditLogsWithHistory",
@@ -498,13 +541,15 @@ impl PaymentApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/payments/attempts/{paymentAttemptId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_audit_logs_with_history(
         &self,
-        request: GetPaymentAuditLogsWithHistoryRequest<'_>
+        request: GetPaymentAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, PaymentApiError> {
         let url = format!(
             "{}/1.0/kb/payments/{}/auditLogsWithHistory",
@@ -516,8 +561,10 @@ impl PaymentApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/payments/{paymentId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_by_external_key(
@@ -534,9 +581,9 @@ impl PaymentApi {
                     ("externalKey", request.external_key),
                     ("withPluginInfo", request.with_plugin_info.map(|b| if b { "true" } else { "false" }).unwrap_or("false")),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string()).as_deref().unwrap_or("")),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -546,13 +593,13 @@ impl PaymentApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_custom_fields(
         &self,
-        request: GetPaymentCustomFieldsRequest<'_>
+        request: GetPaymentCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, PaymentApiError> {
         let url = format!(
             "{}/1.0/kb/payments/{}/customFields",
@@ -563,15 +610,15 @@ impl PaymentApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}/customFields").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_tags(
         &self,
-        request: GetPaymentTagsRequest<'_>
+        request: GetPaymentTagsRequest
     ) -> Result<Vec<models::Tag>, PaymentApiError> {
         let url = format!("{}/1.0/kb/payments/{}/tags", self.config.base_path, request.payment_id);
 
@@ -581,18 +628,18 @@ impl PaymentApi {
             .query(
                 &[
                     ("includedDeleted", request.included_deleted.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|id| id.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payments(
         &self,
         request: GetPaymentsRequest<'_>
-    ) -> Result<Vec<models::Payment>, PaymentApiError> {
+    ) -> Result<Page<models::Payment>, PaymentApiError> {
         let url = format!("{}/1.0/kb/payments/pagination", self.config.base_path);
 
         let req = self.config.client
@@ -605,9 +652,9 @@ impl PaymentApi {
                     ("pluginName", request.plugin_name.map(|id| id.to_string())),
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|id| id.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -617,8 +664,20 @@ impl PaymentApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments/pagination").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_payments_stream<'a>(
+        &'a self,
+        request: GetPaymentsRequest<'a>
+    ) -> impl Stream<Item = Result<models::Payment, PaymentApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_payments(request)
+        })
     }
 
     pub async fn modify_payment_custom_fields(
@@ -639,7 +698,7 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/payments/{paymentId}/customFields").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -677,8 +736,11 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/{paymentId}/refunds")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn refund_payment_by_external_key(
@@ -711,14 +773,17 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/refunds")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn search_payments(
         &self,
         request: SearchPaymentsRequest<'_>
-    ) -> Result<Vec<models::Payment>, PaymentApiError> {
+    ) -> Result<Page<models::Payment>, PaymentApiError> {
         let url = format!(
             "{}/1.0/kb/payments/search/{}",
             self.config.base_path,
@@ -735,9 +800,9 @@ impl PaymentApi {
                     ("pluginName", request.plugin_name.map(|id| id.to_string())),
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|id| id.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -747,8 +812,20 @@ impl PaymentApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/payments/search/{searchKey}").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_payments_stream<'a>(
+        &'a self,
+        request: SearchPaymentsRequest<'a>
+    ) -> impl Stream<Item = Result<models::Payment, PaymentApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_payments(request)
+        })
     }
 
     pub async fn void_payment(
@@ -781,7 +858,10 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments/{paymentId}")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -815,13 +895,19 @@ impl PaymentApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(req, idempotency_key, "/1.0/kb/payments")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, PaymentApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(PaymentApiError::from)
@@ -832,6 +918,15 @@ impl PaymentApi {
             }
         }
     }
+
+    async fn handle_page_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<Page<T>, PaymentApiError> {
+        let headers = response.headers().clone();
+        let items = self.handle_response(response).await?;
+        Ok(Page::new(items, &headers))
+    }
     async fn handle_empty_response(response: Response) -> Result<(), PaymentApiError> {
         match response.status() {
             StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
@@ -888,13 +983,13 @@ impl<'a> CancelScheduledPaymentTransactionByExternalKeyRequestBuilder<'a> {
 
     pub fn build(
         self
-    ) -> Result<CancelScheduledPaymentTransactionByExternalKeyRequest<'a>, &'static str> {
+    ) -> Result<CancelScheduledPaymentTransactionByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(CancelScheduledPaymentTransactionByExternalKeyRequest {
             transaction_external_key: self.transaction_external_key.ok_or(
-                "transaction_external_key is required"
+                ParamError::missing("transaction_external_key")
             )?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -904,7 +999,7 @@ impl<'a> CancelScheduledPaymentTransactionByExternalKeyRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CancelScheduledPaymentTransactionByIdRequest<'a> {
-    pub(crate) payment_transaction_id: &'a str,
+    pub(crate) payment_transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -918,15 +1013,15 @@ impl<'a> CancelScheduledPaymentTransactionByIdRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CancelScheduledPaymentTransactionByIdRequestBuilder<'a> {
-    payment_transaction_id: Option<&'a str>,
+    payment_transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> CancelScheduledPaymentTransactionByIdRequestBuilder<'a> {
-    pub fn payment_transaction_id(mut self, payment_transaction_id: &'a str) -> Self {
-        self.payment_transaction_id = Some(payment_transaction_id);
+    pub fn payment_transaction_id(mut self, payment_transaction_id: impl models::IdParam) -> Self {
+        self.payment_transaction_id = Some(payment_transaction_id.into_id());
         self
     }
 
@@ -945,13 +1040,13 @@ impl<'a> CancelScheduledPaymentTransactionByIdRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CancelScheduledPaymentTransactionByIdRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CancelScheduledPaymentTransactionByIdRequest<'a>, PaymentApiError> {
         Ok(CancelScheduledPaymentTransactionByIdRequest {
-            payment_transaction_id: self.payment_transaction_id.ok_or(
-                "payment_transaction_id is required"
-            )?,
+            payment_transaction_id: self.payment_transaction_id
+                .ok_or(ParamError::missing("payment_transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -961,7 +1056,7 @@ impl<'a> CancelScheduledPaymentTransactionByIdRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CaptureAuthorizationRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -978,7 +1073,7 @@ impl<'a> CaptureAuthorizationRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CaptureAuthorizationRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -988,8 +1083,8 @@ pub struct CaptureAuthorizationRequestBuilder<'a> {
 }
 
 impl<'a> CaptureAuthorizationRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1023,13 +1118,15 @@ impl<'a> CaptureAuthorizationRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CaptureAuthorizationRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CaptureAuthorizationRequest<'a>, PaymentApiError> {
         Ok(CaptureAuthorizationRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1095,12 +1192,12 @@ impl<'a> CaptureAuthorizationByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CaptureAuthorizationByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CaptureAuthorizationByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(CaptureAuthorizationByExternalKeyRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1111,7 +1208,7 @@ impl<'a> CaptureAuthorizationByExternalKeyRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ChargebackPaymentRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -1128,7 +1225,7 @@ impl<'a> ChargebackPaymentRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ChargebackPaymentRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -1138,8 +1235,8 @@ pub struct ChargebackPaymentRequestBuilder<'a> {
 }
 
 impl<'a> ChargebackPaymentRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1173,13 +1270,15 @@ impl<'a> ChargebackPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ChargebackPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ChargebackPaymentRequest<'a>, PaymentApiError> {
         Ok(ChargebackPaymentRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1245,12 +1344,12 @@ impl<'a> ChargebackPaymentByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ChargebackPaymentByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ChargebackPaymentByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(ChargebackPaymentByExternalKeyRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1261,7 +1360,7 @@ impl<'a> ChargebackPaymentByExternalKeyRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ChargebackReversalPaymentRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -1278,7 +1377,7 @@ impl<'a> ChargebackReversalPaymentRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ChargebackReversalPaymentRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -1288,8 +1387,8 @@ pub struct ChargebackReversalPaymentRequestBuilder<'a> {
 }
 
 impl<'a> ChargebackReversalPaymentRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1323,13 +1422,15 @@ impl<'a> ChargebackReversalPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ChargebackReversalPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ChargebackReversalPaymentRequest<'a>, PaymentApiError> {
         Ok(ChargebackReversalPaymentRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1395,12 +1496,12 @@ impl<'a> ChargebackReversalPaymentByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ChargebackReversalPaymentByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ChargebackReversalPaymentByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(ChargebackReversalPaymentByExternalKeyRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1411,7 +1512,7 @@ impl<'a> ChargebackReversalPaymentByExternalKeyRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CompleteTransactionRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -1428,7 +1529,7 @@ impl<'a> CompleteTransactionRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CompleteTransactionRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -1438,8 +1539,8 @@ pub struct CompleteTransactionRequestBuilder<'a> {
 }
 
 impl<'a> CompleteTransactionRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1473,13 +1574,15 @@ impl<'a> CompleteTransactionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CompleteTransactionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CompleteTransactionRequest<'a>, PaymentApiError> {
         Ok(CompleteTransactionRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1545,12 +1648,12 @@ impl<'a> CompleteTransactionByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CompleteTransactionByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CompleteTransactionByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(CompleteTransactionByExternalKeyRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1609,12 +1712,12 @@ impl<'a> CreateComboPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateComboPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateComboPaymentRequest<'a>, PaymentApiError> {
         Ok(CreateComboPaymentRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -1624,7 +1727,7 @@ impl<'a> CreateComboPaymentRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreatePaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1639,7 +1742,7 @@ impl<'a> CreatePaymentCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreatePaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -1647,8 +1750,8 @@ pub struct CreatePaymentCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreatePaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1672,13 +1775,15 @@ impl<'a> CreatePaymentCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreatePaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreatePaymentCustomFieldsRequest<'a>, PaymentApiError> {
         Ok(CreatePaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1687,7 +1792,7 @@ impl<'a> CreatePaymentCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreatePaymentTagsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1702,7 +1807,7 @@ impl<'a> CreatePaymentTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreatePaymentTagsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1710,8 +1815,8 @@ pub struct CreatePaymentTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreatePaymentTagsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1735,13 +1840,15 @@ impl<'a> CreatePaymentTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreatePaymentTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreatePaymentTagsRequest<'a>, PaymentApiError> {
         Ok(CreatePaymentTagsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1750,7 +1857,7 @@ impl<'a> CreatePaymentTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeletePaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1765,7 +1872,7 @@ impl<'a> DeletePaymentCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeletePaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1773,8 +1880,8 @@ pub struct DeletePaymentCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeletePaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1798,11 +1905,13 @@ impl<'a> DeletePaymentCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeletePaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeletePaymentCustomFieldsRequest<'a>, PaymentApiError> {
         Ok(DeletePaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -1813,7 +1922,7 @@ impl<'a> DeletePaymentCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeletePaymentTagsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1828,7 +1937,7 @@ impl<'a> DeletePaymentTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeletePaymentTagsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1836,8 +1945,8 @@ pub struct DeletePaymentTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeletePaymentTagsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1861,11 +1970,13 @@ impl<'a> DeletePaymentTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeletePaymentTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeletePaymentTagsRequest<'a>, PaymentApiError> {
         Ok(DeletePaymentTagsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -1875,32 +1986,32 @@ impl<'a> DeletePaymentTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentRequest<'a> {
-    pub(crate) payment_id: &'a str,
+pub struct GetPaymentRequest {
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentRequest<'a> {
-    pub fn builder() -> GetPaymentRequestBuilder<'a> {
+impl GetPaymentRequest {
+    pub fn builder() -> GetPaymentRequestBuilder {
         GetPaymentRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+pub struct GetPaymentRequestBuilder {
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+impl GetPaymentRequestBuilder {
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -1919,14 +2030,16 @@ impl<'a> GetPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentRequest, PaymentApiError> {
         Ok(GetPaymentRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             with_plugin_info: self.with_plugin_info,
             with_attempts: self.with_attempts,
             plugin_property: self.plugin_property,
@@ -1936,59 +2049,63 @@ impl<'a> GetPaymentRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentAttemptAuditLogsWithHistoryRequest<'a> {
-    pub(crate) payment_attempt_id: &'a str,
+pub struct GetPaymentAttemptAuditLogsWithHistoryRequest {
+    pub(crate) payment_attempt_id: uuid::Uuid,
 }
 
-impl<'a> GetPaymentAttemptAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetPaymentAttemptAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetPaymentAttemptAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetPaymentAttemptAuditLogsWithHistoryRequestBuilder {
         GetPaymentAttemptAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentAttemptAuditLogsWithHistoryRequestBuilder<'a> {
-    payment_attempt_id: Option<&'a str>,
+pub struct GetPaymentAttemptAuditLogsWithHistoryRequestBuilder {
+    payment_attempt_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetPaymentAttemptAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn payment_attempt_id(mut self, payment_attempt_id: &'a str) -> Self {
-        self.payment_attempt_id = Some(payment_attempt_id);
+impl GetPaymentAttemptAuditLogsWithHistoryRequestBuilder {
+    pub fn payment_attempt_id(mut self, payment_attempt_id: impl models::IdParam) -> Self {
+        self.payment_attempt_id = Some(payment_attempt_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentAttemptAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentAttemptAuditLogsWithHistoryRequest, PaymentApiError> {
         Ok(GetPaymentAttemptAuditLogsWithHistoryRequest {
-            payment_attempt_id: self.payment_attempt_id.ok_or("payment_attempt_id is required")?,
+            payment_attempt_id: self.payment_attempt_id
+                .ok_or(ParamError::missing("payment_attempt_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_attempt_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentAuditLogsWithHistoryRequest<'a> {
-    pub(crate) payment_id: &'a str,
+pub struct GetPaymentAuditLogsWithHistoryRequest {
+    pub(crate) payment_id: uuid::Uuid,
 }
 
-impl<'a> GetPaymentAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetPaymentAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetPaymentAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetPaymentAuditLogsWithHistoryRequestBuilder {
         GetPaymentAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentAuditLogsWithHistoryRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+pub struct GetPaymentAuditLogsWithHistoryRequestBuilder {
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetPaymentAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+impl GetPaymentAuditLogsWithHistoryRequestBuilder {
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentAuditLogsWithHistoryRequest, PaymentApiError> {
         Ok(GetPaymentAuditLogsWithHistoryRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
         })
     }
 }
@@ -1999,7 +2116,7 @@ pub struct GetPaymentByExternalKeyRequest<'a> {
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentByExternalKeyRequest<'a> {
@@ -2014,7 +2131,7 @@ pub struct GetPaymentByExternalKeyRequestBuilder<'a> {
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentByExternalKeyRequestBuilder<'a> {
@@ -2038,14 +2155,14 @@ impl<'a> GetPaymentByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(GetPaymentByExternalKeyRequest {
-            external_key: self.external_key.ok_or("external_key is required")?,
+            external_key: self.external_key.ok_or(ParamError::missing("external_key"))?,
             with_plugin_info: self.with_plugin_info,
             with_attempts: self.with_attempts,
             plugin_property: self.plugin_property,
@@ -2055,65 +2172,67 @@ impl<'a> GetPaymentByExternalKeyRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetPaymentCustomFieldsRequest {
+    pub(crate) payment_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentCustomFieldsRequest<'a> {
-    pub fn builder() -> GetPaymentCustomFieldsRequestBuilder<'a> {
+impl GetPaymentCustomFieldsRequest {
+    pub fn builder() -> GetPaymentCustomFieldsRequestBuilder {
         GetPaymentCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetPaymentCustomFieldsRequestBuilder {
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+impl GetPaymentCustomFieldsRequestBuilder {
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentCustomFieldsRequest, PaymentApiError> {
         Ok(GetPaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentTagsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+pub struct GetPaymentTagsRequest {
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentTagsRequest<'a> {
-    pub fn builder() -> GetPaymentTagsRequestBuilder<'a> {
+impl GetPaymentTagsRequest {
+    pub fn builder() -> GetPaymentTagsRequestBuilder {
         GetPaymentTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentTagsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+pub struct GetPaymentTagsRequestBuilder {
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentTagsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+impl GetPaymentTagsRequestBuilder {
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -2122,14 +2241,16 @@ impl<'a> GetPaymentTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentTagsRequest, PaymentApiError> {
         Ok(GetPaymentTagsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             included_deleted: self.included_deleted,
             audit: self.audit,
         })
@@ -2144,7 +2265,7 @@ pub struct GetPaymentsRequest<'a> {
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentsRequest<'a> {
@@ -2161,7 +2282,7 @@ pub struct GetPaymentsRequestBuilder<'a> {
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentsRequestBuilder<'a> {
@@ -2195,12 +2316,12 @@ impl<'a> GetPaymentsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentsRequest<'a>, PaymentApiError> {
         Ok(GetPaymentsRequest {
             offset: self.offset,
             limit: self.limit,
@@ -2215,7 +2336,7 @@ impl<'a> GetPaymentsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyPaymentCustomFieldsRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -2230,7 +2351,7 @@ impl<'a> ModifyPaymentCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyPaymentCustomFieldsRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -2238,8 +2359,8 @@ pub struct ModifyPaymentCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyPaymentCustomFieldsRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -2263,13 +2384,15 @@ impl<'a> ModifyPaymentCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyPaymentCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyPaymentCustomFieldsRequest<'a>, PaymentApiError> {
         Ok(ModifyPaymentCustomFieldsRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -2278,7 +2401,7 @@ impl<'a> ModifyPaymentCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct RefundPaymentRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -2295,7 +2418,7 @@ impl<'a> RefundPaymentRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct RefundPaymentRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -2305,8 +2428,8 @@ pub struct RefundPaymentRequestBuilder<'a> {
 }
 
 impl<'a> RefundPaymentRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -2340,13 +2463,15 @@ impl<'a> RefundPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<RefundPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<RefundPaymentRequest<'a>, PaymentApiError> {
         Ok(RefundPaymentRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -2412,12 +2537,12 @@ impl<'a> RefundPaymentByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<RefundPaymentByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<RefundPaymentByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(RefundPaymentByExternalKeyRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -2435,7 +2560,7 @@ pub struct SearchPaymentsRequest<'a> {
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_name: Option<&'a str>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchPaymentsRequest<'a> {
@@ -2453,7 +2578,7 @@ pub struct SearchPaymentsRequestBuilder<'a> {
     with_attempts: Option<bool>,
     plugin_name: Option<&'a str>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchPaymentsRequestBuilder<'a> {
@@ -2492,14 +2617,14 @@ impl<'a> SearchPaymentsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<SearchPaymentsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SearchPaymentsRequest<'a>, PaymentApiError> {
         Ok(SearchPaymentsRequest {
-            search_key: self.search_key.ok_or("search_key is required")?,
+            search_key: self.search_key.ok_or(ParamError::missing("search_key"))?,
             offset: self.offset,
             limit: self.limit,
             with_plugin_info: self.with_plugin_info,
@@ -2513,7 +2638,7 @@ impl<'a> SearchPaymentsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct VoidPaymentRequest<'a> {
-    pub(crate) payment_id: &'a str,
+    pub(crate) payment_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -2530,7 +2655,7 @@ impl<'a> VoidPaymentRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct VoidPaymentRequestBuilder<'a> {
-    payment_id: Option<&'a str>,
+    payment_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -2540,8 +2665,8 @@ pub struct VoidPaymentRequestBuilder<'a> {
 }
 
 impl<'a> VoidPaymentRequestBuilder<'a> {
-    pub fn payment_id(mut self, payment_id: &'a str) -> Self {
-        self.payment_id = Some(payment_id);
+    pub fn payment_id(mut self, payment_id: impl models::IdParam) -> Self {
+        self.payment_id = Some(payment_id.into_id());
         self
     }
 
@@ -2575,13 +2700,15 @@ impl<'a> VoidPaymentRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<VoidPaymentRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<VoidPaymentRequest<'a>, PaymentApiError> {
         Ok(VoidPaymentRequest {
-            payment_id: self.payment_id.ok_or("payment_id is required")?,
+            payment_id: self.payment_id
+                .ok_or(ParamError::missing("payment_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -2647,12 +2774,12 @@ impl<'a> VoidPaymentByExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<VoidPaymentByExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<VoidPaymentByExternalKeyRequest<'a>, PaymentApiError> {
         Ok(VoidPaymentByExternalKeyRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -2674,7 +2801,10 @@ pub enum PaymentApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl PaymentApiError {
@@ -2702,7 +2832,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = CancelScheduledPaymentTransactionByExternalKeyRequest::builder()
-            .transaction_external_key("test-transaction")
+            .transaction_external_key("e9c09cd7-f2ad-530f-bfd8-ef3d7c29f656")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2717,7 +2847,7 @@ mod tests {
     async fn test_cancel_scheduled_payment_transaction_by_id() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/payments/test-transaction/cancelScheduledPaymentTransaction")
+            .mock("DELETE", "/1.0/kb/payments/e9c09cd7-f2ad-530f-bfd8-ef3d7c29f656/cancelScheduledPaymentTransaction")
             .with_status(204)
             .create_async().await;
 
@@ -2725,7 +2855,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = CancelScheduledPaymentTransactionByIdRequest::builder()
-            .payment_transaction_id("test-transaction")
+            .payment_transaction_id("e9c09cd7-f2ad-530f-bfd8-ef3d7c29f656")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2740,17 +2870,17 @@ mod tests {
     async fn test_capture_authorization() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/payments/test-payment")
+            .mock("POST", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentApi::new(config);
         let request = CaptureAuthorizationRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -2771,7 +2901,7 @@ mod tests {
             .mock("POST", "/1.0/kb/payments")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -2795,17 +2925,17 @@ mod tests {
     async fn test_chargeback_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/payments/test-payment/chargebacks")
+            .mock("POST", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/chargebacks")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentApi::new(config);
         let request = ChargebackPaymentRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -2826,7 +2956,7 @@ mod tests {
             .mock("POST", "/1.0/kb/payments/chargebacks")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -2850,17 +2980,17 @@ mod tests {
     async fn test_chargeback_reversal_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/payments/test-payment/chargebackReversals")
+            .mock("POST", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/chargebackReversals")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentApi::new(config);
         let request = ChargebackReversalPaymentRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -2881,7 +3011,7 @@ mod tests {
             .mock("POST", "/1.0/kb/payments/chargebackReversals")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -2905,7 +3035,7 @@ mod tests {
     async fn test_complete_transaction() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/payments/test-payment")
+            .mock("PUT", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71")
             .with_status(204)
             .create_async().await;
 
@@ -2913,7 +3043,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = CompleteTransactionRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -2956,7 +3086,7 @@ mod tests {
             .mock("POST", "/1.0/kb/payments/combo")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -2980,7 +3110,7 @@ mod tests {
     async fn test_create_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/payments/test-payment/customFields")
+            .mock("POST", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -2990,7 +3120,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = CreatePaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -3010,7 +3140,7 @@ mod tests {
     async fn test_create_payment_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/payments/test-payment/tags")
+            .mock("POST", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tagId": "test-tag"}]"#)
@@ -3020,7 +3150,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = CreatePaymentTagsRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -3036,7 +3166,7 @@ mod tests {
     async fn test_delete_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/payments/test-payment/customFields")
+            .mock("DELETE", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -3044,7 +3174,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = DeletePaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3059,7 +3189,7 @@ mod tests {
     async fn test_delete_payment_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/payments/test-payment/tags")
+            .mock("DELETE", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/tags")
             .with_status(204)
             .create_async().await;
 
@@ -3067,7 +3197,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = DeletePaymentTagsRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -3082,16 +3212,16 @@ mod tests {
     async fn test_get_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/payments/test-payment")
+            .mock("GET", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentApi::new(config);
-        let request = GetPaymentRequest::builder().payment_id("test-payment").build().unwrap();
+        let request = GetPaymentRequest::builder().payment_id("fd128d58-2604-574b-b60c-53f05fa65b71").build().unwrap();
 
         let result = api.get_payment(request).await;
         assert!(result.is_ok());
@@ -3103,7 +3233,7 @@ mod tests {
     async fn test_get_payment_attempt_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/payments/attempts/test-attempt/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/payments/attempts/3d6d64ce-7d5a-54cf-988b-422b1bca4462/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"changeType": "INSERT"}]"#)
@@ -3113,7 +3243,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = GetPaymentAttemptAuditLogsWithHistoryRequest::builder()
-            .payment_attempt_id("test-attempt")
+            .payment_attempt_id("3d6d64ce-7d5a-54cf-988b-422b1bca4462")
             .build()
             .unwrap();
 
@@ -3127,7 +3257,7 @@ mod tests {
     async fn test_get_payment_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/payments/test-payment/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"changeType": "INSERT"}]"#)
@@ -3137,7 +3267,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = GetPaymentAuditLogsWithHistoryRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .build()
             .unwrap();
 
@@ -3154,14 +3284,14 @@ mod tests {
             .mock("GET", "/1.0/kb/payments")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentApi::new(config);
         let request = GetPaymentByExternalKeyRequest::builder()
-            .external_key("test-payment")
+            .external_key("fd128d58-2604-574b-b60c-53f05fa65b71")
             .build()
             .unwrap();
 
@@ -3175,7 +3305,7 @@ mod tests {
     async fn test_get_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/payments/test-payment/customFields")
+            .mock("GET", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -3185,7 +3315,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = GetPaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .build()
             .unwrap();
 
@@ -3199,7 +3329,7 @@ mod tests {
     async fn test_get_payment_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/payments/test-payment/tags")
+            .mock("GET", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tagId": "test-tag"}]"#)
@@ -3208,7 +3338,7 @@ mod tests {
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentApi::new(config);
-        let request = GetPaymentTagsRequest::builder().payment_id("test-payment").build().unwrap();
+        let request = GetPaymentTagsRequest::builder().payment_id("fd128d58-2604-574b-b60c-53f05fa65b71").build().unwrap();
 
         let result = api.get_payment_tags(request).await;
         assert!(result.is_ok());
@@ -3223,7 +3353,7 @@ mod tests {
             .mock("GET", "/1.0/kb/payments/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"paymentId": "test-payment"}]"#)
+            .with_body(r#"[{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3241,7 +3371,7 @@ mod tests {
     async fn test_modify_payment_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/payments/test-payment/customFields")
+            .mock("PUT", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -3249,7 +3379,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = ModifyPaymentCustomFieldsRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -3269,17 +3399,17 @@ mod tests {
     async fn test_refund_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/payments/test-payment/refunds")
+            .mock("POST", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71/refunds")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentApi::new(config);
         let request = RefundPaymentRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -3300,7 +3430,7 @@ mod tests {
             .mock("POST", "/1.0/kb/payments/refunds")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"paymentId": "test-payment"}"#)
+            .with_body(r#"{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3327,7 +3457,7 @@ mod tests {
             .mock("GET", "/1.0/kb/payments/search/test-search")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"paymentId": "test-payment"}]"#)
+            .with_body(r#"[{"paymentId": "fd128d58-2604-574b-b60c-53f05fa65b71"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -3345,7 +3475,7 @@ mod tests {
     async fn test_void_payment() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/payments/test-payment")
+            .mock("DELETE", "/1.0/kb/payments/fd128d58-2604-574b-b60c-53f05fa65b71")
             .with_status(204)
             .create_async().await;
 
@@ -3353,7 +3483,7 @@ mod tests {
 
         let api = PaymentApi::new(config);
         let request = VoidPaymentRequest::builder()
-            .payment_id("test-payment")
+            .payment_id("fd128d58-2604-574b-b60c-53f05fa65b71")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct HostedPaymentPageApi {
@@ -42,8 +43,8 @@ impl HostedPaymentPageApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/paymentGateways/hosted/form").await?;
+        self.handle_response(response).await
     }
 
     pub async fn build_form_descriptor(
@@ -81,8 +82,10 @@ impl HostedPaymentPageApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentGateways/hosted/form/{accountId}")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn process_notification(
@@ -119,13 +122,18 @@ impl HostedPaymentPageApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/paymentGateways/notification/{pluginName}")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, HostedPaymentPageApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(HostedPaymentPageApiError::from)
@@ -205,12 +213,12 @@ impl<'a> BuildComboFormDescriptorRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<BuildComboFormDescriptorRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<BuildComboFormDescriptorRequest<'a>, HostedPaymentPageApiError> {
         Ok(BuildComboFormDescriptorRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -221,10 +229,10 @@ impl<'a> BuildComboFormDescriptorRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct BuildFormDescriptorRequest<'a> {
-    pub(crate) account_id: &'a str,
+    pub(crate) account_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::HostedPaymentPageFields,
-    pub(crate) payment_method_id: Option<&'a str>,
+    pub(crate) payment_method_id: Option<uuid::Uuid>,
     pub(crate) control_plugin_name: Option<Vec<String>>,
     pub(crate) plugin_property: Option<Vec<String>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -239,10 +247,10 @@ impl<'a> BuildFormDescriptorRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct BuildFormDescriptorRequestBuilder<'a> {
-    account_id: Option<&'a str>,
+    account_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::HostedPaymentPageFields>,
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     control_plugin_name: Option<Vec<String>>,
     plugin_property: Option<Vec<String>>,
     x_killbill_reason: Option<&'a str>,
@@ -250,8 +258,8 @@ pub struct BuildFormDescriptorRequestBuilder<'a> {
 }
 
 impl<'a> BuildFormDescriptorRequestBuilder<'a> {
-    pub fn account_id(mut self, account_id: &'a str) -> Self {
-        self.account_id = Some(account_id);
+    pub fn account_id(mut self, account_id: impl models::IdParam) -> Self {
+        self.account_id = Some(account_id.into_id());
         self
     }
 
@@ -265,8 +273,8 @@ impl<'a> BuildFormDescriptorRequestBuilder<'a> {
         self
     }
 
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -290,14 +298,18 @@ impl<'a> BuildFormDescriptorRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<BuildFormDescriptorRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<BuildFormDescriptorRequest<'a>, HostedPaymentPageApiError> {
         Ok(BuildFormDescriptorRequest {
-            account_id: self.account_id.ok_or("account_id is required")?,
+            account_id: self.account_id
+                .ok_or(ParamError::missing("account_id"))?
+                .map_err(|e| ParamError::invalid_id("account_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
-            payment_method_id: self.payment_method_id,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            payment_method_id: self.payment_method_id
+                .transpose()
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -370,13 +382,13 @@ impl<'a> ProcessNotificationRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ProcessNotificationRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ProcessNotificationRequest<'a>, HostedPaymentPageApiError> {
         Ok(ProcessNotificationRequest {
-            plugin_name: self.plugin_name.ok_or("plugin_name is required")?,
+            plugin_name: self.plugin_name.ok_or(ParamError::missing("plugin_name"))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -398,7 +410,10 @@ pub enum HostedPaymentPageApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl HostedPaymentPageApiError {
@@ -448,7 +463,7 @@ mod tests {
     async fn test_build_form_descriptor() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/paymentGateways/hosted/form/test-account-id")
+            .mock("POST", "/1.0/kb/paymentGateways/hosted/form/527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"form_id": "test-form-id"}"#)
@@ -458,7 +473,7 @@ mod tests {
 
         let api = HostedPaymentPageApi::new(config);
         let request = BuildFormDescriptorRequest::builder()
-            .account_id("test-account-id")
+            .account_id("527a22fb-a40b-548b-ad48-290cf05ddc8c")
             .x_killbill_created_by("test")
             .body(HostedPaymentPageFields {
                 ..Default::default()
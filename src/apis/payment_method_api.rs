@@ -1,7 +1,9 @@
+use futures::Stream;
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use uuid::Uuid;
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, pagination::{ paginate, Page }, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct PaymentMethodApi {
@@ -31,8 +33,10 @@ impl PaymentMethodApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentMethods/{paymentMethodId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_payment_method(
@@ -72,7 +76,7 @@ impl PaymentMethodApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/paymentMethods/{paymentMethodId}").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -100,13 +104,15 @@ impl PaymentMethodApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/paymentMethods/{paymentMethodId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_payment_method(
         &self,
-        request: GetPaymentMethodRequest<'_>
+        request: GetPaymentMethodRequest
     ) -> Result<models::PaymentMethod, PaymentMethodApiError> {
         let url = format!(
             "{}/1.0/kb/paymentMethods/{}",
@@ -121,9 +127,9 @@ impl PaymentMethodApi {
                 &[
                     ("includedDeleted", request.included_deleted.map(|b| b.to_string())),
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|s| s.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -133,13 +139,13 @@ impl PaymentMethodApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/paymentMethods/{paymentMethodId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_method_audit_logs_with_history(
         &self,
-        request: GetPaymentMethodAuditLogsWithHistoryRequest<'_>
+        request: GetPaymentMethodAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, PaymentMethodApiError> {
         let url = format!(
             "{}/1.0/kb/paymentMethods/{}/auditLogsWithHistory",
@@ -151,8 +157,10 @@ impl PaymentMethodApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentMethods/{paymentMethodId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_method_by_key(
@@ -169,9 +177,9 @@ impl PaymentMethodApi {
                     ("externalKey", request.external_key),
                     ("includedDeleted", request.included_deleted.map(|b| if b { "true" } else { "false" }).unwrap_or("false")),
                     ("withPluginInfo", request.with_plugin_info.map(|b| if b { "true" } else { "false" }).unwrap_or("false")),
-                    ("audit", request.audit.unwrap_or_default()),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -181,13 +189,13 @@ impl PaymentMethodApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/paymentMethods").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_method_custom_fields(
         &self,
-        request: GetPaymentMethodCustomFieldsRequest<'_>
+        request: GetPaymentMethodCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, PaymentMethodApiError> {
         let url = format!(
             "{}/1.0/kb/paymentMethods/{}/customFields",
@@ -198,16 +206,18 @@ impl PaymentMethodApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentMethods/{paymentMethodId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_methods(
         &self,
         request: GetPaymentMethodsRequest<'_>
-    ) -> Result<Vec<models::PaymentMethod>, PaymentMethodApiError> {
+    ) -> Result<Page<models::PaymentMethod>, PaymentMethodApiError> {
         let url = format!("{}/1.0/kb/paymentMethods/pagination", self.config.base_path);
 
         let req = self.config.client
@@ -219,9 +229,9 @@ impl PaymentMethodApi {
                     ("limit", request.limit.map(|l| l.to_string())),
                     ("pluginName", request.plugin_name.map(|s| s.to_string())),
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|s| s.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -231,8 +241,20 @@ impl PaymentMethodApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/paymentMethods/pagination").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_payment_methods_stream<'a>(
+        &'a self,
+        request: GetPaymentMethodsRequest<'a>
+    ) -> impl Stream<Item = Result<models::PaymentMethod, PaymentMethodApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_payment_methods(request)
+        })
     }
 
     pub async fn modify_payment_method_custom_fields(
@@ -253,14 +275,16 @@ impl PaymentMethodApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/paymentMethods/{paymentMethodId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn search_payment_methods(
         &self,
         request: SearchPaymentMethodsRequest<'_>
-    ) -> Result<Vec<models::PaymentMethod>, PaymentMethodApiError> {
+    ) -> Result<Page<models::PaymentMethod>, PaymentMethodApiError> {
         let url = format!(
             "{}/1.0/kb/paymentMethods/search/{}",
             self.config.base_path,
@@ -276,9 +300,9 @@ impl PaymentMethodApi {
                     ("limit", request.limit.map(|l| l.to_string())),
                     ("pluginName", request.plugin_name.map(|s| s.to_string())),
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|s| s.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -288,13 +312,28 @@ impl PaymentMethodApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/paymentMethods/search/{searchKey}").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_payment_methods_stream<'a>(
+        &'a self,
+        request: SearchPaymentMethodsRequest<'a>
+    ) -> impl Stream<Item = Result<models::PaymentMethod, PaymentMethodApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_payment_methods(request)
+        })
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, PaymentMethodApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(PaymentMethodApiError::from)
@@ -306,6 +345,15 @@ impl PaymentMethodApi {
         }
     }
 
+    async fn handle_page_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<Page<T>, PaymentMethodApiError> {
+        let headers = response.headers().clone();
+        let items = self.handle_response(response).await?;
+        Ok(Page::new(items, &headers))
+    }
+
     async fn handle_empty_response(response: Response) -> Result<(), PaymentMethodApiError> {
         match response.status() {
             StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
@@ -319,7 +367,7 @@ impl PaymentMethodApi {
 
 #[derive(Debug, Clone)]
 pub struct CreatePaymentMethodCustomFieldsRequest<'a> {
-    pub(crate) payment_method_id: &'a str,
+    pub(crate) payment_method_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -334,7 +382,7 @@ impl<'a> CreatePaymentMethodCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreatePaymentMethodCustomFieldsRequestBuilder<'a> {
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -342,8 +390,8 @@ pub struct CreatePaymentMethodCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreatePaymentMethodCustomFieldsRequestBuilder<'a> {
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -367,13 +415,15 @@ impl<'a> CreatePaymentMethodCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreatePaymentMethodCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreatePaymentMethodCustomFieldsRequest<'a>, PaymentMethodApiError> {
         Ok(CreatePaymentMethodCustomFieldsRequest {
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -382,7 +432,7 @@ impl<'a> CreatePaymentMethodCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeletePaymentMethodRequest<'a> {
-    pub(crate) payment_method_id: &'a str,
+    pub(crate) payment_method_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) delete_default_pm_with_auto_pay_off: Option<bool>,
     pub(crate) force_default_pm_deletion: Option<bool>,
@@ -399,7 +449,7 @@ impl<'a> DeletePaymentMethodRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeletePaymentMethodRequestBuilder<'a> {
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     delete_default_pm_with_auto_pay_off: Option<bool>,
     force_default_pm_deletion: Option<bool>,
@@ -409,8 +459,8 @@ pub struct DeletePaymentMethodRequestBuilder<'a> {
 }
 
 impl<'a> DeletePaymentMethodRequestBuilder<'a> {
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -447,11 +497,13 @@ impl<'a> DeletePaymentMethodRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeletePaymentMethodRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeletePaymentMethodRequest<'a>, PaymentMethodApiError> {
         Ok(DeletePaymentMethodRequest {
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             delete_default_pm_with_auto_pay_off: self.delete_default_pm_with_auto_pay_off,
             force_default_pm_deletion: self.force_default_pm_deletion,
@@ -464,7 +516,7 @@ impl<'a> DeletePaymentMethodRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeletePaymentMethodCustomFieldsRequest<'a> {
-    pub(crate) payment_method_id: &'a str,
+    pub(crate) payment_method_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -479,7 +531,7 @@ impl<'a> DeletePaymentMethodCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeletePaymentMethodCustomFieldsRequestBuilder<'a> {
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -487,8 +539,8 @@ pub struct DeletePaymentMethodCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeletePaymentMethodCustomFieldsRequestBuilder<'a> {
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -512,11 +564,13 @@ impl<'a> DeletePaymentMethodCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeletePaymentMethodCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeletePaymentMethodCustomFieldsRequest<'a>, PaymentMethodApiError> {
         Ok(DeletePaymentMethodCustomFieldsRequest {
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -526,32 +580,32 @@ impl<'a> DeletePaymentMethodCustomFieldsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentMethodRequest<'a> {
-    pub(crate) payment_method_id: &'a str,
+pub struct GetPaymentMethodRequest {
+    pub(crate) payment_method_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentMethodRequest<'a> {
-    pub fn builder() -> GetPaymentMethodRequestBuilder<'a> {
+impl GetPaymentMethodRequest {
+    pub fn builder() -> GetPaymentMethodRequestBuilder {
         GetPaymentMethodRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentMethodRequestBuilder<'a> {
-    payment_method_id: Option<&'a str>,
+pub struct GetPaymentMethodRequestBuilder {
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
     with_plugin_info: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentMethodRequestBuilder<'a> {
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+impl GetPaymentMethodRequestBuilder {
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -570,14 +624,16 @@ impl<'a> GetPaymentMethodRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentMethodRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentMethodRequest, PaymentMethodApiError> {
         Ok(GetPaymentMethodRequest {
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             included_deleted: self.included_deleted,
             with_plugin_info: self.with_plugin_info,
             plugin_property: self.plugin_property,
@@ -587,30 +643,32 @@ impl<'a> GetPaymentMethodRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentMethodAuditLogsWithHistoryRequest<'a> {
-    pub(crate) payment_method_id: &'a str,
+pub struct GetPaymentMethodAuditLogsWithHistoryRequest {
+    pub(crate) payment_method_id: uuid::Uuid,
 }
 
-impl<'a> GetPaymentMethodAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetPaymentMethodAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetPaymentMethodAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetPaymentMethodAuditLogsWithHistoryRequestBuilder {
         GetPaymentMethodAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentMethodAuditLogsWithHistoryRequestBuilder<'a> {
-    payment_method_id: Option<&'a str>,
+pub struct GetPaymentMethodAuditLogsWithHistoryRequestBuilder {
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetPaymentMethodAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+impl GetPaymentMethodAuditLogsWithHistoryRequestBuilder {
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentMethodAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentMethodAuditLogsWithHistoryRequest, PaymentMethodApiError> {
         Ok(GetPaymentMethodAuditLogsWithHistoryRequest {
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
         })
     }
 }
@@ -621,7 +679,7 @@ pub struct GetPaymentMethodByKeyRequest<'a> {
     pub(crate) included_deleted: Option<bool>,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentMethodByKeyRequest<'a> {
@@ -636,7 +694,7 @@ pub struct GetPaymentMethodByKeyRequestBuilder<'a> {
     included_deleted: Option<bool>,
     with_plugin_info: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentMethodByKeyRequestBuilder<'a> {
@@ -660,14 +718,14 @@ impl<'a> GetPaymentMethodByKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentMethodByKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentMethodByKeyRequest<'a>, PaymentMethodApiError> {
         Ok(GetPaymentMethodByKeyRequest {
-            external_key: self.external_key.ok_or("external_key is required")?,
+            external_key: self.external_key.ok_or(ParamError::missing("external_key"))?,
             included_deleted: self.included_deleted,
             with_plugin_info: self.with_plugin_info,
             plugin_property: self.plugin_property,
@@ -677,37 +735,39 @@ impl<'a> GetPaymentMethodByKeyRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentMethodCustomFieldsRequest<'a> {
-    pub(crate) payment_method_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetPaymentMethodCustomFieldsRequest {
+    pub(crate) payment_method_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentMethodCustomFieldsRequest<'a> {
-    pub fn builder() -> GetPaymentMethodCustomFieldsRequestBuilder<'a> {
+impl GetPaymentMethodCustomFieldsRequest {
+    pub fn builder() -> GetPaymentMethodCustomFieldsRequestBuilder {
         GetPaymentMethodCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentMethodCustomFieldsRequestBuilder<'a> {
-    payment_method_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetPaymentMethodCustomFieldsRequestBuilder {
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentMethodCustomFieldsRequestBuilder<'a> {
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+impl GetPaymentMethodCustomFieldsRequestBuilder {
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentMethodCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentMethodCustomFieldsRequest, PaymentMethodApiError> {
         Ok(GetPaymentMethodCustomFieldsRequest {
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             audit: self.audit,
         })
     }
@@ -720,7 +780,7 @@ pub struct GetPaymentMethodsRequest<'a> {
     pub(crate) plugin_name: Option<&'a str>,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentMethodsRequest<'a> {
@@ -736,7 +796,7 @@ pub struct GetPaymentMethodsRequestBuilder<'a> {
     plugin_name: Option<&'a str>,
     with_plugin_info: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentMethodsRequestBuilder<'a> {
@@ -765,12 +825,12 @@ impl<'a> GetPaymentMethodsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentMethodsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentMethodsRequest<'a>, PaymentMethodApiError> {
         Ok(GetPaymentMethodsRequest {
             offset: self.offset,
             limit: self.limit,
@@ -784,7 +844,7 @@ impl<'a> GetPaymentMethodsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyPaymentMethodCustomFieldsRequest<'a> {
-    pub(crate) payment_method_id: &'a str,
+    pub(crate) payment_method_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -799,7 +859,7 @@ impl<'a> ModifyPaymentMethodCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyPaymentMethodCustomFieldsRequestBuilder<'a> {
-    payment_method_id: Option<&'a str>,
+    payment_method_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -807,8 +867,8 @@ pub struct ModifyPaymentMethodCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyPaymentMethodCustomFieldsRequestBuilder<'a> {
-    pub fn payment_method_id(mut self, payment_method_id: &'a str) -> Self {
-        self.payment_method_id = Some(payment_method_id);
+    pub fn payment_method_id(mut self, payment_method_id: impl models::IdParam) -> Self {
+        self.payment_method_id = Some(payment_method_id.into_id());
         self
     }
 
@@ -832,13 +892,15 @@ impl<'a> ModifyPaymentMethodCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyPaymentMethodCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyPaymentMethodCustomFieldsRequest<'a>, PaymentMethodApiError> {
         Ok(ModifyPaymentMethodCustomFieldsRequest {
-            payment_method_id: self.payment_method_id.ok_or("payment_method_id is required")?,
+            payment_method_id: self.payment_method_id
+                .ok_or(ParamError::missing("payment_method_id"))?
+                .map_err(|e| ParamError::invalid_id("payment_method_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -853,7 +915,7 @@ pub struct SearchPaymentMethodsRequest<'a> {
     pub(crate) plugin_name: Option<&'a str>,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchPaymentMethodsRequest<'a> {
@@ -870,7 +932,7 @@ pub struct SearchPaymentMethodsRequestBuilder<'a> {
     plugin_name: Option<&'a str>,
     with_plugin_info: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchPaymentMethodsRequestBuilder<'a> {
@@ -904,14 +966,14 @@ impl<'a> SearchPaymentMethodsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<SearchPaymentMethodsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<SearchPaymentMethodsRequest<'a>, PaymentMethodApiError> {
         Ok(SearchPaymentMethodsRequest {
-            search_key: self.search_key.ok_or("search_key is required")?,
+            search_key: self.search_key.ok_or(ParamError::missing("search_key"))?,
             offset: self.offset,
             limit: self.limit,
             plugin_name: self.plugin_name,
@@ -935,7 +997,10 @@ pub enum PaymentMethodApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl PaymentMethodApiError {
@@ -955,7 +1020,7 @@ mod tests {
     async fn test_create_payment_method_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/paymentMethods/test-payment-method-id/customFields")
+            .mock("POST", "/1.0/kb/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -965,7 +1030,7 @@ mod tests {
 
         let api = PaymentMethodApi::new(config);
         let request = CreatePaymentMethodCustomFieldsRequest::builder()
-            .payment_method_id("test-payment-method-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -985,7 +1050,7 @@ mod tests {
     async fn test_delete_payment_method() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/paymentMethods/test-payment-method-id")
+            .mock("DELETE", "/1.0/kb/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .with_status(204)
             .create_async().await;
 
@@ -993,7 +1058,7 @@ mod tests {
 
         let api = PaymentMethodApi::new(config);
         let request = DeletePaymentMethodRequest::builder()
-            .payment_method_id("test-payment-method-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1008,7 +1073,7 @@ mod tests {
     async fn test_delete_payment_method_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/paymentMethods/test-payment-method-id/customFields")
+            .mock("DELETE", "/1.0/kb/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1016,7 +1081,7 @@ mod tests {
 
         let api = PaymentMethodApi::new(config);
         let request = DeletePaymentMethodCustomFieldsRequest::builder()
-            .payment_method_id("test-payment-method-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1031,17 +1096,17 @@ mod tests {
     async fn test_get_payment_method() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/paymentMethods/test-payment-method-id")
+            .mock("GET", "/1.0/kb/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"payment_method_id": "test-payment-method-id"}"#)
+            .with_body(r#"{"payment_method_id": "b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = PaymentMethodApi::new(config);
         let request = GetPaymentMethodRequest::builder()
-            .payment_method_id("test-payment-method-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .build()
             .unwrap();
 
@@ -1055,7 +1120,7 @@ mod tests {
     async fn test_get_payment_method_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/paymentMethods/test-payment-method-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -1065,7 +1130,7 @@ mod tests {
 
         let api = PaymentMethodApi::new(config);
         let request = GetPaymentMethodAuditLogsWithHistoryRequest::builder()
-            .payment_method_id("test-payment-method-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .build()
             .unwrap();
 
@@ -1082,7 +1147,7 @@ mod tests {
             .mock("GET", "/1.0/kb/paymentMethods?externalKey=test-external-key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"payment_method_id": "test-payment-method-id"}"#)
+            .with_body(r#"{"payment_method_id": "b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -1103,7 +1168,7 @@ mod tests {
     async fn test_get_payment_method_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/paymentMethods/test-payment-method-id/customFields")
+            .mock("GET", "/1.0/kb/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -1113,7 +1178,7 @@ mod tests {
 
         let api = PaymentMethodApi::new(config);
         let request = GetPaymentMethodCustomFieldsRequest::builder()
-            .payment_method_id("test-payment-method-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .build()
             .unwrap();
 
@@ -1130,7 +1195,7 @@ mod tests {
             .mock("GET", "/1.0/kb/paymentMethods/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"payment_method_id": "test-payment-method-id"}]"#)
+            .with_body(r#"[{"payment_method_id": "b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -1148,7 +1213,7 @@ mod tests {
     async fn test_modify_payment_method_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/paymentMethods/test-payment-method-id/customFields")
+            .mock("PUT", "/1.0/kb/paymentMethods/b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1156,7 +1221,7 @@ mod tests {
 
         let api = PaymentMethodApi::new(config);
         let request = ModifyPaymentMethodCustomFieldsRequest::builder()
-            .payment_method_id("test-payment-method-id")
+            .payment_method_id("b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -1179,7 +1244,7 @@ mod tests {
             .mock("GET", "/1.0/kb/paymentMethods/search/test-search-key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"payment_method_id": "test-payment-method-id"}]"#)
+            .with_body(r#"[{"payment_method_id": "b53f5bc0-72ee-57c7-b0ae-ccda4d7ad6e7"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -1,7 +1,8 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use uuid::Uuid;
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct PaymentTransactionApi {
@@ -31,8 +32,10 @@ impl PaymentTransactionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_transaction_tags(
@@ -53,8 +56,10 @@ impl PaymentTransactionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/tags")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_transaction_custom_fields(
@@ -81,7 +86,9 @@ impl PaymentTransactionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -109,7 +116,9 @@ impl PaymentTransactionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/tags")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -127,9 +136,9 @@ impl PaymentTransactionApi {
                     ("transactionExternalKey", request.transaction_external_key),
                     ("withPluginInfo", request.with_plugin_info.map(|b| if b { "true" } else { "false" }).unwrap_or("false")),
                     ("withAttempts", request.with_attempts.map(|b| if b { "true" } else { "false" }).unwrap_or("false")),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -139,13 +148,13 @@ impl PaymentTransactionApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/paymentTransactions").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_payment_by_transaction_id(
         &self,
-        request: GetPaymentByTransactionIdRequest<'_>
+        request: GetPaymentByTransactionIdRequest
     ) -> Result<models::Payment, PaymentTransactionApiError> {
         let url = format!(
             "{}/1.0/kb/paymentTransactions/{}",
@@ -160,9 +169,9 @@ impl PaymentTransactionApi {
                 &[
                     ("withPluginInfo", request.with_plugin_info.map(|b| b.to_string())),
                     ("withAttempts", request.with_attempts.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|s| s.to_string())),
                 ]
             )
+            .audit(request.audit)
             .query(
                 &request.plugin_property.as_ref().map_or(vec![], |props| {
                     props
@@ -172,13 +181,13 @@ impl PaymentTransactionApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/paymentTransactions/{transactionId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_transaction_audit_logs_with_history(
         &self,
-        request: GetTransactionAuditLogsWithHistoryRequest<'_>
+        request: GetTransactionAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, PaymentTransactionApiError> {
         let url = format!(
             "{}/1.0/kb/paymentTransactions/{}/auditLogsWithHistory",
@@ -190,13 +199,15 @@ impl PaymentTransactionApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_transaction_custom_fields(
         &self,
-        request: GetTransactionCustomFieldsRequest<'_>
+        request: GetTransactionCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, PaymentTransactionApiError> {
         let url = format!(
             "{}/1.0/kb/paymentTransactions/{}/customFields",
@@ -207,15 +218,17 @@ impl PaymentTransactionApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_transaction_tags(
         &self,
-        request: GetTransactionTagsRequest<'_>
+        request: GetTransactionTagsRequest
     ) -> Result<Vec<models::Tag>, PaymentTransactionApiError> {
         let url = format!(
             "{}/1.0/kb/paymentTransactions/{}/tags",
@@ -234,12 +247,14 @@ impl PaymentTransactionApi {
                             .map(|b| if b { "true" } else { "false" })
                             .unwrap_or("false"),
                     ),
-                    ("audit", request.audit.unwrap_or("")),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/tags")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn modify_transaction_custom_fields(
@@ -260,7 +275,9 @@ impl PaymentTransactionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/paymentTransactions/{transactionId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -290,13 +307,23 @@ impl PaymentTransactionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let idempotency_key = request.body.transaction_external_key.as_deref();
+        let response = self.config
+            .send_with_idempotency_key(
+                req,
+                idempotency_key,
+                "/1.0/kb/paymentTransactions/{transactionId}"
+            )
+            .await?;
+        self.handle_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, PaymentTransactionApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(PaymentTransactionApiError::from)
@@ -321,7 +348,7 @@ impl PaymentTransactionApi {
 
 #[derive(Debug, Clone)]
 pub struct CreateTransactionCustomFieldsRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -336,7 +363,7 @@ impl<'a> CreateTransactionCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateTransactionCustomFieldsRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -344,8 +371,8 @@ pub struct CreateTransactionCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreateTransactionCustomFieldsRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
@@ -369,13 +396,15 @@ impl<'a> CreateTransactionCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateTransactionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateTransactionCustomFieldsRequest<'a>, PaymentTransactionApiError> {
         Ok(CreateTransactionCustomFieldsRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -384,7 +413,7 @@ impl<'a> CreateTransactionCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateTransactionTagsRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -399,7 +428,7 @@ impl<'a> CreateTransactionTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateTransactionTagsRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -407,8 +436,8 @@ pub struct CreateTransactionTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreateTransactionTagsRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
@@ -432,13 +461,15 @@ impl<'a> CreateTransactionTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateTransactionTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateTransactionTagsRequest<'a>, PaymentTransactionApiError> {
         Ok(CreateTransactionTagsRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -447,7 +478,7 @@ impl<'a> CreateTransactionTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteTransactionCustomFieldsRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -462,7 +493,7 @@ impl<'a> DeleteTransactionCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteTransactionCustomFieldsRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -470,8 +501,8 @@ pub struct DeleteTransactionCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteTransactionCustomFieldsRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
@@ -495,11 +526,13 @@ impl<'a> DeleteTransactionCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteTransactionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteTransactionCustomFieldsRequest<'a>, PaymentTransactionApiError> {
         Ok(DeleteTransactionCustomFieldsRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -510,7 +543,7 @@ impl<'a> DeleteTransactionCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteTransactionTagsRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -525,7 +558,7 @@ impl<'a> DeleteTransactionTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteTransactionTagsRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -533,8 +566,8 @@ pub struct DeleteTransactionTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteTransactionTagsRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
@@ -558,11 +591,13 @@ impl<'a> DeleteTransactionTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteTransactionTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteTransactionTagsRequest<'a>, PaymentTransactionApiError> {
         Ok(DeleteTransactionTagsRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -577,7 +612,7 @@ pub struct GetPaymentByTransactionExternalKeyRequest<'a> {
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentByTransactionExternalKeyRequest<'a> {
@@ -592,7 +627,7 @@ pub struct GetPaymentByTransactionExternalKeyRequestBuilder<'a> {
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetPaymentByTransactionExternalKeyRequestBuilder<'a> {
@@ -616,15 +651,15 @@ impl<'a> GetPaymentByTransactionExternalKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentByTransactionExternalKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentByTransactionExternalKeyRequest<'a>, PaymentTransactionApiError> {
         Ok(GetPaymentByTransactionExternalKeyRequest {
             transaction_external_key: self.transaction_external_key.ok_or(
-                "transaction_external_key is required"
+                ParamError::missing("transaction_external_key")
             )?,
             with_plugin_info: self.with_plugin_info,
             with_attempts: self.with_attempts,
@@ -635,32 +670,32 @@ impl<'a> GetPaymentByTransactionExternalKeyRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetPaymentByTransactionIdRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+pub struct GetPaymentByTransactionIdRequest {
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) with_plugin_info: Option<bool>,
     pub(crate) with_attempts: Option<bool>,
     pub(crate) plugin_property: Option<Vec<String>>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentByTransactionIdRequest<'a> {
-    pub fn builder() -> GetPaymentByTransactionIdRequestBuilder<'a> {
+impl GetPaymentByTransactionIdRequest {
+    pub fn builder() -> GetPaymentByTransactionIdRequestBuilder {
         GetPaymentByTransactionIdRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetPaymentByTransactionIdRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+pub struct GetPaymentByTransactionIdRequestBuilder {
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     with_plugin_info: Option<bool>,
     with_attempts: Option<bool>,
     plugin_property: Option<Vec<String>>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetPaymentByTransactionIdRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+impl GetPaymentByTransactionIdRequestBuilder {
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
@@ -679,14 +714,16 @@ impl<'a> GetPaymentByTransactionIdRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetPaymentByTransactionIdRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetPaymentByTransactionIdRequest, PaymentTransactionApiError> {
         Ok(GetPaymentByTransactionIdRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             with_plugin_info: self.with_plugin_info,
             with_attempts: self.with_attempts,
             plugin_property: self.plugin_property,
@@ -696,94 +733,98 @@ impl<'a> GetPaymentByTransactionIdRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetTransactionAuditLogsWithHistoryRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+pub struct GetTransactionAuditLogsWithHistoryRequest {
+    pub(crate) transaction_id: uuid::Uuid,
 }
 
-impl<'a> GetTransactionAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetTransactionAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetTransactionAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetTransactionAuditLogsWithHistoryRequestBuilder {
         GetTransactionAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetTransactionAuditLogsWithHistoryRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+pub struct GetTransactionAuditLogsWithHistoryRequestBuilder {
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetTransactionAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+impl GetTransactionAuditLogsWithHistoryRequestBuilder {
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetTransactionAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetTransactionAuditLogsWithHistoryRequest, PaymentTransactionApiError> {
         Ok(GetTransactionAuditLogsWithHistoryRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetTransactionCustomFieldsRequest<'a> {
-    pub(crate) transaction_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetTransactionCustomFieldsRequest {
+    pub(crate) transaction_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTransactionCustomFieldsRequest<'a> {
-    pub fn builder() -> GetTransactionCustomFieldsRequestBuilder<'a> {
+impl GetTransactionCustomFieldsRequest {
+    pub fn builder() -> GetTransactionCustomFieldsRequestBuilder {
         GetTransactionCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetTransactionCustomFieldsRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetTransactionCustomFieldsRequestBuilder {
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTransactionCustomFieldsRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+impl GetTransactionCustomFieldsRequestBuilder {
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetTransactionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetTransactionCustomFieldsRequest, PaymentTransactionApiError> {
         Ok(GetTransactionCustomFieldsRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetTransactionTagsRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+pub struct GetTransactionTagsRequest {
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTransactionTagsRequest<'a> {
-    pub fn builder() -> GetTransactionTagsRequestBuilder<'a> {
+impl GetTransactionTagsRequest {
+    pub fn builder() -> GetTransactionTagsRequestBuilder {
         GetTransactionTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetTransactionTagsRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+pub struct GetTransactionTagsRequestBuilder {
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTransactionTagsRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+impl GetTransactionTagsRequestBuilder {
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
@@ -792,14 +833,16 @@ impl<'a> GetTransactionTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetTransactionTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetTransactionTagsRequest, PaymentTransactionApiError> {
         Ok(GetTransactionTagsRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             included_deleted: self.included_deleted,
             audit: self.audit,
         })
@@ -808,7 +851,7 @@ impl<'a> GetTransactionTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifyTransactionCustomFieldsRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -823,7 +866,7 @@ impl<'a> ModifyTransactionCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifyTransactionCustomFieldsRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -831,8 +874,8 @@ pub struct ModifyTransactionCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifyTransactionCustomFieldsRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
 
@@ -856,13 +899,15 @@ impl<'a> ModifyTransactionCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifyTransactionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifyTransactionCustomFieldsRequest<'a>, PaymentTransactionApiError> {
         Ok(ModifyTransactionCustomFieldsRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -871,7 +916,7 @@ impl<'a> ModifyTransactionCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct NotifyStateChangedRequest<'a> {
-    pub(crate) transaction_id: &'a str,
+    pub(crate) transaction_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::PaymentTransaction,
     pub(crate) control_plugin_name: Option<Vec<String>>,
@@ -887,7 +932,7 @@ impl<'a> NotifyStateChangedRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct NotifyStateChangedRequestBuilder<'a> {
-    transaction_id: Option<&'a str>,
+    transaction_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::PaymentTransaction>,
     control_plugin_name: Option<Vec<String>>,
@@ -896,8 +941,8 @@ pub struct NotifyStateChangedRequestBuilder<'a> {
 }
 
 impl<'a> NotifyStateChangedRequestBuilder<'a> {
-    pub fn transaction_id(mut self, transaction_id: &'a str) -> Self {
-        self.transaction_id = Some(transaction_id);
+    pub fn transaction_id(mut self, transaction_id: impl models::IdParam) -> Self {
+        self.transaction_id = Some(transaction_id.into_id());
         self
     }
     pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
@@ -925,13 +970,15 @@ impl<'a> NotifyStateChangedRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<NotifyStateChangedRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<NotifyStateChangedRequest<'a>, PaymentTransactionApiError> {
         Ok(NotifyStateChangedRequest {
-            transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+            transaction_id: self.transaction_id
+                .ok_or(ParamError::missing("transaction_id"))?
+                .map_err(|e| ParamError::invalid_id("transaction_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             control_plugin_name: self.control_plugin_name,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -951,7 +998,10 @@ pub enum PaymentTransactionApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl PaymentTransactionApiError {
@@ -971,7 +1021,7 @@ mod tests {
     async fn test_create_transaction_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/paymentTransactions/test-transaction-id/customFields")
+            .mock("POST", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -981,7 +1031,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = CreateTransactionCustomFieldsRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -1001,7 +1051,7 @@ mod tests {
     async fn test_create_transaction_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/paymentTransactions/test-transaction-id/tags")
+            .mock("POST", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -1011,7 +1061,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = CreateTransactionTagsRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -1027,7 +1077,7 @@ mod tests {
     async fn test_delete_transaction_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/paymentTransactions/test-transaction-id/customFields")
+            .mock("DELETE", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1035,7 +1085,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = DeleteTransactionCustomFieldsRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1050,7 +1100,7 @@ mod tests {
     async fn test_delete_transaction_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/paymentTransactions/test-transaction-id/tags")
+            .mock("DELETE", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/tags")
             .with_status(204)
             .create_async().await;
 
@@ -1058,7 +1108,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = DeleteTransactionTagsRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -1100,7 +1150,7 @@ mod tests {
     async fn test_get_payment_by_transaction_id() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/paymentTransactions/test-transaction-id")
+            .mock("GET", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"payment_id": "test-payment-id"}"#)
@@ -1110,7 +1160,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = GetPaymentByTransactionIdRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .build()
             .unwrap();
 
@@ -1124,7 +1174,7 @@ mod tests {
     async fn test_get_transaction_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/paymentTransactions/test-transaction-id/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"change_type": "test-change-type"}]"#)
@@ -1134,7 +1184,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = GetTransactionAuditLogsWithHistoryRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .build()
             .unwrap();
 
@@ -1148,7 +1198,7 @@ mod tests {
     async fn test_get_transaction_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/paymentTransactions/test-transaction-id/customFields")
+            .mock("GET", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -1158,7 +1208,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = GetTransactionCustomFieldsRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .build()
             .unwrap();
 
@@ -1172,7 +1222,7 @@ mod tests {
     async fn test_get_transaction_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/paymentTransactions/test-transaction-id/tags")
+            .mock("GET", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tag_definition_id": "test-tag-def-id"}]"#)
@@ -1182,7 +1232,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = GetTransactionTagsRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .build()
             .unwrap();
 
@@ -1196,7 +1246,7 @@ mod tests {
     async fn test_modify_transaction_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/paymentTransactions/test-transaction-id/customFields")
+            .mock("PUT", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -1204,7 +1254,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = ModifyTransactionCustomFieldsRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -1224,7 +1274,7 @@ mod tests {
     async fn test_notify_state_changed() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/paymentTransactions/test-transaction-id")
+            .mock("POST", "/1.0/kb/paymentTransactions/5677ddb1-e750-501e-bc00-9129dcadfe14")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"payment_id": "test-payment-id"}"#)
@@ -1234,7 +1284,7 @@ mod tests {
 
         let api = PaymentTransactionApi::new(config);
         let request = NotifyStateChangedRequest::builder()
-            .transaction_id("test-transaction-id")
+            .transaction_id("5677ddb1-e750-501e-bc00-9129dcadfe14")
             .x_killbill_created_by("test")
             .body(PaymentTransaction {
                 ..Default::default()
@@ -0,0 +1,410 @@
+use reqwest::{ header::{ HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE }, Method, StatusCode };
+use serde::{ Serialize, de::DeserializeOwned };
+use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
+use thiserror::Error;
+
+/// Passthrough to the routes plugins serve under `/plugins/{pluginName}/...`
+///
+/// Requests carry the configured auth and tenant headers, and are retried per
+/// the retry policy like any other call.
+pub struct PluginApi {
+    config: Configuration,
+}
+
+impl PluginApi {
+    pub fn new(config: Configuration) -> Self {
+        Self { config }
+    }
+
+    pub async fn call(&self, request: PluginRequest) -> Result<PluginResponse, PluginApiError> {
+        let url = format!(
+            "{}/plugins/{}/{}",
+            self.config.base_path,
+            crate::apis::urlencode(&request.plugin_name),
+            request.path.trim_start_matches('/')
+        );
+
+        let mut req = self.config.client
+            .request(request.method, &url)
+            .headers(self.config.get_auth_headers())
+            .headers(request.headers)
+            .query(&request.query);
+        if let Some(created_by) = request.x_killbill_created_by {
+            req = req
+                .header("X-Killbill-CreatedBy", created_by)
+                .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+                .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+        }
+        if let Some(body) = request.body {
+            req = req.header(CONTENT_TYPE, body.content_type).body(body.content);
+        }
+
+        let response = self.config.send(req, "/plugins/{pluginName}/{path}").await?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await?;
+            return Err(PluginApiError::from_response(status, text));
+        }
+
+        Ok(PluginResponse {
+            status,
+            headers: response.headers().clone(),
+            body: response.bytes().await?.to_vec(),
+        })
+    }
+}
+
+/// Successful response of a plugin route, with its body already read
+#[derive(Debug, Clone)]
+pub struct PluginResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl PluginResponse {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.body
+    }
+
+    pub fn text(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(&self.body)
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PluginBody {
+    content_type: HeaderValue,
+    content: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PluginRequest {
+    pub(crate) plugin_name: String,
+    pub(crate) path: String,
+    pub(crate) method: Method,
+    pub(crate) query: Vec<(String, String)>,
+    pub(crate) headers: HeaderMap,
+    body: Option<PluginBody>,
+    pub(crate) x_killbill_created_by: Option<String>,
+    pub(crate) x_killbill_reason: Option<String>,
+    pub(crate) x_killbill_comment: Option<String>,
+}
+
+impl PluginRequest {
+    pub fn builder() -> PluginRequestBuilder {
+        PluginRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PluginRequestBuilder {
+    plugin_name: Option<String>,
+    path: Option<String>,
+    method: Option<Method>,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    body: Option<Result<PluginBody, ParamError>>,
+    x_killbill_created_by: Option<String>,
+    x_killbill_reason: Option<String>,
+    x_killbill_comment: Option<String>,
+}
+
+impl PluginRequestBuilder {
+    /// Name the plugin is registered under, e.g. `killbill-analytics`
+    pub fn plugin_name(mut self, plugin_name: impl Into<String>) -> Self {
+        self.plugin_name = Some(plugin_name.into());
+        self
+    }
+
+    /// Path below `/plugins/{pluginName}`, with or without a leading `/`
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Defaults to `GET`
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Add a query parameter; repeat the call for multi-valued parameters
+    pub fn query(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((name.into(), value.into()));
+        self
+    }
+
+    /// Add a header, sent after the auth and tenant headers
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Self {
+        self.body = Some(
+            serde_json::to_vec(body)
+                .map(|content| PluginBody {
+                    content_type: HeaderValue::from_static("application/json"),
+                    content,
+                })
+                .map_err(|e| ParamError::invalid("body", e.to_string()))
+        );
+        self
+    }
+
+    pub fn text(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(
+            Ok(PluginBody {
+                content_type: HeaderValue::from_static("text/plain; charset=utf-8"),
+                content: body.into().into_bytes(),
+            })
+        );
+        self
+    }
+
+    pub fn bytes(mut self, body: impl Into<Vec<u8>>, content_type: &str) -> Self {
+        self.body = Some(
+            HeaderValue::from_str(content_type)
+                .map(|content_type| PluginBody {
+                    content_type,
+                    content: body.into(),
+                })
+                .map_err(|_| ParamError::invalid("content_type", content_type))
+        );
+        self
+    }
+
+    /// Set for plugin routes that write to Kill Bill; the reason and comment
+    /// are only sent along with it
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: impl Into<String>) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by.into());
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: impl Into<String>) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason.into());
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: impl Into<String>) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment.into());
+        self
+    }
+
+    pub fn build(self) -> Result<PluginRequest, PluginApiError> {
+        let plugin_name = self.plugin_name.ok_or(ParamError::missing("plugin_name"))?;
+        if plugin_name.is_empty() || plugin_name.contains('/') {
+            return Err(ParamError::invalid("plugin_name", plugin_name).into());
+        }
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in self.headers {
+            let header = format!("{name}: {value}");
+            let name = HeaderName::try_from(name).map_err(|_| ParamError::invalid("header", &header))?;
+            let value = HeaderValue::try_from(value).map_err(|_| ParamError::invalid("header", &header))?;
+            headers.append(name, value);
+        }
+
+        Ok(PluginRequest {
+            plugin_name,
+            path: self.path.unwrap_or_default(),
+            method: self.method.unwrap_or(Method::GET),
+            query: self.query,
+            headers,
+            body: self.body.transpose()?,
+            x_killbill_created_by: self.x_killbill_created_by,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginApiError {
+    #[error("Request failed: {0}")] RequestFailed(#[from] reqwest::Error),
+
+    #[error("API error ({status}): {message}")] ApiError {
+        status: StatusCode,
+        message: String,
+    },
+
+    #[error("Configuration error: {0}")] ConfigError(
+        #[from] crate::apis::configuration::ConfigError,
+    ),
+
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
+}
+
+impl PluginApiError {
+    fn from_response(status: StatusCode, message: String) -> Self {
+        Self::ApiError { status, message }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{ Matcher, Server };
+
+    #[tokio::test]
+    async fn test_plugin_get_json() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/plugins/killbill-analytics/reports")
+            .match_header("X-Killbill-ApiKey", "bob")
+            .match_query(Matcher::UrlEncoded("format".into(), "json".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"reportName":"conversions"}]"#)
+            .create_async().await;
+
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .tenant("bob", "lazar")
+            .build()
+            .unwrap();
+
+        let api = PluginApi::new(config);
+        let request = PluginRequest::builder()
+            .plugin_name("killbill-analytics")
+            .path("/reports")
+            .query("format", "json")
+            .build()
+            .unwrap();
+
+        let response = api.call(request).await.unwrap();
+        let reports: serde_json::Value = response.json().unwrap();
+        assert_eq!(reports[0]["reportName"], "conversions");
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_plugin_post_json() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/plugins/killbill-email-notifications/sendTestEmail")
+            .match_header("X-Killbill-CreatedBy", "admin")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({ "to": "john@example.com" })))
+            .with_status(201)
+            .with_body("queued")
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = PluginApi::new(config);
+        let request = PluginRequest::builder()
+            .plugin_name("killbill-email-notifications")
+            .path("sendTestEmail")
+            .method(Method::POST)
+            .json(&serde_json::json!({ "to": "john@example.com" }))
+            .x_killbill_created_by("admin")
+            .build()
+            .unwrap();
+
+        let response = api.call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.text().unwrap(), "queued");
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_plugin_post_text() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/plugins/killbill-invoice-test/notes")
+            .match_header("content-type", "text/plain; charset=utf-8")
+            .match_body("Paid by wire")
+            .with_status(200)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = PluginApi::new(config);
+        let request = PluginRequest::builder()
+            .plugin_name("killbill-invoice-test")
+            .path("notes")
+            .method(Method::PUT)
+            .text("Paid by wire")
+            .build()
+            .unwrap();
+
+        let response = api.call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_plugin_error() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/plugins/killbill-stripe/")
+            .with_status(404)
+            .with_body("Not Found")
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = PluginApi::new(config);
+        let request = PluginRequest::builder()
+            .plugin_name("killbill-stripe")
+            .method(Method::DELETE)
+            .build()
+            .unwrap();
+
+        let result = api.call(request).await;
+        assert!(
+            matches!(result, Err(PluginApiError::ApiError { status, .. }) if status == StatusCode::NOT_FOUND)
+        );
+        assert!(PluginRequest::builder().plugin_name("a/b").build().is_err());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_plugin_name_is_encoded() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/plugins/acme%3Fbeta/status")
+            .with_status(200)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = PluginApi::new(config);
+        let request = PluginRequest::builder()
+            .plugin_name("acme?beta")
+            .path("status")
+            .build()
+            .unwrap();
+
+        let response = api.call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{ BuildHasher, Hasher };
+use std::time::Duration;
+
+use reqwest::{ header::RETRY_AFTER, Response, StatusCode };
+
+/// Retry policy applied to idempotent requests
+///
+/// GET and HEAD requests are always considered idempotent. Other requests are
+/// only retried when they carry an idempotency key, such as the external key
+/// of a payment transaction.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: f64,
+    retryable_statuses: Vec<StatusCode>,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Total number of attempts, including the first one
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Upper bound for the delay between two attempts
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Factor applied to the delay after each attempt
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Fraction of the delay that is randomized, between `0.0` (none) and `1.0` (full jitter)
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Response statuses that trigger a retry
+    pub fn retryable_statuses(mut self, retryable_statuses: Vec<StatusCode>) -> Self {
+        self.retryable_statuses = retryable_statuses;
+        self
+    }
+
+    pub(crate) fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub(crate) fn should_retry_status(&self, status: StatusCode) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    pub(crate) fn should_retry_error(&self, error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout()
+    }
+
+    /// Delay to wait before attempt number `attempt + 1`, `attempt` starting at 1
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let delay = delay.min(self.max_backoff.as_secs_f64());
+        let delay = delay * (1.0 - self.jitter * random_fraction());
+
+        Duration::from_secs_f64(delay.max(0.0))
+    }
+
+    /// Delay requested by the server through `Retry-After`, capped by `max_backoff`
+    pub(crate) fn retry_after(&self, response: &Response) -> Option<Duration> {
+        response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(|seconds| Duration::from_secs(seconds).min(self.max_backoff))
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts with exponential backoff from 200ms, on 502, 503 and 504
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.5,
+            retryable_statuses: vec![
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT
+            ],
+        }
+    }
+}
+
+/// Random number in `[0, 1)`, good enough to spread retries
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_exponentially() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1))
+            .jitter(0.0);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::default().initial_backoff(Duration::from_millis(100)).jitter(1.0);
+
+        for _ in 0..100 {
+            assert!(policy.backoff(1) <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_disabled_policy_makes_single_attempt() {
+        assert_eq!(RetryPolicy::disabled().attempts(), 1);
+        assert_eq!(RetryPolicy::default().max_attempts(0).attempts(), 1);
+    }
+}
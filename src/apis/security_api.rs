@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct SecurityApi {
@@ -26,8 +27,8 @@ impl SecurityApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/security/roles").await?;
+        self.handle_response(response).await
     }
 
     pub async fn add_user_roles(
@@ -44,8 +45,8 @@ impl SecurityApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/security/users").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_current_user_permissions(&self) -> Result<Vec<String>, SecurityApiError> {
@@ -55,8 +56,8 @@ impl SecurityApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/security/permissions").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_current_user_subject(&self) -> Result<models::Subject, SecurityApiError> {
@@ -66,8 +67,8 @@ impl SecurityApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/security/subject").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_role_definition(
@@ -80,8 +81,8 @@ impl SecurityApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/security/roles/{role}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_user_roles(
@@ -98,8 +99,8 @@ impl SecurityApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/security/users/{username}/roles").await?;
+        self.handle_response(response).await
     }
 
     pub async fn invalidate_user(
@@ -115,7 +116,7 @@ impl SecurityApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/security/users/{username}").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -133,7 +134,7 @@ impl SecurityApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/security/roles").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -155,7 +156,7 @@ impl SecurityApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/security/users/{username}/password").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -177,13 +178,16 @@ impl SecurityApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/security/users/{username}/roles").await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, SecurityApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(SecurityApiError::from)
@@ -249,12 +253,12 @@ impl<'a> AddRoleDefinitionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AddRoleDefinitionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AddRoleDefinitionRequest<'a>, SecurityApiError> {
         Ok(AddRoleDefinitionRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -304,12 +308,12 @@ impl<'a> AddUserRolesRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AddUserRolesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AddUserRolesRequest<'a>, SecurityApiError> {
         Ok(AddUserRolesRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -338,9 +342,9 @@ impl<'a> GetRoleDefinitionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetRoleDefinitionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetRoleDefinitionRequest<'a>, SecurityApiError> {
         Ok(GetRoleDefinitionRequest {
-            role: self.role.ok_or("role is required")?,
+            role: self.role.ok_or(ParamError::missing("role"))?,
         })
     }
 }
@@ -367,9 +371,9 @@ impl<'a> GetUserRolesRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetUserRolesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetUserRolesRequest<'a>, SecurityApiError> {
         Ok(GetUserRolesRequest {
-            username: self.username.ok_or("username is required")?,
+            username: self.username.ok_or(ParamError::missing("username"))?,
         })
     }
 }
@@ -417,11 +421,11 @@ impl<'a> InvalidateUserRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<InvalidateUserRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<InvalidateUserRequest<'a>, SecurityApiError> {
         Ok(InvalidateUserRequest {
-            username: self.username.ok_or("username is required")?,
+            username: self.username.ok_or(ParamError::missing("username"))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -472,12 +476,12 @@ impl<'a> UpdateRoleDefinitionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UpdateRoleDefinitionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UpdateRoleDefinitionRequest<'a>, SecurityApiError> {
         Ok(UpdateRoleDefinitionRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -534,13 +538,13 @@ impl<'a> UpdateUserPasswordRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UpdateUserPasswordRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UpdateUserPasswordRequest<'a>, SecurityApiError> {
         Ok(UpdateUserPasswordRequest {
-            username: self.username.ok_or("username is required")?,
+            username: self.username.ok_or(ParamError::missing("username"))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -597,13 +601,13 @@ impl<'a> UpdateUserRolesRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UpdateUserRolesRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UpdateUserRolesRequest<'a>, SecurityApiError> {
         Ok(UpdateUserRolesRequest {
-            username: self.username.ok_or("username is required")?,
+            username: self.username.ok_or(ParamError::missing("username"))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -623,7 +627,10 @@ pub enum SecurityApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl SecurityApiError {
@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -40,8 +41,8 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}/block").await?;
+        self.handle_response(response).await
     }
 
     pub async fn cancel_subscription_plan(
@@ -82,7 +83,7 @@ impl SubscriptionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -120,7 +121,7 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -160,8 +161,8 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/subscriptions").await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_subscription_custom_fields(
@@ -182,7 +183,9 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -204,7 +207,7 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -247,8 +250,10 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/createSubscriptionWithAddOns")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn create_subscriptions_with_add_ons(
@@ -290,8 +295,10 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/createSubscriptionsWithAddOns")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_subscription_custom_fields(
@@ -319,7 +326,9 @@ impl SubscriptionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -348,13 +357,13 @@ impl SubscriptionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}/tags").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_subscription(
         &self,
-        request: GetSubscriptionRequest<'_>
+        request: GetSubscriptionRequest
     ) -> Result<models::Subscription, SubscriptionApiError> {
         let url = format!(
             "{}/1.0/kb/subscriptions/{}",
@@ -365,15 +374,15 @@ impl SubscriptionApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_subscription_audit_logs_with_history(
         &self,
-        request: GetSubscriptionAuditLogsWithHistoryRequest<'_>
+        request: GetSubscriptionAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, SubscriptionApiError> {
         let url = format!(
             "{}/1.0/kb/subscriptions/{}/auditLogsWithHistory",
@@ -385,8 +394,10 @@ impl SubscriptionApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_subscription_by_key(
@@ -401,17 +412,17 @@ impl SubscriptionApi {
             .query(
                 &[
                     ("externalKey", request.external_key),
-                    ("audit", request.audit.unwrap_or_default()),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/subscriptions").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_subscription_custom_fields(
         &self,
-        request: GetSubscriptionCustomFieldsRequest<'_>
+        request: GetSubscriptionCustomFieldsRequest
     ) -> Result<Vec<models::CustomField>, SubscriptionApiError> {
         let url = format!(
             "{}/1.0/kb/subscriptions/{}/customFields",
@@ -422,15 +433,17 @@ impl SubscriptionApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/customFields")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_subscription_event_audit_logs_with_history(
         &self,
-        request: GetSubscriptionEventAuditLogsWithHistoryRequest<'_>
+        request: GetSubscriptionEventAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, SubscriptionApiError> {
         let url = format!(
             "{}/1.0/kb/subscriptions/events/{}/auditLogsWithHistory",
@@ -442,13 +455,15 @@ impl SubscriptionApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/events/{eventId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_subscription_tags(
         &self,
-        request: GetSubscriptionTagsRequest<'_>
+        request: GetSubscriptionTagsRequest
     ) -> Result<Vec<models::Tag>, SubscriptionApiError> {
         let url = format!(
             "{}/1.0/kb/subscriptions/{}/tags",
@@ -462,12 +477,12 @@ impl SubscriptionApi {
             .query(
                 &[
                     ("includedDeleted", request.included_deleted.map(|b| b.to_string())),
-                    ("audit", request.audit.map(|s| s.to_string())),
                 ]
-            );
+            )
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}/tags").await?;
+        self.handle_response(response).await
     }
 
     pub async fn modify_subscription_custom_fields(
@@ -488,7 +503,9 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/customFields")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -517,7 +534,9 @@ impl SubscriptionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/uncancel")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -546,7 +565,9 @@ impl SubscriptionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/undoChangePlan")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
@@ -577,7 +598,7 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/subscriptions/{subscriptionId}/bcd").await?;
         Self::handle_empty_response(response).await
     }
 
@@ -608,13 +629,18 @@ impl SubscriptionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config
+            .send(req, "/1.0/kb/subscriptions/{subscriptionId}/quantity")
+            .await?;
         Self::handle_empty_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, SubscriptionApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(SubscriptionApiError::from)
@@ -639,7 +665,7 @@ impl SubscriptionApi {
 
 #[derive(Debug, Clone)]
 pub struct AddSubscriptionBlockingStateRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::BlockingState,
     pub(crate) requested_date: Option<String>,
@@ -656,7 +682,7 @@ impl<'a> AddSubscriptionBlockingStateRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct AddSubscriptionBlockingStateRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::BlockingState>,
     requested_date: Option<String>,
@@ -666,8 +692,8 @@ pub struct AddSubscriptionBlockingStateRequestBuilder<'a> {
 }
 
 impl<'a> AddSubscriptionBlockingStateRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -681,8 +707,8 @@ impl<'a> AddSubscriptionBlockingStateRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -701,13 +727,15 @@ impl<'a> AddSubscriptionBlockingStateRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<AddSubscriptionBlockingStateRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<AddSubscriptionBlockingStateRequest<'a>, SubscriptionApiError> {
         Ok(AddSubscriptionBlockingStateRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             requested_date: self.requested_date,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -718,7 +746,7 @@ impl<'a> AddSubscriptionBlockingStateRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CancelSubscriptionPlanRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) requested_date: Option<String>,
     pub(crate) call_completion: Option<bool>,
@@ -739,7 +767,7 @@ impl<'a> CancelSubscriptionPlanRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CancelSubscriptionPlanRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     requested_date: Option<String>,
     call_completion: Option<bool>,
@@ -753,8 +781,8 @@ pub struct CancelSubscriptionPlanRequestBuilder<'a> {
 }
 
 impl<'a> CancelSubscriptionPlanRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -763,8 +791,8 @@ impl<'a> CancelSubscriptionPlanRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -808,11 +836,13 @@ impl<'a> CancelSubscriptionPlanRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CancelSubscriptionPlanRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CancelSubscriptionPlanRequest<'a>, SubscriptionApiError> {
         Ok(CancelSubscriptionPlanRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             requested_date: self.requested_date,
             call_completion: self.call_completion,
@@ -829,7 +859,7 @@ impl<'a> CancelSubscriptionPlanRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ChangeSubscriptionPlanRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::Subscription,
     pub(crate) requested_date: Option<String>,
@@ -849,7 +879,7 @@ impl<'a> ChangeSubscriptionPlanRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ChangeSubscriptionPlanRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::Subscription>,
     requested_date: Option<String>,
@@ -862,8 +892,8 @@ pub struct ChangeSubscriptionPlanRequestBuilder<'a> {
 }
 
 impl<'a> ChangeSubscriptionPlanRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -877,8 +907,8 @@ impl<'a> ChangeSubscriptionPlanRequestBuilder<'a> {
         self
     }
 
-    pub fn requested_date(mut self, requested_date: impl Into<String>) -> Self {
-        self.requested_date = Some(requested_date.into());
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
         self
     }
 
@@ -912,13 +942,15 @@ impl<'a> ChangeSubscriptionPlanRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ChangeSubscriptionPlanRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ChangeSubscriptionPlanRequest<'a>, SubscriptionApiError> {
         Ok(ChangeSubscriptionPlanRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             requested_date: self.requested_date,
             call_completion: self.call_completion,
             call_timeout_sec: self.call_timeout_sec,
@@ -979,13 +1011,13 @@ impl<'a> CreateSubscriptionRequestBuilder<'a> {
         self
     }
 
-    pub fn entitlement_date(mut self, entitlement_date: impl Into<String>) -> Self {
-        self.entitlement_date = Some(entitlement_date.into());
+    pub fn entitlement_date(mut self, entitlement_date: impl models::DateParam) -> Self {
+        self.entitlement_date = Some(entitlement_date.into_param());
         self
     }
 
-    pub fn billing_date(mut self, billing_date: impl Into<String>) -> Self {
-        self.billing_date = Some(billing_date.into());
+    pub fn billing_date(mut self, billing_date: impl models::DateParam) -> Self {
+        self.billing_date = Some(billing_date.into_param());
         self
     }
 
@@ -1032,12 +1064,12 @@ impl<'a> CreateSubscriptionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateSubscriptionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateSubscriptionRequest<'a>, SubscriptionApiError> {
         Ok(CreateSubscriptionRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             entitlement_date: self.entitlement_date,
             billing_date: self.billing_date,
             rename_key_if_exists_and_unused: self.rename_key_if_exists_and_unused,
@@ -1054,7 +1086,7 @@ impl<'a> CreateSubscriptionRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateSubscriptionCustomFieldsRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1069,7 +1101,7 @@ impl<'a> CreateSubscriptionCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateSubscriptionCustomFieldsRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -1077,8 +1109,8 @@ pub struct CreateSubscriptionCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> CreateSubscriptionCustomFieldsRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1102,13 +1134,15 @@ impl<'a> CreateSubscriptionCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateSubscriptionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateSubscriptionCustomFieldsRequest<'a>, SubscriptionApiError> {
         Ok(CreateSubscriptionCustomFieldsRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1117,7 +1151,7 @@ impl<'a> CreateSubscriptionCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct CreateSubscriptionTagsRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<Uuid>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1132,7 +1166,7 @@ impl<'a> CreateSubscriptionTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct CreateSubscriptionTagsRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1140,8 +1174,8 @@ pub struct CreateSubscriptionTagsRequestBuilder<'a> {
 }
 
 impl<'a> CreateSubscriptionTagsRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1165,13 +1199,15 @@ impl<'a> CreateSubscriptionTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateSubscriptionTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateSubscriptionTagsRequest<'a>, SubscriptionApiError> {
         Ok(CreateSubscriptionTagsRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1227,13 +1263,13 @@ impl<'a> CreateSubscriptionWithAddOnsRequestBuilder<'a> {
         self
     }
 
-    pub fn entitlement_date(mut self, entitlement_date: impl Into<String>) -> Self {
-        self.entitlement_date = Some(entitlement_date.into());
+    pub fn entitlement_date(mut self, entitlement_date: impl models::DateParam) -> Self {
+        self.entitlement_date = Some(entitlement_date.into_param());
         self
     }
 
-    pub fn billing_date(mut self, billing_date: impl Into<String>) -> Self {
-        self.billing_date = Some(billing_date.into());
+    pub fn billing_date(mut self, billing_date: impl models::DateParam) -> Self {
+        self.billing_date = Some(billing_date.into_param());
         self
     }
 
@@ -1280,12 +1316,12 @@ impl<'a> CreateSubscriptionWithAddOnsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateSubscriptionWithAddOnsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateSubscriptionWithAddOnsRequest<'a>, SubscriptionApiError> {
         Ok(CreateSubscriptionWithAddOnsRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             entitlement_date: self.entitlement_date,
             billing_date: self.billing_date,
             migrated: self.migrated,
@@ -1349,13 +1385,13 @@ impl<'a> CreateSubscriptionsWithAddOnsRequestBuilder<'a> {
         self
     }
 
-    pub fn entitlement_date(mut self, entitlement_date: impl Into<String>) -> Self {
-        self.entitlement_date = Some(entitlement_date.into());
+    pub fn entitlement_date(mut self, entitlement_date: impl models::DateParam) -> Self {
+        self.entitlement_date = Some(entitlement_date.into_param());
         self
     }
 
-    pub fn billing_date(mut self, billing_date: impl Into<String>) -> Self {
-        self.billing_date = Some(billing_date.into());
+    pub fn billing_date(mut self, billing_date: impl models::DateParam) -> Self {
+        self.billing_date = Some(billing_date.into_param());
         self
     }
 
@@ -1402,12 +1438,12 @@ impl<'a> CreateSubscriptionsWithAddOnsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateSubscriptionsWithAddOnsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateSubscriptionsWithAddOnsRequest<'a>, SubscriptionApiError> {
         Ok(CreateSubscriptionsWithAddOnsRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             entitlement_date: self.entitlement_date,
             billing_date: self.billing_date,
             rename_key_if_exists_and_unused: self.rename_key_if_exists_and_unused,
@@ -1424,7 +1460,7 @@ impl<'a> CreateSubscriptionsWithAddOnsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteSubscriptionCustomFieldsRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) custom_field: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1439,7 +1475,7 @@ impl<'a> DeleteSubscriptionCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteSubscriptionCustomFieldsRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     custom_field: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1447,8 +1483,8 @@ pub struct DeleteSubscriptionCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteSubscriptionCustomFieldsRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1472,11 +1508,13 @@ impl<'a> DeleteSubscriptionCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteSubscriptionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteSubscriptionCustomFieldsRequest<'a>, SubscriptionApiError> {
         Ok(DeleteSubscriptionCustomFieldsRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             custom_field: self.custom_field,
             x_killbill_reason: self.x_killbill_reason,
@@ -1487,7 +1525,7 @@ impl<'a> DeleteSubscriptionCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteSubscriptionTagsRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) tag_def: Option<Vec<Uuid>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1502,7 +1540,7 @@ impl<'a> DeleteSubscriptionTagsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteSubscriptionTagsRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     tag_def: Option<Vec<Uuid>>,
     x_killbill_reason: Option<&'a str>,
@@ -1510,8 +1548,8 @@ pub struct DeleteSubscriptionTagsRequestBuilder<'a> {
 }
 
 impl<'a> DeleteSubscriptionTagsRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1535,11 +1573,13 @@ impl<'a> DeleteSubscriptionTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteSubscriptionTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteSubscriptionTagsRequest<'a>, SubscriptionApiError> {
         Ok(DeleteSubscriptionTagsRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             tag_def: self.tag_def,
             x_killbill_reason: self.x_killbill_reason,
@@ -1549,67 +1589,71 @@ impl<'a> DeleteSubscriptionTagsRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetSubscriptionRequest<'a> {
-    pub(crate) subscription_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetSubscriptionRequest {
+    pub(crate) subscription_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetSubscriptionRequest<'a> {
-    pub fn builder() -> GetSubscriptionRequestBuilder<'a> {
+impl GetSubscriptionRequest {
+    pub fn builder() -> GetSubscriptionRequestBuilder {
         GetSubscriptionRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetSubscriptionRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetSubscriptionRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetSubscriptionRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetSubscriptionRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetSubscriptionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetSubscriptionRequest, SubscriptionApiError> {
         Ok(GetSubscriptionRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetSubscriptionAuditLogsWithHistoryRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+pub struct GetSubscriptionAuditLogsWithHistoryRequest {
+    pub(crate) subscription_id: uuid::Uuid,
 }
 
-impl<'a> GetSubscriptionAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetSubscriptionAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetSubscriptionAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetSubscriptionAuditLogsWithHistoryRequestBuilder {
         GetSubscriptionAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetSubscriptionAuditLogsWithHistoryRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+pub struct GetSubscriptionAuditLogsWithHistoryRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetSubscriptionAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetSubscriptionAuditLogsWithHistoryRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetSubscriptionAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetSubscriptionAuditLogsWithHistoryRequest, SubscriptionApiError> {
         Ok(GetSubscriptionAuditLogsWithHistoryRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
         })
     }
 }
@@ -1617,7 +1661,7 @@ impl<'a> GetSubscriptionAuditLogsWithHistoryRequestBuilder<'a> {
 #[derive(Debug, Clone)]
 pub struct GetSubscriptionByKeyRequest<'a> {
     pub(crate) external_key: &'a str,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetSubscriptionByKeyRequest<'a> {
@@ -1629,7 +1673,7 @@ impl<'a> GetSubscriptionByKeyRequest<'a> {
 #[derive(Debug, Default)]
 pub struct GetSubscriptionByKeyRequestBuilder<'a> {
     external_key: Option<&'a str>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> GetSubscriptionByKeyRequestBuilder<'a> {
@@ -1638,110 +1682,114 @@ impl<'a> GetSubscriptionByKeyRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetSubscriptionByKeyRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetSubscriptionByKeyRequest<'a>, SubscriptionApiError> {
         Ok(GetSubscriptionByKeyRequest {
-            external_key: self.external_key.ok_or("external_key is required")?,
+            external_key: self.external_key.ok_or(ParamError::missing("external_key"))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetSubscriptionCustomFieldsRequest<'a> {
-    pub(crate) subscription_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetSubscriptionCustomFieldsRequest {
+    pub(crate) subscription_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetSubscriptionCustomFieldsRequest<'a> {
-    pub fn builder() -> GetSubscriptionCustomFieldsRequestBuilder<'a> {
+impl GetSubscriptionCustomFieldsRequest {
+    pub fn builder() -> GetSubscriptionCustomFieldsRequestBuilder {
         GetSubscriptionCustomFieldsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetSubscriptionCustomFieldsRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetSubscriptionCustomFieldsRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetSubscriptionCustomFieldsRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetSubscriptionCustomFieldsRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetSubscriptionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetSubscriptionCustomFieldsRequest, SubscriptionApiError> {
         Ok(GetSubscriptionCustomFieldsRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetSubscriptionEventAuditLogsWithHistoryRequest<'a> {
-    pub(crate) event_id: &'a str,
+pub struct GetSubscriptionEventAuditLogsWithHistoryRequest {
+    pub(crate) event_id: uuid::Uuid,
 }
 
-impl<'a> GetSubscriptionEventAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetSubscriptionEventAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetSubscriptionEventAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetSubscriptionEventAuditLogsWithHistoryRequestBuilder {
         GetSubscriptionEventAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetSubscriptionEventAuditLogsWithHistoryRequestBuilder<'a> {
-    event_id: Option<&'a str>,
+pub struct GetSubscriptionEventAuditLogsWithHistoryRequestBuilder {
+    event_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetSubscriptionEventAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn event_id(mut self, event_id: &'a str) -> Self {
-        self.event_id = Some(event_id);
+impl GetSubscriptionEventAuditLogsWithHistoryRequestBuilder {
+    pub fn event_id(mut self, event_id: impl models::IdParam) -> Self {
+        self.event_id = Some(event_id.into_id());
         self
     }
 
     pub fn build(
         self
-    ) -> Result<GetSubscriptionEventAuditLogsWithHistoryRequest<'a>, &'static str> {
+    ) -> Result<GetSubscriptionEventAuditLogsWithHistoryRequest, SubscriptionApiError> {
         Ok(GetSubscriptionEventAuditLogsWithHistoryRequest {
-            event_id: self.event_id.ok_or("event_id is required")?,
+            event_id: self.event_id
+                .ok_or(ParamError::missing("event_id"))?
+                .map_err(|e| ParamError::invalid_id("event_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetSubscriptionTagsRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+pub struct GetSubscriptionTagsRequest {
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) included_deleted: Option<bool>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetSubscriptionTagsRequest<'a> {
-    pub fn builder() -> GetSubscriptionTagsRequestBuilder<'a> {
+impl GetSubscriptionTagsRequest {
+    pub fn builder() -> GetSubscriptionTagsRequestBuilder {
         GetSubscriptionTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetSubscriptionTagsRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+pub struct GetSubscriptionTagsRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     included_deleted: Option<bool>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetSubscriptionTagsRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetSubscriptionTagsRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1750,14 +1798,16 @@ impl<'a> GetSubscriptionTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetSubscriptionTagsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetSubscriptionTagsRequest, SubscriptionApiError> {
         Ok(GetSubscriptionTagsRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             included_deleted: self.included_deleted,
             audit: self.audit,
         })
@@ -1766,7 +1816,7 @@ impl<'a> GetSubscriptionTagsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ModifySubscriptionCustomFieldsRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: Vec<models::CustomField>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1781,7 +1831,7 @@ impl<'a> ModifySubscriptionCustomFieldsRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct ModifySubscriptionCustomFieldsRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<Vec<models::CustomField>>,
     x_killbill_reason: Option<&'a str>,
@@ -1789,8 +1839,8 @@ pub struct ModifySubscriptionCustomFieldsRequestBuilder<'a> {
 }
 
 impl<'a> ModifySubscriptionCustomFieldsRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1814,13 +1864,15 @@ impl<'a> ModifySubscriptionCustomFieldsRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<ModifySubscriptionCustomFieldsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<ModifySubscriptionCustomFieldsRequest<'a>, SubscriptionApiError> {
         Ok(ModifySubscriptionCustomFieldsRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -1829,7 +1881,7 @@ impl<'a> ModifySubscriptionCustomFieldsRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct UncancelSubscriptionPlanRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) plugin_property: Option<Vec<String>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1844,7 +1896,7 @@ impl<'a> UncancelSubscriptionPlanRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct UncancelSubscriptionPlanRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     plugin_property: Option<Vec<String>>,
     x_killbill_reason: Option<&'a str>,
@@ -1852,8 +1904,8 @@ pub struct UncancelSubscriptionPlanRequestBuilder<'a> {
 }
 
 impl<'a> UncancelSubscriptionPlanRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1877,11 +1929,13 @@ impl<'a> UncancelSubscriptionPlanRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UncancelSubscriptionPlanRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UncancelSubscriptionPlanRequest<'a>, SubscriptionApiError> {
         Ok(UncancelSubscriptionPlanRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1892,7 +1946,7 @@ impl<'a> UncancelSubscriptionPlanRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct UndoChangeSubscriptionPlanRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) plugin_property: Option<Vec<String>>,
     pub(crate) x_killbill_reason: Option<&'a str>,
@@ -1907,7 +1961,7 @@ impl<'a> UndoChangeSubscriptionPlanRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct UndoChangeSubscriptionPlanRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     plugin_property: Option<Vec<String>>,
     x_killbill_reason: Option<&'a str>,
@@ -1915,8 +1969,8 @@ pub struct UndoChangeSubscriptionPlanRequestBuilder<'a> {
 }
 
 impl<'a> UndoChangeSubscriptionPlanRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1940,11 +1994,13 @@ impl<'a> UndoChangeSubscriptionPlanRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UndoChangeSubscriptionPlanRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UndoChangeSubscriptionPlanRequest<'a>, SubscriptionApiError> {
         Ok(UndoChangeSubscriptionPlanRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             plugin_property: self.plugin_property,
             x_killbill_reason: self.x_killbill_reason,
@@ -1955,7 +2011,7 @@ impl<'a> UndoChangeSubscriptionPlanRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct UpdateSubscriptionBcdRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::Subscription,
     pub(crate) effective_from_date: Option<String>,
@@ -1972,7 +2028,7 @@ impl<'a> UpdateSubscriptionBcdRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct UpdateSubscriptionBcdRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::Subscription>,
     effective_from_date: Option<String>,
@@ -1982,8 +2038,8 @@ pub struct UpdateSubscriptionBcdRequestBuilder<'a> {
 }
 
 impl<'a> UpdateSubscriptionBcdRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -1997,8 +2053,8 @@ impl<'a> UpdateSubscriptionBcdRequestBuilder<'a> {
         self
     }
 
-    pub fn effective_from_date(mut self, effective_from_date: impl Into<String>) -> Self {
-        self.effective_from_date = Some(effective_from_date.into());
+    pub fn effective_from_date(mut self, effective_from_date: impl models::DateParam) -> Self {
+        self.effective_from_date = Some(effective_from_date.into_param());
         self
     }
 
@@ -2020,13 +2076,15 @@ impl<'a> UpdateSubscriptionBcdRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UpdateSubscriptionBcdRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UpdateSubscriptionBcdRequest<'a>, SubscriptionApiError> {
         Ok(UpdateSubscriptionBcdRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             effective_from_date: self.effective_from_date,
             force_new_bcd_with_past_effective_date: self.force_new_bcd_with_past_effective_date,
             x_killbill_reason: self.x_killbill_reason,
@@ -2037,7 +2095,7 @@ impl<'a> UpdateSubscriptionBcdRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct UpdateSubscriptionQuantityRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) body: models::Subscription,
     pub(crate) effective_from_date: Option<String>,
@@ -2054,7 +2112,7 @@ impl<'a> UpdateSubscriptionQuantityRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct UpdateSubscriptionQuantityRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     body: Option<models::Subscription>,
     effective_from_date: Option<String>,
@@ -2064,8 +2122,8 @@ pub struct UpdateSubscriptionQuantityRequestBuilder<'a> {
 }
 
 impl<'a> UpdateSubscriptionQuantityRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -2079,8 +2137,8 @@ impl<'a> UpdateSubscriptionQuantityRequestBuilder<'a> {
         self
     }
 
-    pub fn effective_from_date(mut self, effective_from_date: impl Into<String>) -> Self {
-        self.effective_from_date = Some(effective_from_date.into());
+    pub fn effective_from_date(mut self, effective_from_date: impl models::DateParam) -> Self {
+        self.effective_from_date = Some(effective_from_date.into_param());
         self
     }
 
@@ -2104,13 +2162,15 @@ impl<'a> UpdateSubscriptionQuantityRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<UpdateSubscriptionQuantityRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<UpdateSubscriptionQuantityRequest<'a>, SubscriptionApiError> {
         Ok(UpdateSubscriptionQuantityRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             effective_from_date: self.effective_from_date,
             force_new_quantity_with_past_effective_date: self.force_new_quantity_with_past_effective_date,
             x_killbill_reason: self.x_killbill_reason,
@@ -2132,7 +2192,10 @@ pub enum SubscriptionApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl SubscriptionApiError {
@@ -2152,7 +2215,7 @@ mod tests {
     async fn test_add_subscription_blocking_state() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/subscriptions/test-subscription/block")
+            .mock("POST", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/block")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"stateName": "test-state"}]"#)
@@ -2162,7 +2225,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = AddSubscriptionBlockingStateRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .body(BlockingState {
                 ..Default::default()
@@ -2180,7 +2243,7 @@ mod tests {
     async fn test_cancel_subscription_plan() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/subscriptions/test-subscription")
+            .mock("DELETE", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8")
             .with_status(204)
             .create_async().await;
 
@@ -2188,7 +2251,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = CancelSubscriptionPlanRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2203,7 +2266,7 @@ mod tests {
     async fn test_change_subscription_plan() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/subscriptions/test-subscription")
+            .mock("PUT", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8")
             .with_status(204)
             .create_async().await;
 
@@ -2211,7 +2274,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = ChangeSubscriptionPlanRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .body(Subscription {
                 ..Default::default()
@@ -2232,7 +2295,7 @@ mod tests {
             .mock("POST", "/1.0/kb/subscriptions")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"subscriptionId": "test-subscription"}"#)
+            .with_body(r#"{"subscriptionId": "fa3486b7-d3af-5635-81f1-28261af32bc8"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -2256,7 +2319,7 @@ mod tests {
     async fn test_create_subscription_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/subscriptions/test-subscription/customFields")
+            .mock("POST", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -2264,7 +2327,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = CreateSubscriptionCustomFieldsRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -2284,7 +2347,7 @@ mod tests {
     async fn test_create_subscription_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("POST", "/1.0/kb/subscriptions/test-subscription/tags")
+            .mock("POST", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/tags")
             .with_status(204)
             .create_async().await;
 
@@ -2292,7 +2355,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = CreateSubscriptionTagsRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .body(vec![Uuid::new_v4()])
             .build()
@@ -2366,7 +2429,7 @@ mod tests {
     async fn test_delete_subscription_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/subscriptions/test-subscription/customFields")
+            .mock("DELETE", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -2374,7 +2437,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = DeleteSubscriptionCustomFieldsRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2389,7 +2452,7 @@ mod tests {
     async fn test_delete_subscription_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/subscriptions/test-subscription/tags")
+            .mock("DELETE", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/tags")
             .with_status(204)
             .create_async().await;
 
@@ -2397,7 +2460,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = DeleteSubscriptionTagsRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2412,17 +2475,17 @@ mod tests {
     async fn test_get_subscription() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/subscriptions/test-subscription")
+            .mock("GET", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"subscriptionId": "test-subscription"}"#)
+            .with_body(r#"{"subscriptionId": "fa3486b7-d3af-5635-81f1-28261af32bc8"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = SubscriptionApi::new(config);
         let request = GetSubscriptionRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .build()
             .unwrap();
 
@@ -2436,7 +2499,7 @@ mod tests {
     async fn test_get_subscription_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/subscriptions/test-subscription/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"changeType": "INSERT"}]"#)
@@ -2446,7 +2509,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = GetSubscriptionAuditLogsWithHistoryRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .build()
             .unwrap();
 
@@ -2463,14 +2526,14 @@ mod tests {
             .mock("GET", "/1.0/kb/subscriptions")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"subscriptionId": "test-subscription"}"#)
+            .with_body(r#"{"subscriptionId": "fa3486b7-d3af-5635-81f1-28261af32bc8"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = SubscriptionApi::new(config);
         let request = GetSubscriptionByKeyRequest::builder()
-            .external_key("test-subscription")
+            .external_key("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .build()
             .unwrap();
 
@@ -2484,7 +2547,7 @@ mod tests {
     async fn test_get_subscription_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/subscriptions/test-subscription/customFields")
+            .mock("GET", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/customFields")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"name": "test-name"}]"#)
@@ -2494,7 +2557,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = GetSubscriptionCustomFieldsRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .build()
             .unwrap();
 
@@ -2508,7 +2571,7 @@ mod tests {
     async fn test_get_subscription_event_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/subscriptions/events/test-event/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/subscriptions/events/24f2f5dd-7870-5883-93ca-b7d670bdd821/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"changeType": "INSERT"}]"#)
@@ -2518,7 +2581,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = GetSubscriptionEventAuditLogsWithHistoryRequest::builder()
-            .event_id("test-event")
+            .event_id("24f2f5dd-7870-5883-93ca-b7d670bdd821")
             .build()
             .unwrap();
 
@@ -2532,7 +2595,7 @@ mod tests {
     async fn test_get_subscription_tags() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/subscriptions/test-subscription/tags")
+            .mock("GET", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/tags")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"tagId": "test-tag"}]"#)
@@ -2542,7 +2605,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = GetSubscriptionTagsRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .build()
             .unwrap();
 
@@ -2556,7 +2619,7 @@ mod tests {
     async fn test_modify_subscription_custom_fields() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/subscriptions/test-subscription/customFields")
+            .mock("PUT", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/customFields")
             .with_status(204)
             .create_async().await;
 
@@ -2564,7 +2627,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = ModifySubscriptionCustomFieldsRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .body(
                 vec![CustomField {
@@ -2584,7 +2647,7 @@ mod tests {
     async fn test_uncancel_subscription_plan() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/subscriptions/test-subscription/uncancel")
+            .mock("PUT", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/uncancel")
             .with_status(204)
             .create_async().await;
 
@@ -2592,7 +2655,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = UncancelSubscriptionPlanRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2607,7 +2670,7 @@ mod tests {
     async fn test_undo_change_subscription_plan() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/subscriptions/test-subscription/undoChangePlan")
+            .mock("PUT", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/undoChangePlan")
             .with_status(204)
             .create_async().await;
 
@@ -2615,7 +2678,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = UndoChangeSubscriptionPlanRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -2630,7 +2693,7 @@ mod tests {
     async fn test_update_subscription_bcd() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/subscriptions/test-subscription/bcd")
+            .mock("PUT", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/bcd")
             .with_status(204)
             .create_async().await;
 
@@ -2638,7 +2701,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = UpdateSubscriptionBcdRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .body(Subscription {
                 ..Default::default()
@@ -2656,7 +2719,7 @@ mod tests {
     async fn test_update_subscription_quantity() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("PUT", "/1.0/kb/subscriptions/test-subscription/quantity")
+            .mock("PUT", "/1.0/kb/subscriptions/fa3486b7-d3af-5635-81f1-28261af32bc8/quantity")
             .with_status(204)
             .create_async().await;
 
@@ -2664,7 +2727,7 @@ mod tests {
 
         let api = SubscriptionApi::new(config);
         let request = UpdateSubscriptionQuantityRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .x_killbill_created_by("test")
             .body(Subscription {
                 ..Default::default()
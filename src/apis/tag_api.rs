@@ -1,6 +1,7 @@
+use futures::Stream;
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, pagination::{ paginate, Page }, AuditQuery }, models };
 use thiserror::Error;
 
 pub struct TagApi {
@@ -14,7 +15,7 @@ impl TagApi {
 
     pub async fn get_tag_audit_logs_with_history(
         &self,
-        request: GetTagAuditLogsWithHistoryRequest<'_>
+        request: GetTagAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, TagApiError> {
         let url = format!(
             "{}/1.0/kb/tags/{}/auditLogsWithHistory",
@@ -24,40 +25,65 @@ impl TagApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/tags/{tagId}/auditLogsWithHistory").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_tags(
         &self,
-        request: GetTagsRequest<'_>
-    ) -> Result<Vec<models::Tag>, TagApiError> {
+        request: GetTagsRequest
+    ) -> Result<Page<models::Tag>, TagApiError> {
         let url = format!("{}/1.0/kb/tags/pagination", self.config.base_path);
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&Self::build_pagination_query(&request.offset, &request.limit, &request.audit));
-        let response = req.send().await?;
-        Self::handle_response(response).await
+            .query(&Self::build_pagination_query(&request.offset, &request.limit))
+            .audit(request.audit);
+        let response = self.config.send(req, "/1.0/kb/tags/pagination").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn get_tags_stream<'a>(
+        &'a self,
+        request: GetTagsRequest
+    ) -> impl Stream<Item = Result<models::Tag, TagApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.get_tags(request)
+        })
     }
 
     pub async fn search_tags(
         &self,
         request: SearchTagsRequest<'_>
-    ) -> Result<Vec<models::Tag>, TagApiError> {
+    ) -> Result<Page<models::Tag>, TagApiError> {
         let url = format!("{}/1.0/kb/tags/search/{}", self.config.base_path, request.search_key);
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&Self::build_pagination_query(&request.offset, &request.limit, &request.audit));
-        let response = req.send().await?;
-        Self::handle_response(response).await
+            .query(&Self::build_pagination_query(&request.offset, &request.limit))
+            .audit(request.audit);
+        let response = self.config.send(req, "/1.0/kb/tags/search/{searchKey}").await?;
+        self.handle_page_response(response).await
+    }
+
+    pub fn search_tags_stream<'a>(
+        &'a self,
+        request: SearchTagsRequest<'a>
+    ) -> impl Stream<Item = Result<models::Tag, TagApiError>> + 'a {
+        let offset = request.offset.unwrap_or_default();
+        paginate(offset, move |offset| {
+            let mut request = request.clone();
+            request.offset = Some(offset);
+            self.search_tags(request)
+        })
     }
 
     fn build_pagination_query(
         offset: &Option<i64>,
-        limit: &Option<i64>,
-        audit: &Option<&str>
+        limit: &Option<i64>
     ) -> Vec<(&'static str, String)> {
         let mut query = vec![];
         if let Some(offset) = offset {
@@ -66,13 +92,15 @@ impl TagApi {
         if let Some(limit) = limit {
             query.push(("limit", limit.to_string()));
         }
-        if let Some(audit) = audit {
-            query.push(("audit", audit.to_string()));
-        }
         query
     }
 
-    async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T, TagApiError> {
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, TagApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(TagApiError::from)
@@ -84,40 +112,49 @@ impl TagApi {
             }
         }
     }
+
+    async fn handle_page_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<Page<T>, TagApiError> {
+        let headers = response.headers().clone();
+        let items = self.handle_response(response).await?;
+        Ok(Page::new(items, &headers))
+    }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetTagAuditLogsWithHistoryRequest<'a> {
-    pub(crate) tag_id: &'a str,
+pub struct GetTagAuditLogsWithHistoryRequest {
+    pub(crate) tag_id: uuid::Uuid,
 }
 
-impl<'a> GetTagAuditLogsWithHistoryRequest<'a> {
-    pub fn new(tag_id: &'a str) -> Self {
+impl GetTagAuditLogsWithHistoryRequest {
+    pub fn new(tag_id: uuid::Uuid) -> Self {
         Self { tag_id }
     }
 }
 
 #[derive(Debug, Clone, Default)]
-pub struct GetTagsRequest<'a> {
+pub struct GetTagsRequest {
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTagsRequest<'a> {
-    pub fn builder() -> GetTagsRequestBuilder<'a> {
+impl GetTagsRequest {
+    pub fn builder() -> GetTagsRequestBuilder {
         GetTagsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetTagsRequestBuilder<'a> {
+pub struct GetTagsRequestBuilder {
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTagsRequestBuilder<'a> {
+impl GetTagsRequestBuilder {
     pub fn offset(mut self, offset: i64) -> Self {
         self.offset = Some(offset);
         self
@@ -128,12 +165,12 @@ impl<'a> GetTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> GetTagsRequest<'a> {
+    pub fn build(self) -> GetTagsRequest {
         GetTagsRequest {
             offset: self.offset,
             limit: self.limit,
@@ -147,7 +184,7 @@ pub struct SearchTagsRequest<'a> {
     pub(crate) search_key: &'a str,
     pub(crate) offset: Option<i64>,
     pub(crate) limit: Option<i64>,
-    pub(crate) audit: Option<&'a str>,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchTagsRequest<'a> {
@@ -161,7 +198,7 @@ pub struct SearchTagsRequestBuilder<'a> {
     search_key: Option<&'a str>,
     offset: Option<i64>,
     limit: Option<i64>,
-    audit: Option<&'a str>,
+    audit: Option<models::AuditLevel>,
 }
 
 impl<'a> SearchTagsRequestBuilder<'a> {
@@ -180,7 +217,7 @@ impl<'a> SearchTagsRequestBuilder<'a> {
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
@@ -208,7 +245,10 @@ pub enum TagApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl TagApiError {
@@ -227,7 +267,7 @@ mod tests {
     async fn test_get_tag_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/tags/test-tag/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/tags/8935c94e-4f20-5265-a227-5d6e5f71c8d6/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"changeType": "INSERT"}]"#)
@@ -236,7 +276,7 @@ mod tests {
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = TagApi::new(config);
-        let request = GetTagAuditLogsWithHistoryRequest::new("test-tag");
+        let request = GetTagAuditLogsWithHistoryRequest::new("8935c94e-4f20-5265-a227-5d6e5f71c8d6".parse().unwrap());
 
         let result = api.get_tag_audit_logs_with_history(request).await;
         assert!(result.is_ok());
@@ -251,7 +291,7 @@ mod tests {
             .mock("GET", "/1.0/kb/tags/pagination")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"tagId": "test-tag"}]"#)
+            .with_body(r#"[{"tagId": "8935c94e-4f20-5265-a227-5d6e5f71c8d6"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -272,7 +312,7 @@ mod tests {
             .mock("GET", "/1.0/kb/tags/search/test-search")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"tagId": "test-tag"}]"#)
+            .with_body(r#"[{"tagId": "8935c94e-4f20-5265-a227-5d6e5f71c8d6"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
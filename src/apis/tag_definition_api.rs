@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
-use crate::{ apis::configuration::Configuration, models };
+use crate::{ apis::{ configuration::Configuration, AuditQuery }, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct TagDefinitionApi {
@@ -26,8 +27,8 @@ impl TagDefinitionApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/tagDefinitions").await?;
+        self.handle_response(response).await
     }
 
     pub async fn delete_tag_definition(
@@ -47,13 +48,13 @@ impl TagDefinitionApi {
             .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/tagDefinitions/{tagDefinitionId}").await?;
         Self::handle_empty_response(response).await
     }
 
     pub async fn get_tag_definition(
         &self,
-        request: GetTagDefinitionRequest<'_>
+        request: GetTagDefinitionRequest
     ) -> Result<models::TagDefinition, TagDefinitionApiError> {
         let url = format!(
             "{}/1.0/kb/tagDefinitions/{}",
@@ -64,15 +65,15 @@ impl TagDefinitionApi {
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/tagDefinitions/{tagDefinitionId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_tag_definition_audit_logs_with_history(
         &self,
-        request: GetTagDefinitionAuditLogsWithHistoryRequest<'_>
+        request: GetTagDefinitionAuditLogsWithHistoryRequest
     ) -> Result<Vec<models::AuditLog>, TagDefinitionApiError> {
         let url = format!(
             "{}/1.0/kb/tagDefinitions/{}/auditLogsWithHistory",
@@ -84,28 +85,33 @@ impl TagDefinitionApi {
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers());
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config
+            .send(req, "/1.0/kb/tagDefinitions/{tagDefinitionId}/auditLogsWithHistory")
+            .await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_tag_definitions(
         &self,
-        request: GetTagDefinitionsRequest<'_>
+        request: GetTagDefinitionsRequest
     ) -> Result<Vec<models::TagDefinition>, TagDefinitionApiError> {
         let url = format!("{}/1.0/kb/tagDefinitions", self.config.base_path);
 
         let req = self.config.client
             .request(Method::GET, &url)
             .headers(self.config.get_auth_headers())
-            .query(&[("audit", request.audit)]);
+            .audit(request.audit);
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/tagDefinitions").await?;
+        self.handle_response(response).await
     }
 
     async fn handle_response<T: DeserializeOwned>(
+        &self,
         response: Response
     ) -> Result<T, TagDefinitionApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(TagDefinitionApiError::from)
@@ -171,12 +177,12 @@ impl<'a> CreateTagDefinitionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<CreateTagDefinitionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<CreateTagDefinitionRequest<'a>, TagDefinitionApiError> {
         Ok(CreateTagDefinitionRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -185,7 +191,7 @@ impl<'a> CreateTagDefinitionRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct DeleteTagDefinitionRequest<'a> {
-    pub(crate) tag_definition_id: &'a str,
+    pub(crate) tag_definition_id: uuid::Uuid,
     pub(crate) x_killbill_created_by: &'a str,
     pub(crate) x_killbill_reason: Option<&'a str>,
     pub(crate) x_killbill_comment: Option<&'a str>,
@@ -199,15 +205,15 @@ impl<'a> DeleteTagDefinitionRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct DeleteTagDefinitionRequestBuilder<'a> {
-    tag_definition_id: Option<&'a str>,
+    tag_definition_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     x_killbill_created_by: Option<&'a str>,
     x_killbill_reason: Option<&'a str>,
     x_killbill_comment: Option<&'a str>,
 }
 
 impl<'a> DeleteTagDefinitionRequestBuilder<'a> {
-    pub fn tag_definition_id(mut self, tag_definition_id: &'a str) -> Self {
-        self.tag_definition_id = Some(tag_definition_id);
+    pub fn tag_definition_id(mut self, tag_definition_id: impl models::IdParam) -> Self {
+        self.tag_definition_id = Some(tag_definition_id.into_id());
         self
     }
 
@@ -226,11 +232,13 @@ impl<'a> DeleteTagDefinitionRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<DeleteTagDefinitionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<DeleteTagDefinitionRequest<'a>, TagDefinitionApiError> {
         Ok(DeleteTagDefinitionRequest {
-            tag_definition_id: self.tag_definition_id.ok_or("tag_definition_id is required")?,
+            tag_definition_id: self.tag_definition_id
+                .ok_or(ParamError::missing("tag_definition_id"))?
+                .map_err(|e| ParamError::invalid_id("tag_definition_id", e))?,
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
@@ -239,94 +247,98 @@ impl<'a> DeleteTagDefinitionRequestBuilder<'a> {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetTagDefinitionRequest<'a> {
-    pub(crate) tag_definition_id: &'a str,
-    pub(crate) audit: Option<&'a str>,
+pub struct GetTagDefinitionRequest {
+    pub(crate) tag_definition_id: uuid::Uuid,
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTagDefinitionRequest<'a> {
-    pub fn builder() -> GetTagDefinitionRequestBuilder<'a> {
+impl GetTagDefinitionRequest {
+    pub fn builder() -> GetTagDefinitionRequestBuilder {
         GetTagDefinitionRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetTagDefinitionRequestBuilder<'a> {
-    tag_definition_id: Option<&'a str>,
-    audit: Option<&'a str>,
+pub struct GetTagDefinitionRequestBuilder {
+    tag_definition_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTagDefinitionRequestBuilder<'a> {
-    pub fn tag_definition_id(mut self, tag_definition_id: &'a str) -> Self {
-        self.tag_definition_id = Some(tag_definition_id);
+impl GetTagDefinitionRequestBuilder {
+    pub fn tag_definition_id(mut self, tag_definition_id: impl models::IdParam) -> Self {
+        self.tag_definition_id = Some(tag_definition_id.into_id());
         self
     }
 
-    pub fn audit(mut self, audit: &'a str) -> Self {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetTagDefinitionRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetTagDefinitionRequest, TagDefinitionApiError> {
         Ok(GetTagDefinitionRequest {
-            tag_definition_id: self.tag_definition_id.ok_or("tag_definition_id is required")?,
+            tag_definition_id: self.tag_definition_id
+                .ok_or(ParamError::missing("tag_definition_id"))?
+                .map_err(|e| ParamError::invalid_id("tag_definition_id", e))?,
             audit: self.audit,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetTagDefinitionAuditLogsWithHistoryRequest<'a> {
-    pub(crate) tag_definition_id: &'a str,
+pub struct GetTagDefinitionAuditLogsWithHistoryRequest {
+    pub(crate) tag_definition_id: uuid::Uuid,
 }
 
-impl<'a> GetTagDefinitionAuditLogsWithHistoryRequest<'a> {
-    pub fn builder() -> GetTagDefinitionAuditLogsWithHistoryRequestBuilder<'a> {
+impl GetTagDefinitionAuditLogsWithHistoryRequest {
+    pub fn builder() -> GetTagDefinitionAuditLogsWithHistoryRequestBuilder {
         GetTagDefinitionAuditLogsWithHistoryRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetTagDefinitionAuditLogsWithHistoryRequestBuilder<'a> {
-    tag_definition_id: Option<&'a str>,
+pub struct GetTagDefinitionAuditLogsWithHistoryRequestBuilder {
+    tag_definition_id: Option<Result<uuid::Uuid, models::InvalidId>>,
 }
 
-impl<'a> GetTagDefinitionAuditLogsWithHistoryRequestBuilder<'a> {
-    pub fn tag_definition_id(mut self, tag_definition_id: &'a str) -> Self {
-        self.tag_definition_id = Some(tag_definition_id);
+impl GetTagDefinitionAuditLogsWithHistoryRequestBuilder {
+    pub fn tag_definition_id(mut self, tag_definition_id: impl models::IdParam) -> Self {
+        self.tag_definition_id = Some(tag_definition_id.into_id());
         self
     }
 
-    pub fn build(self) -> Result<GetTagDefinitionAuditLogsWithHistoryRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetTagDefinitionAuditLogsWithHistoryRequest, TagDefinitionApiError> {
         Ok(GetTagDefinitionAuditLogsWithHistoryRequest {
-            tag_definition_id: self.tag_definition_id.ok_or("tag_definition_id is required")?,
+            tag_definition_id: self.tag_definition_id
+                .ok_or(ParamError::missing("tag_definition_id"))?
+                .map_err(|e| ParamError::invalid_id("tag_definition_id", e))?,
         })
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct GetTagDefinitionsRequest<'a> {
-    pub(crate) audit: Option<&'a str>,
+pub struct GetTagDefinitionsRequest {
+    pub(crate) audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTagDefinitionsRequest<'a> {
-    pub fn builder() -> GetTagDefinitionsRequestBuilder<'a> {
+impl GetTagDefinitionsRequest {
+    pub fn builder() -> GetTagDefinitionsRequestBuilder {
         GetTagDefinitionsRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetTagDefinitionsRequestBuilder<'a> {
-    audit: Option<&'a str>,
+pub struct GetTagDefinitionsRequestBuilder {
+    audit: Option<models::AuditLevel>,
 }
 
-impl<'a> GetTagDefinitionsRequestBuilder<'a> {
-    pub fn audit(mut self, audit: &'a str) -> Self {
+impl GetTagDefinitionsRequestBuilder {
+    pub fn audit(mut self, audit: models::AuditLevel) -> Self {
         self.audit = Some(audit);
         self
     }
 
-    pub fn build(self) -> Result<GetTagDefinitionsRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetTagDefinitionsRequest, TagDefinitionApiError> {
         Ok(GetTagDefinitionsRequest {
             audit: self.audit,
         })
@@ -346,7 +358,10 @@ pub enum TagDefinitionApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl TagDefinitionApiError {
@@ -368,7 +383,7 @@ mod tests {
             .mock("POST", "/1.0/kb/tagDefinitions")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"name": "test-tag"}"#)
+            .with_body(r#"{"name": "8935c94e-4f20-5265-a227-5d6e5f71c8d6"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
@@ -392,7 +407,7 @@ mod tests {
     async fn test_delete_tag_definition() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("DELETE", "/1.0/kb/tagDefinitions/test-tag")
+            .mock("DELETE", "/1.0/kb/tagDefinitions/8935c94e-4f20-5265-a227-5d6e5f71c8d6")
             .with_status(204)
             .create_async().await;
 
@@ -400,7 +415,7 @@ mod tests {
 
         let api = TagDefinitionApi::new(config);
         let request = DeleteTagDefinitionRequest::builder()
-            .tag_definition_id("test-tag")
+            .tag_definition_id("8935c94e-4f20-5265-a227-5d6e5f71c8d6")
             .x_killbill_created_by("test")
             .build()
             .unwrap();
@@ -415,17 +430,17 @@ mod tests {
     async fn test_get_tag_definition() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/tagDefinitions/test-tag")
+            .mock("GET", "/1.0/kb/tagDefinitions/8935c94e-4f20-5265-a227-5d6e5f71c8d6")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"name": "test-tag"}"#)
+            .with_body(r#"{"name": "8935c94e-4f20-5265-a227-5d6e5f71c8d6"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = TagDefinitionApi::new(config);
         let request = GetTagDefinitionRequest::builder()
-            .tag_definition_id("test-tag")
+            .tag_definition_id("8935c94e-4f20-5265-a227-5d6e5f71c8d6")
             .build()
             .unwrap();
 
@@ -439,7 +454,7 @@ mod tests {
     async fn test_get_tag_definition_audit_logs_with_history() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/tagDefinitions/test-tag/auditLogsWithHistory")
+            .mock("GET", "/1.0/kb/tagDefinitions/8935c94e-4f20-5265-a227-5d6e5f71c8d6/auditLogsWithHistory")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"[{"changeType": "INSERT"}]"#)
@@ -449,7 +464,7 @@ mod tests {
 
         let api = TagDefinitionApi::new(config);
         let request = GetTagDefinitionAuditLogsWithHistoryRequest::builder()
-            .tag_definition_id("test-tag")
+            .tag_definition_id("8935c94e-4f20-5265-a227-5d6e5f71c8d6")
             .build()
             .unwrap();
 
@@ -466,7 +481,7 @@ mod tests {
             .mock("GET", "/1.0/kb/tagDefinitions")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"[{"name": "test-tag"}]"#)
+            .with_body(r#"[{"name": "8935c94e-4f20-5265-a227-5d6e5f71c8d6"}]"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
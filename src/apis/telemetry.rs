@@ -0,0 +1,316 @@
+use std::future::Future;
+use std::time::{ Duration, Instant };
+
+use reqwest::{ header::HeaderValue, Request, Response, ResponseBuilderExt, StatusCode };
+use tracing::{ field::Empty, Instrument, Span };
+
+use crate::apis::error::BillingException;
+
+pub(crate) const REQUEST_ID: &str = "X-Request-Id";
+
+/// Span of one API call, covering all of its attempts
+///
+/// The status, latency and request id are those of the final response, while
+/// each attempt is recorded as an event of its own.
+pub(crate) struct Call {
+    span: Span,
+    request_id: Option<HeaderValue>,
+    start: Instant,
+}
+
+impl Call {
+    pub(crate) fn new(request: &Request, route: &'static str) -> Self {
+        let span = tracing::info_span!(
+            "Kill Bill request",
+            http.method = %request.method(),
+            http.route = route,
+            http.status_code = Empty,
+            latency_ms = Empty,
+            request_id = Empty,
+            error.class_name = Empty,
+            error.code = Empty
+        );
+
+        Self {
+            span,
+            request_id: request.headers().get(REQUEST_ID).cloned(),
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) async fn run(
+        self,
+        attempts: impl Future<Output = Result<Response, reqwest::Error>>
+    ) -> Result<Response, reqwest::Error> {
+        let result = attempts.instrument(self.span.clone()).await;
+        self.span.record("latency_ms", self.start.elapsed().as_millis() as u64);
+
+        // The request id sent back by Kill Bill wins over the one set on the request
+        let response_id = result
+            .as_ref()
+            .ok()
+            .and_then(|response| response.headers().get(REQUEST_ID));
+        let request_id = response_id.or(self.request_id.as_ref()).and_then(|id| id.to_str().ok());
+        if let Some(request_id) = request_id {
+            self.span.record("request_id", request_id);
+        }
+
+        let status = match &result {
+            Ok(response) => Some(response.status()),
+            Err(error) => error.status(),
+        };
+        if let Some(status) = status {
+            self.span.record("http.status_code", status.as_u16());
+        }
+
+        match result {
+            Ok(response) if !response.status().is_success() => {
+                self.api_error(response).instrument(self.span.clone()).await
+            }
+            result => result,
+        }
+    }
+
+    /// Record the error of a non-success response on the span
+    ///
+    /// The body is read to parse the Kill Bill error, then put back into the
+    /// returned response for the API to turn into its error.
+    async fn api_error(&self, response: Response) -> Result<Response, reqwest::Error> {
+        let status = response.status();
+        let version = response.version();
+        let url = response.url().clone();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let exception = BillingException::parse(&String::from_utf8_lossy(&body));
+        if let Some(exception) = &exception {
+            self.span.record("error.class_name", exception.class_name.as_deref());
+            self.span.record("error.code", exception.code);
+        }
+        api_error(status, exception, &body);
+
+        let mut builder = http::Response::builder().status(status).version(version).url(url);
+        if let Some(response_headers) = builder.headers_mut() {
+            *response_headers = headers;
+        }
+        let response = builder.body(body).expect("status and headers come from a response");
+        Ok(Response::from(response))
+    }
+}
+
+/// Emit an event for one attempt of a call
+pub(crate) fn attempt(attempt: u32, result: &Result<Response, reqwest::Error>, elapsed: Duration) {
+    let latency_ms = elapsed.as_millis() as u64;
+    match result {
+        Ok(response) =>
+            tracing::debug!(
+                attempt,
+                http.status_code = response.status().as_u16(),
+                latency_ms,
+                "Kill Bill request attempt"
+            ),
+        Err(error) =>
+            tracing::warn!(
+                attempt,
+                http.status_code = error.status().map(|status| status.as_u16()),
+                latency_ms,
+                error = %error,
+                "Kill Bill request failed"
+            ),
+    }
+}
+
+/// Emit an error event for a non-success response, with the parsed Kill Bill error if any
+fn api_error(status: StatusCode, exception: Option<BillingException>, body: &[u8]) {
+    match exception {
+        Some(exception) =>
+            tracing::error!(
+                http.status_code = status.as_u16(),
+                error.class_name = exception.class_name.as_deref(),
+                error.code = exception.code,
+                error.message = exception.message.as_deref(),
+                error.cause_class_name = exception.cause_class_name.as_deref(),
+                error.cause_message = exception.cause_message.as_deref(),
+                "Kill Bill API error"
+            ),
+        None =>
+            tracing::error!(
+                http.status_code = status.as_u16(),
+                body = %String::from_utf8_lossy(body),
+                "Kill Bill API error"
+            ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::sync::{ Arc, Mutex };
+
+    use tracing_subscriber::fmt::{ format::FmtSpan, MakeWriter };
+
+    use crate::apis::{
+        configuration::Configuration,
+        nodes_info_api::NodesApi,
+        retry::RetryPolicy,
+        tag_definition_api::{ GetTagDefinitionRequest, TagDefinitionApi },
+    };
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn subscriber(buffer: &Buffer) -> impl tracing::Subscriber {
+        tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_span_events(FmtSpan::CLOSE)
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn test_api_calls_are_traced() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tagDefinitions/5a579cae-a171-5ce4-956e-720acfa7bc4c")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_header("X-Request-Id", "req-7")
+            .with_body(
+                r#"{"className":"org.killbill.billing.util.api.TagDefinitionApiException","code":3902,"message":"Tag definition does not exist"}"#
+            )
+            .create_async().await;
+
+        let buffer = Buffer::default();
+        let _guard = tracing::subscriber::set_default(subscriber(&buffer));
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+        let api = TagDefinitionApi::new(config);
+        let request = GetTagDefinitionRequest::builder()
+            .tag_definition_id("5a579cae-a171-5ce4-956e-720acfa7bc4c")
+            .build()
+            .unwrap();
+        assert!(api.get_tag_definition(request).await.is_err());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("Kill Bill request{http.method=GET"));
+        assert!(output.contains(r#"http.route="/1.0/kb/tagDefinitions/{tagDefinitionId}""#));
+        assert!(output.contains("http.status_code=404"));
+        assert!(output.contains("request_id=\"req-7\""));
+        assert!(output.contains("latency_ms="));
+        assert!(output.contains("error.code=3902"));
+        assert!(output.contains("Tag definition does not exist"));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_api_error_is_recorded_in_the_request_span() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tagDefinitions/5a579cae-a171-5ce4-956e-720acfa7bc4c")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_header("X-Request-Id", "req-8")
+            .with_body(
+                r#"{"className":"org.killbill.billing.util.api.TagDefinitionApiException","code":3902,"message":"Tag definition does not exist"}"#
+            )
+            .create_async().await;
+
+        let buffer = Buffer::default();
+        let _guard = tracing::subscriber::set_default(subscriber(&buffer));
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+        let api = TagDefinitionApi::new(config);
+        let request = GetTagDefinitionRequest::builder()
+            .tag_definition_id("5a579cae-a171-5ce4-956e-720acfa7bc4c")
+            .build()
+            .unwrap();
+        let error = api.get_tag_definition(request).await.unwrap_err();
+        assert!(error.to_string().contains("Tag definition does not exist"));
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let errors: Vec<_> = output
+            .lines()
+            .filter(|line| line.contains("Kill Bill API error"))
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("Kill Bill request{http.method=GET"));
+        assert!(errors[0].contains(r#"http.route="/1.0/kb/tagDefinitions/{tagDefinitionId}""#));
+        assert!(errors[0].contains("http.status_code=404"));
+        assert!(errors[0].contains("request_id=\"req-8\""));
+        assert!(errors[0].contains("error.code=3902"));
+
+        let closed: Vec<_> = output.lines().filter(|line| line.contains(": close ")).collect();
+        assert_eq!(closed.len(), 1);
+        assert!(closed[0].contains("error.code=3902"));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_span_keeps_the_final_attempt() {
+        let mut server = mockito::Server::new_async().await;
+        let unavailable = server
+            .mock("GET", "/1.0/kb/nodesInfo")
+            .with_status(503)
+            .expect(1)
+            .create_async().await;
+        let ok = server
+            .mock("GET", "/1.0/kb/nodesInfo")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .expect(1)
+            .create_async().await;
+
+        let buffer = Buffer::default();
+        let _guard = tracing::subscriber::set_default(subscriber(&buffer));
+
+        let config = Configuration::builder()
+            .base_path(server.url())
+            .retry_policy(
+                RetryPolicy::default()
+                    .max_attempts(2)
+                    .initial_backoff(std::time::Duration::from_millis(1))
+            )
+            .build()
+            .unwrap();
+        NodesApi::new(config).get_nodes_info().await.unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let attempts: Vec<_> = output
+            .lines()
+            .filter(|line| line.contains("Kill Bill request attempt"))
+            .collect();
+        assert_eq!(attempts.len(), 2);
+        assert!(attempts[0].contains("attempt=1 http.status_code=503"));
+        assert!(attempts[1].contains("attempt=2 http.status_code=200"));
+
+        let closed: Vec<_> = output.lines().filter(|line| line.contains(": close ")).collect();
+        assert_eq!(closed.len(), 1);
+        assert!(closed[0].contains("http.status_code=200"));
+
+        unavailable.assert_async().await;
+        ok.assert_async().await;
+    }
+}
@@ -1,1104 +1,1617 @@
-// /*
-//  * Kill Bill
-//  *
-//  * Kill Bill is an open-source billing and payments platform
-//  *
-//  * The version of the OpenAPI document: 0.24.10
-//  * 
-//  * Generated by: https://openapi-generator.tech
-//  */
-
-
-// use reqwest;
-// use serde::{Deserialize, Serialize};
-// use crate::{apis::ResponseContent, models};
-// use super::{Error, configuration};
-
-
-// /// struct for typed errors of method [`create_tenant`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum CreateTenantError {
-//     Status409(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`delete_per_tenant_configuration`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum DeletePerTenantConfigurationError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`delete_plugin_configuration`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum DeletePluginConfigurationError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`delete_plugin_payment_state_machine_config`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum DeletePluginPaymentStateMachineConfigError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`delete_push_notification_callbacks`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum DeletePushNotificationCallbacksError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`delete_user_key_value`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum DeleteUserKeyValueError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_all_plugin_configuration`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetAllPluginConfigurationError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_per_tenant_configuration`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetPerTenantConfigurationError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_plugin_configuration`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetPluginConfigurationError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_plugin_payment_state_machine_config`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetPluginPaymentStateMachineConfigError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_push_notification_callbacks`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetPushNotificationCallbacksError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_tenant`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetTenantError {
-//     Status400(),
-//     Status404(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_tenant_by_api_key`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetTenantByApiKeyError {
-//     Status404(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`get_user_key_value`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum GetUserKeyValueError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`insert_user_key_value`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum InsertUserKeyValueError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`register_push_notification_callback`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum RegisterPushNotificationCallbackError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`upload_per_tenant_configuration`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum UploadPerTenantConfigurationError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`upload_plugin_configuration`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum UploadPluginConfigurationError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-// /// struct for typed errors of method [`upload_plugin_payment_state_machine_config`]
-// #[derive(Debug, Clone, Serialize, Deserialize)]
-// #[serde(untagged)]
-// pub enum UploadPluginPaymentStateMachineConfigError {
-//     Status400(),
-//     UnknownValue(serde_json::Value),
-// }
-
-
-// pub async fn create_tenant(configuration: &configuration::Configuration, x_killbill_created_by: &str, body: models::Tenant, use_global_default: Option<bool>, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<models::Tenant, Error<CreateTenantError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_str) = use_global_default {
-//         local_var_req_builder = local_var_req_builder.query(&[("useGlobalDefault", &local_var_str.to_string())]);
-//     }
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-//     local_var_req_builder = local_var_req_builder.json(&body);
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<CreateTenantError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn delete_per_tenant_configuration(configuration: &configuration::Configuration, x_killbill_created_by: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<(), Error<DeletePerTenantConfigurationError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPerTenantConfig", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         Ok(())
-//     } else {
-//         let local_var_entity: Option<DeletePerTenantConfigurationError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn delete_plugin_configuration(configuration: &configuration::Configuration, plugin_name: &str, x_killbill_created_by: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<(), Error<DeletePluginConfigurationError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPluginConfig/{pluginName}", local_var_configuration.base_path, pluginName=crate::apis::urlencode(plugin_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         Ok(())
-//     } else {
-//         let local_var_entity: Option<DeletePluginConfigurationError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn delete_plugin_payment_state_machine_config(configuration: &configuration::Configuration, plugin_name: &str, x_killbill_created_by: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<(), Error<DeletePluginPaymentStateMachineConfigError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{pluginName}", local_var_configuration.base_path, pluginName=crate::apis::urlencode(plugin_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         Ok(())
-//     } else {
-//         let local_var_entity: Option<DeletePluginPaymentStateMachineConfigError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn delete_push_notification_callbacks(configuration: &configuration::Configuration, x_killbill_created_by: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<(), Error<DeletePushNotificationCallbacksError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/registerNotificationCallback", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         Ok(())
-//     } else {
-//         let local_var_entity: Option<DeletePushNotificationCallbacksError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn delete_user_key_value(configuration: &configuration::Configuration, key_name: &str, x_killbill_created_by: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<(), Error<DeleteUserKeyValueError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/userKeyValue/{keyName}", local_var_configuration.base_path, keyName=crate::apis::urlencode(key_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         Ok(())
-//     } else {
-//         let local_var_entity: Option<DeleteUserKeyValueError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_all_plugin_configuration(configuration: &configuration::Configuration, key_prefix: &str) -> Result<Vec<models::TenantKeyValue>, Error<GetAllPluginConfigurationError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPerTenantConfig/{keyPrefix}/search", local_var_configuration.base_path, keyPrefix=crate::apis::urlencode(key_prefix));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetAllPluginConfigurationError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_per_tenant_configuration(configuration: &configuration::Configuration, ) -> Result<models::TenantKeyValue, Error<GetPerTenantConfigurationError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPerTenantConfig", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetPerTenantConfigurationError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_plugin_configuration(configuration: &configuration::Configuration, plugin_name: &str) -> Result<models::TenantKeyValue, Error<GetPluginConfigurationError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPluginConfig/{pluginName}", local_var_configuration.base_path, pluginName=crate::apis::urlencode(plugin_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetPluginConfigurationError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_plugin_payment_state_machine_config(configuration: &configuration::Configuration, plugin_name: &str) -> Result<models::TenantKeyValue, Error<GetPluginPaymentStateMachineConfigError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{pluginName}", local_var_configuration.base_path, pluginName=crate::apis::urlencode(plugin_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetPluginPaymentStateMachineConfigError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_push_notification_callbacks(configuration: &configuration::Configuration, ) -> Result<models::TenantKeyValue, Error<GetPushNotificationCallbacksError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/registerNotificationCallback", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetPushNotificationCallbacksError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_tenant(configuration: &configuration::Configuration, tenant_id: &str) -> Result<models::Tenant, Error<GetTenantError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/{tenantId}", local_var_configuration.base_path, tenantId=crate::apis::urlencode(tenant_id));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetTenantError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_tenant_by_api_key(configuration: &configuration::Configuration, api_key: Option<&str>) -> Result<models::Tenant, Error<GetTenantByApiKeyError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_str) = api_key {
-//         local_var_req_builder = local_var_req_builder.query(&[("apiKey", &local_var_str.to_string())]);
-//     }
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetTenantByApiKeyError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn get_user_key_value(configuration: &configuration::Configuration, key_name: &str) -> Result<models::TenantKeyValue, Error<GetUserKeyValueError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/userKeyValue/{keyName}", local_var_configuration.base_path, keyName=crate::apis::urlencode(key_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<GetUserKeyValueError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn insert_user_key_value(configuration: &configuration::Configuration, key_name: &str, x_killbill_created_by: &str, body: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<models::TenantKeyValue, Error<InsertUserKeyValueError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/userKeyValue/{keyName}", local_var_configuration.base_path, keyName=crate::apis::urlencode(key_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-//     local_var_req_builder = local_var_req_builder.json(&body);
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<InsertUserKeyValueError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn register_push_notification_callback(configuration: &configuration::Configuration, x_killbill_created_by: &str, cb: Option<&str>, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<models::TenantKeyValue, Error<RegisterPushNotificationCallbackError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/registerNotificationCallback", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_str) = cb {
-//         local_var_req_builder = local_var_req_builder.query(&[("cb", &local_var_str.to_string())]);
-//     }
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<RegisterPushNotificationCallbackError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn upload_per_tenant_configuration(configuration: &configuration::Configuration, x_killbill_created_by: &str, body: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<models::TenantKeyValue, Error<UploadPerTenantConfigurationError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPerTenantConfig", local_var_configuration.base_path);
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-//     local_var_req_builder = local_var_req_builder.json(&body);
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<UploadPerTenantConfigurationError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn upload_plugin_configuration(configuration: &configuration::Configuration, plugin_name: &str, x_killbill_created_by: &str, body: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<models::TenantKeyValue, Error<UploadPluginConfigurationError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPluginConfig/{pluginName}", local_var_configuration.base_path, pluginName=crate::apis::urlencode(plugin_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-//     local_var_req_builder = local_var_req_builder.json(&body);
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<UploadPluginConfigurationError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
-// pub async fn upload_plugin_payment_state_machine_config(configuration: &configuration::Configuration, plugin_name: &str, x_killbill_created_by: &str, body: &str, x_killbill_reason: Option<&str>, x_killbill_comment: Option<&str>) -> Result<models::TenantKeyValue, Error<UploadPluginPaymentStateMachineConfigError>> {
-//     let local_var_configuration = configuration;
-
-//     let local_var_client = &local_var_configuration.client;
-
-//     let local_var_uri_str = format!("{}/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{pluginName}", local_var_configuration.base_path, pluginName=crate::apis::urlencode(plugin_name));
-//     let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-//     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-//         local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-//     }
-//     local_var_req_builder = local_var_req_builder.header("X-Killbill-CreatedBy", x_killbill_created_by.to_string());
-//     if let Some(local_var_param_value) = x_killbill_reason {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Reason", local_var_param_value.to_string());
-//     }
-//     if let Some(local_var_param_value) = x_killbill_comment {
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-Comment", local_var_param_value.to_string());
-//     }
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiKey", local_var_value);
-//     };
-//     if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-//         let local_var_key = local_var_apikey.key.clone();
-//         let local_var_value = match local_var_apikey.prefix {
-//             Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-//             None => local_var_key,
-//         };
-//         local_var_req_builder = local_var_req_builder.header("X-Killbill-ApiSecret", local_var_value);
-//     };
-//     if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-//         local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-//     };
-//     local_var_req_builder = local_var_req_builder.json(&body);
-
-//     let local_var_req = local_var_req_builder.build()?;
-//     let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-//     let local_var_status = local_var_resp.status();
-//     let local_var_content = local_var_resp.text().await?;
-
-//     if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-//         serde_json::from_str(&local_var_content).map_err(Error::from)
-//     } else {
-//         let local_var_entity: Option<UploadPluginPaymentStateMachineConfigError> = serde_json::from_str(&local_var_content).ok();
-//         let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-//         Err(Error::ResponseError(local_var_error))
-//     }
-// }
-
+use reqwest::{ header::CONTENT_TYPE, Method, Response, StatusCode };
+use serde::{ Deserialize, de::DeserializeOwned };
+use crate::{ apis::{ configuration::Configuration, urlencode }, models };
+use crate::apis::error::ParamError;
+use thiserror::Error;
+
+pub struct TenantApi {
+    config: Configuration,
+}
+
+impl TenantApi {
+    pub fn new(config: Configuration) -> Self {
+        Self { config }
+    }
+
+    pub async fn create_tenant(
+        &self,
+        request: CreateTenantRequest<'_>
+    ) -> Result<models::Tenant, TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::POST, &url)
+            .headers(self.config.get_auth_headers())
+            .query(&[("useGlobalDefault", request.use_global_default.map(|b| b.to_string()))])
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
+            .json(&request.body);
+
+        let response = self.config.send(req, "/1.0/kb/tenants").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn delete_per_tenant_configuration(
+        &self,
+        request: DeletePerTenantConfigurationRequest<'_>
+    ) -> Result<(), TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants/uploadPerTenantConfig", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::DELETE, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/uploadPerTenantConfig").await?;
+        Self::handle_empty_response(response).await
+    }
+
+    pub async fn delete_plugin_configuration(
+        &self,
+        request: DeletePluginConfigurationRequest<'_>
+    ) -> Result<(), TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/uploadPluginConfig/{}",
+            self.config.base_path,
+            urlencode(request.plugin_name)
+        );
+
+        let req = self.config.client
+            .request(Method::DELETE, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+
+        let response = self.config
+            .send(req, "/1.0/kb/tenants/uploadPluginConfig/{pluginName}")
+            .await?;
+        Self::handle_empty_response(response).await
+    }
+
+    pub async fn delete_plugin_payment_state_machine_config(
+        &self,
+        request: DeletePluginPaymentStateMachineConfigRequest<'_>
+    ) -> Result<(), TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{}",
+            self.config.base_path,
+            urlencode(request.plugin_name)
+        );
+
+        let req = self.config.client
+            .request(Method::DELETE, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+
+        let response = self.config
+            .send(req, "/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{pluginName}")
+            .await?;
+        Self::handle_empty_response(response).await
+    }
+
+    pub async fn delete_push_notification_callbacks(
+        &self,
+        request: DeletePushNotificationCallbacksRequest<'_>
+    ) -> Result<(), TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants/registerNotificationCallback", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::DELETE, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/registerNotificationCallback").await?;
+        Self::handle_empty_response(response).await
+    }
+
+    pub async fn delete_user_key_value(
+        &self,
+        request: DeleteUserKeyValueRequest<'_>
+    ) -> Result<(), TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/userKeyValue/{}",
+            self.config.base_path,
+            urlencode(request.key_name)
+        );
+
+        let req = self.config.client
+            .request(Method::DELETE, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/userKeyValue/{keyName}").await?;
+        Self::handle_empty_response(response).await
+    }
+
+    pub async fn get_all_plugin_configuration(
+        &self,
+        request: GetAllPluginConfigurationRequest<'_>
+    ) -> Result<Vec<models::TenantKeyValue>, TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/uploadPerTenantConfig/{}/search",
+            self.config.base_path,
+            urlencode(request.key_prefix)
+        );
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config
+            .send(req, "/1.0/kb/tenants/uploadPerTenantConfig/{keyPrefix}/search")
+            .await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_per_tenant_configuration(
+        &self
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants/uploadPerTenantConfig", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/uploadPerTenantConfig").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_plugin_configuration(
+        &self,
+        request: GetPluginConfigurationRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/uploadPluginConfig/{}",
+            self.config.base_path,
+            urlencode(request.plugin_name)
+        );
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config
+            .send(req, "/1.0/kb/tenants/uploadPluginConfig/{pluginName}")
+            .await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_plugin_payment_state_machine_config(
+        &self,
+        request: GetPluginPaymentStateMachineConfigRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{}",
+            self.config.base_path,
+            urlencode(request.plugin_name)
+        );
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config
+            .send(req, "/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{pluginName}")
+            .await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_push_notification_callbacks(
+        &self
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants/registerNotificationCallback", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/registerNotificationCallback").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_tenant(
+        &self,
+        request: GetTenantRequest
+    ) -> Result<models::Tenant, TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants/{}", self.config.base_path, request.tenant_id);
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/{tenantId}").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_tenant_by_api_key(
+        &self,
+        request: GetTenantByApiKeyRequest<'_>
+    ) -> Result<models::Tenant, TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers())
+            .query(&[("apiKey", request.api_key)]);
+
+        let response = self.config.send(req, "/1.0/kb/tenants").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_user_key_value(
+        &self,
+        request: GetUserKeyValueRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/userKeyValue/{}",
+            self.config.base_path,
+            urlencode(request.key_name)
+        );
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/userKeyValue/{keyName}").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn insert_user_key_value(
+        &self,
+        request: InsertUserKeyValueRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/userKeyValue/{}",
+            self.config.base_path,
+            urlencode(request.key_name)
+        );
+
+        let req = self.config.client
+            .request(Method::POST, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
+            .header(CONTENT_TYPE, "text/plain")
+            .body(request.body.to_owned());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/userKeyValue/{keyName}").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn register_push_notification_callback(
+        &self,
+        request: RegisterPushNotificationCallbackRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants/registerNotificationCallback", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::POST, &url)
+            .headers(self.config.get_auth_headers())
+            .query(&[("cb", request.cb)])
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/registerNotificationCallback").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn upload_per_tenant_configuration(
+        &self,
+        request: UploadPerTenantConfigurationRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!("{}/1.0/kb/tenants/uploadPerTenantConfig", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::POST, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
+            .header(CONTENT_TYPE, "text/plain")
+            .body(request.body.to_owned());
+
+        let response = self.config.send(req, "/1.0/kb/tenants/uploadPerTenantConfig").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn upload_plugin_configuration(
+        &self,
+        request: UploadPluginConfigurationRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/uploadPluginConfig/{}",
+            self.config.base_path,
+            urlencode(request.plugin_name)
+        );
+
+        let req = self.config.client
+            .request(Method::POST, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
+            .header(CONTENT_TYPE, "text/plain")
+            .body(request.body.to_owned());
+
+        let response = self.config
+            .send(req, "/1.0/kb/tenants/uploadPluginConfig/{pluginName}")
+            .await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn upload_plugin_payment_state_machine_config(
+        &self,
+        request: UploadPluginPaymentStateMachineConfigRequest<'_>
+    ) -> Result<models::TenantKeyValue, TenantApiError> {
+        let url = format!(
+            "{}/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{}",
+            self.config.base_path,
+            urlencode(request.plugin_name)
+        );
+
+        let req = self.config.client
+            .request(Method::POST, &url)
+            .headers(self.config.get_auth_headers())
+            .header("X-Killbill-CreatedBy", request.x_killbill_created_by)
+            .header("X-Killbill-Reason", request.x_killbill_reason.unwrap_or_default())
+            .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
+            .header(CONTENT_TYPE, "text/plain")
+            .body(request.body.to_owned());
+
+        let response = self.config
+            .send(req, "/1.0/kb/tenants/uploadPluginPaymentStateMachineConfig/{pluginName}")
+            .await?;
+        self.handle_response(response).await
+    }
+
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, TenantApiError> {
+        let response = self.config.follow_location(response).await?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                response.json().await.map_err(TenantApiError::from)
+            }
+            status => {
+                let text = response.text().await?;
+                Err(TenantApiError::from_response(status, text))
+            }
+        }
+    }
+
+    async fn handle_empty_response(response: Response) -> Result<(), TenantApiError> {
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
+            status => {
+                let text = response.text().await?;
+                Err(TenantApiError::from_response(status, text))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateTenantRequest<'a> {
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) body: models::Tenant,
+    pub(crate) use_global_default: Option<bool>,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> CreateTenantRequest<'a> {
+    pub fn builder() -> CreateTenantRequestBuilder<'a> {
+        CreateTenantRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CreateTenantRequestBuilder<'a> {
+    x_killbill_created_by: Option<&'a str>,
+    body: Option<models::Tenant>,
+    use_global_default: Option<bool>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> CreateTenantRequestBuilder<'a> {
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn body(mut self, body: models::Tenant) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn use_global_default(mut self, use_global_default: bool) -> Self {
+        self.use_global_default = Some(use_global_default);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<CreateTenantRequest<'a>, TenantApiError> {
+        Ok(CreateTenantRequest {
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            use_global_default: self.use_global_default,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeletePerTenantConfigurationRequest<'a> {
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePerTenantConfigurationRequest<'a> {
+    pub fn builder() -> DeletePerTenantConfigurationRequestBuilder<'a> {
+        DeletePerTenantConfigurationRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeletePerTenantConfigurationRequestBuilder<'a> {
+    x_killbill_created_by: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePerTenantConfigurationRequestBuilder<'a> {
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<DeletePerTenantConfigurationRequest<'a>, TenantApiError> {
+        Ok(DeletePerTenantConfigurationRequest {
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeletePluginConfigurationRequest<'a> {
+    pub(crate) plugin_name: &'a str,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePluginConfigurationRequest<'a> {
+    pub fn builder() -> DeletePluginConfigurationRequestBuilder<'a> {
+        DeletePluginConfigurationRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeletePluginConfigurationRequestBuilder<'a> {
+    plugin_name: Option<&'a str>,
+    x_killbill_created_by: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePluginConfigurationRequestBuilder<'a> {
+    pub fn plugin_name(mut self, plugin_name: &'a str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<DeletePluginConfigurationRequest<'a>, TenantApiError> {
+        Ok(DeletePluginConfigurationRequest {
+            plugin_name: self.plugin_name.ok_or(ParamError::missing("plugin_name"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeletePluginPaymentStateMachineConfigRequest<'a> {
+    pub(crate) plugin_name: &'a str,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePluginPaymentStateMachineConfigRequest<'a> {
+    pub fn builder() -> DeletePluginPaymentStateMachineConfigRequestBuilder<'a> {
+        DeletePluginPaymentStateMachineConfigRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeletePluginPaymentStateMachineConfigRequestBuilder<'a> {
+    plugin_name: Option<&'a str>,
+    x_killbill_created_by: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePluginPaymentStateMachineConfigRequestBuilder<'a> {
+    pub fn plugin_name(mut self, plugin_name: &'a str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<DeletePluginPaymentStateMachineConfigRequest<'a>, TenantApiError> {
+        Ok(DeletePluginPaymentStateMachineConfigRequest {
+            plugin_name: self.plugin_name.ok_or(ParamError::missing("plugin_name"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeletePushNotificationCallbacksRequest<'a> {
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePushNotificationCallbacksRequest<'a> {
+    pub fn builder() -> DeletePushNotificationCallbacksRequestBuilder<'a> {
+        DeletePushNotificationCallbacksRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeletePushNotificationCallbacksRequestBuilder<'a> {
+    x_killbill_created_by: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeletePushNotificationCallbacksRequestBuilder<'a> {
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<DeletePushNotificationCallbacksRequest<'a>, TenantApiError> {
+        Ok(DeletePushNotificationCallbacksRequest {
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteUserKeyValueRequest<'a> {
+    pub(crate) key_name: &'a str,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeleteUserKeyValueRequest<'a> {
+    pub fn builder() -> DeleteUserKeyValueRequestBuilder<'a> {
+        DeleteUserKeyValueRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeleteUserKeyValueRequestBuilder<'a> {
+    key_name: Option<&'a str>,
+    x_killbill_created_by: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> DeleteUserKeyValueRequestBuilder<'a> {
+    pub fn key_name(mut self, key_name: &'a str) -> Self {
+        self.key_name = Some(key_name);
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<DeleteUserKeyValueRequest<'a>, TenantApiError> {
+        Ok(DeleteUserKeyValueRequest {
+            key_name: self.key_name.ok_or(ParamError::missing("key_name"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetAllPluginConfigurationRequest<'a> {
+    pub(crate) key_prefix: &'a str,
+}
+
+impl<'a> GetAllPluginConfigurationRequest<'a> {
+    pub fn builder() -> GetAllPluginConfigurationRequestBuilder<'a> {
+        GetAllPluginConfigurationRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetAllPluginConfigurationRequestBuilder<'a> {
+    key_prefix: Option<&'a str>,
+}
+
+impl<'a> GetAllPluginConfigurationRequestBuilder<'a> {
+    pub fn key_prefix(mut self, key_prefix: &'a str) -> Self {
+        self.key_prefix = Some(key_prefix);
+        self
+    }
+
+    pub fn build(self) -> Result<GetAllPluginConfigurationRequest<'a>, TenantApiError> {
+        Ok(GetAllPluginConfigurationRequest {
+            key_prefix: self.key_prefix.ok_or(ParamError::missing("key_prefix"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetPluginConfigurationRequest<'a> {
+    pub(crate) plugin_name: &'a str,
+}
+
+impl<'a> GetPluginConfigurationRequest<'a> {
+    pub fn builder() -> GetPluginConfigurationRequestBuilder<'a> {
+        GetPluginConfigurationRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetPluginConfigurationRequestBuilder<'a> {
+    plugin_name: Option<&'a str>,
+}
+
+impl<'a> GetPluginConfigurationRequestBuilder<'a> {
+    pub fn plugin_name(mut self, plugin_name: &'a str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    pub fn build(self) -> Result<GetPluginConfigurationRequest<'a>, TenantApiError> {
+        Ok(GetPluginConfigurationRequest {
+            plugin_name: self.plugin_name.ok_or(ParamError::missing("plugin_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetPluginPaymentStateMachineConfigRequest<'a> {
+    pub(crate) plugin_name: &'a str,
+}
+
+impl<'a> GetPluginPaymentStateMachineConfigRequest<'a> {
+    pub fn builder() -> GetPluginPaymentStateMachineConfigRequestBuilder<'a> {
+        GetPluginPaymentStateMachineConfigRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetPluginPaymentStateMachineConfigRequestBuilder<'a> {
+    plugin_name: Option<&'a str>,
+}
+
+impl<'a> GetPluginPaymentStateMachineConfigRequestBuilder<'a> {
+    pub fn plugin_name(mut self, plugin_name: &'a str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    pub fn build(self) -> Result<GetPluginPaymentStateMachineConfigRequest<'a>, TenantApiError> {
+        Ok(GetPluginPaymentStateMachineConfigRequest {
+            plugin_name: self.plugin_name.ok_or(ParamError::missing("plugin_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetTenantRequest {
+    pub(crate) tenant_id: uuid::Uuid,
+}
+
+impl GetTenantRequest {
+    pub fn builder() -> GetTenantRequestBuilder {
+        GetTenantRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetTenantRequestBuilder {
+    tenant_id: Option<Result<uuid::Uuid, models::InvalidId>>,
+}
+
+impl GetTenantRequestBuilder {
+    pub fn tenant_id(mut self, tenant_id: impl models::IdParam) -> Self {
+        self.tenant_id = Some(tenant_id.into_id());
+        self
+    }
+
+    pub fn build(self) -> Result<GetTenantRequest, TenantApiError> {
+        Ok(GetTenantRequest {
+            tenant_id: self.tenant_id
+                .ok_or(ParamError::missing("tenant_id"))?
+                .map_err(|e| ParamError::invalid_id("tenant_id", e))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetTenantByApiKeyRequest<'a> {
+    pub(crate) api_key: Option<&'a str>,
+}
+
+impl<'a> GetTenantByApiKeyRequest<'a> {
+    pub fn builder() -> GetTenantByApiKeyRequestBuilder<'a> {
+        GetTenantByApiKeyRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetTenantByApiKeyRequestBuilder<'a> {
+    api_key: Option<&'a str>,
+}
+
+impl<'a> GetTenantByApiKeyRequestBuilder<'a> {
+    pub fn api_key(mut self, api_key: &'a str) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    pub fn build(self) -> Result<GetTenantByApiKeyRequest<'a>, TenantApiError> {
+        Ok(GetTenantByApiKeyRequest {
+            api_key: self.api_key,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetUserKeyValueRequest<'a> {
+    pub(crate) key_name: &'a str,
+}
+
+impl<'a> GetUserKeyValueRequest<'a> {
+    pub fn builder() -> GetUserKeyValueRequestBuilder<'a> {
+        GetUserKeyValueRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetUserKeyValueRequestBuilder<'a> {
+    key_name: Option<&'a str>,
+}
+
+impl<'a> GetUserKeyValueRequestBuilder<'a> {
+    pub fn key_name(mut self, key_name: &'a str) -> Self {
+        self.key_name = Some(key_name);
+        self
+    }
+
+    pub fn build(self) -> Result<GetUserKeyValueRequest<'a>, TenantApiError> {
+        Ok(GetUserKeyValueRequest {
+            key_name: self.key_name.ok_or(ParamError::missing("key_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InsertUserKeyValueRequest<'a> {
+    pub(crate) key_name: &'a str,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) body: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> InsertUserKeyValueRequest<'a> {
+    pub fn builder() -> InsertUserKeyValueRequestBuilder<'a> {
+        InsertUserKeyValueRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InsertUserKeyValueRequestBuilder<'a> {
+    key_name: Option<&'a str>,
+    x_killbill_created_by: Option<&'a str>,
+    body: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> InsertUserKeyValueRequestBuilder<'a> {
+    pub fn key_name(mut self, key_name: &'a str) -> Self {
+        self.key_name = Some(key_name);
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn body(mut self, body: &'a str) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<InsertUserKeyValueRequest<'a>, TenantApiError> {
+        Ok(InsertUserKeyValueRequest {
+            key_name: self.key_name.ok_or(ParamError::missing("key_name"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RegisterPushNotificationCallbackRequest<'a> {
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) cb: Option<&'a str>,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> RegisterPushNotificationCallbackRequest<'a> {
+    pub fn builder() -> RegisterPushNotificationCallbackRequestBuilder<'a> {
+        RegisterPushNotificationCallbackRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RegisterPushNotificationCallbackRequestBuilder<'a> {
+    x_killbill_created_by: Option<&'a str>,
+    cb: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> RegisterPushNotificationCallbackRequestBuilder<'a> {
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn cb(mut self, cb: &'a str) -> Self {
+        self.cb = Some(cb);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<RegisterPushNotificationCallbackRequest<'a>, TenantApiError> {
+        Ok(RegisterPushNotificationCallbackRequest {
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            cb: self.cb,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadPerTenantConfigurationRequest<'a> {
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) body: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> UploadPerTenantConfigurationRequest<'a> {
+    pub fn builder() -> UploadPerTenantConfigurationRequestBuilder<'a> {
+        UploadPerTenantConfigurationRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UploadPerTenantConfigurationRequestBuilder<'a> {
+    x_killbill_created_by: Option<&'a str>,
+    body: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> UploadPerTenantConfigurationRequestBuilder<'a> {
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn body(mut self, body: &'a str) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<UploadPerTenantConfigurationRequest<'a>, TenantApiError> {
+        Ok(UploadPerTenantConfigurationRequest {
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadPluginConfigurationRequest<'a> {
+    pub(crate) plugin_name: &'a str,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) body: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> UploadPluginConfigurationRequest<'a> {
+    pub fn builder() -> UploadPluginConfigurationRequestBuilder<'a> {
+        UploadPluginConfigurationRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UploadPluginConfigurationRequestBuilder<'a> {
+    plugin_name: Option<&'a str>,
+    x_killbill_created_by: Option<&'a str>,
+    body: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> UploadPluginConfigurationRequestBuilder<'a> {
+    pub fn plugin_name(mut self, plugin_name: &'a str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn body(mut self, body: &'a str) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<UploadPluginConfigurationRequest<'a>, TenantApiError> {
+        Ok(UploadPluginConfigurationRequest {
+            plugin_name: self.plugin_name.ok_or(ParamError::missing("plugin_name"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadPluginPaymentStateMachineConfigRequest<'a> {
+    pub(crate) plugin_name: &'a str,
+    pub(crate) x_killbill_created_by: &'a str,
+    pub(crate) body: &'a str,
+    pub(crate) x_killbill_reason: Option<&'a str>,
+    pub(crate) x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> UploadPluginPaymentStateMachineConfigRequest<'a> {
+    pub fn builder() -> UploadPluginPaymentStateMachineConfigRequestBuilder<'a> {
+        UploadPluginPaymentStateMachineConfigRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UploadPluginPaymentStateMachineConfigRequestBuilder<'a> {
+    plugin_name: Option<&'a str>,
+    x_killbill_created_by: Option<&'a str>,
+    body: Option<&'a str>,
+    x_killbill_reason: Option<&'a str>,
+    x_killbill_comment: Option<&'a str>,
+}
+
+impl<'a> UploadPluginPaymentStateMachineConfigRequestBuilder<'a> {
+    pub fn plugin_name(mut self, plugin_name: &'a str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    pub fn x_killbill_created_by(mut self, x_killbill_created_by: &'a str) -> Self {
+        self.x_killbill_created_by = Some(x_killbill_created_by);
+        self
+    }
+
+    pub fn body(mut self, body: &'a str) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn x_killbill_reason(mut self, x_killbill_reason: &'a str) -> Self {
+        self.x_killbill_reason = Some(x_killbill_reason);
+        self
+    }
+
+    pub fn x_killbill_comment(mut self, x_killbill_comment: &'a str) -> Self {
+        self.x_killbill_comment = Some(x_killbill_comment);
+        self
+    }
+
+    pub fn build(self) -> Result<UploadPluginPaymentStateMachineConfigRequest<'a>, TenantApiError> {
+        Ok(UploadPluginPaymentStateMachineConfigRequest {
+            plugin_name: self.plugin_name.ok_or(ParamError::missing("plugin_name"))?,
+            x_killbill_created_by: self.x_killbill_created_by.ok_or(
+                ParamError::missing("x_killbill_created_by")
+            )?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
+            x_killbill_reason: self.x_killbill_reason,
+            x_killbill_comment: self.x_killbill_comment,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TenantApiError {
+    #[error("Request failed: {0}")] RequestFailed(#[from] reqwest::Error),
+
+    #[error("API error ({status}): {message}")] ApiError {
+        status: StatusCode,
+        message: String,
+    },
+
+    #[error("Configuration error: {0}")] ConfigError(
+        #[from] crate::apis::configuration::ConfigError,
+    ),
+
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
+}
+
+impl TenantApiError {
+    fn from_response(status: StatusCode, message: String) -> Self {
+        Self::ApiError { status, message }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{ Matcher, Server };
+    use crate::models::Tenant;
+
+    #[tokio::test]
+    async fn test_create_tenant() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/tenants")
+            .match_query(Matcher::UrlEncoded("useGlobalDefault".into(), "true".into()))
+            .match_header("X-Killbill-CreatedBy", "test")
+            .match_body(Matcher::PartialJsonString(r#"{"apiKey": "bob", "apiSecret": "lazar"}"#.into()))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"tenantId": "2e3c9e4c-9bd1-4bc5-9c6f-4d0b3c0a1a52", "apiKey": "bob", "apiSecret": "lazar"}"#
+            )
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = CreateTenantRequest::builder()
+            .x_killbill_created_by("test")
+            .body(Tenant::new("bob".to_owned(), "lazar".to_owned()))
+            .use_global_default(true)
+            .build()
+            .unwrap();
+
+        let tenant = api.create_tenant(request).await.unwrap();
+        assert_eq!(tenant.api_key, "bob");
+        assert!(tenant.tenant_id.is_some());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_tenant_conflict() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/tenants")
+            .with_status(409)
+            .with_body("Tenant already exists")
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = CreateTenantRequest::builder()
+            .x_killbill_created_by("test")
+            .body(Tenant::new("bob".to_owned(), "lazar".to_owned()))
+            .build()
+            .unwrap();
+
+        let result = api.create_tenant(request).await;
+        assert!(
+            matches!(result, Err(TenantApiError::ApiError { status, .. }) if status == StatusCode::CONFLICT)
+        );
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_tenant() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tenants/2e3c9e4c-9bd1-4bc5-9c6f-4d0b3c0a1a52")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"apiKey": "bob", "apiSecret": "lazar"}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = GetTenantRequest::builder()
+            .tenant_id("2e3c9e4c-9bd1-4bc5-9c6f-4d0b3c0a1a52")
+            .build()
+            .unwrap();
+
+        let result = api.get_tenant(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_tenant_by_api_key() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tenants")
+            .match_query(Matcher::UrlEncoded("apiKey".into(), "bob".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"apiKey": "bob", "apiSecret": "lazar"}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = GetTenantByApiKeyRequest::builder().api_key("bob").build().unwrap();
+
+        let result = api.get_tenant_by_api_key(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_per_tenant_configuration() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/tenants/uploadPerTenantConfig")
+            .match_header("content-type", "text/plain")
+            .match_body("org.killbill.invoice.dryRunNotificationSchedule=1d")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"key": "PER_TENANT_CONFIG", "values": ["org.killbill.invoice.dryRunNotificationSchedule=1d"]}"#
+            )
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = UploadPerTenantConfigurationRequest::builder()
+            .x_killbill_created_by("test")
+            .body("org.killbill.invoice.dryRunNotificationSchedule=1d")
+            .build()
+            .unwrap();
+
+        let result = api.upload_per_tenant_configuration(request).await.unwrap();
+        assert_eq!(result.key.as_deref(), Some("PER_TENANT_CONFIG"));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_per_tenant_configuration() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tenants/uploadPerTenantConfig")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"key": "PER_TENANT_CONFIG", "values": []}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+
+        let result = api.get_per_tenant_configuration().await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_plugin_configuration() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/tenants/uploadPluginConfig/killbill-stripe")
+            .match_header("content-type", "text/plain")
+            .match_body("org.killbill.billing.plugin.stripe.apiKey=sk_test")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"key": "PLUGIN_CONFIG_killbill-stripe"}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = UploadPluginConfigurationRequest::builder()
+            .plugin_name("killbill-stripe")
+            .x_killbill_created_by("test")
+            .body("org.killbill.billing.plugin.stripe.apiKey=sk_test")
+            .build()
+            .unwrap();
+
+        let result = api.upload_plugin_configuration(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_all_plugin_configuration() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tenants/uploadPerTenantConfig/PLUGIN_CONFIG_/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"key": "PLUGIN_CONFIG_killbill-stripe", "values": []}]"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = GetAllPluginConfigurationRequest::builder()
+            .key_prefix("PLUGIN_CONFIG_")
+            .build()
+            .unwrap();
+
+        let result = api.get_all_plugin_configuration(request).await.unwrap();
+        assert_eq!(result.len(), 1);
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_plugin_configuration() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/1.0/kb/tenants/uploadPluginConfig/killbill-stripe")
+            .with_status(204)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = DeletePluginConfigurationRequest::builder()
+            .plugin_name("killbill-stripe")
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+
+        let result = api.delete_plugin_configuration(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_register_push_notification_callback() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/tenants/registerNotificationCallback")
+            .match_query(
+                Matcher::UrlEncoded("cb".into(), "http://127.0.0.1:8080/callmeback".into())
+            )
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"key": "PUSH_NOTIFICATION_CB", "values": ["http://127.0.0.1:8080/callmeback"]}"#
+            )
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = RegisterPushNotificationCallbackRequest::builder()
+            .x_killbill_created_by("test")
+            .cb("http://127.0.0.1:8080/callmeback")
+            .build()
+            .unwrap();
+
+        let result = api.register_push_notification_callback(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_push_notification_callbacks() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tenants/registerNotificationCallback")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"key": "PUSH_NOTIFICATION_CB", "values": []}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+
+        let result = api.get_push_notification_callbacks().await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_push_notification_callbacks() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/1.0/kb/tenants/registerNotificationCallback")
+            .with_status(204)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = DeletePushNotificationCallbacksRequest::builder()
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+
+        let result = api.delete_push_notification_callbacks(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_insert_user_key_value() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/tenants/userKeyValue/environment")
+            .match_header("content-type", "text/plain")
+            .match_body("staging")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"key": "environment", "values": ["staging"]}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = InsertUserKeyValueRequest::builder()
+            .key_name("environment")
+            .x_killbill_created_by("test")
+            .body("staging")
+            .build()
+            .unwrap();
+
+        let result = api.insert_user_key_value(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_user_key_value() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tenants/userKeyValue/environment")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"key": "environment", "values": ["staging"]}"#)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = GetUserKeyValueRequest::builder().key_name("environment").build().unwrap();
+
+        let result = api.get_user_key_value(request).await.unwrap();
+        assert_eq!(result.values, Some(vec!["staging".to_owned()]));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_key_value() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/1.0/kb/tenants/userKeyValue/environment")
+            .with_status(204)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TenantApi::new(config);
+        let request = DeleteUserKeyValueRequest::builder()
+            .key_name("environment")
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+
+        let result = api.delete_user_key_value(request).await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_builder_requires_created_by() {
+        let result = UploadPerTenantConfigurationRequest::builder().body("key=value").build();
+        assert!(
+            matches!(
+                result,
+                Err(TenantApiError::ValidationError(ParamError::Missing { field: "x_killbill_created_by" }))
+            )
+        );
+    }
+}
@@ -0,0 +1,433 @@
+use reqwest::{ Method, Response, StatusCode };
+use serde::{ Deserialize, de::DeserializeOwned };
+use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
+use thiserror::Error;
+
+/// Test clock and queue endpoints, only available when Kill Bill runs in test mode
+/// (`org.killbill.server.test.mode=true`)
+pub struct TestApi {
+    config: Configuration,
+}
+
+impl TestApi {
+    pub fn new(config: Configuration) -> Self {
+        Self { config }
+    }
+
+    pub async fn get_clock(
+        &self,
+        request: GetClockRequest<'_>
+    ) -> Result<models::Clock, TestApiError> {
+        let url = format!("{}/1.0/kb/test/clock", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers())
+            .query(&[("timeZone", request.time_zone)]);
+
+        let response = self.config.send(req, "/1.0/kb/test/clock").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn set_clock(
+        &self,
+        request: SetClockRequest<'_>
+    ) -> Result<models::Clock, TestApiError> {
+        let url = format!("{}/1.0/kb/test/clock", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::POST, &url)
+            .headers(self.config.get_auth_headers())
+            .query(
+                &[
+                    ("requestedDate", request.requested_date.as_deref()),
+                    ("timeZone", request.time_zone),
+                    ("timeoutSec", request.timeout_sec.map(|s| s.to_string()).as_deref()),
+                ]
+            );
+
+        let response = self.config.send(req, "/1.0/kb/test/clock").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn move_clock(
+        &self,
+        request: MoveClockRequest<'_>
+    ) -> Result<models::Clock, TestApiError> {
+        let url = format!("{}/1.0/kb/test/clock", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::PUT, &url)
+            .headers(self.config.get_auth_headers())
+            .query(
+                &[
+                    ("days", request.days.map(|d| d.to_string())),
+                    ("weeks", request.weeks.map(|w| w.to_string())),
+                    ("months", request.months.map(|m| m.to_string())),
+                    ("years", request.years.map(|y| y.to_string())),
+                    ("timeZone", request.time_zone.map(str::to_owned)),
+                    ("timeoutSec", request.timeout_sec.map(|s| s.to_string())),
+                ]
+            );
+
+        let response = self.config.send(req, "/1.0/kb/test/clock").await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn wait_for_queues(&self, request: WaitForQueuesRequest) -> Result<(), TestApiError> {
+        let url = format!("{}/1.0/kb/test/queues", self.config.base_path);
+
+        let req = self.config.client
+            .request(Method::GET, &url)
+            .headers(self.config.get_auth_headers())
+            .query(&[("timeoutSec", request.timeout_sec)]);
+
+        let response = self.config.send(req, "/1.0/kb/test/queues").await?;
+        Self::handle_empty_response(response).await
+    }
+
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, TestApiError> {
+        let response = self.config.follow_location(response).await?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                response.json().await.map_err(TestApiError::from)
+            }
+            status => {
+                let text = response.text().await?;
+                Err(TestApiError::from_response(status, text))
+            }
+        }
+    }
+
+    async fn handle_empty_response(response: Response) -> Result<(), TestApiError> {
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
+            status => {
+                let text = response.text().await?;
+                Err(TestApiError::from_response(status, text))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GetClockRequest<'a> {
+    pub(crate) time_zone: Option<&'a str>,
+}
+
+impl<'a> GetClockRequest<'a> {
+    pub fn builder() -> GetClockRequestBuilder<'a> {
+        GetClockRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetClockRequestBuilder<'a> {
+    time_zone: Option<&'a str>,
+}
+
+impl<'a> GetClockRequestBuilder<'a> {
+    /// Time zone used to compute the returned `localDate`, e.g. `America/Los_Angeles`
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    pub fn build(self) -> Result<GetClockRequest<'a>, TestApiError> {
+        Ok(GetClockRequest {
+            time_zone: self.time_zone,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SetClockRequest<'a> {
+    pub(crate) requested_date: Option<String>,
+    pub(crate) time_zone: Option<&'a str>,
+    pub(crate) timeout_sec: Option<i64>,
+}
+
+impl<'a> SetClockRequest<'a> {
+    pub fn builder() -> SetClockRequestBuilder<'a> {
+        SetClockRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SetClockRequestBuilder<'a> {
+    requested_date: Option<String>,
+    time_zone: Option<&'a str>,
+    timeout_sec: Option<i64>,
+}
+
+impl<'a> SetClockRequestBuilder<'a> {
+    /// Date to set the clock to; the clock is reset to the current time if absent
+    pub fn requested_date(mut self, requested_date: impl models::DateParam) -> Self {
+        self.requested_date = Some(requested_date.into_param());
+        self
+    }
+
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// How long the server waits for the bus and notification queues to drain
+    pub fn timeout_sec(mut self, timeout_sec: i64) -> Self {
+        self.timeout_sec = Some(timeout_sec);
+        self
+    }
+
+    pub fn build(self) -> Result<SetClockRequest<'a>, TestApiError> {
+        Ok(SetClockRequest {
+            requested_date: self.requested_date,
+            time_zone: self.time_zone,
+            timeout_sec: self.timeout_sec,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MoveClockRequest<'a> {
+    pub(crate) days: Option<i32>,
+    pub(crate) weeks: Option<i32>,
+    pub(crate) months: Option<i32>,
+    pub(crate) years: Option<i32>,
+    pub(crate) time_zone: Option<&'a str>,
+    pub(crate) timeout_sec: Option<i64>,
+}
+
+impl<'a> MoveClockRequest<'a> {
+    pub fn builder() -> MoveClockRequestBuilder<'a> {
+        MoveClockRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MoveClockRequestBuilder<'a> {
+    days: Option<i32>,
+    weeks: Option<i32>,
+    months: Option<i32>,
+    years: Option<i32>,
+    time_zone: Option<&'a str>,
+    timeout_sec: Option<i64>,
+}
+
+impl<'a> MoveClockRequestBuilder<'a> {
+    pub fn days(mut self, days: i32) -> Self {
+        self.days = Some(days);
+        self
+    }
+
+    pub fn weeks(mut self, weeks: i32) -> Self {
+        self.weeks = Some(weeks);
+        self
+    }
+
+    pub fn months(mut self, months: i32) -> Self {
+        self.months = Some(months);
+        self
+    }
+
+    pub fn years(mut self, years: i32) -> Self {
+        self.years = Some(years);
+        self
+    }
+
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// How long the server waits for the bus and notification queues to drain
+    pub fn timeout_sec(mut self, timeout_sec: i64) -> Self {
+        self.timeout_sec = Some(timeout_sec);
+        self
+    }
+
+    pub fn build(self) -> Result<MoveClockRequest<'a>, TestApiError> {
+        if [self.days, self.weeks, self.months, self.years].iter().all(Option::is_none) {
+            return Err(
+                ParamError::MissingOneOf { fields: &["days", "weeks", "months", "years"] }.into()
+            );
+        }
+
+        Ok(MoveClockRequest {
+            days: self.days,
+            weeks: self.weeks,
+            months: self.months,
+            years: self.years,
+            time_zone: self.time_zone,
+            timeout_sec: self.timeout_sec,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WaitForQueuesRequest {
+    pub(crate) timeout_sec: Option<i64>,
+}
+
+impl WaitForQueuesRequest {
+    pub fn builder() -> WaitForQueuesRequestBuilder {
+        WaitForQueuesRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WaitForQueuesRequestBuilder {
+    timeout_sec: Option<i64>,
+}
+
+impl WaitForQueuesRequestBuilder {
+    /// How long the server waits for the queues to drain before answering
+    /// `412 Precondition Failed`
+    pub fn timeout_sec(mut self, timeout_sec: i64) -> Self {
+        self.timeout_sec = Some(timeout_sec);
+        self
+    }
+
+    pub fn build(self) -> Result<WaitForQueuesRequest, TestApiError> {
+        Ok(WaitForQueuesRequest {
+            timeout_sec: self.timeout_sec,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TestApiError {
+    #[error("Request failed: {0}")] RequestFailed(#[from] reqwest::Error),
+
+    #[error("API error ({status}): {message}")] ApiError {
+        status: StatusCode,
+        message: String,
+    },
+
+    #[error("Configuration error: {0}")] ConfigError(
+        #[from] crate::apis::configuration::ConfigError,
+    ),
+
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
+}
+
+impl TestApiError {
+    fn from_response(status: StatusCode, message: String) -> Self {
+        Self::ApiError { status, message }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{ Matcher, Server };
+
+    const CLOCK: &str =
+        r#"{"currentUtcTime":"2024-02-29T00:00:00.000Z","timeZone":"UTC","localDate":"2024-02-29"}"#;
+
+    #[tokio::test]
+    async fn test_get_clock() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/test/clock")
+            .match_query(Matcher::UrlEncoded("timeZone".into(), "UTC".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(CLOCK)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TestApi::new(config);
+        let request = GetClockRequest::builder().time_zone("UTC").build().unwrap();
+
+        let clock = api.get_clock(request).await.unwrap();
+        assert_eq!(clock.time_zone.as_deref(), Some("UTC"));
+        assert!(clock.local_date.is_some());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_set_clock() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/1.0/kb/test/clock")
+            .match_query(
+                Matcher::AllOf(
+                    vec![
+                        Matcher::UrlEncoded("requestedDate".into(), "2024-02-29".into()),
+                        Matcher::UrlEncoded("timeoutSec".into(), "10".into())
+                    ]
+                )
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(CLOCK)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TestApi::new(config);
+        let request = SetClockRequest::builder()
+            .requested_date("2024-02-29")
+            .timeout_sec(10)
+            .build()
+            .unwrap();
+
+        assert!(api.set_clock(request).await.is_ok());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_move_clock() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/1.0/kb/test/clock")
+            .match_query(Matcher::UrlEncoded("months".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(CLOCK)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TestApi::new(config);
+        let request = MoveClockRequest::builder().months(1).build().unwrap();
+
+        assert!(api.move_clock(request).await.is_ok());
+        assert!(MoveClockRequest::builder().time_zone("UTC").build().is_err());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_queues_timeout() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/test/queues")
+            .match_query(Matcher::UrlEncoded("timeoutSec".into(), "5".into()))
+            .with_status(412)
+            .create_async().await;
+
+        let config = Configuration::builder().base_path(server.url()).build().unwrap();
+
+        let api = TestApi::new(config);
+        let request = WaitForQueuesRequest::builder().timeout_sec(5).build().unwrap();
+
+        let result = api.wait_for_queues(request).await;
+        assert!(
+            matches!(result, Err(TestApiError::ApiError { status, .. }) if status == StatusCode::PRECONDITION_FAILED)
+        );
+
+        mock.assert_async().await;
+    }
+}
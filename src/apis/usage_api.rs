@@ -1,6 +1,7 @@
 use reqwest::{ Method, Response, StatusCode };
 use serde::{ Deserialize, de::DeserializeOwned };
 use crate::{ apis::configuration::Configuration, models };
+use crate::apis::error::ParamError;
 use thiserror::Error;
 
 pub struct UsageApi {
@@ -14,7 +15,7 @@ impl UsageApi {
 
     pub async fn get_all_usage(
         &self,
-        request: GetAllUsageRequest<'_>
+        request: GetAllUsageRequest
     ) -> Result<models::RolledUpUsage, UsageApiError> {
         let url = format!("{}/1.0/kb/usages/{}", self.config.base_path, request.subscription_id);
 
@@ -36,8 +37,8 @@ impl UsageApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/usages/{subscriptionId}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn get_usage(
@@ -69,8 +70,8 @@ impl UsageApi {
                 })
             );
 
-        let response = req.send().await?;
-        Self::handle_response(response).await
+        let response = self.config.send(req, "/1.0/kb/usages/{subscriptionId}/{unitType}").await?;
+        self.handle_response(response).await
     }
 
     pub async fn record_usage(&self, request: RecordUsageRequest<'_>) -> Result<(), UsageApiError> {
@@ -84,11 +85,16 @@ impl UsageApi {
             .header("X-Killbill-Comment", request.x_killbill_comment.unwrap_or_default())
             .json(&request.body);
 
-        let response = req.send().await?;
+        let response = self.config.send(req, "/1.0/kb/usages").await?;
         Self::handle_empty_response(response).await
     }
 
-    async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T, UsageApiError> {
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        response: Response
+    ) -> Result<T, UsageApiError> {
+        let response = self.config.follow_location(response).await?;
+
         match response.status() {
             StatusCode::OK | StatusCode::CREATED => {
                 response.json().await.map_err(UsageApiError::from)
@@ -112,40 +118,40 @@ impl UsageApi {
 }
 
 #[derive(Debug, Clone)]
-pub struct GetAllUsageRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+pub struct GetAllUsageRequest {
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) start_date: Option<String>,
     pub(crate) end_date: Option<String>,
     pub(crate) plugin_property: Option<Vec<String>>,
 }
 
-impl<'a> GetAllUsageRequest<'a> {
-    pub fn builder() -> GetAllUsageRequestBuilder<'a> {
+impl GetAllUsageRequest {
+    pub fn builder() -> GetAllUsageRequestBuilder {
         GetAllUsageRequestBuilder::default()
     }
 }
 
 #[derive(Debug, Default)]
-pub struct GetAllUsageRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+pub struct GetAllUsageRequestBuilder {
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     start_date: Option<String>,
     end_date: Option<String>,
     plugin_property: Option<Vec<String>>,
 }
 
-impl<'a> GetAllUsageRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+impl GetAllUsageRequestBuilder {
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
-    pub fn start_date(mut self, start_date: impl Into<String>) -> Self {
-        self.start_date = Some(start_date.into());
+    pub fn start_date(mut self, start_date: impl models::DateParam) -> Self {
+        self.start_date = Some(start_date.into_param());
         self
     }
 
-    pub fn end_date(mut self, end_date: impl Into<String>) -> Self {
-        self.end_date = Some(end_date.into());
+    pub fn end_date(mut self, end_date: impl models::DateParam) -> Self {
+        self.end_date = Some(end_date.into_param());
         self
     }
 
@@ -154,9 +160,11 @@ impl<'a> GetAllUsageRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetAllUsageRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetAllUsageRequest, UsageApiError> {
         Ok(GetAllUsageRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
             start_date: self.start_date,
             end_date: self.end_date,
             plugin_property: self.plugin_property,
@@ -166,7 +174,7 @@ impl<'a> GetAllUsageRequestBuilder<'a> {
 
 #[derive(Debug, Clone)]
 pub struct GetUsageRequest<'a> {
-    pub(crate) subscription_id: &'a str,
+    pub(crate) subscription_id: uuid::Uuid,
     pub(crate) unit_type: &'a str,
     pub(crate) start_date: Option<String>,
     pub(crate) end_date: Option<String>,
@@ -181,7 +189,7 @@ impl<'a> GetUsageRequest<'a> {
 
 #[derive(Debug, Default)]
 pub struct GetUsageRequestBuilder<'a> {
-    subscription_id: Option<&'a str>,
+    subscription_id: Option<Result<uuid::Uuid, models::InvalidId>>,
     unit_type: Option<&'a str>,
     start_date: Option<String>,
     end_date: Option<String>,
@@ -189,8 +197,8 @@ pub struct GetUsageRequestBuilder<'a> {
 }
 
 impl<'a> GetUsageRequestBuilder<'a> {
-    pub fn subscription_id(mut self, subscription_id: &'a str) -> Self {
-        self.subscription_id = Some(subscription_id);
+    pub fn subscription_id(mut self, subscription_id: impl models::IdParam) -> Self {
+        self.subscription_id = Some(subscription_id.into_id());
         self
     }
 
@@ -199,13 +207,13 @@ impl<'a> GetUsageRequestBuilder<'a> {
         self
     }
 
-    pub fn start_date(mut self, start_date: impl Into<String>) -> Self {
-        self.start_date = Some(start_date.into());
+    pub fn start_date(mut self, start_date: impl models::DateParam) -> Self {
+        self.start_date = Some(start_date.into_param());
         self
     }
 
-    pub fn end_date(mut self, end_date: impl Into<String>) -> Self {
-        self.end_date = Some(end_date.into());
+    pub fn end_date(mut self, end_date: impl models::DateParam) -> Self {
+        self.end_date = Some(end_date.into_param());
         self
     }
 
@@ -214,10 +222,12 @@ impl<'a> GetUsageRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<GetUsageRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<GetUsageRequest<'a>, UsageApiError> {
         Ok(GetUsageRequest {
-            subscription_id: self.subscription_id.ok_or("subscription_id is required")?,
-            unit_type: self.unit_type.ok_or("unit_type is required")?,
+            subscription_id: self.subscription_id
+                .ok_or(ParamError::missing("subscription_id"))?
+                .map_err(|e| ParamError::invalid_id("subscription_id", e))?,
+            unit_type: self.unit_type.ok_or(ParamError::missing("unit_type"))?,
             start_date: self.start_date,
             end_date: self.end_date,
             plugin_property: self.plugin_property,
@@ -268,12 +278,12 @@ impl<'a> RecordUsageRequestBuilder<'a> {
         self
     }
 
-    pub fn build(self) -> Result<RecordUsageRequest<'a>, &'static str> {
+    pub fn build(self) -> Result<RecordUsageRequest<'a>, UsageApiError> {
         Ok(RecordUsageRequest {
             x_killbill_created_by: self.x_killbill_created_by.ok_or(
-                "x_killbill_created_by is required"
+                ParamError::missing("x_killbill_created_by")
             )?,
-            body: self.body.ok_or("body is required")?,
+            body: self.body.ok_or(ParamError::missing("body"))?,
             x_killbill_reason: self.x_killbill_reason,
             x_killbill_comment: self.x_killbill_comment,
         })
@@ -293,7 +303,10 @@ pub enum UsageApiError {
         #[from] crate::apis::configuration::ConfigError,
     ),
 
-    #[error("Validation error: {0}")] ValidationError(String),
+    #[error("Validation error: {0}")] ValidationError(#[from] crate::apis::error::ParamError),
+
+    #[cfg(feature = "blocking")]
+    #[error("The blocking client cannot be used from within an async runtime")] InsideAsyncRuntime,
 }
 
 impl UsageApiError {
@@ -314,17 +327,17 @@ mod tests {
     async fn test_get_all_usage() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/usages/test-subscription")
+            .mock("GET", "/1.0/kb/usages/fa3486b7-d3af-5635-81f1-28261af32bc8")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"subscriptionId": "test-subscription"}"#)
+            .with_body(r#"{"subscriptionId": "fa3486b7-d3af-5635-81f1-28261af32bc8"}"#)
             .create_async().await;
 
         let config = Configuration::builder().base_path(server.url()).build().unwrap();
 
         let api = UsageApi::new(config);
         let request = GetAllUsageRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .build()
             .unwrap();
 
@@ -338,7 +351,7 @@ mod tests {
     async fn test_get_usage() {
         let mut server = Server::new_async().await;
         let mock = server
-            .mock("GET", "/1.0/kb/usages/test-subscription/test-unit")
+            .mock("GET", "/1.0/kb/usages/fa3486b7-d3af-5635-81f1-28261af32bc8/test-unit")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(r#"{"subscriptionId": "550e8400-e29b-41d4-a716-446655440000", "unitType": "test-unit"}"#)
@@ -348,7 +361,7 @@ mod tests {
 
         let api = UsageApi::new(config);
         let request = GetUsageRequest::builder()
-            .subscription_id("test-subscription")
+            .subscription_id("fa3486b7-d3af-5635-81f1-28261af32bc8")
             .unit_type("test-unit")
             .build()
             .unwrap();
@@ -0,0 +1,980 @@
+//! Synchronous mirrors of the API structs, behind the `blocking` feature
+//!
+//! This is not built on `reqwest::blocking`. The blocking [`KillBillClient`]
+//! wraps the async [`crate::KillBillClient`] and owns a current-thread Tokio
+//! runtime, started when the client is built and shared by its clones; every
+//! blocking method calls `block_on` on that runtime with the async method.
+//! Enabling the feature therefore pulls in Tokio's runtime.
+//!
+//! Each blocking API lists the methods it mirrors in a `blocking_api!`
+//! invocation below, and `*_stream` methods become `*_iter` methods. Request
+//! builders are the ones of [`crate::apis`], re-exported from every module, and
+//! methods return the same errors as the async ones.
+//!
+//! These types must not be used from within an async runtime, where blocking
+//! the thread would stall its other tasks. Instead of panicking like
+//! `reqwest::blocking`, their methods then fail with the `InsideAsyncRuntime`
+//! variant of the API error, e.g. when a sync helper is called from a
+//! `#[tokio::main]` program; call the async APIs there instead.
+//!
+//! ```no_run
+//! use killbill_client::blocking::KillBillClient;
+//! use killbill_client::blocking::account_api::GetAccountRequest;
+//!
+//! let client = KillBillClient::builder()
+//!     .base_path("http://localhost:8080")
+//!     .basic_auth("admin", Some("password"))
+//!     .tenant("bob", "lazar")
+//!     .build_blocking_client()
+//!     .unwrap();
+//!
+//! let request = GetAccountRequest::builder()
+//!     .account_id("2e3c9e4c-9bd1-4bc5-9c6f-4d0b3c0a1a52")
+//!     .build()
+//!     .unwrap();
+//! let account = client.accounts().get_account(request).unwrap();
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::{ Stream, StreamExt };
+
+use crate::apis::configuration::{ ConfigBuilder, ConfigError, Configuration };
+
+/// API error built when a blocking method is called from within an async runtime
+trait InsideAsyncRuntime {
+    fn inside_async_runtime() -> Self;
+}
+
+/// Runtime shared by the blocking APIs of a client
+#[derive(Clone)]
+struct Runtime(Arc<OwnedRuntime>);
+
+/// Runtime shut down without waiting when dropped, so that dropping a client
+/// within an async runtime does not panic
+struct OwnedRuntime(Option<tokio::runtime::Runtime>);
+
+impl Drop for OwnedRuntime {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl Runtime {
+    fn new() -> Result<Self, ConfigError> {
+        let runtime = tokio::runtime::Builder
+            ::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(ConfigError::RuntimeError)?;
+        Ok(Self(Arc::new(OwnedRuntime(Some(runtime)))))
+    }
+
+    fn runtime(&self) -> &tokio::runtime::Runtime {
+        self.0.0.as_ref().expect("the runtime is only taken when dropped")
+    }
+
+    /// Whether the current thread runs an async runtime, where blocking panics
+    fn is_inside_async_runtime() -> bool {
+        tokio::runtime::Handle::try_current().is_ok()
+    }
+
+    fn block_on<T, E: InsideAsyncRuntime>(
+        &self,
+        future: impl Future<Output = Result<T, E>>
+    ) -> Result<T, E> {
+        if Self::is_inside_async_runtime() {
+            return Err(E::inside_async_runtime());
+        }
+        self.runtime().block_on(future)
+    }
+
+    fn iter<'a, T: 'a, E: InsideAsyncRuntime + 'a>(
+        &'a self,
+        stream: impl Stream<Item = Result<T, E>> + 'a
+    ) -> Iter<'a, T, E> {
+        Iter {
+            runtime: self,
+            stream: Box::pin(stream),
+            inside_async_runtime: E::inside_async_runtime,
+            done: false,
+        }
+    }
+}
+
+/// Blocking iterator over the items of every page, see the `*_iter` methods
+///
+/// Within an async runtime it yields a single `InsideAsyncRuntime` error.
+pub struct Iter<'a, T, E> {
+    runtime: &'a Runtime,
+    stream: Pin<Box<dyn Stream<Item = Result<T, E>> + 'a>>,
+    inside_async_runtime: fn() -> E,
+    done: bool,
+}
+
+impl<T, E> Iterator for Iter<'_, T, E> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Result<T, E>> {
+        if self.done {
+            return None;
+        }
+        if Runtime::is_inside_async_runtime() {
+            self.done = true;
+            return Some(Err((self.inside_async_runtime)()));
+        }
+        self.runtime.runtime().block_on(self.stream.next())
+    }
+}
+
+/// Blocking version of [`crate::KillBillClient`]
+///
+/// The APIs borrow the wrapped client and its runtime. Cloning the client is
+/// cheap.
+#[derive(Clone)]
+pub struct KillBillClient {
+    client: crate::KillBillClient,
+    runtime: Runtime,
+}
+
+impl KillBillClient {
+    /// Fails with [`ConfigError::RuntimeError`] if the runtime cannot be started
+    pub fn new(config: Configuration) -> Result<Self, ConfigError> {
+        Ok(Self {
+            client: crate::KillBillClient::new(config),
+            runtime: Runtime::new()?,
+        })
+    }
+
+    /// Start configuring a client, finished with [`ConfigBuilder::build_blocking_client`]
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+
+    pub fn config(&self) -> &Configuration {
+        self.client.config()
+    }
+}
+
+impl TryFrom<Configuration> for KillBillClient {
+    type Error = ConfigError;
+
+    fn try_from(config: Configuration) -> Result<Self, ConfigError> {
+        Self::new(config)
+    }
+}
+
+impl std::fmt::Debug for KillBillClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KillBillClient").field("config", self.config()).finish()
+    }
+}
+
+impl ConfigBuilder {
+    /// Build the configuration and wrap it in a blocking [`KillBillClient`]
+    pub fn build_blocking_client(self) -> Result<KillBillClient, ConfigError> {
+        self.build().and_then(KillBillClient::new)
+    }
+}
+
+/// Blocking view of an API struct of the wrapped client, with its accessor on [`KillBillClient`]
+///
+/// Lists the async methods to mirror, with their `Ok` type, and the streams
+/// to turn into iterators, with their item type.
+macro_rules! blocking_api {
+    (
+        $(#[$accessor_doc:meta])*
+        $module:ident::$api:ident as $accessor:ident => $error:ident {
+            $(fn $method:ident($($arg:ident: $arg_ty:ty),*) -> $ok:ty;)*
+            $(iter $iter:ident = $stream:ident($request:ident: $request_ty:ty) -> $item:ty;)*
+        }
+    ) => {
+        pub mod $module {
+            pub use crate::apis::$module::*;
+
+            use crate::{ apis::pagination::Page, models };
+            use super::{ Iter, Runtime };
+
+            #[doc = concat!("Blocking version of [`crate::apis::", stringify!($module), "::", stringify!($api), "`]")]
+            #[derive(Clone, Copy)]
+            pub struct $api<'a> {
+                pub(super) inner: &'a crate::apis::$module::$api,
+                pub(super) runtime: &'a Runtime,
+            }
+
+            impl<'a> $api<'a> {
+                $(
+                    pub fn $method(&self $(, $arg: $arg_ty)*) -> Result<$ok, $error> {
+                        self.runtime.block_on(self.inner.$method($($arg),*))
+                    }
+                )*
+
+                $(
+                    pub fn $iter(&self, $request: $request_ty) -> Iter<'a, $item, $error> {
+                        self.runtime.iter(self.inner.$stream($request))
+                    }
+                )*
+            }
+
+            impl super::InsideAsyncRuntime for $error {
+                fn inside_async_runtime() -> Self {
+                    Self::InsideAsyncRuntime
+                }
+            }
+        }
+
+        impl KillBillClient {
+            $(#[$accessor_doc])*
+            pub fn $accessor(&self) -> $module::$api<'_> {
+                $module::$api {
+                    inner: self.client.$accessor(),
+                    runtime: &self.runtime,
+                }
+            }
+        }
+    };
+}
+
+blocking_api! {
+    account_api::AccountApi as accounts => AccountApiError {
+        fn add_account_blocking_state(
+            request: AddAccountBlockingStateRequest<'_>
+        ) -> Vec<models::BlockingState>;
+        fn add_email(request: AddEmailRequest<'_>) -> Vec<models::AccountEmail>;
+        fn close_account(request: CloseAccountRequest<'_>) -> ();
+        fn create_account(request: CreateAccountRequest<'_>) -> models::Account;
+        fn create_account_custom_fields(
+            request: CreateAccountCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn create_account_tags(request: CreateAccountTagsRequest<'_>) -> Vec<models::Tag>;
+        fn create_payment_method(request: CreatePaymentMethodRequest<'_>) -> models::PaymentMethod;
+        fn delete_account_custom_fields(request: DeleteAccountCustomFieldsRequest<'_>) -> ();
+        fn delete_account_tags(request: DeleteAccountTagsRequest<'_>) -> ();
+        fn get_account(request: GetAccountRequest) -> models::Account;
+        fn get_account_audit_logs(request: GetAccountAuditLogsRequest) -> Vec<models::AuditLog>;
+        fn get_account_audit_logs_with_history(
+            request: GetAccountAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_account_bundles(request: GetAccountBundlesRequest<'_>) -> Vec<models::Bundle>;
+        fn get_account_bundles_paginated(
+            request: GetAccountBundlesPaginatedRequest
+        ) -> Page<models::Bundle>;
+        fn get_account_by_key(request: GetAccountByKeyRequest<'_>) -> models::Account;
+        fn get_account_custom_fields(
+            request: GetAccountCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_account_email_audit_logs_with_history(
+            request: GetAccountEmailAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_account_tags(request: GetAccountTagsRequest) -> Vec<models::Tag>;
+        fn get_account_timeline(request: GetAccountTimelineRequest) -> models::AccountTimeline;
+        fn get_accounts(request: GetAccountsRequest) -> Page<models::Account>;
+        fn get_all_custom_fields(
+            request: GetAllCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn get_all_tags(request: GetAllTagsRequest<'_>) -> Vec<models::Tag>;
+        fn get_blocking_state_audit_logs_with_history(
+            request: GetBlockingStateAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_blocking_states(request: GetBlockingStatesRequest) -> Vec<models::BlockingState>;
+        fn get_children_accounts(request: GetChildrenAccountsRequest) -> Vec<models::Account>;
+        fn get_emails(request: GetEmailsRequest) -> Vec<models::AccountEmail>;
+        fn get_invoice_payments(request: GetInvoicePaymentsRequest) -> Vec<models::InvoicePayment>;
+        fn get_invoices_for_account(
+            request: GetInvoicesForAccountRequest<'_>
+        ) -> Vec<models::Invoice>;
+        fn get_invoices_for_account_paginated(
+            request: GetInvoicesForAccountPaginatedRequest
+        ) -> Page<models::Invoice>;
+        fn get_overdue_account(request: GetOverdueAccountRequest) -> models::OverdueState;
+        fn get_payment_methods_for_account(
+            request: GetPaymentMethodsForAccountRequest
+        ) -> Vec<models::PaymentMethod>;
+        fn get_payments_for_account(request: GetPaymentsForAccountRequest) -> Vec<models::Payment>;
+        fn modify_account_custom_fields(request: ModifyAccountCustomFieldsRequest<'_>) -> ();
+        fn pay_all_invoices(request: PayAllInvoicesRequest<'_>) -> Vec<models::Invoice>;
+        fn process_payment(request: ProcessPaymentRequest<'_>) -> models::Payment;
+        fn process_payment_by_external_key(
+            request: ProcessPaymentByExternalKeyRequest<'_>
+        ) -> models::Payment;
+        fn rebalance_existing_cbaon_account(
+            request: RebalanceExistingCbaonAccountRequest<'_>
+        ) -> ();
+        fn refresh_payment_methods(request: RefreshPaymentMethodsRequest<'_>) -> ();
+        fn remove_email(request: RemoveEmailRequest<'_>) -> ();
+        fn search_accounts(request: SearchAccountsRequest<'_>) -> Page<models::Account>;
+        fn set_default_payment_method(request: SetDefaultPaymentMethodRequest<'_>) -> ();
+        fn transfer_child_credit_to_parent(request: TransferChildCreditToParentRequest<'_>) -> ();
+        fn update_account(request: UpdateAccountRequest<'_>) -> ();
+        iter get_account_bundles_paginated_iter = get_account_bundles_paginated_stream(
+            request: GetAccountBundlesPaginatedRequest
+        ) -> models::Bundle;
+        iter get_accounts_iter = get_accounts_stream(
+            request: GetAccountsRequest
+        ) -> models::Account;
+        iter get_invoices_for_account_paginated_iter = get_invoices_for_account_paginated_stream(
+            request: GetInvoicesForAccountPaginatedRequest
+        ) -> models::Invoice;
+        iter search_accounts_iter = search_accounts_stream(
+            request: SearchAccountsRequest<'a>
+        ) -> models::Account;
+    }
+}
+
+blocking_api! {
+    admin_api::AdminApi as admin => AdminApiError {
+        fn get_queue_entries(request: GetQueueEntriesRequest<'_>) -> ();
+        fn invalidates_cache(request: InvalidatesCacheRequest<'_>) -> ();
+        fn invalidates_cache_by_account(request: InvalidatesCacheByAccountRequest) -> ();
+        fn invalidates_cache_by_tenant() -> ();
+        fn put_in_rotation() -> ();
+        fn put_out_of_rotation() -> ();
+        fn trigger_invoice_generation_for_parked_accounts(
+            request: TriggerInvoiceGenerationForParkedAccountsRequest<'_>
+        ) -> ();
+        fn update_payment_transaction_state(
+            request: UpdatePaymentTransactionStateRequest<'_>
+        ) -> ();
+    }
+}
+
+blocking_api! {
+    /// Routes of the killbill-analytics plugin, which must be installed
+    analytics_api::AnalyticsApi as analytics => AnalyticsApiError {
+        fn get_business_snapshot(request: GetBusinessSnapshotRequest) -> models::BusinessSnapshot;
+        fn refresh_business_snapshot(request: RefreshBusinessSnapshotRequest<'_>) -> ();
+        fn get_report_configurations() -> Vec<models::ReportConfiguration>;
+        fn get_reports(request: GetReportsRequest<'_>) -> Vec<models::Report>;
+        fn refresh_report(request: RefreshReportRequest<'_>) -> ();
+    }
+}
+
+blocking_api! {
+    bundle_api::BundleApi as bundles => BundleApiError {
+        fn add_bundle_blocking_state(
+            request: AddBundleBlockingStateRequest<'_>
+        ) -> Vec<models::BlockingState>;
+        fn create_bundle_custom_fields(
+            request: CreateBundleCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn create_bundle_tags(request: CreateBundleTagsRequest<'_>) -> Vec<models::Tag>;
+        fn delete_bundle_custom_fields(request: DeleteBundleCustomFieldsRequest<'_>) -> ();
+        fn delete_bundle_tags(request: DeleteBundleTagsRequest<'_>) -> ();
+        fn get_bundle(request: GetBundleRequest) -> models::Bundle;
+        fn get_bundle_audit_logs_with_history(
+            request: GetBundleAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_bundle_by_key(request: GetBundleByKeyRequest<'_>) -> Vec<models::Bundle>;
+        fn get_bundle_custom_fields(
+            request: GetBundleCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_bundle_tags(request: GetBundleTagsRequest) -> Vec<models::Tag>;
+        fn get_bundles(request: GetBundlesRequest) -> Page<models::Bundle>;
+        fn modify_bundle_custom_fields(request: ModifyBundleCustomFieldsRequest<'_>) -> ();
+        fn pause_bundle(request: PauseBundleRequest<'_>) -> ();
+        fn rename_external_key(request: RenameExternalKeyRequest<'_>) -> ();
+        fn resume_bundle(request: ResumeBundleRequest<'_>) -> ();
+        fn search_bundles(request: SearchBundlesRequest<'_>) -> Page<models::Bundle>;
+        fn transfer_bundle(request: TransferBundleRequest<'_>) -> models::Bundle;
+        iter get_bundles_iter = get_bundles_stream(request: GetBundlesRequest) -> models::Bundle;
+        iter search_bundles_iter = search_bundles_stream(
+            request: SearchBundlesRequest<'a>
+        ) -> models::Bundle;
+    }
+}
+
+blocking_api! {
+    catalog_api::CatalogApi as catalog => CatalogApiError {
+        fn add_simple_plan(request: AddSimplePlanRequest<'_>) -> String;
+        fn delete_catalog(request: DeleteCatalogRequest<'_>) -> ();
+        fn get_available_addons(request: GetAvailableAddonsRequest<'_>) -> Vec<models::PlanDetail>;
+        fn get_available_base_plans(
+            request: GetAvailableBasePlansRequest
+        ) -> Vec<models::PlanDetail>;
+        fn get_catalog_json(request: GetCatalogJsonRequest) -> Vec<models::Catalog>;
+        fn get_catalog_versions(request: GetCatalogVersionsRequest) -> Vec<String>;
+        fn get_catalog_xml(request: GetCatalogXmlRequest) -> String;
+        fn get_phase_for_subscription_and_date(
+            request: GetPhaseForSubscriptionAndDateRequest
+        ) -> models::Phase;
+        fn get_plan_for_subscription_and_date(
+            request: GetPlanForSubscriptionAndDateRequest
+        ) -> models::Plan;
+        fn get_price_list_for_subscription_and_date(
+            request: GetPriceListForSubscriptionAndDateRequest
+        ) -> models::PriceList;
+        fn get_product_for_subscription_and_date(
+            request: GetProductForSubscriptionAndDateRequest
+        ) -> models::Product;
+        fn upload_catalog_xml(request: UploadCatalogXmlRequest<'_>) -> ();
+        fn validate_catalog_xml(
+            request: ValidateCatalogXmlRequest<'_>
+        ) -> models::CatalogValidation;
+    }
+}
+
+blocking_api! {
+    credit_api::CreditApi as credits => CreditApiError {
+        fn create_credits(request: CreateCreditsRequest<'_>) -> Vec<models::InvoiceItem>;
+        fn get_credit(request: GetCreditRequest) -> models::InvoiceItem;
+    }
+}
+
+blocking_api! {
+    custom_field_api::CustomFieldApi as custom_fields => CustomFieldApiError {
+        fn get_custom_field_audit_logs_with_history(
+            request: GetCustomFieldAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_custom_fields(request: GetCustomFieldsRequest) -> Page<models::CustomField>;
+        fn search_custom_fields(
+            request: SearchCustomFieldsRequest<'_>
+        ) -> Page<models::CustomField>;
+        fn search_custom_fields_by_type_name(
+            request: SearchCustomFieldsByTypeNameRequest<'_>
+        ) -> Page<models::CustomField>;
+        iter get_custom_fields_iter = get_custom_fields_stream(
+            request: GetCustomFieldsRequest
+        ) -> models::CustomField;
+        iter search_custom_fields_iter = search_custom_fields_stream(
+            request: SearchCustomFieldsRequest<'a>
+        ) -> models::CustomField;
+        iter search_custom_fields_by_type_name_iter = search_custom_fields_by_type_name_stream(
+            request: SearchCustomFieldsByTypeNameRequest<'a>
+        ) -> models::CustomField;
+    }
+}
+
+blocking_api! {
+    export_api::ExportApi as export => ExportApiError {
+        fn export_data_for_account(request: ExportDataForAccountRequest<'_>) -> ();
+    }
+}
+
+blocking_api! {
+    invoice_api::InvoiceApi as invoices => InvoiceApiError {
+        fn adjust_invoice_item(request: AdjustInvoiceItemRequest<'_>) -> models::Invoice;
+        fn commit_invoice(request: CommitInvoiceRequest<'_>) -> ();
+        fn create_external_charges(
+            request: CreateExternalChargesRequest<'_>
+        ) -> Vec<models::InvoiceItem>;
+        fn create_future_invoice(request: CreateFutureInvoiceRequest<'_>) -> models::Invoice;
+        fn create_future_invoice_group(
+            request: CreateFutureInvoiceGroupRequest<'_>
+        ) -> Vec<models::Invoice>;
+        fn create_instant_payment(
+            request: CreateInstantPaymentRequest<'_>
+        ) -> models::InvoicePayment;
+        fn create_invoice_custom_fields(
+            request: CreateInvoiceCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn create_invoice_tags(request: CreateInvoiceTagsRequest<'_>) -> Vec<models::Tag>;
+        fn create_migration_invoice(request: CreateMigrationInvoiceRequest<'_>) -> models::Invoice;
+        fn create_tax_items(request: CreateTaxItemsRequest<'_>) -> Vec<models::InvoiceItem>;
+        fn delete_cba(request: DeleteCbaRequest<'_>) -> ();
+        fn delete_invoice_custom_fields(request: DeleteInvoiceCustomFieldsRequest<'_>) -> ();
+        fn delete_invoice_tags(request: DeleteInvoiceTagsRequest<'_>) -> ();
+        fn generate_dry_run_invoice(request: GenerateDryRunInvoiceRequest<'_>) -> models::Invoice;
+        fn get_catalog_translation(request: GetCatalogTranslationRequest<'_>) -> String;
+        fn get_invoice(request: GetInvoiceRequest) -> models::Invoice;
+        fn get_invoice_as_html(request: GetInvoiceAsHtmlRequest) -> String;
+        fn get_invoice_audit_logs_with_history(
+            request: GetInvoiceAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_invoice_by_item_id(request: GetInvoiceByItemIdRequest) -> models::Invoice;
+        fn get_invoice_by_number(request: GetInvoiceByNumberRequest) -> models::Invoice;
+        fn get_invoice_custom_fields(
+            request: GetInvoiceCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_invoice_mp_template(request: GetInvoiceMpTemplateRequest<'_>) -> String;
+        fn get_invoice_tags(request: GetInvoiceTagsRequest) -> Vec<models::Tag>;
+        fn get_invoice_template() -> String;
+        fn get_invoice_translation(request: GetInvoiceTranslationRequest<'_>) -> String;
+        fn get_invoices(request: GetInvoicesRequest) -> Page<models::Invoice>;
+        fn get_invoices_group(request: GetInvoicesGroupRequest) -> Vec<models::Invoice>;
+        fn get_payments_for_invoice(
+            request: GetPaymentsForInvoiceRequest
+        ) -> Vec<models::InvoicePayment>;
+        fn modify_invoice_custom_fields(request: ModifyInvoiceCustomFieldsRequest<'_>) -> ();
+        fn search_invoices(request: SearchInvoicesRequest<'_>) -> Page<models::Invoice>;
+        fn upload_catalog_translation(request: UploadCatalogTranslationRequest<'_>) -> String;
+        fn upload_invoice_mp_template(request: UploadInvoiceMpTemplateRequest<'_>) -> String;
+        fn upload_invoice_template(request: UploadInvoiceTemplateRequest<'_>) -> String;
+        fn upload_invoice_translation(request: UploadInvoiceTranslationRequest<'_>) -> String;
+        fn void_invoice(request: VoidInvoiceRequest<'_>) -> ();
+        iter get_invoices_iter = get_invoices_stream(
+            request: GetInvoicesRequest
+        ) -> models::Invoice;
+        iter search_invoices_iter = search_invoices_stream(
+            request: SearchInvoicesRequest<'a>
+        ) -> models::Invoice;
+    }
+}
+
+blocking_api! {
+    invoice_item_api::InvoiceItemApi as invoice_items => InvoiceItemApiError {
+        fn create_invoice_item_custom_fields(
+            request: CreateInvoiceItemCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn create_invoice_item_tags(request: CreateInvoiceItemTagsRequest<'_>) -> Vec<models::Tag>;
+        fn delete_invoice_item_custom_fields(
+            request: DeleteInvoiceItemCustomFieldsRequest<'_>
+        ) -> ();
+        fn delete_invoice_item_tags(request: DeleteInvoiceItemTagsRequest<'_>) -> ();
+        fn get_invoice_item_audit_logs_with_history(
+            request: GetInvoiceItemAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_invoice_item_custom_fields(
+            request: GetInvoiceItemCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_invoice_item_tags(request: GetInvoiceItemTagsRequest) -> Vec<models::Tag>;
+        fn modify_invoice_item_custom_fields(
+            request: ModifyInvoiceItemCustomFieldsRequest<'_>
+        ) -> ();
+    }
+}
+
+blocking_api! {
+    invoice_payment_api::InvoicePaymentApi as invoice_payments => InvoicePaymentApiError {
+        fn complete_invoice_payment_transaction(
+            request: CompleteInvoicePaymentTransactionRequest<'_>
+        ) -> ();
+        fn create_chargeback(request: CreateChargebackRequest<'_>) -> models::InvoicePayment;
+        fn create_chargeback_reversal(
+            request: CreateChargebackReversalRequest<'_>
+        ) -> models::InvoicePayment;
+        fn create_invoice_payment_custom_fields(
+            request: CreateInvoicePaymentCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn create_invoice_payment_tags(
+            request: CreateInvoicePaymentTagsRequest<'_>
+        ) -> Vec<models::Tag>;
+        fn create_refund_with_adjustments(
+            request: CreateRefundWithAdjustmentsRequest<'_>
+        ) -> models::InvoicePayment;
+        fn delete_invoice_payment_custom_fields(
+            request: DeleteInvoicePaymentCustomFieldsRequest<'_>
+        ) -> ();
+        fn delete_invoice_payment_tags(request: DeleteInvoicePaymentTagsRequest<'_>) -> ();
+        fn get_invoice_payment(request: GetInvoicePaymentRequest) -> models::InvoicePayment;
+        fn get_invoice_payment_audit_logs_with_history(
+            request: GetInvoicePaymentAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_invoice_payment_custom_fields(
+            request: GetInvoicePaymentCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_invoice_payment_tags(request: GetInvoicePaymentTagsRequest) -> Vec<models::Tag>;
+        fn modify_invoice_payment_custom_fields(
+            request: ModifyInvoicePaymentCustomFieldsRequest<'_>
+        ) -> ();
+    }
+}
+
+blocking_api! {
+    nodes_info_api::NodesApi as nodes => NodesApiError {
+        fn get_nodes_info() -> Vec<models::NodeInfo>;
+        fn trigger_node_command(request: TriggerNodeCommandRequest<'_>) -> ();
+    }
+}
+
+blocking_api! {
+    overdue_api::OverdueApi as overdue => OverdueApiError {
+        fn get_overdue_config_json() -> models::Overdue;
+        fn get_overdue_config_xml() -> String;
+        fn upload_overdue_config_json(
+            request: UploadOverdueConfigJsonRequest<'_>
+        ) -> models::Overdue;
+        fn upload_overdue_config_xml(request: UploadOverdueConfigXmlRequest<'_>) -> ();
+    }
+}
+
+blocking_api! {
+    payment_api::PaymentApi as payments => PaymentApiError {
+        fn cancel_scheduled_payment_transaction_by_external_key(
+            request: CancelScheduledPaymentTransactionByExternalKeyRequest<'_>
+        ) -> ();
+        fn cancel_scheduled_payment_transaction_by_id(
+            request: CancelScheduledPaymentTransactionByIdRequest<'_>
+        ) -> ();
+        fn capture_authorization(request: CaptureAuthorizationRequest<'_>) -> models::Payment;
+        fn capture_authorization_by_external_key(
+            request: CaptureAuthorizationByExternalKeyRequest<'_>
+        ) -> models::Payment;
+        fn chargeback_payment(request: ChargebackPaymentRequest<'_>) -> models::Payment;
+        fn chargeback_payment_by_external_key(
+            request: ChargebackPaymentByExternalKeyRequest<'_>
+        ) -> models::Payment;
+        fn chargeback_reversal_payment(
+            request: ChargebackReversalPaymentRequest<'_>
+        ) -> models::Payment;
+        fn chargeback_reversal_payment_by_external_key(
+            request: ChargebackReversalPaymentByExternalKeyRequest<'_>
+        ) -> models::Payment;
+        fn complete_transaction(request: CompleteTransactionRequest<'_>) -> ();
+        fn complete_transaction_by_external_key(
+            request: CompleteTransactionByExternalKeyRequest<'_>
+        ) -> ();
+        fn create_combo_payment(request: CreateComboPaymentRequest<'_>) -> models::Payment;
+        fn create_payment_custom_fields(
+            request: CreatePaymentCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn create_payment_tags(request: CreatePaymentTagsRequest<'_>) -> Vec<models::Tag>;
+        fn delete_payment_custom_fields(request: DeletePaymentCustomFieldsRequest<'_>) -> ();
+        fn delete_payment_tags(request: DeletePaymentTagsRequest<'_>) -> ();
+        fn get_payment(request: GetPaymentRequest) -> models::Payment;
+        fn get_payment_attempt_audit_logs_with_history(
+            request: GetPaymentAttemptAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_payment_audit_logs_with_history(
+            request: GetPaymentAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_payment_by_external_key(
+            request: GetPaymentByExternalKeyRequest<'_>
+        ) -> models::Payment;
+        fn get_payment_custom_fields(
+            request: GetPaymentCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_payment_tags(request: GetPaymentTagsRequest) -> Vec<models::Tag>;
+        fn get_payments(request: GetPaymentsRequest<'_>) -> Page<models::Payment>;
+        fn modify_payment_custom_fields(request: ModifyPaymentCustomFieldsRequest<'_>) -> ();
+        fn refund_payment(request: RefundPaymentRequest<'_>) -> models::Payment;
+        fn refund_payment_by_external_key(
+            request: RefundPaymentByExternalKeyRequest<'_>
+        ) -> models::Payment;
+        fn search_payments(request: SearchPaymentsRequest<'_>) -> Page<models::Payment>;
+        fn void_payment(request: VoidPaymentRequest<'_>) -> ();
+        fn void_payment_by_external_key(request: VoidPaymentByExternalKeyRequest<'_>) -> ();
+        iter get_payments_iter = get_payments_stream(
+            request: GetPaymentsRequest<'a>
+        ) -> models::Payment;
+        iter search_payments_iter = search_payments_stream(
+            request: SearchPaymentsRequest<'a>
+        ) -> models::Payment;
+    }
+}
+
+blocking_api! {
+    payment_gateway_api::HostedPaymentPageApi as hosted_payment_pages => HostedPaymentPageApiError {
+        fn build_combo_form_descriptor(
+            request: BuildComboFormDescriptorRequest<'_>
+        ) -> models::HostedPaymentPageFormDescriptor;
+        fn build_form_descriptor(
+            request: BuildFormDescriptorRequest<'_>
+        ) -> models::HostedPaymentPageFormDescriptor;
+        fn process_notification(request: ProcessNotificationRequest<'_>) -> ();
+    }
+}
+
+blocking_api! {
+    payment_method_api::PaymentMethodApi as payment_methods => PaymentMethodApiError {
+        fn create_payment_method_custom_fields(
+            request: CreatePaymentMethodCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn delete_payment_method(request: DeletePaymentMethodRequest<'_>) -> ();
+        fn delete_payment_method_custom_fields(
+            request: DeletePaymentMethodCustomFieldsRequest<'_>
+        ) -> ();
+        fn get_payment_method(request: GetPaymentMethodRequest) -> models::PaymentMethod;
+        fn get_payment_method_audit_logs_with_history(
+            request: GetPaymentMethodAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_payment_method_by_key(
+            request: GetPaymentMethodByKeyRequest<'_>
+        ) -> models::PaymentMethod;
+        fn get_payment_method_custom_fields(
+            request: GetPaymentMethodCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_payment_methods(
+            request: GetPaymentMethodsRequest<'_>
+        ) -> Page<models::PaymentMethod>;
+        fn modify_payment_method_custom_fields(
+            request: ModifyPaymentMethodCustomFieldsRequest<'_>
+        ) -> ();
+        fn search_payment_methods(
+            request: SearchPaymentMethodsRequest<'_>
+        ) -> Page<models::PaymentMethod>;
+        iter get_payment_methods_iter = get_payment_methods_stream(
+            request: GetPaymentMethodsRequest<'a>
+        ) -> models::PaymentMethod;
+        iter search_payment_methods_iter = search_payment_methods_stream(
+            request: SearchPaymentMethodsRequest<'a>
+        ) -> models::PaymentMethod;
+    }
+}
+
+blocking_api! {
+    payment_transaction_api::PaymentTransactionApi as payment_transactions
+        => PaymentTransactionApiError {
+        fn create_transaction_custom_fields(
+            request: CreateTransactionCustomFieldsRequest<'_>
+        ) -> Vec<models::CustomField>;
+        fn create_transaction_tags(request: CreateTransactionTagsRequest<'_>) -> Vec<models::Tag>;
+        fn delete_transaction_custom_fields(
+            request: DeleteTransactionCustomFieldsRequest<'_>
+        ) -> ();
+        fn delete_transaction_tags(request: DeleteTransactionTagsRequest<'_>) -> ();
+        fn get_payment_by_transaction_external_key(
+            request: GetPaymentByTransactionExternalKeyRequest<'_>
+        ) -> models::Payment;
+        fn get_payment_by_transaction_id(
+            request: GetPaymentByTransactionIdRequest
+        ) -> models::Payment;
+        fn get_transaction_audit_logs_with_history(
+            request: GetTransactionAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_transaction_custom_fields(
+            request: GetTransactionCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_transaction_tags(request: GetTransactionTagsRequest) -> Vec<models::Tag>;
+        fn modify_transaction_custom_fields(
+            request: ModifyTransactionCustomFieldsRequest<'_>
+        ) -> ();
+        fn notify_state_changed(request: NotifyStateChangedRequest<'_>) -> models::Payment;
+    }
+}
+
+blocking_api! {
+    /// Routes served by the plugins themselves, under `/plugins/{pluginName}`
+    plugin_api::PluginApi as plugin_routes => PluginApiError {
+        fn call(request: PluginRequest) -> PluginResponse;
+    }
+}
+
+blocking_api! {
+    plugin_info_api::PluginsApi as plugins => PluginsApiError {
+        fn get_plugins_info() -> Vec<models::PluginInfo>;
+    }
+}
+
+blocking_api! {
+    security_api::SecurityApi as security => SecurityApiError {
+        fn add_role_definition(request: AddRoleDefinitionRequest<'_>) -> models::RoleDefinition;
+        fn add_user_roles(request: AddUserRolesRequest<'_>) -> models::UserRoles;
+        fn get_current_user_permissions() -> Vec<String>;
+        fn get_current_user_subject() -> models::Subject;
+        fn get_role_definition(request: GetRoleDefinitionRequest<'_>) -> models::RoleDefinition;
+        fn get_user_roles(request: GetUserRolesRequest<'_>) -> models::UserRoles;
+        fn invalidate_user(request: InvalidateUserRequest<'_>) -> ();
+        fn update_role_definition(request: UpdateRoleDefinitionRequest<'_>) -> ();
+        fn update_user_password(request: UpdateUserPasswordRequest<'_>) -> ();
+        fn update_user_roles(request: UpdateUserRolesRequest<'_>) -> ();
+    }
+}
+
+blocking_api! {
+    subscription_api::SubscriptionApi as subscriptions => SubscriptionApiError {
+        fn add_subscription_blocking_state(
+            request: AddSubscriptionBlockingStateRequest<'_>
+        ) -> Vec<models::BlockingState>;
+        fn cancel_subscription_plan(request: CancelSubscriptionPlanRequest<'_>) -> ();
+        fn change_subscription_plan(request: ChangeSubscriptionPlanRequest<'_>) -> ();
+        fn create_subscription(request: CreateSubscriptionRequest<'_>) -> models::Subscription;
+        fn create_subscription_custom_fields(
+            request: CreateSubscriptionCustomFieldsRequest<'_>
+        ) -> ();
+        fn create_subscription_tags(request: CreateSubscriptionTagsRequest<'_>) -> ();
+        fn create_subscription_with_add_ons(
+            request: CreateSubscriptionWithAddOnsRequest<'_>
+        ) -> models::Bundle;
+        fn create_subscriptions_with_add_ons(
+            request: CreateSubscriptionsWithAddOnsRequest<'_>
+        ) -> Vec<models::Bundle>;
+        fn delete_subscription_custom_fields(
+            request: DeleteSubscriptionCustomFieldsRequest<'_>
+        ) -> ();
+        fn delete_subscription_tags(request: DeleteSubscriptionTagsRequest<'_>) -> ();
+        fn get_subscription(request: GetSubscriptionRequest) -> models::Subscription;
+        fn get_subscription_audit_logs_with_history(
+            request: GetSubscriptionAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_subscription_by_key(
+            request: GetSubscriptionByKeyRequest<'_>
+        ) -> models::Subscription;
+        fn get_subscription_custom_fields(
+            request: GetSubscriptionCustomFieldsRequest
+        ) -> Vec<models::CustomField>;
+        fn get_subscription_event_audit_logs_with_history(
+            request: GetSubscriptionEventAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_subscription_tags(request: GetSubscriptionTagsRequest) -> Vec<models::Tag>;
+        fn modify_subscription_custom_fields(
+            request: ModifySubscriptionCustomFieldsRequest<'_>
+        ) -> ();
+        fn uncancel_subscription_plan(request: UncancelSubscriptionPlanRequest<'_>) -> ();
+        fn undo_change_subscription_plan(request: UndoChangeSubscriptionPlanRequest<'_>) -> ();
+        fn update_subscription_bcd(request: UpdateSubscriptionBcdRequest<'_>) -> ();
+        fn update_subscription_quantity(request: UpdateSubscriptionQuantityRequest<'_>) -> ();
+    }
+}
+
+blocking_api! {
+    tag_api::TagApi as tags => TagApiError {
+        fn get_tag_audit_logs_with_history(
+            request: GetTagAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_tags(request: GetTagsRequest) -> Page<models::Tag>;
+        fn search_tags(request: SearchTagsRequest<'_>) -> Page<models::Tag>;
+        iter get_tags_iter = get_tags_stream(request: GetTagsRequest) -> models::Tag;
+        iter search_tags_iter = search_tags_stream(request: SearchTagsRequest<'a>) -> models::Tag;
+    }
+}
+
+blocking_api! {
+    tag_definition_api::TagDefinitionApi as tag_definitions => TagDefinitionApiError {
+        fn create_tag_definition(request: CreateTagDefinitionRequest<'_>) -> models::TagDefinition;
+        fn delete_tag_definition(request: DeleteTagDefinitionRequest<'_>) -> ();
+        fn get_tag_definition(request: GetTagDefinitionRequest) -> models::TagDefinition;
+        fn get_tag_definition_audit_logs_with_history(
+            request: GetTagDefinitionAuditLogsWithHistoryRequest
+        ) -> Vec<models::AuditLog>;
+        fn get_tag_definitions(request: GetTagDefinitionsRequest) -> Vec<models::TagDefinition>;
+    }
+}
+
+blocking_api! {
+    tenant_api::TenantApi as tenants => TenantApiError {
+        fn create_tenant(request: CreateTenantRequest<'_>) -> models::Tenant;
+        fn delete_per_tenant_configuration(request: DeletePerTenantConfigurationRequest<'_>) -> ();
+        fn delete_plugin_configuration(request: DeletePluginConfigurationRequest<'_>) -> ();
+        fn delete_plugin_payment_state_machine_config(
+            request: DeletePluginPaymentStateMachineConfigRequest<'_>
+        ) -> ();
+        fn delete_push_notification_callbacks(
+            request: DeletePushNotificationCallbacksRequest<'_>
+        ) -> ();
+        fn delete_user_key_value(request: DeleteUserKeyValueRequest<'_>) -> ();
+        fn get_all_plugin_configuration(
+            request: GetAllPluginConfigurationRequest<'_>
+        ) -> Vec<models::TenantKeyValue>;
+        fn get_per_tenant_configuration() -> models::TenantKeyValue;
+        fn get_plugin_configuration(
+            request: GetPluginConfigurationRequest<'_>
+        ) -> models::TenantKeyValue;
+        fn get_plugin_payment_state_machine_config(
+            request: GetPluginPaymentStateMachineConfigRequest<'_>
+        ) -> models::TenantKeyValue;
+        fn get_push_notification_callbacks() -> models::TenantKeyValue;
+        fn get_tenant(request: GetTenantRequest) -> models::Tenant;
+        fn get_tenant_by_api_key(request: GetTenantByApiKeyRequest<'_>) -> models::Tenant;
+        fn get_user_key_value(request: GetUserKeyValueRequest<'_>) -> models::TenantKeyValue;
+        fn insert_user_key_value(request: InsertUserKeyValueRequest<'_>) -> models::TenantKeyValue;
+        fn register_push_notification_callback(
+            request: RegisterPushNotificationCallbackRequest<'_>
+        ) -> models::TenantKeyValue;
+        fn upload_per_tenant_configuration(
+            request: UploadPerTenantConfigurationRequest<'_>
+        ) -> models::TenantKeyValue;
+        fn upload_plugin_configuration(
+            request: UploadPluginConfigurationRequest<'_>
+        ) -> models::TenantKeyValue;
+        fn upload_plugin_payment_state_machine_config(
+            request: UploadPluginPaymentStateMachineConfigRequest<'_>
+        ) -> models::TenantKeyValue;
+    }
+}
+
+blocking_api! {
+    /// Test clock and queues, only usable against a server running in test mode
+    test_api::TestApi as test => TestApiError {
+        fn get_clock(request: GetClockRequest<'_>) -> models::Clock;
+        fn set_clock(request: SetClockRequest<'_>) -> models::Clock;
+        fn move_clock(request: MoveClockRequest<'_>) -> models::Clock;
+        fn wait_for_queues(request: WaitForQueuesRequest) -> ();
+    }
+}
+
+blocking_api! {
+    usage_api::UsageApi as usages => UsageApiError {
+        fn get_all_usage(request: GetAllUsageRequest) -> models::RolledUpUsage;
+        fn get_usage(request: GetUsageRequest<'_>) -> models::RolledUpUsage;
+        fn record_usage(request: RecordUsageRequest<'_>) -> ();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::account_api::{ AccountApiError, GetAccountRequest };
+    use super::tag_api::{ GetTagsRequest, TagApiError };
+    use super::tag_definition_api::{ GetTagDefinitionRequest, TagDefinitionApiError };
+
+    #[test]
+    fn test_blocking_call() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/1.0/kb/tagDefinitions/2e3c9e4c-9bd1-4bc5-9c6f-4d0b3c0a1a52")
+            .with_status(404)
+            .with_body("not found")
+            .create();
+
+        let client = KillBillClient::builder().base_path(server.url()).build_blocking_client().unwrap();
+        let request = GetTagDefinitionRequest::builder()
+            .tag_definition_id("2e3c9e4c-9bd1-4bc5-9c6f-4d0b3c0a1a52")
+            .build()
+            .unwrap();
+
+        let result = client.tag_definitions().get_tag_definition(request);
+        assert!(
+            matches!(
+                result,
+                Err(TagDefinitionApiError::ApiError { status, .. }) if status == 404
+            )
+        );
+
+        mock.assert();
+    }
+
+    #[test]
+    fn test_blocking_iter() {
+        let mut server = mockito::Server::new();
+        let first = server
+            .mock("GET", "/1.0/kb/tags/pagination")
+            .match_query(mockito::Matcher::UrlEncoded("offset".into(), "0".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("X-Killbill-Pagination-NextOffset", "1")
+            .with_body(r#"[{"tagDefinitionName":"AUTO_PAY_OFF"}]"#)
+            .create();
+        let second = server
+            .mock("GET", "/1.0/kb/tags/pagination")
+            .match_query(mockito::Matcher::UrlEncoded("offset".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"tagDefinitionName":"TEST"}]"#)
+            .create();
+
+        let client = KillBillClient::builder().base_path(server.url()).build_blocking_client().unwrap();
+        let request = GetTagsRequest::builder().offset(0).build();
+        let names: Vec<_> = client
+            .tags()
+            .get_tags_iter(request)
+            .map(|tag| tag.unwrap().tag_definition_name.unwrap())
+            .collect();
+
+        assert_eq!(names, vec!["AUTO_PAY_OFF", "TEST"]);
+        first.assert();
+        second.assert();
+    }
+
+    #[tokio::test]
+    async fn test_blocking_call_within_async_runtime() {
+        let client = KillBillClient::builder()
+            .base_path("http://localhost:8080")
+            .build_blocking_client()
+            .unwrap();
+
+        let request = GetAccountRequest::builder()
+            .account_id("2e3c9e4c-9bd1-4bc5-9c6f-4d0b3c0a1a52")
+            .build()
+            .unwrap();
+        let result = client.accounts().get_account(request);
+        assert!(matches!(result, Err(AccountApiError::InsideAsyncRuntime)));
+
+        let mut tags = client.tags().get_tags_iter(GetTagsRequest::builder().build());
+        assert!(matches!(tags.next(), Some(Err(TagApiError::InsideAsyncRuntime))));
+        assert!(tags.next().is_none());
+    }
+}
@@ -0,0 +1,739 @@
+use std::fmt::{ self, Display };
+
+use serde::Serialize;
+
+use crate::models::{ self, Currency, Decimal };
+
+use super::{ json, xml, Catalog, Limit, Phase, PhaseType, Plan, Price, Tier, Usage };
+
+/// Compare two catalog versions, e.g. the current one and the one about to be uploaded
+///
+/// Prices and usage tiers are compared for the plans of both versions, the
+/// first phase of a type with the first of that type in the other version,
+/// the second with the second, and so on. The report prints as a
+/// human-readable summary and serializes to JSON.
+///
+/// Snapshots come from
+/// [`CatalogApi::get_catalog_xml`](crate::apis::catalog_api::CatalogApi::get_catalog_xml),
+/// read with [`xml::versions_from_str`], or from [`Catalog::builder`]. JSON
+/// snapshots are compared with [`diff_json`].
+pub fn diff(old: &Catalog, new: &Catalog) -> CatalogDiff {
+    let (added_products, removed_products) = compare(
+        old.products.iter().map(|product| product.name.as_str()),
+        new.products.iter().map(|product| product.name.as_str())
+    );
+    let (added_plans, removed_plans) = compare(
+        old.plans.iter().map(|plan| plan.name.as_str()),
+        new.plans.iter().map(|plan| plan.name.as_str())
+    );
+    let (added_price_lists, removed_price_lists) = compare(
+        old.price_lists().map(|price_list| price_list.name.as_str()),
+        new.price_lists().map(|price_list| price_list.name.as_str())
+    );
+
+    let mut diff = CatalogDiff {
+        added_products,
+        removed_products,
+        added_plans,
+        removed_plans,
+        added_price_lists,
+        removed_price_lists,
+        ..CatalogDiff::default()
+    };
+
+    for new_list in new.price_lists() {
+        let Some(old_list) = old.price_lists().find(|old_list| old_list.name == new_list.name) else {
+            continue;
+        };
+        let (added_plans, removed_plans) = compare(
+            old_list.plans.iter().map(String::as_str),
+            new_list.plans.iter().map(String::as_str)
+        );
+        if !added_plans.is_empty() || !removed_plans.is_empty() {
+            diff.price_list_changes.push(PriceListChange {
+                price_list: new_list.name.clone(),
+                added_plans,
+                removed_plans,
+            });
+        }
+    }
+
+    for new_plan in &new.plans {
+        if let Some(old_plan) = old.plan(&new_plan.name) {
+            diff_plan(&mut diff, old_plan, new_plan);
+        }
+    }
+
+    diff
+}
+
+/// Compare two JSON catalog snapshots, as returned by
+/// [`CatalogApi::get_catalog_json`](crate::apis::catalog_api::CatalogApi::get_catalog_json)
+/// for two `requestedDate`s
+///
+/// The snapshots are read with [`json::from_model`], which names usages by
+/// their position in the phase, so tier changes report e.g. `usage-1`.
+pub fn diff_json(
+    old: &models::Catalog,
+    new: &models::Catalog
+) -> Result<CatalogDiff, json::JsonError> {
+    Ok(diff(&json::from_model(old)?, &json::from_model(new)?))
+}
+
+/// Changes between two catalog versions, see [`diff`]
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogDiff {
+    pub added_products: Vec<String>,
+    pub removed_products: Vec<String>,
+    pub added_plans: Vec<String>,
+    pub removed_plans: Vec<String>,
+    pub added_price_lists: Vec<String>,
+    pub removed_price_lists: Vec<String>,
+    /// Plans added to or removed from the price lists of both versions
+    pub price_list_changes: Vec<PriceListChange>,
+    pub price_changes: Vec<PriceChange>,
+    pub tier_changes: Vec<TierChange>,
+}
+
+impl CatalogDiff {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Display for CatalogDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        write_names(f, "Products", &self.added_products, &self.removed_products)?;
+        write_names(f, "Plans", &self.added_plans, &self.removed_plans)?;
+        write_names(f, "Price lists", &self.added_price_lists, &self.removed_price_lists)?;
+        write_changes(f, "Price list plans", &self.price_list_changes)?;
+        write_changes(f, "Prices", &self.price_changes)?;
+        write_changes(f, "Usage tiers", &self.tier_changes)
+    }
+}
+
+/// Plans added to or removed from a price list
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceListChange {
+    pub price_list: String,
+    pub added_plans: Vec<String>,
+    pub removed_plans: Vec<String>,
+}
+
+impl Display for PriceListChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plans: Vec<_> = self.added_plans
+            .iter()
+            .map(|plan| format!("+ {plan}"))
+            .chain(self.removed_plans.iter().map(|plan| format!("- {plan}")))
+            .collect();
+        write!(f, "~ {}: {}", self.price_list, plans.join(", "))
+    }
+}
+
+/// Price of a phase in one currency, `None` when the version has no such price
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceChange {
+    pub plan: String,
+    pub phase_type: PhaseType,
+    /// Which phase of this type, starting at 1, for plans with several of them
+    pub phase_occurrence: usize,
+    pub kind: PriceKind,
+    pub currency: Currency,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub old: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub new: Option<Decimal>,
+}
+
+impl Display for PriceChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            PriceKind::Fixed => "fixed",
+            PriceKind::Recurring => "recurring",
+        };
+        let prefix = format!(
+            "{} {} {kind} {}",
+            self.plan,
+            phase_label(self.phase_type, self.phase_occurrence),
+            self.currency
+        );
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, "~ {prefix}: {old} -> {new}"),
+            (None, Some(new)) => write!(f, "+ {prefix}: {new}"),
+            (Some(old), None) => write!(f, "- {prefix}: {old}"),
+            (None, None) => write!(f, "  {prefix}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PriceKind {
+    /// One-time price charged when the phase starts
+    Fixed,
+    Recurring,
+}
+
+/// Tier of a usage section, `None` when the version has no such tier
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TierChange {
+    pub plan: String,
+    pub phase_type: PhaseType,
+    /// Which phase of this type, starting at 1, for plans with several of them
+    pub phase_occurrence: usize,
+    pub usage: String,
+    /// Position of the tier in the usage section, starting at 1
+    pub tier: usize,
+    pub old: Option<Tier>,
+    pub new: Option<Tier>,
+}
+
+impl Display for TierChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = format!(
+            "{} {} {} tier {}",
+            self.plan,
+            phase_label(self.phase_type, self.phase_occurrence),
+            self.usage,
+            self.tier
+        );
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) =>
+                write!(f, "~ {prefix}: {} -> {}", describe_tier(old), describe_tier(new)),
+            (None, Some(new)) => write!(f, "+ {prefix}: {}", describe_tier(new)),
+            (Some(old), None) => write!(f, "- {prefix}: {}", describe_tier(old)),
+            (None, None) => write!(f, "  {prefix}"),
+        }
+    }
+}
+
+/// Phase of a plan: its type and which phase of that type it is, starting at 1
+#[derive(Clone, Copy, PartialEq)]
+struct PhaseKey {
+    phase_type: PhaseType,
+    occurrence: usize,
+}
+
+fn diff_plan(diff: &mut CatalogDiff, old: &Plan, new: &Plan) {
+    for key in union(phase_keys(old).into_iter(), phase_keys(new).into_iter()) {
+        let old_phase = phase(old, key);
+        let new_phase = phase(new, key);
+
+        diff_prices(
+            &mut diff.price_changes,
+            &new.name,
+            key,
+            PriceKind::Fixed,
+            fixed_prices(old_phase),
+            fixed_prices(new_phase)
+        );
+        diff_prices(
+            &mut diff.price_changes,
+            &new.name,
+            key,
+            PriceKind::Recurring,
+            recurring_prices(old_phase),
+            recurring_prices(new_phase)
+        );
+        diff_usages(
+            &mut diff.tier_changes,
+            &new.name,
+            key,
+            old_phase.map_or(&[], |phase| &phase.usages),
+            new_phase.map_or(&[], |phase| &phase.usages)
+        );
+    }
+}
+
+fn diff_prices(
+    changes: &mut Vec<PriceChange>,
+    plan: &str,
+    key: PhaseKey,
+    kind: PriceKind,
+    old: &[Price],
+    new: &[Price]
+) {
+    let currencies = union(
+        old.iter().map(|price| price.currency),
+        new.iter().map(|price| price.currency)
+    );
+    for currency in currencies {
+        let old = value(old, currency);
+        let new = value(new, currency);
+        if old != new {
+            changes.push(PriceChange {
+                plan: plan.to_owned(),
+                phase_type: key.phase_type,
+                phase_occurrence: key.occurrence,
+                kind,
+                currency,
+                old,
+                new,
+            });
+        }
+    }
+}
+
+fn diff_usages(
+    changes: &mut Vec<TierChange>,
+    plan: &str,
+    key: PhaseKey,
+    old: &[Usage],
+    new: &[Usage]
+) {
+    let names = union(
+        old.iter().map(|usage| usage.name.as_str()),
+        new.iter().map(|usage| usage.name.as_str())
+    );
+    for name in names {
+        let old_tiers = tiers(old, name);
+        let new_tiers = tiers(new, name);
+        for index in 0..old_tiers.len().max(new_tiers.len()) {
+            let old = old_tiers.get(index);
+            let new = new_tiers.get(index);
+            if old != new {
+                changes.push(TierChange {
+                    plan: plan.to_owned(),
+                    phase_type: key.phase_type,
+                    phase_occurrence: key.occurrence,
+                    usage: name.to_owned(),
+                    tier: index + 1,
+                    old: old.cloned(),
+                    new: new.cloned(),
+                });
+            }
+        }
+    }
+}
+
+fn phase_keys(plan: &Plan) -> Vec<PhaseKey> {
+    let mut keys: Vec<PhaseKey> = Vec::new();
+    for phase in plan.phases() {
+        let occurrence = keys
+            .iter()
+            .filter(|key| key.phase_type == phase.phase_type)
+            .count();
+        keys.push(PhaseKey { phase_type: phase.phase_type, occurrence: occurrence + 1 });
+    }
+    keys
+}
+
+fn phase(plan: &Plan, key: PhaseKey) -> Option<&Phase> {
+    plan.phases()
+        .filter(|phase| phase.phase_type == key.phase_type)
+        .nth(key.occurrence - 1)
+}
+
+/// Phase type, followed by the occurrence for the second and later phases of a type
+fn phase_label(phase_type: PhaseType, occurrence: usize) -> String {
+    match occurrence {
+        1 => xml::name(&phase_type),
+        occurrence => format!("{} {occurrence}", xml::name(&phase_type)),
+    }
+}
+
+fn fixed_prices(phase: Option<&Phase>) -> &[Price] {
+    phase.and_then(|phase| phase.fixed_prices.as_deref()).unwrap_or_default()
+}
+
+fn recurring_prices(phase: Option<&Phase>) -> &[Price] {
+    phase
+        .and_then(|phase| phase.recurring.as_ref())
+        .map_or(&[], |recurring| &recurring.prices)
+}
+
+fn tiers<'a>(usages: &'a [Usage], name: &str) -> &'a [Tier] {
+    usages
+        .iter()
+        .find(|usage| usage.name == name)
+        .map_or(&[], |usage| &usage.tiers)
+}
+
+fn value(prices: &[Price], currency: Currency) -> Option<Decimal> {
+    prices
+        .iter()
+        .find(|price| price.currency == currency)
+        .map(|price| price.value)
+}
+
+/// Names only in `new` and names only in `old`, in the order of their version
+fn compare<'a>(
+    old: impl Iterator<Item = &'a str>,
+    new: impl Iterator<Item = &'a str>
+) -> (Vec<String>, Vec<String>) {
+    let old: Vec<_> = old.collect();
+    let new: Vec<_> = new.collect();
+    let only_in = |names: &[&str], other: &[&str]| -> Vec<String> {
+        names
+            .iter()
+            .filter(|name| !other.contains(name))
+            .map(|name| (*name).to_owned())
+            .collect()
+    };
+    (only_in(&new, &old), only_in(&old, &new))
+}
+
+/// Values of `old` followed by the values only in `new`
+fn union<T: PartialEq>(old: impl Iterator<Item = T>, new: impl Iterator<Item = T>) -> Vec<T> {
+    let mut values: Vec<T> = old.collect();
+    for value in new {
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    }
+    values
+}
+
+fn write_names(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    added: &[String],
+    removed: &[String]
+) -> fmt::Result {
+    if added.is_empty() && removed.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "{title}:")?;
+    for name in added {
+        writeln!(f, "  + {name}")?;
+    }
+    for name in removed {
+        writeln!(f, "  - {name}")?;
+    }
+    Ok(())
+}
+
+fn write_changes(f: &mut fmt::Formatter<'_>, title: &str, changes: &[impl Display]) -> fmt::Result {
+    if changes.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "{title}:")?;
+    for change in changes {
+        writeln!(f, "  {change}")?;
+    }
+    Ok(())
+}
+
+/// Short description of a tier, e.g. `blocks of 100 api-calls up to 10 at USD 1.5`
+fn describe_tier(tier: &Tier) -> String {
+    let mut parts: Vec<_> = tier.limits.iter().map(describe_limit).collect();
+    for block in &tier.blocks {
+        parts.push(
+            format!(
+                "blocks of {} {} up to {} at {}",
+                block.size,
+                block.unit,
+                block.max,
+                describe_prices(&block.prices)
+            )
+        );
+    }
+    if let Some(prices) = &tier.fixed_prices {
+        parts.push(format!("fixed {}", describe_prices(prices)));
+    }
+    if let Some(prices) = &tier.recurring_prices {
+        parts.push(format!("recurring {}", describe_prices(prices)));
+    }
+    parts.join(", ")
+}
+
+fn describe_limit(limit: &Limit) -> String {
+    match (&limit.min, &limit.max) {
+        (Some(min), Some(max)) => format!("{min} to {max} {}", limit.unit),
+        (Some(min), None) => format!("at least {min} {}", limit.unit),
+        (None, Some(max)) => format!("up to {max} {}", limit.unit),
+        (None, None) => format!("any {}", limit.unit),
+    }
+}
+
+fn describe_prices(prices: &[Price]) -> String {
+    if prices.is_empty() {
+        return "no price".to_owned();
+    }
+    prices
+        .iter()
+        .map(|price| format!("{} {}", price.currency, price.value))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::catalog::{ BillingPeriod, Duration, PriceList, Product, TieredBlock, Unit };
+
+    fn amount(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn usd(value: &str) -> Price {
+        Price::new(Currency::Usd, amount(value))
+    }
+
+    fn catalog() -> Catalog {
+        let api_calls = Usage::consumable("standard-api-calls", BillingPeriod::Monthly)
+            .tier(
+                Tier::new()
+                    .block(TieredBlock::new("api-calls", amount("100"), amount("10"), [usd("1.5")]))
+            )
+            .tier(
+                Tier::new()
+                    .block(TieredBlock::new("api-calls", amount("100"), amount("-1"), [usd("1")]))
+            );
+
+        let standard = Plan::builder()
+            .name("standard-monthly")
+            .product("Standard")
+            .initial_phase(Phase::trial(Duration::days(30)).fixed_price([]))
+            .final_phase(
+                Phase::evergreen()
+                    .recurring(
+                        BillingPeriod::Monthly,
+                        [usd("29.95"), Price::new(Currency::Eur, amount("25"))]
+                    )
+                    .usage(api_calls)
+            )
+            .build()
+            .unwrap();
+
+        let support = Plan::builder()
+            .name("support-annual")
+            .product("Support")
+            .final_phase(Phase::evergreen().recurring(BillingPeriod::Annual, [usd("100")]))
+            .build()
+            .unwrap();
+
+        Catalog::builder()
+            .name("SpyCarAdvanced")
+            .effective_date("2024-01-01T00:00:00+00:00")
+            .currency(Currency::Usd)
+            .currency(Currency::Eur)
+            .unit(Unit::new("api-calls"))
+            .product(Product::base("Standard"))
+            .product(Product::add_on("Support"))
+            .plan(standard)
+            .plan(support)
+            .default_price_list(["standard-monthly", "support-annual"])
+            .price_list(PriceList::new("PROMO", ["standard-monthly"]))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = catalog();
+        assert!(diff(&old, &old).is_empty());
+        assert_eq!(diff(&old, &old).to_string(), "No changes\n");
+
+        let mut new = catalog();
+        new.effective_date = "2024-06-01T00:00:00+00:00".to_owned();
+        new.products.push(Product::base("Premium"));
+        new.plans.retain(|plan| plan.name != "support-annual");
+        new.plans.push(
+            Plan::builder()
+                .name("premium-monthly")
+                .product("Premium")
+                .final_phase(Phase::evergreen().recurring(BillingPeriod::Monthly, [usd("99")]))
+                .build()
+                .unwrap()
+        );
+        new.default_price_list = PriceList::new(
+            "DEFAULT",
+            ["standard-monthly", "premium-monthly"]
+        );
+        new.child_price_lists = vec![PriceList::new("SALE", ["standard-monthly"])];
+
+        let standard = &mut new.plans[0];
+        standard.initial_phases[0].fixed_prices = Some(vec![usd("5")]);
+        let recurring = standard.final_phase.recurring.as_mut().unwrap();
+        recurring.prices = vec![usd("34.95")];
+        let tiers = &mut standard.final_phase.usages[0].tiers;
+        tiers[1].blocks[0].prices = vec![usd("0.8")];
+        tiers.push(
+            Tier::new().block(TieredBlock::new("api-calls", amount("1000"), amount("-1"), [usd("5")]))
+        );
+
+        let diff = diff(&old, &new);
+        assert_eq!(diff.added_products, ["Premium"]);
+        assert!(diff.removed_products.is_empty());
+        assert_eq!(diff.added_plans, ["premium-monthly"]);
+        assert_eq!(diff.removed_plans, ["support-annual"]);
+        assert_eq!(diff.added_price_lists, ["SALE"]);
+        assert_eq!(diff.removed_price_lists, ["PROMO"]);
+        assert_eq!(
+            diff.price_list_changes,
+            [
+                PriceListChange {
+                    price_list: "DEFAULT".to_owned(),
+                    added_plans: vec!["premium-monthly".to_owned()],
+                    removed_plans: vec!["support-annual".to_owned()],
+                },
+            ]
+        );
+        assert_eq!(diff.price_changes.len(), 3);
+        assert_eq!(diff.tier_changes.len(), 2);
+
+        assert_eq!(
+            diff.to_string(),
+            "\
+Products:
+  + Premium
+Plans:
+  + premium-monthly
+  - support-annual
+Price lists:
+  + SALE
+  - PROMO
+Price list plans:
+  ~ DEFAULT: + premium-monthly, - support-annual
+Prices:
+  + standard-monthly TRIAL fixed USD: 5
+  ~ standard-monthly EVERGREEN recurring USD: 29.95 -> 34.95
+  - standard-monthly EVERGREEN recurring EUR: 25
+Usage tiers:
+  ~ standard-monthly EVERGREEN standard-api-calls tier 2: blocks of 100 api-calls up to -1 at USD 1 -> blocks of 100 api-calls up to -1 at USD 0.8
+  + standard-monthly EVERGREEN standard-api-calls tier 3: blocks of 1000 api-calls up to -1 at USD 5
+"
+        );
+
+        let report = serde_json::to_value(&diff).unwrap();
+        assert_eq!(
+            report["priceChanges"][1],
+            json!({
+                "plan": "standard-monthly",
+                "phaseType": "EVERGREEN",
+                "phaseOccurrence": 1,
+                "kind": "RECURRING",
+                "currency": "USD",
+                "old": 29.95,
+                "new": 34.95,
+            })
+        );
+        let tier = &report["tierChanges"][1];
+        assert_eq!(tier["plan"], "standard-monthly");
+        assert_eq!(tier["phaseType"], "EVERGREEN");
+        assert_eq!(tier["usage"], "standard-api-calls");
+        assert_eq!(tier["tier"], 3);
+        assert!(tier["old"].is_null());
+        let block = &tier["new"]["blocks"][0];
+        assert_eq!(block["unit"], "api-calls");
+        assert_eq!(block["size"].as_f64(), Some(1000.0));
+        assert_eq!(block["max"].as_f64(), Some(-1.0));
+        assert_eq!(block["prices"][0]["currency"], "USD");
+        assert_eq!(block["prices"][0]["value"].as_f64(), Some(5.0));
+    }
+
+    #[test]
+    fn test_diff_json() {
+        let snapshot = |price: f64, block_price: f64, names: &[&str]| -> models::Catalog {
+            let plans: Vec<_> = names
+                .iter()
+                .map(|name| {
+                    json!({
+                        "name": name,
+                        "billingPeriod": "MONTHLY",
+                        "phases": [{
+                            "type": "EVERGREEN",
+                            "prices": [{ "currency": "USD", "value": price }],
+                            "fixedPrices": [],
+                            "duration": { "unit": "UNLIMITED", "number": -1 },
+                            "usages": [{
+                                "billingPeriod": "MONTHLY",
+                                "tiers": [{
+                                    "blocks": [{
+                                        "unit": "api-calls",
+                                        "size": "100",
+                                        "max": "-1",
+                                        "prices": [{ "currency": "USD", "value": block_price }]
+                                    }]
+                                }]
+                            }]
+                        }]
+                    })
+                })
+                .collect();
+            serde_json
+                ::from_value(
+                    json!({
+                        "name": "SpyCarBasic",
+                        "effectiveDate": "2024-01-01T00:00:00.000Z",
+                        "currencies": ["USD"],
+                        "products": [{ "type": "BASE", "name": "Standard", "plans": plans }],
+                        "priceLists": [{ "name": "DEFAULT", "plans": names }]
+                    })
+                )
+                .unwrap()
+        };
+
+        let old = snapshot(29.95, 1.5, &["standard-monthly"]);
+        assert!(diff_json(&old, &old).unwrap().is_empty());
+
+        let new = snapshot(34.95, 0.8, &["standard-monthly", "standard-monthly-promo"]);
+        assert_eq!(
+            diff_json(&old, &new).unwrap().to_string(),
+            "\
+Plans:
+  + standard-monthly-promo
+Price list plans:
+  ~ DEFAULT: + standard-monthly-promo
+Prices:
+  ~ standard-monthly EVERGREEN recurring USD: 29.95 -> 34.95
+Usage tiers:
+  ~ standard-monthly EVERGREEN usage-1 tier 1: blocks of 100 api-calls up to -1 at USD 1.5 -> blocks of 100 api-calls up to -1 at USD 0.8
+"
+        );
+
+        let mut invalid = new.clone();
+        invalid.price_lists = None;
+        assert!(diff_json(&old, &invalid).is_err());
+    }
+
+    #[test]
+    fn test_diff_phases_of_the_same_type() {
+        let discounted = |first: &str, second: &str| {
+            let mut catalog = catalog();
+            catalog.plans[0].initial_phases = vec![
+                Phase::discount(Duration::months(1)).fixed_price([usd(first)]),
+                Phase::discount(Duration::months(1)).fixed_price([usd(second)]),
+            ];
+            catalog
+        };
+
+        let old = discounted("5", "10");
+        assert!(diff(&old, &old).is_empty());
+
+        let diff = diff(&old, &discounted("5", "15"));
+        assert_eq!(
+            diff.price_changes,
+            [
+                PriceChange {
+                    plan: "standard-monthly".to_owned(),
+                    phase_type: PhaseType::Discount,
+                    phase_occurrence: 2,
+                    kind: PriceKind::Fixed,
+                    currency: Currency::Usd,
+                    old: Some(amount("10")),
+                    new: Some(amount("15")),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "\
+Prices:
+  ~ standard-monthly DISCOUNT 2 fixed USD: 10 -> 15
+"
+        );
+    }
+}
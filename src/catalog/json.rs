@@ -0,0 +1,379 @@
+//! Kill Bill JSON catalogs
+//!
+//! [`from_model`] turns a catalog version returned by
+//! [`CatalogApi::get_catalog_json`](crate::apis::catalog_api::CatalogApi::get_catalog_json)
+//! into a [`Catalog`], so that JSON snapshots can be compared with
+//! [`diff_json`](super::diff_json). The JSON catalog carries less than the
+//! XML one: it has no rules or product limits, which are left empty, and its
+//! usage sections have no name or type. Usages are named by their position
+//! in the phase, `usage-1`, `usage-2` and so on, and typed as capacity when
+//! their tiers only have limits, as consumable otherwise.
+
+use chrono::SecondsFormat;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::models::{ self, Decimal };
+
+use super::{
+    Catalog,
+    Duration,
+    Limit,
+    Phase,
+    Plan,
+    Price,
+    PriceList,
+    Product,
+    Recurring,
+    Rules,
+    Tier,
+    TieredBlock,
+    Unit,
+    Usage,
+    UsageType,
+    DEFAULT_PRICE_LIST,
+};
+
+/// Error returned by [`from_model`]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum JsonError {
+    #[error("Invalid catalog at {path}: {message}")] Invalid {
+        /// Field the error is about, e.g. `products[Standard]/plans[standard-monthly]/phases[0]`
+        path: String,
+        message: String,
+    },
+}
+
+/// Read a catalog version as returned by
+/// [`CatalogApi::get_catalog_json`](crate::apis::catalog_api::CatalogApi::get_catalog_json)
+pub fn from_model(catalog: &models::Catalog) -> Result<Catalog, JsonError> {
+    let effective_date = catalog.effective_date.ok_or_else(|| missing("effectiveDate"))?;
+
+    let mut products = Vec::new();
+    let mut plans = Vec::new();
+    for product in catalog.products.iter().flatten() {
+        let name = required(&product.name, "products[]/name")?;
+        let path = format!("products[{name}]");
+        let category = parse_enum(&path, "type", product.r#type.as_deref())?;
+        products.push(Product {
+            name: name.clone(),
+            pretty_name: product.pretty_name.clone(),
+            category,
+            included: product.included.clone().unwrap_or_default(),
+            available: product.available.clone().unwrap_or_default(),
+            limits: Vec::new(),
+        });
+        for plan in product.plans.iter().flatten() {
+            plans.push(read_plan(&path, name, plan)?);
+        }
+    }
+
+    let mut price_lists = Vec::new();
+    for price_list in catalog.price_lists.iter().flatten() {
+        let name = required(&price_list.name, "priceLists[]/name")?;
+        let plans = price_list.plans.clone().unwrap_or_default();
+        price_lists.push(PriceList::new(name.clone(), plans));
+    }
+    let default = price_lists
+        .iter()
+        .position(|price_list| price_list.name == DEFAULT_PRICE_LIST)
+        .ok_or_else(|| invalid("priceLists", format!("missing {DEFAULT_PRICE_LIST}")))?;
+    let default_price_list = price_lists.remove(default);
+
+    let units = catalog.units
+        .iter()
+        .flatten()
+        .map(|unit| {
+            Ok(Unit {
+                name: required(&unit.name, "units[]/name")?.clone(),
+                pretty_name: unit.pretty_name.clone(),
+            })
+        })
+        .collect::<Result<_, JsonError>>()?;
+
+    Ok(Catalog {
+        name: required(&catalog.name, "name")?.clone(),
+        effective_date: effective_date.to_rfc3339_opts(SecondsFormat::Secs, true),
+        currencies: catalog.currencies.clone().unwrap_or_default(),
+        units,
+        products,
+        rules: Rules::default(),
+        plans,
+        default_price_list,
+        child_price_lists: price_lists,
+    })
+}
+
+fn read_plan(product_path: &str, product: &str, plan: &models::Plan) -> Result<Plan, JsonError> {
+    let name = required(&plan.name, &format!("{product_path}/plans[]/name"))?;
+    let path = format!("{product_path}/plans[{name}]");
+
+    let mut phases = plan.phases
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, phase)| read_phase(&format!("{path}/phases[{index}]"), plan, phase))
+        .collect::<Result<Vec<_>, _>>()?;
+    let final_phase = phases.pop().ok_or_else(|| invalid(&path, "missing phases"))?;
+
+    Ok(Plan {
+        name: name.clone(),
+        pretty_name: plan.pretty_name.clone(),
+        product: product.to_owned(),
+        recurring_billing_mode: plan.recurring_billing_mode.unwrap_or_default(),
+        initial_phases: phases,
+        final_phase,
+        plans_allowed_in_bundle: None,
+        effective_date_for_existing_subscriptions: None,
+    })
+}
+
+fn read_phase(path: &str, plan: &models::Plan, phase: &models::Phase) -> Result<Phase, JsonError> {
+    let phase_type = parse_enum(path, "type", phase.r#type.as_deref())?;
+    let duration = phase.duration.as_deref().ok_or_else(|| missing(&format!("{path}/duration")))?;
+    let duration = Duration {
+        unit: duration.unit.ok_or_else(|| missing(&format!("{path}/duration/unit")))?,
+        number: duration.number,
+    };
+
+    let recurring_prices = read_prices(&format!("{path}/prices"), phase.prices.as_deref())?;
+    let recurring = match (plan.billing_period, recurring_prices) {
+        (Some(billing_period), Some(prices)) if !prices.is_empty() => {
+            Some(Recurring { billing_period, prices })
+        }
+        _ => None,
+    };
+
+    let usages = phase.usages
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, usage)| read_usage(&format!("{path}/usages[{index}]"), index, usage))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Phase {
+        phase_type,
+        duration,
+        fixed_prices: read_prices(&format!("{path}/fixedPrices"), phase.fixed_prices.as_deref())?,
+        recurring,
+        usages,
+    })
+}
+
+fn read_usage(path: &str, index: usize, usage: &models::Usage) -> Result<Usage, JsonError> {
+    let billing_period = parse_enum(path, "billingPeriod", usage.billing_period.as_deref())?;
+    let tiers = usage.tiers
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, tier)| read_tier(&format!("{path}/tiers[{index}]"), tier))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let capacity = tiers.iter().all(|tier| tier.blocks.is_empty() && !tier.limits.is_empty());
+    let usage_type = if !tiers.is_empty() && capacity {
+        UsageType::Capacity
+    } else {
+        UsageType::Consumable
+    };
+
+    let mut usage = Usage::new(format!("usage-{}", index + 1), usage_type, billing_period);
+    usage.tiers = tiers;
+    Ok(usage)
+}
+
+fn read_tier(path: &str, tier: &models::Tier) -> Result<Tier, JsonError> {
+    let limits = tier.limits
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, limit)| {
+            let path = format!("{path}/limits[{index}]");
+            Ok(Limit {
+                unit: required(&limit.unit, &format!("{path}/unit"))?.clone(),
+                max: optional_number(&path, "max", limit.max.as_deref())?,
+                min: optional_number(&path, "min", limit.min.as_deref())?,
+            })
+        })
+        .collect::<Result<_, JsonError>>()?;
+
+    let blocks = tier.blocks
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, block)| {
+            let path = format!("{path}/blocks[{index}]");
+            Ok(TieredBlock {
+                unit: required(&block.unit, &format!("{path}/unit"))?.clone(),
+                size: required_number(&path, "size", block.size.as_deref())?,
+                max: required_number(&path, "max", block.max.as_deref())?,
+                prices: read_prices(&format!("{path}/prices"), block.prices.as_deref())?
+                    .unwrap_or_default(),
+            })
+        })
+        .collect::<Result<_, JsonError>>()?;
+
+    Ok(Tier {
+        limits,
+        blocks,
+        fixed_prices: read_prices(&format!("{path}/fixedPrice"), tier.fixed_price.as_deref())?,
+        recurring_prices: read_prices(
+            &format!("{path}/recurringPrice"),
+            tier.recurring_price.as_deref()
+        )?,
+    })
+}
+
+fn read_prices(
+    path: &str,
+    prices: Option<&[models::Price]>
+) -> Result<Option<Vec<Price>>, JsonError> {
+    prices
+        .map(|prices| {
+            prices
+                .iter()
+                .enumerate()
+                .map(|(index, price)| {
+                    let path = format!("{path}[{index}]");
+                    Ok(Price {
+                        currency: *required(&price.currency, &format!("{path}/currency"))?,
+                        value: *required(&price.value, &format!("{path}/value"))?,
+                    })
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+fn required<'a, T>(value: &'a Option<T>, path: &str) -> Result<&'a T, JsonError> {
+    value.as_ref().ok_or_else(|| missing(path))
+}
+
+/// Parse an enum value from its name in the catalog, e.g. `ADD_ON`
+fn parse_enum<T: DeserializeOwned>(
+    path: &str,
+    field: &str,
+    value: Option<&str>
+) -> Result<T, JsonError> {
+    let path = format!("{path}/{field}");
+    let value = value.ok_or_else(|| missing(&path))?;
+    serde_json
+        ::from_value(serde_json::Value::String(value.to_owned()))
+        .map_err(|_| invalid(&path, format!("unknown value {value}")))
+}
+
+fn optional_number(
+    path: &str,
+    field: &str,
+    value: Option<&str>
+) -> Result<Option<Decimal>, JsonError> {
+    let invalid_number = |value| {
+        invalid(&format!("{path}/{field}"), format!("invalid number {value}"))
+    };
+    value
+        .map(|value| value.parse().map_err(|_| invalid_number(value)))
+        .transpose()
+}
+
+fn required_number(path: &str, field: &str, value: Option<&str>) -> Result<Decimal, JsonError> {
+    optional_number(path, field, value)?.ok_or_else(|| missing(&format!("{path}/{field}")))
+}
+
+fn missing(path: &str) -> JsonError {
+    invalid(path, "missing value")
+}
+
+fn invalid(path: &str, message: impl Into<String>) -> JsonError {
+    JsonError::Invalid { path: path.to_owned(), message: message.into() }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::catalog::{ BillingPeriod, PhaseType, ProductCategory };
+
+    #[test]
+    fn test_from_model() {
+        let catalog: models::Catalog = serde_json::from_value(json!({
+            "name": "SpyCarBasic",
+            "effectiveDate": "2024-01-01T00:00:00.000Z",
+            "currencies": ["USD"],
+            "products": [{
+                "type": "BASE",
+                "name": "Standard",
+                "plans": [{
+                    "name": "standard-monthly",
+                    "billingPeriod": "MONTHLY",
+                    "phases": [
+                        {
+                            "type": "TRIAL",
+                            "prices": [],
+                            "fixedPrices": [],
+                            "duration": { "unit": "DAYS", "number": 30 },
+                            "usages": []
+                        },
+                        {
+                            "type": "EVERGREEN",
+                            "prices": [{ "currency": "USD", "value": 29.95 }],
+                            "fixedPrices": [],
+                            "duration": { "unit": "UNLIMITED", "number": -1 },
+                            "usages": [{
+                                "billingPeriod": "MONTHLY",
+                                "tiers": [{
+                                    "blocks": [{
+                                        "unit": "api-calls",
+                                        "size": "100",
+                                        "max": "-1",
+                                        "prices": [{ "currency": "USD", "value": 1.5 }]
+                                    }]
+                                }]
+                            }]
+                        }
+                    ]
+                }],
+                "included": [],
+                "available": []
+            }],
+            "priceLists": [{ "name": "DEFAULT", "plans": ["standard-monthly"] }]
+        })).unwrap();
+
+        let catalog = from_model(&catalog).unwrap();
+        assert_eq!(catalog.effective_date, "2024-01-01T00:00:00Z");
+        assert_eq!(catalog.products[0].category, ProductCategory::Base);
+        assert_eq!(catalog.default_price_list.plans, ["standard-monthly"]);
+
+        let plan = catalog.plan("standard-monthly").unwrap();
+        assert_eq!(plan.product, "Standard");
+        assert_eq!(plan.initial_phases[0].phase_type, PhaseType::Trial);
+        assert!(plan.initial_phases[0].recurring.is_none());
+        assert_eq!(plan.billing_period(), Some(BillingPeriod::Monthly));
+
+        let usage = &plan.final_phase.usages[0];
+        assert_eq!(usage.name, "usage-1");
+        assert_eq!(usage.usage_type, UsageType::Consumable);
+        assert_eq!(usage.tiers[0].blocks[0].max, "-1".parse().unwrap());
+    }
+
+    #[test]
+    fn test_from_model_reports_the_invalid_field() {
+        let catalog: models::Catalog = serde_json::from_value(json!({
+            "name": "SpyCarBasic",
+            "effectiveDate": "2024-01-01T00:00:00.000Z",
+            "products": [{
+                "type": "BASE",
+                "name": "Standard",
+                "plans": [{ "name": "standard-monthly", "phases": [{ "type": "PROMO" }] }]
+            }],
+            "priceLists": [{ "name": "DEFAULT", "plans": [] }]
+        })).unwrap();
+
+        assert_eq!(
+            from_model(&catalog).unwrap_err(),
+            JsonError::Invalid {
+                path: "products[Standard]/plans[standard-monthly]/phases[0]/type".to_owned(),
+                message: "unknown value PROMO".to_owned(),
+            }
+        );
+    }
+}
@@ -0,0 +1,445 @@
+//! Kill Bill catalogs as code
+//!
+//! [`Catalog`] and the types it is made of follow Kill Bill's XML catalog
+//! schema, which carries more than the JSON [`models::Catalog`]: plan rules,
+//! billing modes, usage sections and limits. Build one with
+//! [`Catalog::builder`], write it with [`Catalog::to_xml`] and upload it with
+//! [`CatalogApi::upload_catalog_xml`](crate::apis::catalog_api::CatalogApi::upload_catalog_xml).
+//! Existing catalogs are read with [`xml::versions_from_str`] and compared
+//! with [`diff`] before a new version is uploaded, or fetched as JSON and
+//! compared with [`diff_json`]. JSON catalogs are checked offline with
+//! [`validate`].
+//!
+//! ```
+//! use killbill_client::catalog::{
+//!     BillingPeriod, Catalog, Duration, Phase, Plan, PlanAlignmentCreate, Price, Product, Rules,
+//!     CreateAlignmentCase,
+//! };
+//! use killbill_client::models::Currency;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let plan = Plan::builder()
+//!     .name("standard-monthly")
+//!     .product("Standard")
+//!     .initial_phase(Phase::trial(Duration::days(30)).fixed_price([]))
+//!     .final_phase(
+//!         Phase::evergreen()
+//!             .recurring(BillingPeriod::Monthly, [Price::new(Currency::Usd, "29.95".parse()?)])
+//!     )
+//!     .build()?;
+//!
+//! let catalog = Catalog::builder()
+//!     .name("SpyCarBasic")
+//!     .effective_date("2024-01-01T00:00:00+00:00")
+//!     .currency(Currency::Usd)
+//!     .product(Product::base("Standard"))
+//!     .rules(
+//!         Rules::new().create_alignment(
+//!             CreateAlignmentCase::new(PlanAlignmentCreate::StartOfBundle)
+//!         )
+//!     )
+//!     .plan(plan)
+//!     .default_price_list(["standard-monthly"])
+//!     .build()?;
+//!
+//! let xml = catalog.to_xml();
+//! assert!(xml.contains("<plan name=\"standard-monthly\">"));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod json;
+pub mod xml;
+
+mod diff;
+mod plan;
+mod rules;
+mod usage;
+mod validate;
+
+pub use crate::models::duration::Unit as TimeUnit;
+pub use crate::models::plan::{ BillingPeriod, RecurringBillingMode };
+pub use crate::models::subscription::{ PhaseType, ProductCategory };
+pub use crate::models::usage_price::{ TierBlockPolicy, UsageType };
+
+pub use self::diff::{ diff, diff_json, CatalogDiff, PriceChange, PriceKind, PriceListChange, TierChange };
+pub use self::plan::{ Duration, Phase, Plan, PlanBuilder, Price, Recurring };
+pub use self::rules::{
+    BillingActionPolicy,
+    BillingAlignment,
+    BillingAlignmentCase,
+    CancelPolicyCase,
+    ChangeAlignmentCase,
+    ChangePolicyCase,
+    CreateAlignmentCase,
+    PlanAlignmentChange,
+    PlanAlignmentCreate,
+    PlanSpecifier,
+    PriceListCase,
+    Rules,
+};
+pub use self::usage::{ Block, BlockType, Tier, TieredBlock, Usage };
+pub use self::validate::{ validate, ValidationError, ValidationErrorKind };
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::models::{ self, Currency };
+
+/// Name of the price list every catalog must have
+pub const DEFAULT_PRICE_LIST: &str = "DEFAULT";
+
+/// A catalog version
+#[derive(Clone, Debug, PartialEq)]
+pub struct Catalog {
+    pub name: String,
+    /// Date from which this version applies, e.g. `2024-01-01T00:00:00+00:00`
+    pub effective_date: String,
+    pub currencies: Vec<Currency>,
+    pub units: Vec<Unit>,
+    pub products: Vec<Product>,
+    pub rules: Rules,
+    pub plans: Vec<Plan>,
+    pub default_price_list: PriceList,
+    pub child_price_lists: Vec<PriceList>,
+}
+
+impl Catalog {
+    pub fn builder() -> CatalogBuilder {
+        CatalogBuilder::default()
+    }
+
+    /// Kill Bill catalog XML, as accepted by
+    /// [`CatalogApi::upload_catalog_xml`](crate::apis::catalog_api::CatalogApi::upload_catalog_xml)
+    pub fn to_xml(&self) -> String {
+        xml::to_string(self)
+    }
+
+    /// Read a `<catalog>` document, see [`xml::from_str`]
+    pub fn from_xml(xml: &str) -> Result<Self, xml::XmlError> {
+        xml::from_str(xml)
+    }
+
+    /// Read a catalog version returned by the API as JSON, see [`json::from_model`]
+    pub fn from_json(catalog: &models::Catalog) -> Result<Self, json::JsonError> {
+        json::from_model(catalog)
+    }
+
+    pub fn product(&self, name: &str) -> Option<&Product> {
+        self.products.iter().find(|product| product.name == name)
+    }
+
+    pub fn plan(&self, name: &str) -> Option<&Plan> {
+        self.plans.iter().find(|plan| plan.name == name)
+    }
+
+    /// The default price list followed by the child ones
+    pub fn price_lists(&self) -> impl Iterator<Item = &PriceList> {
+        std::iter::once(&self.default_price_list).chain(&self.child_price_lists)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CatalogBuilder {
+    name: Option<String>,
+    effective_date: Option<String>,
+    currencies: Vec<Currency>,
+    units: Vec<Unit>,
+    products: Vec<Product>,
+    rules: Rules,
+    plans: Vec<Plan>,
+    default_price_list: Option<PriceList>,
+    child_price_lists: Vec<PriceList>,
+}
+
+impl CatalogBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Date the version takes effect; a plain date is written as midnight UTC
+    pub fn effective_date(mut self, effective_date: impl models::DateParam) -> Self {
+        self.effective_date = Some(effective_date.into_param());
+        self
+    }
+
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currencies.push(currency);
+        self
+    }
+
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.units.push(unit);
+        self
+    }
+
+    pub fn product(mut self, product: Product) -> Self {
+        self.products.push(product);
+        self
+    }
+
+    pub fn rules(mut self, rules: Rules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    pub fn plan(mut self, plan: Plan) -> Self {
+        self.plans.push(plan);
+        self
+    }
+
+    /// Set the plans of the [`DEFAULT_PRICE_LIST`]
+    pub fn default_price_list<I, S>(mut self, plans: I) -> Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        self.default_price_list = Some(PriceList::new(DEFAULT_PRICE_LIST, plans));
+        self
+    }
+
+    /// Add a child price list, e.g. for promotions
+    pub fn price_list(mut self, price_list: PriceList) -> Self {
+        self.child_price_lists.push(price_list);
+        self
+    }
+
+    /// Fails on a missing name, effective date or default price list, on a
+    /// catalog without currencies, and on a price list naming a plan that
+    /// was not added with [`plan`](Self::plan)
+    pub fn build(self) -> Result<Catalog, BuildError> {
+        let catalog = Catalog {
+            name: self.name.ok_or(BuildError::Missing { field: "name" })?,
+            effective_date: self.effective_date.ok_or(BuildError::Missing { field: "effective_date" })?,
+            currencies: self.currencies,
+            units: self.units,
+            products: self.products,
+            rules: self.rules,
+            plans: self.plans,
+            default_price_list: self.default_price_list.ok_or(BuildError::Missing {
+                field: "default_price_list",
+            })?,
+            child_price_lists: self.child_price_lists,
+        };
+
+        if catalog.currencies.is_empty() {
+            return Err(BuildError::NoCurrency);
+        }
+        for price_list in catalog.price_lists() {
+            if let Some(plan) = price_list.plans.iter().find(|plan| catalog.plan(plan).is_none()) {
+                return Err(BuildError::UnknownPlan {
+                    price_list: price_list.name.clone(),
+                    plan: plan.clone(),
+                });
+            }
+        }
+        Ok(catalog)
+    }
+}
+
+/// Error returned by [`CatalogBuilder::build`] and [`PlanBuilder::build`]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BuildError {
+    #[error("{field} is required")] Missing {
+        field: &'static str,
+    },
+
+    #[error("at least one currency is required")] NoCurrency,
+
+    #[error("price list {price_list} names plan {plan}, which is not in the catalog")] UnknownPlan {
+        price_list: String,
+        plan: String,
+    },
+}
+
+/// Unit in which usage is measured, e.g. API calls
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit {
+    pub name: String,
+    pub pretty_name: Option<String>,
+}
+
+impl Unit {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), pretty_name: None }
+    }
+
+    pub fn pretty_name(mut self, pretty_name: impl Into<String>) -> Self {
+        self.pretty_name = Some(pretty_name.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Product {
+    pub name: String,
+    pub pretty_name: Option<String>,
+    pub category: ProductCategory,
+    /// Add-ons included with this product
+    pub included: Vec<String>,
+    /// Add-ons that can be bought with this product
+    pub available: Vec<String>,
+    pub limits: Vec<Limit>,
+}
+
+impl Product {
+    pub fn new(name: impl Into<String>, category: ProductCategory) -> Self {
+        Self {
+            name: name.into(),
+            pretty_name: None,
+            category,
+            included: Vec::new(),
+            available: Vec::new(),
+            limits: Vec::new(),
+        }
+    }
+
+    pub fn base(name: impl Into<String>) -> Self {
+        Self::new(name, ProductCategory::Base)
+    }
+
+    pub fn add_on(name: impl Into<String>) -> Self {
+        Self::new(name, ProductCategory::AddOn)
+    }
+
+    pub fn standalone(name: impl Into<String>) -> Self {
+        Self::new(name, ProductCategory::Standalone)
+    }
+
+    pub fn pretty_name(mut self, pretty_name: impl Into<String>) -> Self {
+        self.pretty_name = Some(pretty_name.into());
+        self
+    }
+
+    pub fn included(mut self, add_on: impl Into<String>) -> Self {
+        self.included.push(add_on.into());
+        self
+    }
+
+    pub fn available(mut self, add_on: impl Into<String>) -> Self {
+        self.available.push(add_on.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: Limit) -> Self {
+        self.limits.push(limit);
+        self
+    }
+}
+
+/// Bounds on the quantity of a unit
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Limit {
+    #[serde(rename = "unit")]
+    pub unit: String,
+    #[serde(rename = "max", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub max: Option<models::Decimal>,
+    #[serde(rename = "min", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub min: Option<models::Decimal>,
+}
+
+impl Limit {
+    pub fn new(unit: impl Into<String>) -> Self {
+        Self { unit: unit.into(), max: None, min: None }
+    }
+
+    pub fn max(mut self, max: models::Decimal) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn min(mut self, min: models::Decimal) -> Self {
+        self.min = Some(min);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceList {
+    pub name: String,
+    pub pretty_name: Option<String>,
+    /// Names of the plans of the list
+    pub plans: Vec<String>,
+}
+
+impl PriceList {
+    pub fn new<I, S>(name: impl Into<String>, plans: I) -> Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        Self {
+            name: name.into(),
+            pretty_name: None,
+            plans: plans.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn pretty_name(mut self, pretty_name: impl Into<String>) -> Self {
+        self.pretty_name = Some(pretty_name.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_builder_requires_name_date_and_default_price_list() {
+        let result = Catalog::builder().effective_date("2024-01-01").default_price_list(["a"]).build();
+        assert_eq!(result.unwrap_err(), BuildError::Missing { field: "name" });
+
+        let result = Catalog::builder().name("Basic").default_price_list(["a"]).build();
+        assert_eq!(result.unwrap_err(), BuildError::Missing { field: "effective_date" });
+
+        let result = Catalog::builder().name("Basic").effective_date("2024-01-01").build();
+        assert_eq!(result.unwrap_err(), BuildError::Missing { field: "default_price_list" });
+
+        let catalog = Catalog::builder()
+            .name("Basic")
+            .effective_date("2024-01-01")
+            .currency(Currency::Usd)
+            .plan(plan("basic-monthly"))
+            .plan(plan("basic-monthly-promo"))
+            .default_price_list(["basic-monthly"])
+            .price_list(PriceList::new("PROMO", ["basic-monthly-promo"]))
+            .build()
+            .unwrap();
+        let names: Vec<_> = catalog.price_lists().map(|list| list.name.as_str()).collect();
+        assert_eq!(names, ["DEFAULT", "PROMO"]);
+        assert!(xml::to_string(&catalog).contains("<effectiveDate>2024-01-01T00:00:00Z</effectiveDate>"));
+    }
+
+    #[test]
+    fn test_catalog_builder_requires_currencies_and_known_plans() {
+        let builder = Catalog::builder().name("Basic").effective_date("2024-01-01");
+
+        let result = builder
+            .clone()
+            .plan(plan("basic-monthly"))
+            .default_price_list(["basic-monthly"])
+            .build();
+        assert_eq!(result.unwrap_err(), BuildError::NoCurrency);
+
+        let builder = builder.currency(Currency::Usd).plan(plan("basic-monthly"));
+
+        let result = builder.clone().default_price_list(["basic-monthly", "basic-annual"]).build();
+        assert_eq!(
+            result.unwrap_err(),
+            BuildError::UnknownPlan { price_list: "DEFAULT".to_owned(), plan: "basic-annual".to_owned() }
+        );
+
+        let result = builder
+            .default_price_list(["basic-monthly"])
+            .price_list(PriceList::new("PROMO", ["basic-monthly-promo"]))
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            BuildError::UnknownPlan { price_list: "PROMO".to_owned(), plan: "basic-monthly-promo".to_owned() }
+        );
+    }
+
+    fn plan(name: &str) -> Plan {
+        Plan::builder().name(name).product("Basic").final_phase(Phase::evergreen()).build().unwrap()
+    }
+}
@@ -0,0 +1,244 @@
+use serde::Serialize;
+
+use crate::models::{ self, Currency };
+
+use super::{ BillingPeriod, BuildError, PhaseType, RecurringBillingMode, TimeUnit, Usage };
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plan {
+    pub name: String,
+    pub pretty_name: Option<String>,
+    /// Name of the product the plan sells
+    pub product: String,
+    pub recurring_billing_mode: RecurringBillingMode,
+    /// Phases before the final one, e.g. a trial
+    pub initial_phases: Vec<Phase>,
+    pub final_phase: Phase,
+    /// How many subscriptions to this plan a bundle may hold, unlimited if unset
+    pub plans_allowed_in_bundle: Option<i32>,
+    /// Date from which existing subscriptions move to this version of the plan
+    pub effective_date_for_existing_subscriptions: Option<String>,
+}
+
+impl Plan {
+    pub fn builder() -> PlanBuilder {
+        PlanBuilder::default()
+    }
+
+    /// The initial phases followed by the final one
+    pub fn phases(&self) -> impl Iterator<Item = &Phase> {
+        self.initial_phases.iter().chain(std::iter::once(&self.final_phase))
+    }
+
+    /// Billing period of the final phase, if it has a recurring price
+    pub fn billing_period(&self) -> Option<BillingPeriod> {
+        self.final_phase.recurring.as_ref().map(|recurring| recurring.billing_period)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PlanBuilder {
+    name: Option<String>,
+    pretty_name: Option<String>,
+    product: Option<String>,
+    recurring_billing_mode: RecurringBillingMode,
+    initial_phases: Vec<Phase>,
+    final_phase: Option<Phase>,
+    plans_allowed_in_bundle: Option<i32>,
+    effective_date_for_existing_subscriptions: Option<String>,
+}
+
+impl PlanBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn pretty_name(mut self, pretty_name: impl Into<String>) -> Self {
+        self.pretty_name = Some(pretty_name.into());
+        self
+    }
+
+    pub fn product(mut self, product: impl Into<String>) -> Self {
+        self.product = Some(product.into());
+        self
+    }
+
+    /// Bill recurring prices in advance (the default) or in arrear
+    pub fn recurring_billing_mode(mut self, recurring_billing_mode: RecurringBillingMode) -> Self {
+        self.recurring_billing_mode = recurring_billing_mode;
+        self
+    }
+
+    pub fn initial_phase(mut self, phase: Phase) -> Self {
+        self.initial_phases.push(phase);
+        self
+    }
+
+    pub fn final_phase(mut self, phase: Phase) -> Self {
+        self.final_phase = Some(phase);
+        self
+    }
+
+    pub fn plans_allowed_in_bundle(mut self, plans_allowed_in_bundle: i32) -> Self {
+        self.plans_allowed_in_bundle = Some(plans_allowed_in_bundle);
+        self
+    }
+
+    /// Date existing subscriptions move to this version of the plan; a plain date is
+    /// written as midnight UTC
+    pub fn effective_date_for_existing_subscriptions(mut self, date: impl models::DateParam) -> Self {
+        self.effective_date_for_existing_subscriptions = Some(date.into_param());
+        self
+    }
+
+    pub fn build(self) -> Result<Plan, BuildError> {
+        Ok(Plan {
+            name: self.name.ok_or(BuildError::Missing { field: "name" })?,
+            pretty_name: self.pretty_name,
+            product: self.product.ok_or(BuildError::Missing { field: "product" })?,
+            recurring_billing_mode: self.recurring_billing_mode,
+            initial_phases: self.initial_phases,
+            final_phase: self.final_phase.ok_or(BuildError::Missing { field: "final_phase" })?,
+            plans_allowed_in_bundle: self.plans_allowed_in_bundle,
+            effective_date_for_existing_subscriptions: self.effective_date_for_existing_subscriptions,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phase {
+    pub phase_type: PhaseType,
+    pub duration: Duration,
+    /// One-time price charged when the phase starts; an empty list makes it free
+    pub fixed_prices: Option<Vec<Price>>,
+    pub recurring: Option<Recurring>,
+    pub usages: Vec<Usage>,
+}
+
+impl Phase {
+    pub fn new(phase_type: PhaseType, duration: Duration) -> Self {
+        Self {
+            phase_type,
+            duration,
+            fixed_prices: None,
+            recurring: None,
+            usages: Vec::new(),
+        }
+    }
+
+    pub fn trial(duration: Duration) -> Self {
+        Self::new(PhaseType::Trial, duration)
+    }
+
+    pub fn discount(duration: Duration) -> Self {
+        Self::new(PhaseType::Discount, duration)
+    }
+
+    pub fn fixed_term(duration: Duration) -> Self {
+        Self::new(PhaseType::Fixedterm, duration)
+    }
+
+    /// Phase lasting until the subscription is cancelled
+    pub fn evergreen() -> Self {
+        Self::new(PhaseType::Evergreen, Duration::unlimited())
+    }
+
+    pub fn fixed_price(mut self, prices: impl IntoIterator<Item = Price>) -> Self {
+        self.fixed_prices = Some(prices.into_iter().collect());
+        self
+    }
+
+    pub fn recurring(
+        mut self,
+        billing_period: BillingPeriod,
+        prices: impl IntoIterator<Item = Price>
+    ) -> Self {
+        self.recurring = Some(Recurring { billing_period, prices: prices.into_iter().collect() });
+        self
+    }
+
+    pub fn usage(mut self, usage: Usage) -> Self {
+        self.usages.push(usage);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Duration {
+    pub unit: TimeUnit,
+    pub number: Option<i32>,
+}
+
+impl Duration {
+    pub fn new(unit: TimeUnit, number: i32) -> Self {
+        Self { unit, number: Some(number) }
+    }
+
+    pub fn days(number: i32) -> Self {
+        Self::new(TimeUnit::Days, number)
+    }
+
+    pub fn weeks(number: i32) -> Self {
+        Self::new(TimeUnit::Weeks, number)
+    }
+
+    pub fn months(number: i32) -> Self {
+        Self::new(TimeUnit::Months, number)
+    }
+
+    pub fn years(number: i32) -> Self {
+        Self::new(TimeUnit::Years, number)
+    }
+
+    pub fn unlimited() -> Self {
+        Self { unit: TimeUnit::Unlimited, number: None }
+    }
+}
+
+/// Recurring price of a phase
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recurring {
+    pub billing_period: BillingPeriod,
+    pub prices: Vec<Price>,
+}
+
+/// Price in one currency
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Price {
+    #[serde(rename = "currency")]
+    pub currency: Currency,
+    #[serde(rename = "value")]
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub value: models::Decimal,
+}
+
+impl Price {
+    pub fn new(currency: Currency, value: models::Decimal) -> Self {
+        Self { currency, value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_builder() {
+        let result = Plan::builder().name("basic-monthly").final_phase(Phase::evergreen()).build();
+        assert_eq!(result.unwrap_err(), BuildError::Missing { field: "product" });
+
+        let plan = Plan::builder()
+            .name("basic-monthly")
+            .product("Basic")
+            .initial_phase(Phase::trial(Duration::days(14)).fixed_price([]))
+            .final_phase(Phase::evergreen().recurring(BillingPeriod::Monthly, []))
+            .build()
+            .unwrap();
+
+        let phases: Vec<_> = plan.phases().map(|phase| phase.phase_type).collect();
+        assert_eq!(phases, [PhaseType::Trial, PhaseType::Evergreen]);
+        assert_eq!(plan.billing_period(), Some(BillingPeriod::Monthly));
+        assert_eq!(plan.recurring_billing_mode, RecurringBillingMode::Advance);
+    }
+}
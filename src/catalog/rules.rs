@@ -0,0 +1,292 @@
+use serde::{ Deserialize, Serialize };
+
+use super::{ BillingPeriod, PhaseType, ProductCategory };
+
+/// Plan rules of a catalog
+///
+/// Each rule is a list of cases. Kill Bill applies the first case matching
+/// the subscription, so more specific cases go first and a case matching
+/// everything usually comes last.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rules {
+    pub change_policy: Vec<ChangePolicyCase>,
+    pub change_alignment: Vec<ChangeAlignmentCase>,
+    pub cancel_policy: Vec<CancelPolicyCase>,
+    pub create_alignment: Vec<CreateAlignmentCase>,
+    pub billing_alignment: Vec<BillingAlignmentCase>,
+    pub price_list: Vec<PriceListCase>,
+}
+
+impl Rules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn change_policy(mut self, case: ChangePolicyCase) -> Self {
+        self.change_policy.push(case);
+        self
+    }
+
+    pub fn change_alignment(mut self, case: ChangeAlignmentCase) -> Self {
+        self.change_alignment.push(case);
+        self
+    }
+
+    pub fn cancel_policy(mut self, case: CancelPolicyCase) -> Self {
+        self.cancel_policy.push(case);
+        self
+    }
+
+    pub fn create_alignment(mut self, case: CreateAlignmentCase) -> Self {
+        self.create_alignment.push(case);
+        self
+    }
+
+    pub fn billing_alignment(mut self, case: BillingAlignmentCase) -> Self {
+        self.billing_alignment.push(case);
+        self
+    }
+
+    pub fn price_list(mut self, case: PriceListCase) -> Self {
+        self.price_list.push(case);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.change_policy.is_empty() &&
+            self.change_alignment.is_empty() &&
+            self.cancel_policy.is_empty() &&
+            self.create_alignment.is_empty() &&
+            self.billing_alignment.is_empty() &&
+            self.price_list.is_empty()
+    }
+}
+
+/// Plans a case applies to; unset criteria match any plan
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlanSpecifier {
+    pub product: Option<String>,
+    pub product_category: Option<ProductCategory>,
+    pub billing_period: Option<BillingPeriod>,
+    pub price_list: Option<String>,
+}
+
+impl PlanSpecifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn product(mut self, product: impl Into<String>) -> Self {
+        self.product = Some(product.into());
+        self
+    }
+
+    pub fn product_category(mut self, product_category: ProductCategory) -> Self {
+        self.product_category = Some(product_category);
+        self
+    }
+
+    pub fn billing_period(mut self, billing_period: BillingPeriod) -> Self {
+        self.billing_period = Some(billing_period);
+        self
+    }
+
+    pub fn price_list(mut self, price_list: impl Into<String>) -> Self {
+        self.price_list = Some(price_list.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// When a plan change takes effect
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangePolicyCase {
+    pub phase_type: Option<PhaseType>,
+    pub from: PlanSpecifier,
+    pub to: PlanSpecifier,
+    pub policy: BillingActionPolicy,
+}
+
+impl ChangePolicyCase {
+    pub fn new(policy: BillingActionPolicy) -> Self {
+        Self { phase_type: None, from: PlanSpecifier::new(), to: PlanSpecifier::new(), policy }
+    }
+
+    pub fn phase_type(mut self, phase_type: PhaseType) -> Self {
+        self.phase_type = Some(phase_type);
+        self
+    }
+
+    pub fn from(mut self, from: PlanSpecifier) -> Self {
+        self.from = from;
+        self
+    }
+
+    pub fn to(mut self, to: PlanSpecifier) -> Self {
+        self.to = to;
+        self
+    }
+}
+
+/// How the phases of the new plan align after a plan change
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangeAlignmentCase {
+    pub phase_type: Option<PhaseType>,
+    pub from: PlanSpecifier,
+    pub to: PlanSpecifier,
+    pub alignment: PlanAlignmentChange,
+}
+
+impl ChangeAlignmentCase {
+    pub fn new(alignment: PlanAlignmentChange) -> Self {
+        Self { phase_type: None, from: PlanSpecifier::new(), to: PlanSpecifier::new(), alignment }
+    }
+
+    pub fn phase_type(mut self, phase_type: PhaseType) -> Self {
+        self.phase_type = Some(phase_type);
+        self
+    }
+
+    pub fn from(mut self, from: PlanSpecifier) -> Self {
+        self.from = from;
+        self
+    }
+
+    pub fn to(mut self, to: PlanSpecifier) -> Self {
+        self.to = to;
+        self
+    }
+}
+
+/// When a cancellation takes effect
+#[derive(Clone, Debug, PartialEq)]
+pub struct CancelPolicyCase {
+    pub plan: PlanSpecifier,
+    pub phase_type: Option<PhaseType>,
+    pub policy: BillingActionPolicy,
+}
+
+impl CancelPolicyCase {
+    pub fn new(policy: BillingActionPolicy) -> Self {
+        Self { plan: PlanSpecifier::new(), phase_type: None, policy }
+    }
+
+    pub fn plan(mut self, plan: PlanSpecifier) -> Self {
+        self.plan = plan;
+        self
+    }
+
+    pub fn phase_type(mut self, phase_type: PhaseType) -> Self {
+        self.phase_type = Some(phase_type);
+        self
+    }
+}
+
+/// How the phases of a new subscription align
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateAlignmentCase {
+    pub plan: PlanSpecifier,
+    pub alignment: PlanAlignmentCreate,
+}
+
+impl CreateAlignmentCase {
+    pub fn new(alignment: PlanAlignmentCreate) -> Self {
+        Self { plan: PlanSpecifier::new(), alignment }
+    }
+
+    pub fn plan(mut self, plan: PlanSpecifier) -> Self {
+        self.plan = plan;
+        self
+    }
+}
+
+/// Which date recurring charges are billed on
+#[derive(Clone, Debug, PartialEq)]
+pub struct BillingAlignmentCase {
+    pub plan: PlanSpecifier,
+    pub phase_type: Option<PhaseType>,
+    pub alignment: BillingAlignment,
+}
+
+impl BillingAlignmentCase {
+    pub fn new(alignment: BillingAlignment) -> Self {
+        Self { plan: PlanSpecifier::new(), phase_type: None, alignment }
+    }
+
+    pub fn plan(mut self, plan: PlanSpecifier) -> Self {
+        self.plan = plan;
+        self
+    }
+
+    pub fn phase_type(mut self, phase_type: PhaseType) -> Self {
+        self.phase_type = Some(phase_type);
+        self
+    }
+}
+
+/// Price list a plan change moves to
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceListCase {
+    pub from: PlanSpecifier,
+    pub to_price_list: String,
+}
+
+impl PriceListCase {
+    pub fn new(to_price_list: impl Into<String>) -> Self {
+        Self { from: PlanSpecifier::new(), to_price_list: to_price_list.into() }
+    }
+
+    pub fn from(mut self, from: PlanSpecifier) -> Self {
+        self.from = from;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum BillingActionPolicy {
+    #[serde(rename = "START_OF_TERM")]
+    StartOfTerm,
+    #[default]
+    #[serde(rename = "END_OF_TERM")]
+    EndOfTerm,
+    #[serde(rename = "IMMEDIATE")]
+    Immediate,
+    #[serde(rename = "ILLEGAL")]
+    Illegal,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum PlanAlignmentChange {
+    #[default]
+    #[serde(rename = "START_OF_BUNDLE")]
+    StartOfBundle,
+    #[serde(rename = "START_OF_SUBSCRIPTION")]
+    StartOfSubscription,
+    #[serde(rename = "CHANGE_OF_PLAN")]
+    ChangeOfPlan,
+    #[serde(rename = "CHANGE_OF_PRICELIST")]
+    ChangeOfPricelist,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum PlanAlignmentCreate {
+    #[default]
+    #[serde(rename = "START_OF_BUNDLE")]
+    StartOfBundle,
+    #[serde(rename = "START_OF_SUBSCRIPTION")]
+    StartOfSubscription,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum BillingAlignment {
+    #[default]
+    #[serde(rename = "ACCOUNT")]
+    Account,
+    #[serde(rename = "BUNDLE")]
+    Bundle,
+    #[serde(rename = "SUBSCRIPTION")]
+    Subscription,
+}
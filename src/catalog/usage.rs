@@ -0,0 +1,199 @@
+use serde::{ Deserialize, Serialize };
+
+use crate::models;
+
+use super::{ BillingPeriod, Limit, Price, RecurringBillingMode, TierBlockPolicy, UsageType };
+
+/// Usage section of a phase, billing what a subscription consumed or reserved
+#[derive(Clone, Debug, PartialEq)]
+pub struct Usage {
+    pub name: String,
+    pub pretty_name: Option<String>,
+    pub billing_mode: RecurringBillingMode,
+    pub usage_type: UsageType,
+    pub tier_block_policy: Option<TierBlockPolicy>,
+    pub billing_period: BillingPeriod,
+    pub limits: Vec<Limit>,
+    pub blocks: Vec<Block>,
+    pub tiers: Vec<Tier>,
+    pub fixed_prices: Option<Vec<Price>>,
+    pub recurring_prices: Option<Vec<Price>>,
+}
+
+impl Usage {
+    pub fn new(name: impl Into<String>, usage_type: UsageType, billing_period: BillingPeriod) -> Self {
+        Self {
+            name: name.into(),
+            pretty_name: None,
+            billing_mode: RecurringBillingMode::Arrear,
+            usage_type,
+            tier_block_policy: None,
+            billing_period,
+            limits: Vec::new(),
+            blocks: Vec::new(),
+            tiers: Vec::new(),
+            fixed_prices: None,
+            recurring_prices: None,
+        }
+    }
+
+    /// Usage billed per unit consumed, in arrear
+    pub fn consumable(name: impl Into<String>, billing_period: BillingPeriod) -> Self {
+        Self::new(name, UsageType::Consumable, billing_period)
+    }
+
+    /// Usage billed per tier of units reserved, in arrear
+    pub fn capacity(name: impl Into<String>, billing_period: BillingPeriod) -> Self {
+        Self::new(name, UsageType::Capacity, billing_period)
+    }
+
+    pub fn pretty_name(mut self, pretty_name: impl Into<String>) -> Self {
+        self.pretty_name = Some(pretty_name.into());
+        self
+    }
+
+    pub fn billing_mode(mut self, billing_mode: RecurringBillingMode) -> Self {
+        self.billing_mode = billing_mode;
+        self
+    }
+
+    pub fn tier_block_policy(mut self, tier_block_policy: TierBlockPolicy) -> Self {
+        self.tier_block_policy = Some(tier_block_policy);
+        self
+    }
+
+    pub fn limit(mut self, limit: Limit) -> Self {
+        self.limits.push(limit);
+        self
+    }
+
+    pub fn block(mut self, block: Block) -> Self {
+        self.blocks.push(block);
+        self
+    }
+
+    pub fn tier(mut self, tier: Tier) -> Self {
+        self.tiers.push(tier);
+        self
+    }
+
+    pub fn fixed_price(mut self, prices: impl IntoIterator<Item = Price>) -> Self {
+        self.fixed_prices = Some(prices.into_iter().collect());
+        self
+    }
+
+    pub fn recurring_price(mut self, prices: impl IntoIterator<Item = Price>) -> Self {
+        self.recurring_prices = Some(prices.into_iter().collect());
+        self
+    }
+}
+
+/// Tier of a usage section
+///
+/// Consumable usages price their tiers with blocks, capacity usages with
+/// limits and fixed or recurring prices.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Tier {
+    #[serde(rename = "limits")]
+    pub limits: Vec<Limit>,
+    #[serde(rename = "blocks")]
+    pub blocks: Vec<TieredBlock>,
+    #[serde(rename = "fixedPrice", skip_serializing_if = "Option::is_none")]
+    pub fixed_prices: Option<Vec<Price>>,
+    #[serde(rename = "recurringPrice", skip_serializing_if = "Option::is_none")]
+    pub recurring_prices: Option<Vec<Price>>,
+}
+
+impl Tier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, limit: Limit) -> Self {
+        self.limits.push(limit);
+        self
+    }
+
+    pub fn block(mut self, block: TieredBlock) -> Self {
+        self.blocks.push(block);
+        self
+    }
+
+    pub fn fixed_price(mut self, prices: impl IntoIterator<Item = Price>) -> Self {
+        self.fixed_prices = Some(prices.into_iter().collect());
+        self
+    }
+
+    pub fn recurring_price(mut self, prices: impl IntoIterator<Item = Price>) -> Self {
+        self.recurring_prices = Some(prices.into_iter().collect());
+        self
+    }
+}
+
+/// Block of `size` units of a tier, priced up to `max` units
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TieredBlock {
+    #[serde(rename = "unit")]
+    pub unit: String,
+    #[serde(rename = "size")]
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub size: models::Decimal,
+    #[serde(rename = "max")]
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub max: models::Decimal,
+    #[serde(rename = "prices")]
+    pub prices: Vec<Price>,
+}
+
+impl TieredBlock {
+    pub fn new(
+        unit: impl Into<String>,
+        size: models::Decimal,
+        max: models::Decimal,
+        prices: impl IntoIterator<Item = Price>
+    ) -> Self {
+        Self { unit: unit.into(), size, max, prices: prices.into_iter().collect() }
+    }
+}
+
+/// Block of `size` units of a usage section without tiers
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block {
+    pub block_type: BlockType,
+    pub unit: String,
+    pub size: models::Decimal,
+    pub prices: Vec<Price>,
+    pub min_top_up_credit: Option<models::Decimal>,
+}
+
+impl Block {
+    pub fn new(
+        unit: impl Into<String>,
+        size: models::Decimal,
+        prices: impl IntoIterator<Item = Price>
+    ) -> Self {
+        Self {
+            block_type: BlockType::Vanilla,
+            unit: unit.into(),
+            size,
+            prices: prices.into_iter().collect(),
+            min_top_up_credit: None,
+        }
+    }
+
+    /// Block bought in advance, topped up once the credit falls below `min_top_up_credit`
+    pub fn top_up(mut self, min_top_up_credit: models::Decimal) -> Self {
+        self.block_type = BlockType::TopUp;
+        self.min_top_up_credit = Some(min_top_up_credit);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum BlockType {
+    #[default]
+    #[serde(rename = "VANILLA")]
+    Vanilla,
+    #[serde(rename = "TOP_UP")]
+    TopUp,
+}
@@ -0,0 +1,379 @@
+use thiserror::Error;
+
+use crate::models::{ self, Currency };
+
+/// Check a JSON catalog for the mistakes Kill Bill rejects on upload, without a
+/// round trip to [`CatalogApi::validate_catalog_xml`](crate::apis::catalog_api::CatalogApi::validate_catalog_xml)
+///
+/// Every error found is returned, in catalog order.
+pub fn validate(catalog: &models::Catalog) -> Result<(), Vec<ValidationError>> {
+    let currencies = catalog.currencies.as_deref().unwrap_or_default();
+    let products = catalog.products.as_deref().unwrap_or_default();
+    let price_lists = catalog.price_lists.as_deref().unwrap_or_default();
+
+    let plans: Vec<&str> = products
+        .iter()
+        .flat_map(|product| product.plans.as_deref().unwrap_or_default())
+        .filter_map(|plan| plan.name.as_deref())
+        .collect();
+    let listed_plans: Vec<&str> = price_lists
+        .iter()
+        .flat_map(|price_list| price_list.plans.as_deref().unwrap_or_default())
+        .map(String::as_str)
+        .collect();
+
+    let mut errors = Vec::new();
+    let mut seen_plans = Vec::new();
+
+    for (index, product) in products.iter().enumerate() {
+        let product_names = products.iter().map(|product| product.name.as_deref());
+        let path = element("products", product_names, index);
+
+        let add_ons = product.included
+            .iter()
+            .flatten()
+            .map(|add_on| ("included", add_on))
+            .chain(product.available.iter().flatten().map(|add_on| ("available", add_on)));
+        for (tag, add_on) in add_ons {
+            let target = products.iter().find(|product| product.name.as_ref() == Some(add_on));
+            let kind = match target {
+                None => ValidationErrorKind::UnknownAddOn { product: add_on.clone() },
+                Some(target) if target.r#type.as_deref() != Some("ADD_ON") => {
+                    ValidationErrorKind::NotAnAddOn { product: add_on.clone() }
+                }
+                Some(_) => {
+                    continue;
+                }
+            };
+            errors.push(ValidationError::new(format!("{path}/{tag}"), kind));
+        }
+
+        for (index, plan) in product.plans.iter().flatten().enumerate() {
+            let plan_names = product.plans.iter().flatten().map(|plan| plan.name.as_deref());
+            let path = format!("{path}/{}", element("plans", plan_names, index));
+
+            if let Some(name) = plan.name.as_deref() {
+                if seen_plans.contains(&name) {
+                    errors.push(ValidationError::new(&path, ValidationErrorKind::DuplicatePlan));
+                } else {
+                    seen_plans.push(name);
+                }
+                if !listed_plans.contains(&name) {
+                    errors.push(ValidationError::new(&path, ValidationErrorKind::UnreferencedPlan));
+                }
+            }
+
+            for (index, phase) in plan.phases.iter().flatten().enumerate() {
+                let phase_types = plan.phases.iter().flatten().map(|phase| phase.r#type.as_deref());
+                let path = format!("{path}/{}", element("phases", phase_types, index));
+                validate_phase(&mut errors, &path, phase, currencies);
+            }
+        }
+    }
+
+    for (index, price_list) in price_lists.iter().enumerate() {
+        let price_list_names = price_lists.iter().map(|price_list| price_list.name.as_deref());
+        let path = element("priceLists", price_list_names, index);
+        for plan in price_list.plans.iter().flatten() {
+            if !plans.contains(&plan.as_str()) {
+                errors.push(
+                    ValidationError::new(
+                        format!("{path}/plans"),
+                        ValidationErrorKind::UnknownPlan { plan: plan.clone() }
+                    )
+                );
+            }
+        }
+    }
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Error found by [`validate`]
+#[derive(Clone, Debug, PartialEq, Error)]
+#[error("{path}: {kind}")]
+pub struct ValidationError {
+    /// Element the error is about, e.g. `products[Standard]/plans[standard-monthly]/phases[TRIAL]`
+    pub path: String,
+    pub kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    fn new(path: impl Into<String>, kind: ValidationErrorKind) -> Self {
+        Self { path: path.into(), kind }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum ValidationErrorKind {
+    #[error("no price in {currency}")] MissingCurrency {
+        currency: Currency,
+    },
+
+    #[error("price in {currency}, which is not a currency of the catalog")] UnknownCurrency {
+        currency: Currency,
+    },
+
+    #[error("phase has no length")]
+    ZeroLengthPhase,
+
+    #[error("only the evergreen phase may last forever")]
+    UnlimitedPhase,
+
+    #[error("plan name is already used")]
+    DuplicatePlan,
+
+    #[error("plan is not in any price list")]
+    UnreferencedPlan,
+
+    #[error("unknown plan {plan}")] UnknownPlan {
+        plan: String,
+    },
+
+    #[error("unknown add-on product {product}")] UnknownAddOn {
+        product: String,
+    },
+
+    #[error("{product} is not an add-on product")] NotAnAddOn {
+        product: String,
+    },
+}
+
+fn validate_phase(
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+    phase: &models::Phase,
+    currencies: &[Currency]
+) {
+    // Only the final, evergreen phase may last forever; the others must end
+    let evergreen = phase.r#type.as_deref() == Some("EVERGREEN");
+    let unlimited = phase.duration
+        .as_ref()
+        .is_some_and(|duration| duration.unit == Some(models::duration::Unit::Unlimited));
+    let length = phase.duration.as_ref().and_then(|duration| duration.number).unwrap_or(0);
+    if !evergreen && unlimited {
+        errors.push(ValidationError::new(path, ValidationErrorKind::UnlimitedPhase));
+    } else if !evergreen && length <= 0 {
+        errors.push(ValidationError::new(path, ValidationErrorKind::ZeroLengthPhase));
+    }
+
+    validate_prices(errors, &format!("{path}/fixedPrices"), phase.fixed_prices.as_deref(), currencies);
+    validate_prices(errors, &format!("{path}/prices"), phase.prices.as_deref(), currencies);
+
+    for (index, usage) in phase.usages.iter().flatten().enumerate() {
+        for (tier_index, tier) in usage.tiers.iter().flatten().enumerate() {
+            let path = format!("{path}/usages[{index}]/tiers[{tier_index}]");
+            validate_prices(errors, &format!("{path}/fixedPrice"), tier.fixed_price.as_deref(), currencies);
+            validate_prices(
+                errors,
+                &format!("{path}/recurringPrice"),
+                tier.recurring_price.as_deref(),
+                currencies
+            );
+            for (block_index, block) in tier.blocks.iter().flatten().enumerate() {
+                validate_prices(
+                    errors,
+                    &format!("{path}/blocks[{block_index}]/prices"),
+                    block.prices.as_deref(),
+                    currencies
+                );
+            }
+        }
+    }
+}
+
+/// Check that a price list has one price per catalog currency; an empty list is free
+fn validate_prices(
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+    prices: Option<&[models::Price]>,
+    currencies: &[Currency]
+) {
+    let prices = prices.unwrap_or_default();
+    if prices.is_empty() {
+        return;
+    }
+
+    let priced: Vec<Currency> = prices.iter().filter_map(|price| price.currency).collect();
+    for currency in currencies {
+        if !priced.contains(currency) {
+            errors.push(
+                ValidationError::new(path, ValidationErrorKind::MissingCurrency { currency: *currency })
+            );
+        }
+    }
+    for currency in priced {
+        if !currencies.contains(&currency) {
+            errors.push(ValidationError::new(path, ValidationErrorKind::UnknownCurrency { currency }));
+        }
+    }
+}
+
+/// Path element naming the item at `index` of a list, by name or else by position
+///
+/// An item named like an earlier one gets both, e.g. `plans[1:support-monthly]`,
+/// so that its errors are told apart from those of the first one.
+fn element<'a>(tag: &str, names: impl Iterator<Item = Option<&'a str>>, index: usize) -> String {
+    let names: Vec<_> = names.take(index + 1).collect();
+    match names[index] {
+        Some(name) if names[..index].contains(&Some(name)) => format!("{tag}[{index}:{name}]"),
+        Some(name) => format!("{tag}[{name}]"),
+        None => format!("{tag}[{index}]"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::duration::Unit;
+
+    fn price(currency: Currency, value: &str) -> models::Price {
+        models::Price { currency: Some(currency), value: Some(value.parse().unwrap()) }
+    }
+
+    fn phase(phase_type: &str, unit: Unit, number: i32, prices: Vec<models::Price>) -> models::Phase {
+        models::Phase {
+            r#type: Some(phase_type.to_owned()),
+            duration: Some(Box::new(models::Duration { unit: Some(unit), number: Some(number) })),
+            prices: Some(prices),
+            fixed_prices: Some(Vec::new()),
+            ..models::Phase::default()
+        }
+    }
+
+    fn plan(name: &str, phases: Vec<models::Phase>) -> models::Plan {
+        models::Plan {
+            name: Some(name.to_owned()),
+            phases: Some(phases),
+            ..models::Plan::default()
+        }
+    }
+
+    fn product(name: &str, product_type: &str, plans: Vec<models::Plan>) -> models::Product {
+        models::Product {
+            r#type: Some(product_type.to_owned()),
+            name: Some(name.to_owned()),
+            plans: Some(plans),
+            ..models::Product::default()
+        }
+    }
+
+    fn catalog() -> models::Catalog {
+        let standard = plan(
+            "standard-monthly",
+            vec![
+                phase("TRIAL", Unit::Days, 30, Vec::new()),
+                phase(
+                    "EVERGREEN",
+                    Unit::Unlimited,
+                    -1,
+                    vec![price(Currency::Usd, "29.95"), price(Currency::Eur, "25")]
+                )
+            ]
+        );
+        let support = plan(
+            "support-monthly",
+            vec![
+                phase(
+                    "EVERGREEN",
+                    Unit::Unlimited,
+                    -1,
+                    vec![price(Currency::Usd, "10"), price(Currency::Eur, "9")]
+                )
+            ]
+        );
+
+        models::Catalog {
+            name: Some("SpyCarBasic".to_owned()),
+            currencies: Some(vec![Currency::Usd, Currency::Eur]),
+            products: Some(
+                vec![
+                    models::Product {
+                        available: Some(vec!["Support".to_owned()]),
+                        ..product("Standard", "BASE", vec![standard])
+                    },
+                    product("Support", "ADD_ON", vec![support])
+                ]
+            ),
+            price_lists: Some(
+                vec![models::PriceList {
+                    name: Some("DEFAULT".to_owned()),
+                    plans: Some(vec!["standard-monthly".to_owned(), "support-monthly".to_owned()]),
+                }]
+            ),
+            ..models::Catalog::default()
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(&catalog()), Ok(()));
+
+        let mut catalog = catalog();
+        let products = catalog.products.as_mut().unwrap();
+        products[0].available = Some(vec!["Support".to_owned(), "Insurance".to_owned()]);
+        products[0].included = Some(vec!["Standard".to_owned()]);
+
+        let standard = &mut products[0].plans.as_mut().unwrap()[0];
+        let phases = standard.phases.as_mut().unwrap();
+        phases[0].duration.as_mut().unwrap().number = Some(0);
+        phases[1].prices = Some(vec![price(Currency::Usd, "29.95"), price(Currency::Gbp, "22")]);
+
+        let mut duplicate = products[1].plans.as_ref().unwrap()[0].clone();
+        duplicate.phases.as_mut().unwrap()[0].prices = Some(Vec::new());
+        let mut unlisted = duplicate.clone();
+        unlisted.name = Some("support-annual".to_owned());
+        unlisted.phases.as_mut().unwrap().insert(0, phase("TRIAL", Unit::Unlimited, -1, Vec::new()));
+        products[1].plans.as_mut().unwrap().extend([duplicate, unlisted]);
+
+        let price_list = &mut catalog.price_lists.as_mut().unwrap()[0];
+        price_list.plans.as_mut().unwrap().push("premium-monthly".to_owned());
+
+        let errors = validate(&catalog).unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                ValidationError::new(
+                    "products[Standard]/included",
+                    ValidationErrorKind::NotAnAddOn { product: "Standard".to_owned() }
+                ),
+                ValidationError::new(
+                    "products[Standard]/available",
+                    ValidationErrorKind::UnknownAddOn { product: "Insurance".to_owned() }
+                ),
+                ValidationError::new(
+                    "products[Standard]/plans[standard-monthly]/phases[TRIAL]",
+                    ValidationErrorKind::ZeroLengthPhase
+                ),
+                ValidationError::new(
+                    "products[Standard]/plans[standard-monthly]/phases[EVERGREEN]/prices",
+                    ValidationErrorKind::MissingCurrency { currency: Currency::Eur }
+                ),
+                ValidationError::new(
+                    "products[Standard]/plans[standard-monthly]/phases[EVERGREEN]/prices",
+                    ValidationErrorKind::UnknownCurrency { currency: Currency::Gbp }
+                ),
+                ValidationError::new(
+                    "products[Support]/plans[1:support-monthly]",
+                    ValidationErrorKind::DuplicatePlan
+                ),
+                ValidationError::new(
+                    "products[Support]/plans[support-annual]",
+                    ValidationErrorKind::UnreferencedPlan
+                ),
+                ValidationError::new(
+                    "products[Support]/plans[support-annual]/phases[TRIAL]",
+                    ValidationErrorKind::UnlimitedPhase
+                ),
+                ValidationError::new(
+                    "priceLists[DEFAULT]/plans",
+                    ValidationErrorKind::UnknownPlan { plan: "premium-monthly".to_owned() }
+                ),
+            ]
+        );
+        assert_eq!(
+            errors[3].to_string(),
+            "products[Standard]/plans[standard-monthly]/phases[EVERGREEN]/prices: no price in EUR"
+        );
+    }
+}
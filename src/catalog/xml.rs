@@ -0,0 +1,1139 @@
+//! Kill Bill catalog XML
+//!
+//! [`to_string`] writes a [`Catalog`] with the elements in the order of Kill
+//! Bill's `CatalogSchema.xsd`. [`from_str`] and [`versions_from_str`] read
+//! catalogs back, including those written by Kill Bill or by hand, so that
+//! `from_str(&to_string(&catalog))` gives back `catalog`.
+
+use std::fmt::{ Display, Write as _ };
+use std::str::FromStr;
+
+use roxmltree::Node;
+use serde::{ de::DeserializeOwned, Serialize };
+use thiserror::Error;
+
+use super::{
+    BillingAlignmentCase,
+    Block,
+    CancelPolicyCase,
+    Catalog,
+    ChangeAlignmentCase,
+    ChangePolicyCase,
+    CreateAlignmentCase,
+    Duration,
+    Limit,
+    Phase,
+    Plan,
+    PlanSpecifier,
+    Price,
+    PriceList,
+    PriceListCase,
+    Product,
+    Recurring,
+    RecurringBillingMode,
+    Rules,
+    Tier,
+    TieredBlock,
+    Unit,
+    Usage,
+};
+
+const XSI: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Write a catalog as Kill Bill catalog XML
+pub fn to_string(catalog: &Catalog) -> String {
+    let mut xml = Writer::default();
+    xml.out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n");
+    xml.start(
+        "catalog",
+        &[
+            ("xmlns:xsi", Some(XSI.to_owned())),
+            ("xsi:noNamespaceSchemaLocation", Some("CatalogSchema.xsd".to_owned())),
+        ]
+    );
+    xml.text("effectiveDate", date_time(&catalog.effective_date));
+    xml.text("catalogName", &catalog.name);
+    xml.list("currencies", &catalog.currencies, |xml, currency| xml.text("currency", currency));
+    xml.list("units", &catalog.units, write_unit);
+    xml.list("products", &catalog.products, write_product);
+    write_rules(&mut xml, &catalog.rules);
+    xml.list("plans", &catalog.plans, write_plan);
+    xml.start("priceLists", &[]);
+    write_price_list(&mut xml, "defaultPriceList", &catalog.default_price_list);
+    for price_list in &catalog.child_price_lists {
+        write_price_list(&mut xml, "childPriceList", price_list);
+    }
+    xml.end("priceLists");
+    xml.end("catalog");
+    xml.out
+}
+
+fn write_unit(xml: &mut Writer, unit: &Unit) {
+    xml.empty("unit", &[("name", Some(unit.name.clone())), ("prettyName", unit.pretty_name.clone())]);
+}
+
+fn write_product(xml: &mut Writer, product: &Product) {
+    xml.start(
+        "product",
+        &[("name", Some(product.name.clone())), ("prettyName", product.pretty_name.clone())]
+    );
+    xml.text("category", name(&product.category));
+    xml.list("included", &product.included, |xml, add_on| xml.text("addonProduct", add_on));
+    xml.list("available", &product.available, |xml, add_on| xml.text("addonProduct", add_on));
+    xml.list("limits", &product.limits, write_limit);
+    xml.end("product");
+}
+
+fn write_limit(xml: &mut Writer, limit: &Limit) {
+    xml.start("limit", &[]);
+    xml.text("unit", &limit.unit);
+    xml.optional("max", limit.max.as_ref());
+    xml.optional("min", limit.min.as_ref());
+    xml.end("limit");
+}
+
+fn write_rules(xml: &mut Writer, rules: &Rules) {
+    if rules.is_empty() {
+        return;
+    }
+
+    xml.start("rules", &[]);
+    xml.list("changePolicy", &rules.change_policy, |xml, case| {
+        xml.start("changePolicyCase", &[]);
+        xml.optional("phaseType", case.phase_type.as_ref().map(name));
+        write_plan_specifier(xml, "from", &case.from);
+        write_plan_specifier(xml, "to", &case.to);
+        xml.text("policy", name(&case.policy));
+        xml.end("changePolicyCase");
+    });
+    xml.list("changeAlignment", &rules.change_alignment, |xml, case| {
+        xml.start("changeAlignmentCase", &[]);
+        xml.optional("phaseType", case.phase_type.as_ref().map(name));
+        write_plan_specifier(xml, "from", &case.from);
+        write_plan_specifier(xml, "to", &case.to);
+        xml.text("alignment", name(&case.alignment));
+        xml.end("changeAlignmentCase");
+    });
+    xml.list("cancelPolicy", &rules.cancel_policy, |xml, case| {
+        xml.start("cancelPolicyCase", &[]);
+        write_plan_specifier(xml, "", &case.plan);
+        xml.optional("phaseType", case.phase_type.as_ref().map(name));
+        xml.text("policy", name(&case.policy));
+        xml.end("cancelPolicyCase");
+    });
+    xml.list("createAlignment", &rules.create_alignment, |xml, case| {
+        xml.start("createAlignmentCase", &[]);
+        write_plan_specifier(xml, "", &case.plan);
+        xml.text("alignment", name(&case.alignment));
+        xml.end("createAlignmentCase");
+    });
+    xml.list("billingAlignment", &rules.billing_alignment, |xml, case| {
+        xml.start("billingAlignmentCase", &[]);
+        write_plan_specifier(xml, "", &case.plan);
+        xml.optional("phaseType", case.phase_type.as_ref().map(name));
+        xml.text("alignment", name(&case.alignment));
+        xml.end("billingAlignmentCase");
+    });
+    xml.list("priceList", &rules.price_list, |xml, case| {
+        xml.start("priceListCase", &[]);
+        write_plan_specifier(xml, "from", &case.from);
+        xml.text("toPriceList", &case.to_price_list);
+        xml.end("priceListCase");
+    });
+    xml.end("rules");
+}
+
+/// Write the criteria of a case, e.g. `fromProduct` for the `from` prefix
+fn write_plan_specifier(xml: &mut Writer, prefix: &str, plan: &PlanSpecifier) {
+    xml.optional(&prefixed(prefix, "product"), plan.product.as_ref());
+    xml.optional(&prefixed(prefix, "productCategory"), plan.product_category.as_ref().map(name));
+    xml.optional(&prefixed(prefix, "billingPeriod"), plan.billing_period.as_ref().map(name));
+    xml.optional(&prefixed(prefix, "priceList"), plan.price_list.as_ref());
+}
+
+/// Tag of a case criterion, e.g. `fromProduct` for `product` and the `from` prefix
+fn prefixed(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() {
+        field.to_owned()
+    } else {
+        format!("{prefix}{}{}", field[..1].to_uppercase(), &field[1..])
+    }
+}
+
+fn write_plan(xml: &mut Writer, plan: &Plan) {
+    xml.start(
+        "plan",
+        &[("name", Some(plan.name.clone())), ("prettyName", plan.pretty_name.clone())]
+    );
+    xml.optional(
+        "effectiveDateForExistingSubscriptions",
+        plan.effective_date_for_existing_subscriptions.as_deref().map(date_time)
+    );
+    xml.text("product", &plan.product);
+    xml.text("recurringBillingMode", name(&plan.recurring_billing_mode));
+    xml.list("initialPhases", &plan.initial_phases, |xml, phase| write_phase(xml, "phase", phase));
+    write_phase(xml, "finalPhase", &plan.final_phase);
+    xml.optional("plansAllowedInBundle", plan.plans_allowed_in_bundle);
+    xml.end("plan");
+}
+
+/// Catalog dates are `xs:dateTime`, so plain dates are written as midnight UTC
+fn date_time(date: &str) -> String {
+    if date.contains('T') { date.to_owned() } else { format!("{date}T00:00:00Z") }
+}
+
+fn write_phase(xml: &mut Writer, tag: &str, phase: &Phase) {
+    xml.start(tag, &[("type", Some(name(&phase.phase_type)))]);
+    write_duration(xml, &phase.duration);
+    if let Some(prices) = &phase.fixed_prices {
+        xml.start("fixed", &[("type", Some("ONE_TIME".to_owned()))]);
+        write_prices(xml, "fixedPrice", prices);
+        xml.end("fixed");
+    }
+    if let Some(recurring) = &phase.recurring {
+        xml.start("recurring", &[]);
+        xml.text("billingPeriod", name(&recurring.billing_period));
+        write_prices(xml, "recurringPrice", &recurring.prices);
+        xml.end("recurring");
+    }
+    xml.list("usages", &phase.usages, write_usage);
+    xml.end(tag);
+}
+
+fn write_duration(xml: &mut Writer, duration: &Duration) {
+    xml.start("duration", &[]);
+    xml.text("unit", name(&duration.unit));
+    xml.optional("number", duration.number);
+    xml.end("duration");
+}
+
+fn write_usage(xml: &mut Writer, usage: &Usage) {
+    xml.start(
+        "usage",
+        &[
+            ("name", Some(usage.name.clone())),
+            ("prettyName", usage.pretty_name.clone()),
+            ("billingMode", Some(name(&usage.billing_mode))),
+            ("usageType", Some(name(&usage.usage_type))),
+            ("tierBlockPolicy", usage.tier_block_policy.as_ref().map(name)),
+        ]
+    );
+    xml.text("billingPeriod", name(&usage.billing_period));
+    xml.list("limits", &usage.limits, write_limit);
+    xml.list("blocks", &usage.blocks, write_block);
+    xml.list("tiers", &usage.tiers, write_tier);
+    if let Some(prices) = &usage.fixed_prices {
+        write_prices(xml, "fixedPrice", prices);
+    }
+    if let Some(prices) = &usage.recurring_prices {
+        write_prices(xml, "recurringPrice", prices);
+    }
+    xml.end("usage");
+}
+
+fn write_block(xml: &mut Writer, block: &Block) {
+    xml.start("block", &[("type", Some(name(&block.block_type)))]);
+    xml.text("unit", &block.unit);
+    xml.text("size", block.size);
+    write_prices(xml, "prices", &block.prices);
+    xml.optional("minTopUpCredit", block.min_top_up_credit);
+    xml.end("block");
+}
+
+fn write_tier(xml: &mut Writer, tier: &Tier) {
+    xml.start("tier", &[]);
+    xml.list("limits", &tier.limits, write_limit);
+    xml.list("blocks", &tier.blocks, write_tiered_block);
+    if let Some(prices) = &tier.fixed_prices {
+        write_prices(xml, "fixedPrice", prices);
+    }
+    if let Some(prices) = &tier.recurring_prices {
+        write_prices(xml, "recurringPrice", prices);
+    }
+    xml.end("tier");
+}
+
+fn write_tiered_block(xml: &mut Writer, block: &TieredBlock) {
+    xml.start("tieredBlock", &[]);
+    xml.text("unit", &block.unit);
+    xml.text("size", block.size);
+    write_prices(xml, "prices", &block.prices);
+    xml.text("max", block.max);
+    xml.end("tieredBlock");
+}
+
+/// Write prices, as an empty element when there are none
+fn write_prices(xml: &mut Writer, tag: &str, prices: &[Price]) {
+    if prices.is_empty() {
+        xml.empty(tag, &[]);
+        return;
+    }
+    xml.list(tag, prices, |xml, price| {
+        xml.start("price", &[]);
+        xml.text("currency", price.currency);
+        xml.text("value", price.value);
+        xml.end("price");
+    });
+}
+
+fn write_price_list(xml: &mut Writer, tag: &str, price_list: &PriceList) {
+    xml.start(
+        tag,
+        &[("name", Some(price_list.name.clone())), ("prettyName", price_list.pretty_name.clone())]
+    );
+    xml.start("plans", &[]);
+    for plan in &price_list.plans {
+        xml.text("plan", plan);
+    }
+    xml.end("plans");
+    xml.end(tag);
+}
+
+/// Error returned when reading catalog XML
+#[derive(Debug, Error)]
+pub enum XmlError {
+    #[error("Invalid XML: {0}")] Syntax(#[from] roxmltree::Error),
+
+    #[error("Invalid catalog at {path}: {message}")] Invalid {
+        /// Element the error is about, e.g. `catalog/plans/plan[standard-monthly]/product`
+        path: String,
+        message: String,
+    },
+}
+
+/// Read a `<catalog>` document, as uploaded with
+/// [`CatalogApi::upload_catalog_xml`](crate::apis::catalog_api::CatalogApi::upload_catalog_xml)
+pub fn from_str(xml: &str) -> Result<Catalog, XmlError> {
+    let document = roxmltree::Document::parse(xml)?;
+    let root = document.root_element();
+    if root.tag_name().name() != "catalog" {
+        return Err(invalid(root, "expected <catalog>, read <catalogs> with versions_from_str"));
+    }
+    read_catalog(root, None)
+}
+
+/// Read every version of a `<catalogs>` document, as returned by
+/// [`CatalogApi::get_catalog_xml`](crate::apis::catalog_api::CatalogApi::get_catalog_xml)
+///
+/// A `<catalog>` document is read as a single version.
+pub fn versions_from_str(xml: &str) -> Result<Vec<Catalog>, XmlError> {
+    let document = roxmltree::Document::parse(xml)?;
+    let root = document.root_element();
+    match root.tag_name().name() {
+        "catalog" => Ok(vec![read_catalog(root, None)?]),
+        "catalogs" => {
+            let name = optional_text(root, "catalogName");
+            read_list(root, "versions", "version", |version| read_catalog(version, name.as_deref()))
+        }
+        _ => Err(invalid(root, "expected <catalog> or <catalogs>")),
+    }
+}
+
+fn read_catalog(node: Node<'_, '_>, versioned_name: Option<&str>) -> Result<Catalog, XmlError> {
+    // Catalogs written before Kill Bill 0.20 set the billing mode of every plan here
+    let recurring_billing_mode = optional_enum(node, "recurringBillingMode")?.unwrap_or_default();
+    let price_lists = required_child(node, "priceLists")?;
+
+    Ok(Catalog {
+        name: match (optional_text(node, "catalogName"), versioned_name) {
+            (Some(name), _) => name,
+            (None, Some(name)) => name.to_owned(),
+            (None, None) => {
+                return Err(invalid(node, "missing <catalogName>"));
+            }
+        },
+        effective_date: required_text(node, "effectiveDate")?,
+        currencies: read_list(node, "currencies", "currency", read_enum)?,
+        units: read_list(node, "units", "unit", read_unit)?,
+        products: read_list(node, "products", "product", read_product)?,
+        rules: child(node, "rules").map(read_rules).transpose()?.unwrap_or_default(),
+        plans: read_list(node, "plans", "plan", |plan| read_plan(plan, recurring_billing_mode))?,
+        default_price_list: read_price_list(required_child(price_lists, "defaultPriceList")?)?,
+        child_price_lists: children(price_lists, "childPriceList")
+            .map(read_price_list)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn read_unit(node: Node<'_, '_>) -> Result<Unit, XmlError> {
+    Ok(Unit {
+        name: required_attribute(node, "name")?,
+        pretty_name: attribute(node, "prettyName"),
+    })
+}
+
+fn read_product(node: Node<'_, '_>) -> Result<Product, XmlError> {
+    Ok(Product {
+        name: required_attribute(node, "name")?,
+        pretty_name: attribute(node, "prettyName"),
+        category: required_enum(node, "category")?,
+        included: elements(node, "included", "addonProduct").map(text).collect(),
+        available: elements(node, "available", "addonProduct").map(text).collect(),
+        limits: read_list(node, "limits", "limit", read_limit)?,
+    })
+}
+
+fn read_limit(node: Node<'_, '_>) -> Result<Limit, XmlError> {
+    Ok(Limit {
+        unit: required_text(node, "unit")?,
+        max: optional_number(node, "max")?,
+        min: optional_number(node, "min")?,
+    })
+}
+
+fn read_rules(node: Node<'_, '_>) -> Result<Rules, XmlError> {
+    Ok(Rules {
+        change_policy: read_list(node, "changePolicy", "changePolicyCase", |case| {
+            Ok(ChangePolicyCase {
+                phase_type: optional_enum(case, "phaseType")?,
+                from: read_plan_specifier(case, "from")?,
+                to: read_plan_specifier(case, "to")?,
+                policy: required_enum(case, "policy")?,
+            })
+        })?,
+        change_alignment: read_list(node, "changeAlignment", "changeAlignmentCase", |case| {
+            Ok(ChangeAlignmentCase {
+                phase_type: optional_enum(case, "phaseType")?,
+                from: read_plan_specifier(case, "from")?,
+                to: read_plan_specifier(case, "to")?,
+                alignment: required_enum(case, "alignment")?,
+            })
+        })?,
+        cancel_policy: read_list(node, "cancelPolicy", "cancelPolicyCase", |case| {
+            Ok(CancelPolicyCase {
+                plan: read_plan_specifier(case, "")?,
+                phase_type: optional_enum(case, "phaseType")?,
+                policy: required_enum(case, "policy")?,
+            })
+        })?,
+        create_alignment: read_list(node, "createAlignment", "createAlignmentCase", |case| {
+            Ok(CreateAlignmentCase {
+                plan: read_plan_specifier(case, "")?,
+                alignment: required_enum(case, "alignment")?,
+            })
+        })?,
+        billing_alignment: read_list(node, "billingAlignment", "billingAlignmentCase", |case| {
+            Ok(BillingAlignmentCase {
+                plan: read_plan_specifier(case, "")?,
+                phase_type: optional_enum(case, "phaseType")?,
+                alignment: required_enum(case, "alignment")?,
+            })
+        })?,
+        price_list: read_list(node, "priceList", "priceListCase", |case| {
+            Ok(PriceListCase {
+                from: read_plan_specifier(case, "from")?,
+                to_price_list: required_text(case, "toPriceList")?,
+            })
+        })?,
+    })
+}
+
+fn read_plan_specifier(node: Node<'_, '_>, prefix: &str) -> Result<PlanSpecifier, XmlError> {
+    Ok(PlanSpecifier {
+        product: optional_text(node, &prefixed(prefix, "product")),
+        product_category: optional_enum(node, &prefixed(prefix, "productCategory"))?,
+        billing_period: optional_enum(node, &prefixed(prefix, "billingPeriod"))?,
+        price_list: optional_text(node, &prefixed(prefix, "priceList")),
+    })
+}
+
+fn read_plan(
+    node: Node<'_, '_>,
+    recurring_billing_mode: RecurringBillingMode
+) -> Result<Plan, XmlError> {
+    Ok(Plan {
+        name: required_attribute(node, "name")?,
+        pretty_name: attribute(node, "prettyName"),
+        product: required_text(node, "product")?,
+        recurring_billing_mode: optional_enum(node, "recurringBillingMode")?
+            .unwrap_or(recurring_billing_mode),
+        initial_phases: read_list(node, "initialPhases", "phase", read_phase)?,
+        final_phase: read_phase(required_child(node, "finalPhase")?)?,
+        plans_allowed_in_bundle: optional_number(node, "plansAllowedInBundle")?,
+        effective_date_for_existing_subscriptions: optional_text(
+            node,
+            "effectiveDateForExistingSubscriptions"
+        ),
+    })
+}
+
+fn read_phase(node: Node<'_, '_>) -> Result<Phase, XmlError> {
+    let fixed_prices = match child(node, "fixed") {
+        Some(fixed) => Some(read_prices(fixed, "fixedPrice")?.unwrap_or_default()),
+        None => None,
+    };
+    let recurring = match child(node, "recurring") {
+        Some(recurring) =>
+            Some(Recurring {
+                billing_period: required_enum(recurring, "billingPeriod")?,
+                prices: read_prices(recurring, "recurringPrice")?.unwrap_or_default(),
+            }),
+        None => None,
+    };
+    let duration = required_child(node, "duration")?;
+
+    Ok(Phase {
+        phase_type: required_enum_attribute(node, "type")?,
+        duration: Duration {
+            unit: required_enum(duration, "unit")?,
+            number: optional_number(duration, "number")?,
+        },
+        fixed_prices,
+        recurring,
+        usages: read_list(node, "usages", "usage", read_usage)?,
+    })
+}
+
+fn read_usage(node: Node<'_, '_>) -> Result<Usage, XmlError> {
+    Ok(Usage {
+        name: required_attribute(node, "name")?,
+        pretty_name: attribute(node, "prettyName"),
+        billing_mode: required_enum_attribute(node, "billingMode")?,
+        usage_type: required_enum_attribute(node, "usageType")?,
+        tier_block_policy: attribute(node, "tierBlockPolicy")
+            .map(|policy| parse_enum(node, &policy))
+            .transpose()?,
+        billing_period: required_enum(node, "billingPeriod")?,
+        limits: read_list(node, "limits", "limit", read_limit)?,
+        blocks: read_list(node, "blocks", "block", read_block)?,
+        tiers: read_list(node, "tiers", "tier", read_tier)?,
+        fixed_prices: read_prices(node, "fixedPrice")?,
+        recurring_prices: read_prices(node, "recurringPrice")?,
+    })
+}
+
+fn read_block(node: Node<'_, '_>) -> Result<Block, XmlError> {
+    Ok(Block {
+        block_type: attribute(node, "type")
+            .map(|block_type| parse_enum(node, &block_type))
+            .transpose()?
+            .unwrap_or_default(),
+        unit: required_text(node, "unit")?,
+        size: required_number(node, "size")?,
+        prices: read_prices(node, "prices")?.unwrap_or_default(),
+        min_top_up_credit: optional_number(node, "minTopUpCredit")?,
+    })
+}
+
+fn read_tier(node: Node<'_, '_>) -> Result<Tier, XmlError> {
+    Ok(Tier {
+        limits: read_list(node, "limits", "limit", read_limit)?,
+        blocks: read_list(node, "blocks", "tieredBlock", |block| {
+            Ok(TieredBlock {
+                unit: required_text(block, "unit")?,
+                size: required_number(block, "size")?,
+                max: required_number(block, "max")?,
+                prices: read_prices(block, "prices")?.unwrap_or_default(),
+            })
+        })?,
+        fixed_prices: read_prices(node, "fixedPrice")?,
+        recurring_prices: read_prices(node, "recurringPrice")?,
+    })
+}
+
+/// Read the prices inside `tag`, `None` when there is no such element
+fn read_prices(node: Node<'_, '_>, tag: &str) -> Result<Option<Vec<Price>>, XmlError> {
+    child(node, tag)
+        .map(|prices| {
+            children(prices, "price")
+                .map(|price| {
+                    Ok(Price {
+                        currency: required_enum(price, "currency")?,
+                        value: required_number(price, "value")?,
+                    })
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+fn read_price_list(node: Node<'_, '_>) -> Result<PriceList, XmlError> {
+    Ok(PriceList {
+        name: required_attribute(node, "name")?,
+        pretty_name: attribute(node, "prettyName"),
+        plans: elements(node, "plans", "plan").map(text).collect(),
+    })
+}
+
+fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    tag: &'a str
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children().filter(move |child| child.is_element() && child.tag_name().name() == tag)
+}
+
+fn child<'a, 'input: 'a>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.is_element() && child.tag_name().name() == tag)
+}
+
+/// The `tag` elements inside the `wrapper` element, e.g. the plans of `<plans>`
+fn elements<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    wrapper: &'a str,
+    tag: &'a str
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    child(node, wrapper)
+        .into_iter()
+        .flat_map(move |wrapper| children(wrapper, tag))
+}
+
+/// Read the `tag` elements inside the `wrapper` element
+fn read_list<'a, 'input: 'a, T>(
+    node: Node<'a, 'input>,
+    wrapper: &'a str,
+    tag: &'a str,
+    read: impl FnMut(Node<'a, 'input>) -> Result<T, XmlError>
+) -> Result<Vec<T>, XmlError> {
+    elements(node, wrapper, tag).map(read).collect()
+}
+
+fn required_child<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    tag: &str
+) -> Result<Node<'a, 'input>, XmlError> {
+    child(node, tag).ok_or_else(|| invalid(node, format!("missing <{tag}>")))
+}
+
+fn text(node: Node<'_, '_>) -> String {
+    node.text().unwrap_or_default().trim().to_owned()
+}
+
+fn optional_text(node: Node<'_, '_>, tag: &str) -> Option<String> {
+    child(node, tag).map(text)
+}
+
+fn required_text(node: Node<'_, '_>, tag: &str) -> Result<String, XmlError> {
+    required_child(node, tag).map(text)
+}
+
+fn attribute(node: Node<'_, '_>, name: &str) -> Option<String> {
+    node.attribute(name).map(str::to_owned)
+}
+
+fn required_attribute(node: Node<'_, '_>, name: &str) -> Result<String, XmlError> {
+    attribute(node, name).ok_or_else(|| invalid(node, format!("missing {name} attribute")))
+}
+
+/// Parse an enum value from its name in the catalog, see [`name`]
+fn parse_enum<T: DeserializeOwned>(node: Node<'_, '_>, value: &str) -> Result<T, XmlError> {
+    serde_json
+        ::from_value(serde_json::Value::String(value.to_owned()))
+        .map_err(|_| invalid(node, format!("unknown value {value}")))
+}
+
+fn read_enum<T: DeserializeOwned>(node: Node<'_, '_>) -> Result<T, XmlError> {
+    parse_enum(node, &text(node))
+}
+
+fn optional_enum<T: DeserializeOwned>(node: Node<'_, '_>, tag: &str) -> Result<Option<T>, XmlError> {
+    child(node, tag).map(read_enum).transpose()
+}
+
+fn required_enum<T: DeserializeOwned>(node: Node<'_, '_>, tag: &str) -> Result<T, XmlError> {
+    read_enum(required_child(node, tag)?)
+}
+
+fn required_enum_attribute<T: DeserializeOwned>(
+    node: Node<'_, '_>,
+    name: &str
+) -> Result<T, XmlError> {
+    parse_enum(node, &required_attribute(node, name)?)
+}
+
+fn read_number<T: FromStr>(node: Node<'_, '_>) -> Result<T, XmlError> {
+    let value = text(node);
+    value.parse().map_err(|_| invalid(node, format!("invalid number {value}")))
+}
+
+fn optional_number<T: FromStr>(node: Node<'_, '_>, tag: &str) -> Result<Option<T>, XmlError> {
+    child(node, tag).map(read_number).transpose()
+}
+
+fn required_number<T: FromStr>(node: Node<'_, '_>, tag: &str) -> Result<T, XmlError> {
+    read_number(required_child(node, tag)?)
+}
+
+fn invalid(node: Node<'_, '_>, message: impl Into<String>) -> XmlError {
+    XmlError::Invalid { path: path(node), message: message.into() }
+}
+
+/// Path of an element, naming the elements with a `name` attribute, e.g.
+/// `catalog/plans/plan[standard-monthly]/finalPhase`
+fn path(node: Node<'_, '_>) -> String {
+    let mut elements: Vec<_> = node
+        .ancestors()
+        .filter(Node::is_element)
+        .map(|element| match element.attribute("name") {
+            Some(name) => format!("{}[{name}]", element.tag_name().name()),
+            None => element.tag_name().name().to_owned(),
+        })
+        .collect();
+    elements.reverse();
+    elements.join("/")
+}
+
+/// Name of an enum value in the catalog, which is its JSON name
+pub(super) fn name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => unreachable!("catalog enums serialize to strings"),
+    }
+}
+
+/// Indented XML output
+#[derive(Default)]
+struct Writer {
+    out: String,
+    depth: usize,
+}
+
+impl Writer {
+    fn indent(&mut self) {
+        for _ in 0..self.depth {
+            self.out.push_str("    ");
+        }
+    }
+
+    fn open_tag(&mut self, tag: &str, attributes: &[(&str, Option<String>)]) {
+        self.indent();
+        self.out.push('<');
+        self.out.push_str(tag);
+        for (attribute, value) in attributes {
+            if let Some(value) = value {
+                let _ = write!(self.out, " {attribute}=\"{}\"", escape(value));
+            }
+        }
+    }
+
+    fn start(&mut self, tag: &str, attributes: &[(&str, Option<String>)]) {
+        self.open_tag(tag, attributes);
+        self.out.push_str(">\n");
+        self.depth += 1;
+    }
+
+    fn end(&mut self, tag: &str) {
+        self.depth -= 1;
+        self.indent();
+        let _ = writeln!(self.out, "</{tag}>");
+    }
+
+    fn empty(&mut self, tag: &str, attributes: &[(&str, Option<String>)]) {
+        self.open_tag(tag, attributes);
+        self.out.push_str("/>\n");
+    }
+
+    fn text(&mut self, tag: &str, value: impl Display) {
+        self.indent();
+        let _ = writeln!(self.out, "<{tag}>{}</{tag}>", escape(&value.to_string()));
+    }
+
+    fn optional(&mut self, tag: &str, value: Option<impl Display>) {
+        if let Some(value) = value {
+            self.text(tag, value);
+        }
+    }
+
+    /// Write the items inside `tag`, or nothing when there are none
+    fn list<T>(&mut self, tag: &str, items: &[T], mut write: impl FnMut(&mut Self, &T)) {
+        if items.is_empty() {
+            return;
+        }
+        self.start(tag, &[]);
+        for item in items {
+            write(self, item);
+        }
+        self.end(tag);
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::{
+        BillingActionPolicy,
+        BillingAlignment,
+        BillingAlignmentCase,
+        BillingPeriod,
+        CancelPolicyCase,
+        ChangePolicyCase,
+        CreateAlignmentCase,
+        PhaseType,
+        PlanAlignmentCreate,
+        PriceListCase,
+        ProductCategory,
+        TierBlockPolicy,
+        TimeUnit,
+    };
+    use crate::models::{ Currency, Decimal };
+
+    fn amount(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn usd(value: &str) -> Price {
+        Price::new(Currency::Usd, amount(value))
+    }
+
+    fn catalog() -> Catalog {
+        let api_calls = Usage::consumable("standard-api-calls", BillingPeriod::Monthly)
+            .tier_block_policy(TierBlockPolicy::AllTiers)
+            .tier(
+                Tier::new()
+                    .block(TieredBlock::new("api-calls", amount("100"), amount("10"), [usd("1.5")]))
+            )
+            .tier(
+                Tier::new()
+                    .block(TieredBlock::new("api-calls", amount("100"), amount("-1"), [usd("1")]))
+            );
+
+        let standard = Plan::builder()
+            .name("standard-monthly")
+            .pretty_name("Standard & API")
+            .product("Standard")
+            .initial_phase(Phase::trial(Duration::days(30)).fixed_price([]))
+            .final_phase(
+                Phase::evergreen()
+                    .recurring(
+                        BillingPeriod::Monthly,
+                        [usd("29.95"), Price::new(Currency::Eur, amount("25"))]
+                    )
+                    .usage(api_calls)
+            )
+            .build()
+            .unwrap();
+
+        let support = Plan::builder()
+            .name("support-annual")
+            .product("Support")
+            .final_phase(
+                Phase::evergreen()
+                    .usage(
+                        Usage::capacity("seats", BillingPeriod::Annual)
+                            .tier(
+                                Tier::new()
+                                    .limit(Limit::new("seats").max(amount("5")))
+                                    .recurring_price([usd("100")])
+                            )
+                    )
+                    .usage(
+                        Usage::consumable("credits", BillingPeriod::Monthly).block(
+                            Block::new("credits", amount("1000"), [usd("10")]).top_up(amount("100"))
+                        )
+                    )
+            )
+            .plans_allowed_in_bundle(1)
+            .build()
+            .unwrap();
+
+        let rules = Rules::new()
+            .change_policy(
+                ChangePolicyCase::new(BillingActionPolicy::Immediate)
+                    .phase_type(PhaseType::Trial)
+            )
+            .change_policy(
+                ChangePolicyCase::new(BillingActionPolicy::EndOfTerm)
+                    .from(PlanSpecifier::new().billing_period(BillingPeriod::Annual))
+                    .to(PlanSpecifier::new().billing_period(BillingPeriod::Monthly))
+            )
+            .cancel_policy(CancelPolicyCase::new(BillingActionPolicy::EndOfTerm))
+            .create_alignment(CreateAlignmentCase::new(PlanAlignmentCreate::StartOfBundle))
+            .billing_alignment(
+                BillingAlignmentCase::new(BillingAlignment::Bundle).plan(
+                    PlanSpecifier::new().product_category(ProductCategory::AddOn)
+                )
+            )
+            .billing_alignment(BillingAlignmentCase::new(BillingAlignment::Account))
+            .price_list(
+                PriceListCase::new("DEFAULT").from(PlanSpecifier::new().price_list("PROMO"))
+            );
+
+        Catalog::builder()
+            .name("SpyCarAdvanced")
+            .effective_date("2024-01-01T00:00:00+00:00")
+            .currency(Currency::Usd)
+            .currency(Currency::Eur)
+            .unit(Unit::new("api-calls").pretty_name("API calls"))
+            .unit(Unit::new("seats"))
+            .unit(Unit::new("credits"))
+            .product(Product::base("Standard").available("Support"))
+            .product(Product::add_on("Support").limit(Limit::new("seats").max(amount("5")).min(amount("1"))))
+            .rules(rules)
+            .plan(standard)
+            .plan(support)
+            .default_price_list(["standard-monthly", "support-annual"])
+            .price_list(PriceList::new("PROMO", ["standard-monthly"]).pretty_name("Promotion"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_catalog_to_xml() {
+        let xml = to_string(&catalog());
+
+        assert!(
+            xml.starts_with(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<catalog xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:noNamespaceSchemaLocation=\"CatalogSchema.xsd\">\n    <effectiveDate>2024-01-01T00:00:00+00:00</effectiveDate>\n    <catalogName>SpyCarAdvanced</catalogName>\n"
+            )
+        );
+        assert!(xml.contains(r#"<plan name="standard-monthly" prettyName="Standard &amp; API">"#));
+
+        let standard = &xml[xml.find("<plan name=\"standard-monthly\"").unwrap()..];
+        let standard = &standard[..standard.find("</plan>").unwrap()];
+        assert_eq!(
+            standard.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<_>>(),
+            [
+                r#"<plan name="standard-monthly" prettyName="Standard &amp; API">"#,
+                "<product>Standard</product>",
+                "<recurringBillingMode>IN_ADVANCE</recurringBillingMode>",
+                "<initialPhases>",
+                r#"<phase type="TRIAL">"#,
+                "<duration>",
+                "<unit>DAYS</unit>",
+                "<number>30</number>",
+                "</duration>",
+                r#"<fixed type="ONE_TIME">"#,
+                "<fixedPrice/>",
+                "</fixed>",
+                "</phase>",
+                "</initialPhases>",
+                r#"<finalPhase type="EVERGREEN">"#,
+                "<duration>",
+                "<unit>UNLIMITED</unit>",
+                "</duration>",
+                "<recurring>",
+                "<billingPeriod>MONTHLY</billingPeriod>",
+                "<recurringPrice>",
+                "<price>",
+                "<currency>USD</currency>",
+                "<value>29.95</value>",
+                "</price>",
+                "<price>",
+                "<currency>EUR</currency>",
+                "<value>25</value>",
+                "</price>",
+                "</recurringPrice>",
+                "</recurring>",
+                "<usages>",
+                r#"<usage name="standard-api-calls" billingMode="IN_ARREAR" usageType="CONSUMABLE" tierBlockPolicy="ALL_TIERS">"#,
+                "<billingPeriod>MONTHLY</billingPeriod>",
+                "<tiers>",
+                "<tier>",
+                "<blocks>",
+                "<tieredBlock>",
+                "<unit>api-calls</unit>",
+                "<size>100</size>",
+                "<prices>",
+                "<price>",
+                "<currency>USD</currency>",
+                "<value>1.5</value>",
+                "</price>",
+                "</prices>",
+                "<max>10</max>",
+                "</tieredBlock>",
+                "</blocks>",
+                "</tier>",
+                "<tier>",
+                "<blocks>",
+                "<tieredBlock>",
+                "<unit>api-calls</unit>",
+                "<size>100</size>",
+                "<prices>",
+                "<price>",
+                "<currency>USD</currency>",
+                "<value>1</value>",
+                "</price>",
+                "</prices>",
+                "<max>-1</max>",
+                "</tieredBlock>",
+                "</blocks>",
+                "</tier>",
+                "</tiers>",
+                "</usage>",
+                "</usages>",
+                "</finalPhase>",
+            ]
+        );
+
+        assert!(
+            xml.contains(
+                "        <changePolicy>\n            <changePolicyCase>\n                <phaseType>TRIAL</phaseType>\n                <policy>IMMEDIATE</policy>\n            </changePolicyCase>\n            <changePolicyCase>\n                <fromBillingPeriod>ANNUAL</fromBillingPeriod>\n                <toBillingPeriod>MONTHLY</toBillingPeriod>\n                <policy>END_OF_TERM</policy>\n            </changePolicyCase>\n        </changePolicy>\n"
+            )
+        );
+        assert!(xml.contains("<productCategory>ADD_ON</productCategory>\n                <alignment>BUNDLE</alignment>"));
+        assert!(xml.contains("<fromPriceList>PROMO</fromPriceList>\n                <toPriceList>DEFAULT</toPriceList>"));
+        assert!(xml.contains(r#"<block type="TOP_UP">"#));
+        assert!(xml.contains("<minTopUpCredit>100</minTopUpCredit>"));
+        assert!(xml.contains("<plansAllowedInBundle>1</plansAllowedInBundle>"));
+        assert!(!xml.contains("effectiveDateForExistingSubscriptions"));
+        assert!(
+            xml.ends_with(
+                "    <priceLists>\n        <defaultPriceList name=\"DEFAULT\">\n            <plans>\n                <plan>standard-monthly</plan>\n                <plan>support-annual</plan>\n            </plans>\n        </defaultPriceList>\n        <childPriceList name=\"PROMO\" prettyName=\"Promotion\">\n            <plans>\n                <plan>standard-monthly</plan>\n            </plans>\n        </childPriceList>\n    </priceLists>\n</catalog>\n"
+            )
+        );
+
+        let mut catalog = catalog();
+        catalog.plans[1].effective_date_for_existing_subscriptions = Some(
+            "2024-03-01T00:00:00+00:00".to_owned()
+        );
+        assert!(
+            to_string(&catalog).contains(
+                "        <plan name=\"support-annual\">\n            <effectiveDateForExistingSubscriptions>2024-03-01T00:00:00+00:00</effectiveDateForExistingSubscriptions>\n            <product>Support</product>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_catalog_from_xml() {
+        assert_eq!(from_str(&to_string(&catalog())).unwrap(), catalog());
+        assert_eq!(versions_from_str(&to_string(&catalog())).unwrap(), [catalog()]);
+
+        let mut catalog = catalog();
+        catalog.plans[1].effective_date_for_existing_subscriptions = Some(
+            "2024-03-01T00:00:00+00:00".to_owned()
+        );
+        assert_eq!(from_str(&to_string(&catalog)).unwrap(), catalog);
+    }
+
+    #[test]
+    fn test_catalog_versions_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<catalogs>
+    <versions>
+        <version>
+            <effectiveDate>2023-01-01T00:00:00Z</effectiveDate>
+            <catalogName>SpyCarBasic</catalogName>
+            <recurringBillingMode>IN_ARREAR</recurringBillingMode>
+            <currencies>
+                <currency>USD</currency>
+            </currencies>
+            <products>
+                <product name="Standard">
+                    <category>BASE</category>
+                </product>
+            </products>
+            <rules>
+                <changePolicy>
+                    <changePolicyCase>
+                        <policy>IMMEDIATE</policy>
+                    </changePolicyCase>
+                </changePolicy>
+            </rules>
+            <plans>
+                <plan name="standard-monthly">
+                    <effectiveDateForExistingSubscriptions>2023-02-01T00:00:00Z</effectiveDateForExistingSubscriptions>
+                    <product>Standard</product>
+                    <finalPhase type="EVERGREEN">
+                        <duration>
+                            <unit>UNLIMITED</unit>
+                            <number>-1</number>
+                        </duration>
+                        <recurring>
+                            <billingPeriod>MONTHLY</billingPeriod>
+                            <recurringPrice>
+                                <price>
+                                    <currency>USD</currency>
+                                    <value> 75.00 </value>
+                                </price>
+                            </recurringPrice>
+                        </recurring>
+                    </finalPhase>
+                </plan>
+            </plans>
+            <priceLists>
+                <defaultPriceList name="DEFAULT">
+                    <plans>
+                        <plan>standard-monthly</plan>
+                    </plans>
+                </defaultPriceList>
+            </priceLists>
+        </version>
+        <version>
+            <effectiveDate>2024-01-01T00:00:00Z</effectiveDate>
+            <plans/>
+            <priceLists>
+                <defaultPriceList name="DEFAULT"/>
+            </priceLists>
+        </version>
+    </versions>
+    <catalogName>SpyCarBasic</catalogName>
+</catalogs>"#;
+
+        let versions = versions_from_str(xml).unwrap();
+        assert_eq!(versions.len(), 2);
+
+        let plan = Plan::builder()
+            .name("standard-monthly")
+            .product("Standard")
+            .recurring_billing_mode(RecurringBillingMode::Arrear)
+            .effective_date_for_existing_subscriptions("2023-02-01T00:00:00Z")
+            .final_phase(
+                Phase::new(PhaseType::Evergreen, Duration { unit: TimeUnit::Unlimited, number: Some(-1) })
+                    .recurring(BillingPeriod::Monthly, [usd("75")])
+            )
+            .build()
+            .unwrap();
+        let first = Catalog::builder()
+            .name("SpyCarBasic")
+            .effective_date("2023-01-01T00:00:00Z")
+            .currency(Currency::Usd)
+            .product(Product::base("Standard"))
+            .rules(Rules::new().change_policy(ChangePolicyCase::new(BillingActionPolicy::Immediate)))
+            .plan(plan)
+            .default_price_list(["standard-monthly"])
+            .build()
+            .unwrap();
+        assert_eq!(versions[0], first);
+
+        assert_eq!(versions[1].name, "SpyCarBasic");
+        assert_eq!(versions[1].effective_date, "2024-01-01T00:00:00Z");
+        assert!(versions[1].plans.is_empty());
+        assert!(versions[1].default_price_list.plans.is_empty());
+
+        let error = from_str(xml).unwrap_err();
+        assert!(
+            matches!(error, XmlError::Invalid { ref path, .. } if path == "catalogs"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_catalog_from_invalid_xml() {
+        let xml = to_string(&catalog()).replace(
+            "<billingPeriod>MONTHLY</billingPeriod>\n                    <recurringPrice>",
+            "<billingPeriod>FORTNIGHTLY</billingPeriod>\n                    <recurringPrice>"
+        );
+        match from_str(&xml).unwrap_err() {
+            XmlError::Invalid { path, message } => {
+                assert_eq!(
+                    path,
+                    "catalog/plans/plan[standard-monthly]/finalPhase/recurring/billingPeriod"
+                );
+                assert_eq!(message, "unknown value FORTNIGHTLY");
+            }
+            error => panic!("unexpected error {error}"),
+        }
+
+        let xml = to_string(&catalog()).replace("<product>Standard</product>", "");
+        assert_eq!(
+            from_str(&xml).unwrap_err().to_string(),
+            "Invalid catalog at catalog/plans/plan[standard-monthly]: missing <product>"
+        );
+
+        assert!(matches!(from_str("<catalog>").unwrap_err(), XmlError::Syntax(_)));
+    }
+}
@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use crate::apis::{
+    account_api::AccountApi,
+    admin_api::AdminApi,
+    analytics_api::AnalyticsApi,
+    bundle_api::BundleApi,
+    catalog_api::CatalogApi,
+    credit_api::CreditApi,
+    custom_field_api::CustomFieldApi,
+    export_api::ExportApi,
+    invoice_api::InvoiceApi,
+    invoice_item_api::InvoiceItemApi,
+    invoice_payment_api::InvoicePaymentApi,
+    nodes_info_api::NodesApi,
+    overdue_api::OverdueApi,
+    payment_api::PaymentApi,
+    payment_gateway_api::HostedPaymentPageApi,
+    payment_method_api::PaymentMethodApi,
+    payment_transaction_api::PaymentTransactionApi,
+    plugin_api::PluginApi,
+    plugin_info_api::PluginsApi,
+    security_api::SecurityApi,
+    subscription_api::SubscriptionApi,
+    tag_api::TagApi,
+    tag_definition_api::TagDefinitionApi,
+    tenant_api::TenantApi,
+    test_api::TestApi,
+    usage_api::UsageApi,
+    configuration::{ ConfigBuilder, ConfigError, Configuration },
+};
+
+/// Entry point owning a handle to every Kill Bill API
+///
+/// All handles share the same [`Configuration`], and so the same HTTP
+/// connection pool. Cloning the client is cheap, and it is `Send + Sync`, so
+/// it can be kept in application state.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use killbill_client::KillBillClient;
+/// use killbill_client::apis::account_api::GetAccountsRequest;
+///
+/// let client = KillBillClient::builder()
+///     .base_path("http://localhost:8080")
+///     .basic_auth("admin", Some("password"))
+///     .tenant("bob", "lazar")
+///     .build_client()?;
+///
+/// let accounts = client.accounts().get_accounts(GetAccountsRequest::builder().build()?).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct KillBillClient {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    config: Configuration,
+    accounts: AccountApi,
+    admin: AdminApi,
+    analytics: AnalyticsApi,
+    bundles: BundleApi,
+    catalog: CatalogApi,
+    credits: CreditApi,
+    custom_fields: CustomFieldApi,
+    export: ExportApi,
+    invoices: InvoiceApi,
+    invoice_items: InvoiceItemApi,
+    invoice_payments: InvoicePaymentApi,
+    nodes: NodesApi,
+    overdue: OverdueApi,
+    payments: PaymentApi,
+    hosted_payment_pages: HostedPaymentPageApi,
+    payment_methods: PaymentMethodApi,
+    payment_transactions: PaymentTransactionApi,
+    plugins: PluginsApi,
+    plugin_routes: PluginApi,
+    security: SecurityApi,
+    subscriptions: SubscriptionApi,
+    tags: TagApi,
+    tag_definitions: TagDefinitionApi,
+    tenants: TenantApi,
+    test: TestApi,
+    usages: UsageApi,
+}
+
+impl KillBillClient {
+    pub fn new(config: Configuration) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                accounts: AccountApi::new(config.clone()),
+                admin: AdminApi::new(config.clone()),
+                analytics: AnalyticsApi::new(config.clone()),
+                bundles: BundleApi::new(config.clone()),
+                catalog: CatalogApi::new(config.clone()),
+                credits: CreditApi::new(config.clone()),
+                custom_fields: CustomFieldApi::new(config.clone()),
+                export: ExportApi::new(config.clone()),
+                invoices: InvoiceApi::new(config.clone()),
+                invoice_items: InvoiceItemApi::new(config.clone()),
+                invoice_payments: InvoicePaymentApi::new(config.clone()),
+                nodes: NodesApi::new(config.clone()),
+                overdue: OverdueApi::new(config.clone()),
+                payments: PaymentApi::new(config.clone()),
+                hosted_payment_pages: HostedPaymentPageApi::new(config.clone()),
+                payment_methods: PaymentMethodApi::new(config.clone()),
+                payment_transactions: PaymentTransactionApi::new(config.clone()),
+                plugins: PluginsApi::new(config.clone()),
+                plugin_routes: PluginApi::new(config.clone()),
+                security: SecurityApi::new(config.clone()),
+                subscriptions: SubscriptionApi::new(config.clone()),
+                tags: TagApi::new(config.clone()),
+                tag_definitions: TagDefinitionApi::new(config.clone()),
+                tenants: TenantApi::new(config.clone()),
+                test: TestApi::new(config.clone()),
+                usages: UsageApi::new(config.clone()),
+                config,
+            }),
+        }
+    }
+
+    /// Start configuring a client, finished with [`ConfigBuilder::build_client`]
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+
+    pub fn config(&self) -> &Configuration {
+        &self.inner.config
+    }
+
+    pub fn accounts(&self) -> &AccountApi {
+        &self.inner.accounts
+    }
+
+    pub fn admin(&self) -> &AdminApi {
+        &self.inner.admin
+    }
+
+    /// Routes of the killbill-analytics plugin, which must be installed
+    pub fn analytics(&self) -> &AnalyticsApi {
+        &self.inner.analytics
+    }
+
+    pub fn bundles(&self) -> &BundleApi {
+        &self.inner.bundles
+    }
+
+    pub fn catalog(&self) -> &CatalogApi {
+        &self.inner.catalog
+    }
+
+    pub fn credits(&self) -> &CreditApi {
+        &self.inner.credits
+    }
+
+    pub fn custom_fields(&self) -> &CustomFieldApi {
+        &self.inner.custom_fields
+    }
+
+    pub fn export(&self) -> &ExportApi {
+        &self.inner.export
+    }
+
+    pub fn invoices(&self) -> &InvoiceApi {
+        &self.inner.invoices
+    }
+
+    pub fn invoice_items(&self) -> &InvoiceItemApi {
+        &self.inner.invoice_items
+    }
+
+    pub fn invoice_payments(&self) -> &InvoicePaymentApi {
+        &self.inner.invoice_payments
+    }
+
+    pub fn nodes(&self) -> &NodesApi {
+        &self.inner.nodes
+    }
+
+    pub fn overdue(&self) -> &OverdueApi {
+        &self.inner.overdue
+    }
+
+    pub fn payments(&self) -> &PaymentApi {
+        &self.inner.payments
+    }
+
+    pub fn hosted_payment_pages(&self) -> &HostedPaymentPageApi {
+        &self.inner.hosted_payment_pages
+    }
+
+    pub fn payment_methods(&self) -> &PaymentMethodApi {
+        &self.inner.payment_methods
+    }
+
+    pub fn payment_transactions(&self) -> &PaymentTransactionApi {
+        &self.inner.payment_transactions
+    }
+
+    pub fn plugins(&self) -> &PluginsApi {
+        &self.inner.plugins
+    }
+
+    /// Routes served by the plugins themselves, under `/plugins/{pluginName}`
+    pub fn plugin_routes(&self) -> &PluginApi {
+        &self.inner.plugin_routes
+    }
+
+    pub fn security(&self) -> &SecurityApi {
+        &self.inner.security
+    }
+
+    pub fn subscriptions(&self) -> &SubscriptionApi {
+        &self.inner.subscriptions
+    }
+
+    pub fn tags(&self) -> &TagApi {
+        &self.inner.tags
+    }
+
+    pub fn tag_definitions(&self) -> &TagDefinitionApi {
+        &self.inner.tag_definitions
+    }
+
+    pub fn tenants(&self) -> &TenantApi {
+        &self.inner.tenants
+    }
+
+    /// Test clock and queues, only usable against a server running in test mode
+    pub fn test(&self) -> &TestApi {
+        &self.inner.test
+    }
+
+    pub fn usages(&self) -> &UsageApi {
+        &self.inner.usages
+    }
+}
+
+impl From<Configuration> for KillBillClient {
+    fn from(config: Configuration) -> Self {
+        Self::new(config)
+    }
+}
+
+impl std::fmt::Debug for KillBillClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KillBillClient").field("config", &self.inner.config).finish()
+    }
+}
+
+impl ConfigBuilder {
+    /// Build the configuration and wrap it in a [`KillBillClient`]
+    pub fn build_client(self) -> Result<KillBillClient, ConfigError> {
+        self.build().map(KillBillClient::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apis::tag_definition_api::GetTagDefinitionsRequest;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_client_is_send_and_sync() {
+        assert_send_sync::<KillBillClient>();
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_the_configuration() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/1.0/kb/tagDefinitions")
+            .match_header("X-Killbill-ApiKey", "bob")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .expect(2)
+            .create_async().await;
+
+        let client = KillBillClient::builder()
+            .base_path(server.url())
+            .tenant("bob", "lazar")
+            .build_client()
+            .unwrap();
+        let clone = client.clone();
+        assert!(Arc::ptr_eq(&client.inner, &clone.inner));
+
+        for client in [client, clone] {
+            let request = GetTagDefinitionsRequest::builder().build().unwrap();
+            let tag_definitions = client.tag_definitions().get_tag_definitions(request).await;
+            assert!(tag_definitions.unwrap().is_empty());
+        }
+
+        mock.assert_async().await;
+    }
+}
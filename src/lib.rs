@@ -1,3 +1,13 @@
+//! Client for the Kill Bill billing and payments platform
+//!
+//! # Cargo features
+//!
+//! - `tracing`: wrap every API call in a `tracing` span
+//! - `blocking`: synchronous mirrors of the API structs, in `blocking`, running the
+//!   async client on a current-thread Tokio runtime
+//! - `axum`: axum handler receiving push notifications
+//! - `testing`: in-memory fake Kill Bill server for tests, in `testing`
+
 #![allow(unused_imports)]
 #![allow(clippy::too_many_arguments)]
 
@@ -8,4 +18,13 @@ extern crate url;
 extern crate reqwest;
 
 pub mod apis;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod catalog;
+pub mod client;
 pub mod models;
+pub mod notifications;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use client::KillBillClient;
@@ -0,0 +1,41 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Level of audit logs returned alongside an object (`audit` query parameter)
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum AuditLevel {
+    #[default]
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "MINIMAL")]
+    Minimal,
+    #[serde(rename = "FULL")]
+    Full,
+}
+
+impl AuditLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::Minimal => "MINIMAL",
+            Self::Full => "FULL",
+        }
+    }
+}
+
+impl fmt::Display for AuditLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -16,7 +16,7 @@ pub struct AuditLog {
     #[serde(rename = "changeType", skip_serializing_if = "Option::is_none")]
     pub change_type: Option<String>,
     #[serde(rename = "changeDate", skip_serializing_if = "Option::is_none")]
-    pub change_date: Option<String>,
+    pub change_date: Option<models::DateTime>,
     #[serde(rename = "objectType", skip_serializing_if = "Option::is_none")]
     pub object_type: Option<ObjectType>,
     #[serde(rename = "objectId", skip_serializing_if = "Option::is_none")]
@@ -16,11 +16,14 @@ pub struct BlockPrice {
     #[serde(rename = "unitName", skip_serializing_if = "Option::is_none")]
     pub unit_name: Option<String>,
     #[serde(rename = "size", skip_serializing_if = "Option::is_none")]
-    pub size: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub size: Option<models::Decimal>,
     #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
-    pub price: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub price: Option<models::Decimal>,
     #[serde(rename = "max", skip_serializing_if = "Option::is_none")]
-    pub max: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub max: Option<models::Decimal>,
 }
 
 impl BlockPrice {
@@ -26,7 +26,7 @@ pub struct BlockingState {
     #[serde(rename = "isBlockBilling", skip_serializing_if = "Option::is_none")]
     pub is_block_billing: Option<bool>,
     #[serde(rename = "effectiveDate", skip_serializing_if = "Option::is_none")]
-    pub effective_date: Option<String>,
+    pub effective_date: Option<models::DateTime>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<Type>,
     #[serde(rename = "auditLogs", skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,134 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Denormalized account row of the analytics plugin (`analytics_accounts`)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessAccount {
+    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(rename = "firstNameLength", skip_serializing_if = "Option::is_none")]
+    pub first_name_length: Option<i32>,
+    #[serde(rename = "currency", skip_serializing_if = "Option::is_none")]
+    pub currency: Option<models::Currency>,
+    #[serde(rename = "billingCycleDayLocal", skip_serializing_if = "Option::is_none")]
+    pub billing_cycle_day_local: Option<i32>,
+    #[serde(rename = "paymentMethodId", skip_serializing_if = "Option::is_none")]
+    pub payment_method_id: Option<uuid::Uuid>,
+    #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[serde(rename = "locale", skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(rename = "address1", skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(rename = "address2", skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(rename = "companyName", skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    #[serde(rename = "city", skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(rename = "stateOrProvince", skip_serializing_if = "Option::is_none")]
+    pub state_or_province: Option<String>,
+    #[serde(rename = "country", skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(rename = "postalCode", skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(rename = "phone", skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(rename = "isMigrated", skip_serializing_if = "Option::is_none")]
+    pub is_migrated: Option<bool>,
+    #[serde(rename = "isNotifiedForInvoices", skip_serializing_if = "Option::is_none")]
+    pub is_notified_for_invoices: Option<bool>,
+    #[serde(rename = "balance", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub balance: Option<models::Decimal>,
+    #[serde(rename = "convertedBalance", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_balance: Option<models::Decimal>,
+    #[serde(rename = "lastInvoiceDate", skip_serializing_if = "Option::is_none")]
+    pub last_invoice_date: Option<models::Date>,
+    #[serde(rename = "lastPaymentDate", skip_serializing_if = "Option::is_none")]
+    pub last_payment_date: Option<models::DateTime>,
+    #[serde(rename = "lastPaymentStatus", skip_serializing_if = "Option::is_none")]
+    pub last_payment_status: Option<String>,
+    #[serde(rename = "nbActiveBundles", skip_serializing_if = "Option::is_none")]
+    pub nb_active_bundles: Option<i32>,
+    #[serde(rename = "convertedCurrency", skip_serializing_if = "Option::is_none")]
+    pub converted_currency: Option<models::Currency>,
+    #[serde(rename = "parentAccountId", skip_serializing_if = "Option::is_none")]
+    pub parent_account_id: Option<uuid::Uuid>,
+    #[serde(rename = "parentAccountName", skip_serializing_if = "Option::is_none")]
+    pub parent_account_name: Option<String>,
+    #[serde(rename = "parentAccountExternalKey", skip_serializing_if = "Option::is_none")]
+    pub parent_account_external_key: Option<String>,
+    #[serde(rename = "updatedDate", skip_serializing_if = "Option::is_none")]
+    pub updated_date: Option<models::DateTime>,
+    #[serde(rename = "accountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<uuid::Uuid>,
+    #[serde(rename = "accountName", skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+    #[serde(rename = "accountExternalKey", skip_serializing_if = "Option::is_none")]
+    pub account_external_key: Option<String>,
+    #[serde(rename = "createdDate", skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<models::DateTime>,
+    #[serde(rename = "createdBy", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(rename = "createdReasonCode", skip_serializing_if = "Option::is_none")]
+    pub created_reason_code: Option<String>,
+    #[serde(rename = "createdComments", skip_serializing_if = "Option::is_none")]
+    pub created_comments: Option<String>,
+    #[serde(rename = "reportGroup", skip_serializing_if = "Option::is_none")]
+    pub report_group: Option<String>,
+}
+
+impl BusinessAccount {
+    pub fn new() -> BusinessAccount {
+        BusinessAccount {
+            email: None,
+            first_name_length: None,
+            currency: None,
+            billing_cycle_day_local: None,
+            payment_method_id: None,
+            time_zone: None,
+            locale: None,
+            address1: None,
+            address2: None,
+            company_name: None,
+            city: None,
+            state_or_province: None,
+            country: None,
+            postal_code: None,
+            phone: None,
+            is_migrated: None,
+            is_notified_for_invoices: None,
+            balance: None,
+            converted_balance: None,
+            last_invoice_date: None,
+            last_payment_date: None,
+            last_payment_status: None,
+            nb_active_bundles: None,
+            converted_currency: None,
+            parent_account_id: None,
+            parent_account_name: None,
+            parent_account_external_key: None,
+            updated_date: None,
+            account_id: None,
+            account_name: None,
+            account_external_key: None,
+            created_date: None,
+            created_by: None,
+            created_reason_code: None,
+            created_comments: None,
+            report_group: None,
+        }
+    }
+}
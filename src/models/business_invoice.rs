@@ -0,0 +1,117 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Denormalized invoice row of the analytics plugin (`analytics_invoices`)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessInvoice {
+    #[serde(rename = "invoiceId", skip_serializing_if = "Option::is_none")]
+    pub invoice_id: Option<uuid::Uuid>,
+    #[serde(rename = "invoiceNumber", skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<i64>,
+    #[serde(rename = "invoiceDate", skip_serializing_if = "Option::is_none")]
+    pub invoice_date: Option<models::Date>,
+    #[serde(rename = "targetDate", skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<models::Date>,
+    #[serde(rename = "currency", skip_serializing_if = "Option::is_none")]
+    pub currency: Option<models::Currency>,
+    #[serde(rename = "balance", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub balance: Option<models::Decimal>,
+    #[serde(rename = "convertedBalance", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_balance: Option<models::Decimal>,
+    #[serde(rename = "amountPaid", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub amount_paid: Option<models::Decimal>,
+    #[serde(rename = "convertedAmountPaid", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_amount_paid: Option<models::Decimal>,
+    #[serde(rename = "amountCharged", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub amount_charged: Option<models::Decimal>,
+    #[serde(rename = "convertedAmountCharged", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_amount_charged: Option<models::Decimal>,
+    #[serde(rename = "originalAmountCharged", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub original_amount_charged: Option<models::Decimal>,
+    #[serde(rename = "convertedOriginalAmountCharged", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_original_amount_charged: Option<models::Decimal>,
+    #[serde(rename = "amountCredited", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub amount_credited: Option<models::Decimal>,
+    #[serde(rename = "convertedAmountCredited", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_amount_credited: Option<models::Decimal>,
+    #[serde(rename = "amountRefunded", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub amount_refunded: Option<models::Decimal>,
+    #[serde(rename = "convertedAmountRefunded", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_amount_refunded: Option<models::Decimal>,
+    #[serde(rename = "convertedCurrency", skip_serializing_if = "Option::is_none")]
+    pub converted_currency: Option<models::Currency>,
+    #[serde(rename = "invoiceItems", skip_serializing_if = "Option::is_none")]
+    pub invoice_items: Option<Vec<models::BusinessInvoiceItem>>,
+    #[serde(rename = "accountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<uuid::Uuid>,
+    #[serde(rename = "accountName", skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+    #[serde(rename = "accountExternalKey", skip_serializing_if = "Option::is_none")]
+    pub account_external_key: Option<String>,
+    #[serde(rename = "createdDate", skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<models::DateTime>,
+    #[serde(rename = "createdBy", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(rename = "createdReasonCode", skip_serializing_if = "Option::is_none")]
+    pub created_reason_code: Option<String>,
+    #[serde(rename = "createdComments", skip_serializing_if = "Option::is_none")]
+    pub created_comments: Option<String>,
+    #[serde(rename = "reportGroup", skip_serializing_if = "Option::is_none")]
+    pub report_group: Option<String>,
+}
+
+impl BusinessInvoice {
+    pub fn new() -> BusinessInvoice {
+        BusinessInvoice {
+            invoice_id: None,
+            invoice_number: None,
+            invoice_date: None,
+            target_date: None,
+            currency: None,
+            balance: None,
+            converted_balance: None,
+            amount_paid: None,
+            converted_amount_paid: None,
+            amount_charged: None,
+            converted_amount_charged: None,
+            original_amount_charged: None,
+            converted_original_amount_charged: None,
+            amount_credited: None,
+            converted_amount_credited: None,
+            amount_refunded: None,
+            converted_amount_refunded: None,
+            converted_currency: None,
+            invoice_items: None,
+            account_id: None,
+            account_name: None,
+            account_external_key: None,
+            created_date: None,
+            created_by: None,
+            created_reason_code: None,
+            created_comments: None,
+            report_group: None,
+        }
+    }
+}
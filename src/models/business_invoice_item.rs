@@ -0,0 +1,86 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Invoice item of a [`BusinessInvoice`](models::BusinessInvoice)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessInvoiceItem {
+    #[serde(rename = "itemId", skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<uuid::Uuid>,
+    #[serde(rename = "linkedItemId", skip_serializing_if = "Option::is_none")]
+    pub linked_item_id: Option<uuid::Uuid>,
+    #[serde(rename = "itemType", skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+    #[serde(rename = "itemSource", skip_serializing_if = "Option::is_none")]
+    pub item_source: Option<String>,
+    #[serde(rename = "bundleId", skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<uuid::Uuid>,
+    #[serde(rename = "bundleExternalKey", skip_serializing_if = "Option::is_none")]
+    pub bundle_external_key: Option<String>,
+    #[serde(rename = "productName", skip_serializing_if = "Option::is_none")]
+    pub product_name: Option<String>,
+    #[serde(rename = "productType", skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<String>,
+    #[serde(rename = "productCategory", skip_serializing_if = "Option::is_none")]
+    pub product_category: Option<String>,
+    #[serde(rename = "slug", skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(rename = "usageName", skip_serializing_if = "Option::is_none")]
+    pub usage_name: Option<String>,
+    #[serde(rename = "phase", skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    #[serde(rename = "billingPeriod", skip_serializing_if = "Option::is_none")]
+    pub billing_period: Option<String>,
+    #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<models::Date>,
+    #[serde(rename = "endDate", skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<models::Date>,
+    #[serde(rename = "amount", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub amount: Option<models::Decimal>,
+    #[serde(rename = "convertedAmount", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_amount: Option<models::Decimal>,
+    #[serde(rename = "currency", skip_serializing_if = "Option::is_none")]
+    pub currency: Option<models::Currency>,
+    #[serde(rename = "convertedCurrency", skip_serializing_if = "Option::is_none")]
+    pub converted_currency: Option<models::Currency>,
+    #[serde(rename = "createdDate", skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<models::DateTime>,
+}
+
+impl BusinessInvoiceItem {
+    pub fn new() -> BusinessInvoiceItem {
+        BusinessInvoiceItem {
+            item_id: None,
+            linked_item_id: None,
+            item_type: None,
+            item_source: None,
+            bundle_id: None,
+            bundle_external_key: None,
+            product_name: None,
+            product_type: None,
+            product_category: None,
+            slug: None,
+            usage_name: None,
+            phase: None,
+            billing_period: None,
+            start_date: None,
+            end_date: None,
+            amount: None,
+            converted_amount: None,
+            currency: None,
+            converted_currency: None,
+            created_date: None,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Analytics view of an account, as returned by `GET /plugins/killbill-analytics/{accountId}`
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessSnapshot {
+    #[serde(rename = "businessAccount", skip_serializing_if = "Option::is_none")]
+    pub business_account: Option<models::BusinessAccount>,
+    #[serde(rename = "businessSubscriptionTransitions", skip_serializing_if = "Option::is_none")]
+    pub business_subscription_transitions: Option<Vec<models::BusinessSubscriptionTransition>>,
+    #[serde(rename = "businessInvoices", skip_serializing_if = "Option::is_none")]
+    pub business_invoices: Option<Vec<models::BusinessInvoice>>,
+}
+
+impl BusinessSnapshot {
+    pub fn new() -> BusinessSnapshot {
+        BusinessSnapshot {
+            business_account: None,
+            business_subscription_transitions: None,
+            business_invoices: None,
+        }
+    }
+}
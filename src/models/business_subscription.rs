@@ -0,0 +1,79 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Subscription state before or after a [`BusinessSubscriptionTransition`](models::BusinessSubscriptionTransition)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessSubscription {
+    #[serde(rename = "productName", skip_serializing_if = "Option::is_none")]
+    pub product_name: Option<String>,
+    #[serde(rename = "productType", skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<String>,
+    #[serde(rename = "productCategory", skip_serializing_if = "Option::is_none")]
+    pub product_category: Option<String>,
+    #[serde(rename = "slug", skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(rename = "phase", skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    #[serde(rename = "billingPeriod", skip_serializing_if = "Option::is_none")]
+    pub billing_period: Option<String>,
+    #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub price: Option<models::Decimal>,
+    #[serde(rename = "convertedPrice", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_price: Option<models::Decimal>,
+    #[serde(rename = "mrr", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub mrr: Option<models::Decimal>,
+    #[serde(rename = "convertedMrr", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub converted_mrr: Option<models::Decimal>,
+    #[serde(rename = "currency", skip_serializing_if = "Option::is_none")]
+    pub currency: Option<models::Currency>,
+    #[serde(rename = "convertedCurrency", skip_serializing_if = "Option::is_none")]
+    pub converted_currency: Option<models::Currency>,
+    #[serde(rename = "service", skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(rename = "state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(rename = "businessActive", skip_serializing_if = "Option::is_none")]
+    pub business_active: Option<bool>,
+    #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<models::Date>,
+    #[serde(rename = "endDate", skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<models::Date>,
+}
+
+impl BusinessSubscription {
+    pub fn new() -> BusinessSubscription {
+        BusinessSubscription {
+            product_name: None,
+            product_type: None,
+            product_category: None,
+            slug: None,
+            phase: None,
+            billing_period: None,
+            price: None,
+            converted_price: None,
+            mrr: None,
+            converted_mrr: None,
+            currency: None,
+            converted_currency: None,
+            service: None,
+            state: None,
+            business_active: None,
+            start_date: None,
+            end_date: None,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Subscription event of the analytics plugin (`analytics_subscription_transitions`)
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessSubscriptionTransition {
+    #[serde(rename = "subscriptionEventRecordId", skip_serializing_if = "Option::is_none")]
+    pub subscription_event_record_id: Option<i64>,
+    #[serde(rename = "bundleId", skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<uuid::Uuid>,
+    #[serde(rename = "bundleExternalKey", skip_serializing_if = "Option::is_none")]
+    pub bundle_external_key: Option<String>,
+    #[serde(rename = "subscriptionId", skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<uuid::Uuid>,
+    #[serde(rename = "requestedTimestamp", skip_serializing_if = "Option::is_none")]
+    pub requested_timestamp: Option<models::Date>,
+    #[serde(rename = "event", skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    #[serde(rename = "prevSubscription", skip_serializing_if = "Option::is_none")]
+    pub prev_subscription: Option<models::BusinessSubscription>,
+    #[serde(rename = "nextSubscription", skip_serializing_if = "Option::is_none")]
+    pub next_subscription: Option<models::BusinessSubscription>,
+    #[serde(rename = "accountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<uuid::Uuid>,
+    #[serde(rename = "accountName", skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+    #[serde(rename = "accountExternalKey", skip_serializing_if = "Option::is_none")]
+    pub account_external_key: Option<String>,
+    #[serde(rename = "createdDate", skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<models::DateTime>,
+    #[serde(rename = "createdBy", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(rename = "createdReasonCode", skip_serializing_if = "Option::is_none")]
+    pub created_reason_code: Option<String>,
+    #[serde(rename = "createdComments", skip_serializing_if = "Option::is_none")]
+    pub created_comments: Option<String>,
+    #[serde(rename = "reportGroup", skip_serializing_if = "Option::is_none")]
+    pub report_group: Option<String>,
+}
+
+impl BusinessSubscriptionTransition {
+    pub fn new() -> BusinessSubscriptionTransition {
+        BusinessSubscriptionTransition {
+            subscription_event_record_id: None,
+            bundle_id: None,
+            bundle_external_key: None,
+            subscription_id: None,
+            requested_timestamp: None,
+            event: None,
+            prev_subscription: None,
+            next_subscription: None,
+            account_id: None,
+            account_name: None,
+            account_external_key: None,
+            created_date: None,
+            created_by: None,
+            created_reason_code: None,
+            created_comments: None,
+            report_group: None,
+        }
+    }
+}
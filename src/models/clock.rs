@@ -0,0 +1,33 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Current time of the Kill Bill test clock
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Clock {
+    #[serde(rename = "currentUtcTime", skip_serializing_if = "Option::is_none")]
+    pub current_utc_time: Option<models::DateTime>,
+    #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[serde(rename = "localDate", skip_serializing_if = "Option::is_none")]
+    pub local_date: Option<models::Date>,
+}
+
+impl Clock {
+    pub fn new() -> Clock {
+        Clock {
+            current_utc_time: None,
+            time_zone: None,
+            local_date: None,
+        }
+    }
+}
@@ -0,0 +1,866 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models;
+
+/// Currency supported by Kill Bill, identified by its ISO 4217 code
+///
+/// Shared by every model with a currency, so e.g. an `Account::currency` can
+/// be assigned to an `InvoiceItem::currency` directly.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Currency {
+    #[default]
+    #[serde(rename = "AED")]
+    Aed,
+    #[serde(rename = "AFN")]
+    Afn,
+    #[serde(rename = "ALL")]
+    All,
+    #[serde(rename = "AMD")]
+    Amd,
+    #[serde(rename = "ANG")]
+    Ang,
+    #[serde(rename = "AOA")]
+    Aoa,
+    #[serde(rename = "ARS")]
+    Ars,
+    #[serde(rename = "AUD")]
+    Aud,
+    #[serde(rename = "AWG")]
+    Awg,
+    #[serde(rename = "AZN")]
+    Azn,
+    #[serde(rename = "BAM")]
+    Bam,
+    #[serde(rename = "BBD")]
+    Bbd,
+    #[serde(rename = "BDT")]
+    Bdt,
+    #[serde(rename = "BGN")]
+    Bgn,
+    #[serde(rename = "BHD")]
+    Bhd,
+    #[serde(rename = "BIF")]
+    Bif,
+    #[serde(rename = "BMD")]
+    Bmd,
+    #[serde(rename = "BND")]
+    Bnd,
+    #[serde(rename = "BOB")]
+    Bob,
+    #[serde(rename = "BRL")]
+    Brl,
+    #[serde(rename = "BSD")]
+    Bsd,
+    #[serde(rename = "BTN")]
+    Btn,
+    #[serde(rename = "BWP")]
+    Bwp,
+    #[serde(rename = "BYR")]
+    Byr,
+    #[serde(rename = "BZD")]
+    Bzd,
+    #[serde(rename = "CAD")]
+    Cad,
+    #[serde(rename = "CDF")]
+    Cdf,
+    #[serde(rename = "CHF")]
+    Chf,
+    #[serde(rename = "CLP")]
+    Clp,
+    #[serde(rename = "CNY")]
+    Cny,
+    #[serde(rename = "COP")]
+    Cop,
+    #[serde(rename = "CRC")]
+    Crc,
+    #[serde(rename = "CUC")]
+    Cuc,
+    #[serde(rename = "CUP")]
+    Cup,
+    #[serde(rename = "CVE")]
+    Cve,
+    #[serde(rename = "CZK")]
+    Czk,
+    #[serde(rename = "DJF")]
+    Djf,
+    #[serde(rename = "DKK")]
+    Dkk,
+    #[serde(rename = "DOP")]
+    Dop,
+    #[serde(rename = "DZD")]
+    Dzd,
+    #[serde(rename = "EGP")]
+    Egp,
+    #[serde(rename = "ERN")]
+    Ern,
+    #[serde(rename = "ETB")]
+    Etb,
+    #[serde(rename = "EUR")]
+    Eur,
+    #[serde(rename = "FJD")]
+    Fjd,
+    #[serde(rename = "FKP")]
+    Fkp,
+    #[serde(rename = "GBP")]
+    Gbp,
+    #[serde(rename = "GEL")]
+    Gel,
+    #[serde(rename = "GGP")]
+    Ggp,
+    #[serde(rename = "GHS")]
+    Ghs,
+    #[serde(rename = "GIP")]
+    Gip,
+    #[serde(rename = "GMD")]
+    Gmd,
+    #[serde(rename = "GNF")]
+    Gnf,
+    #[serde(rename = "GTQ")]
+    Gtq,
+    #[serde(rename = "GYD")]
+    Gyd,
+    #[serde(rename = "HKD")]
+    Hkd,
+    #[serde(rename = "HNL")]
+    Hnl,
+    #[serde(rename = "HRK")]
+    Hrk,
+    #[serde(rename = "HTG")]
+    Htg,
+    #[serde(rename = "HUF")]
+    Huf,
+    #[serde(rename = "IDR")]
+    Idr,
+    #[serde(rename = "ILS")]
+    Ils,
+    #[serde(rename = "IMP")]
+    Imp,
+    #[serde(rename = "INR")]
+    Inr,
+    #[serde(rename = "IQD")]
+    Iqd,
+    #[serde(rename = "IRR")]
+    Irr,
+    #[serde(rename = "ISK")]
+    Isk,
+    #[serde(rename = "JEP")]
+    Jep,
+    #[serde(rename = "JMD")]
+    Jmd,
+    #[serde(rename = "JOD")]
+    Jod,
+    #[serde(rename = "JPY")]
+    Jpy,
+    #[serde(rename = "KES")]
+    Kes,
+    #[serde(rename = "KGS")]
+    Kgs,
+    #[serde(rename = "KHR")]
+    Khr,
+    #[serde(rename = "KMF")]
+    Kmf,
+    #[serde(rename = "KPW")]
+    Kpw,
+    #[serde(rename = "KRW")]
+    Krw,
+    #[serde(rename = "KWD")]
+    Kwd,
+    #[serde(rename = "KYD")]
+    Kyd,
+    #[serde(rename = "KZT")]
+    Kzt,
+    #[serde(rename = "LAK")]
+    Lak,
+    #[serde(rename = "LBP")]
+    Lbp,
+    #[serde(rename = "LKR")]
+    Lkr,
+    #[serde(rename = "LRD")]
+    Lrd,
+    #[serde(rename = "LSL")]
+    Lsl,
+    #[serde(rename = "LTL")]
+    Ltl,
+    #[serde(rename = "LVL")]
+    Lvl,
+    #[serde(rename = "LYD")]
+    Lyd,
+    #[serde(rename = "MAD")]
+    Mad,
+    #[serde(rename = "MDL")]
+    Mdl,
+    #[serde(rename = "MGA")]
+    Mga,
+    #[serde(rename = "MKD")]
+    Mkd,
+    #[serde(rename = "MMK")]
+    Mmk,
+    #[serde(rename = "MNT")]
+    Mnt,
+    #[serde(rename = "MOP")]
+    Mop,
+    #[serde(rename = "MRO")]
+    Mro,
+    #[serde(rename = "MUR")]
+    Mur,
+    #[serde(rename = "MVR")]
+    Mvr,
+    #[serde(rename = "MWK")]
+    Mwk,
+    #[serde(rename = "MXN")]
+    Mxn,
+    #[serde(rename = "MYR")]
+    Myr,
+    #[serde(rename = "MZN")]
+    Mzn,
+    #[serde(rename = "NAD")]
+    Nad,
+    #[serde(rename = "NGN")]
+    Ngn,
+    #[serde(rename = "NIO")]
+    Nio,
+    #[serde(rename = "NOK")]
+    Nok,
+    #[serde(rename = "NPR")]
+    Npr,
+    #[serde(rename = "NZD")]
+    Nzd,
+    #[serde(rename = "OMR")]
+    Omr,
+    #[serde(rename = "PAB")]
+    Pab,
+    #[serde(rename = "PEN")]
+    Pen,
+    #[serde(rename = "PGK")]
+    Pgk,
+    #[serde(rename = "PHP")]
+    Php,
+    #[serde(rename = "PKR")]
+    Pkr,
+    #[serde(rename = "PLN")]
+    Pln,
+    #[serde(rename = "PYG")]
+    Pyg,
+    #[serde(rename = "QAR")]
+    Qar,
+    #[serde(rename = "RON")]
+    Ron,
+    #[serde(rename = "RSD")]
+    Rsd,
+    #[serde(rename = "RUB")]
+    Rub,
+    #[serde(rename = "RWF")]
+    Rwf,
+    #[serde(rename = "SAR")]
+    Sar,
+    #[serde(rename = "SBD")]
+    Sbd,
+    #[serde(rename = "SCR")]
+    Scr,
+    #[serde(rename = "SDG")]
+    Sdg,
+    #[serde(rename = "SEK")]
+    Sek,
+    #[serde(rename = "SGD")]
+    Sgd,
+    #[serde(rename = "SHP")]
+    Shp,
+    #[serde(rename = "SLL")]
+    Sll,
+    #[serde(rename = "SOS")]
+    Sos,
+    #[serde(rename = "SPL")]
+    Spl,
+    #[serde(rename = "SRD")]
+    Srd,
+    #[serde(rename = "STD")]
+    Std,
+    #[serde(rename = "SVC")]
+    Svc,
+    #[serde(rename = "SYP")]
+    Syp,
+    #[serde(rename = "SZL")]
+    Szl,
+    #[serde(rename = "THB")]
+    Thb,
+    #[serde(rename = "TJS")]
+    Tjs,
+    #[serde(rename = "TMT")]
+    Tmt,
+    #[serde(rename = "TND")]
+    Tnd,
+    #[serde(rename = "TOP")]
+    Top,
+    #[serde(rename = "TRY")]
+    Try,
+    #[serde(rename = "TTD")]
+    Ttd,
+    #[serde(rename = "TVD")]
+    Tvd,
+    #[serde(rename = "TWD")]
+    Twd,
+    #[serde(rename = "TZS")]
+    Tzs,
+    #[serde(rename = "UAH")]
+    Uah,
+    #[serde(rename = "UGX")]
+    Ugx,
+    #[serde(rename = "USD")]
+    Usd,
+    #[serde(rename = "UYU")]
+    Uyu,
+    #[serde(rename = "UZS")]
+    Uzs,
+    #[serde(rename = "VEF")]
+    Vef,
+    #[serde(rename = "VND")]
+    Vnd,
+    #[serde(rename = "VUV")]
+    Vuv,
+    #[serde(rename = "WST")]
+    Wst,
+    #[serde(rename = "XAF")]
+    Xaf,
+    #[serde(rename = "XCD")]
+    Xcd,
+    #[serde(rename = "XDR")]
+    Xdr,
+    #[serde(rename = "XOF")]
+    Xof,
+    #[serde(rename = "XPF")]
+    Xpf,
+    #[serde(rename = "YER")]
+    Yer,
+    #[serde(rename = "ZAR")]
+    Zar,
+    #[serde(rename = "ZMW")]
+    Zmw,
+    #[serde(rename = "ZWD")]
+    Zwd,
+    #[serde(rename = "BTC")]
+    Btc,
+}
+
+impl Currency {
+    /// Every currency, in declaration order
+    pub const ALL: [Currency; 165] = [
+        Self::Aed,
+        Self::Afn,
+        Self::All,
+        Self::Amd,
+        Self::Ang,
+        Self::Aoa,
+        Self::Ars,
+        Self::Aud,
+        Self::Awg,
+        Self::Azn,
+        Self::Bam,
+        Self::Bbd,
+        Self::Bdt,
+        Self::Bgn,
+        Self::Bhd,
+        Self::Bif,
+        Self::Bmd,
+        Self::Bnd,
+        Self::Bob,
+        Self::Brl,
+        Self::Bsd,
+        Self::Btn,
+        Self::Bwp,
+        Self::Byr,
+        Self::Bzd,
+        Self::Cad,
+        Self::Cdf,
+        Self::Chf,
+        Self::Clp,
+        Self::Cny,
+        Self::Cop,
+        Self::Crc,
+        Self::Cuc,
+        Self::Cup,
+        Self::Cve,
+        Self::Czk,
+        Self::Djf,
+        Self::Dkk,
+        Self::Dop,
+        Self::Dzd,
+        Self::Egp,
+        Self::Ern,
+        Self::Etb,
+        Self::Eur,
+        Self::Fjd,
+        Self::Fkp,
+        Self::Gbp,
+        Self::Gel,
+        Self::Ggp,
+        Self::Ghs,
+        Self::Gip,
+        Self::Gmd,
+        Self::Gnf,
+        Self::Gtq,
+        Self::Gyd,
+        Self::Hkd,
+        Self::Hnl,
+        Self::Hrk,
+        Self::Htg,
+        Self::Huf,
+        Self::Idr,
+        Self::Ils,
+        Self::Imp,
+        Self::Inr,
+        Self::Iqd,
+        Self::Irr,
+        Self::Isk,
+        Self::Jep,
+        Self::Jmd,
+        Self::Jod,
+        Self::Jpy,
+        Self::Kes,
+        Self::Kgs,
+        Self::Khr,
+        Self::Kmf,
+        Self::Kpw,
+        Self::Krw,
+        Self::Kwd,
+        Self::Kyd,
+        Self::Kzt,
+        Self::Lak,
+        Self::Lbp,
+        Self::Lkr,
+        Self::Lrd,
+        Self::Lsl,
+        Self::Ltl,
+        Self::Lvl,
+        Self::Lyd,
+        Self::Mad,
+        Self::Mdl,
+        Self::Mga,
+        Self::Mkd,
+        Self::Mmk,
+        Self::Mnt,
+        Self::Mop,
+        Self::Mro,
+        Self::Mur,
+        Self::Mvr,
+        Self::Mwk,
+        Self::Mxn,
+        Self::Myr,
+        Self::Mzn,
+        Self::Nad,
+        Self::Ngn,
+        Self::Nio,
+        Self::Nok,
+        Self::Npr,
+        Self::Nzd,
+        Self::Omr,
+        Self::Pab,
+        Self::Pen,
+        Self::Pgk,
+        Self::Php,
+        Self::Pkr,
+        Self::Pln,
+        Self::Pyg,
+        Self::Qar,
+        Self::Ron,
+        Self::Rsd,
+        Self::Rub,
+        Self::Rwf,
+        Self::Sar,
+        Self::Sbd,
+        Self::Scr,
+        Self::Sdg,
+        Self::Sek,
+        Self::Sgd,
+        Self::Shp,
+        Self::Sll,
+        Self::Sos,
+        Self::Spl,
+        Self::Srd,
+        Self::Std,
+        Self::Svc,
+        Self::Syp,
+        Self::Szl,
+        Self::Thb,
+        Self::Tjs,
+        Self::Tmt,
+        Self::Tnd,
+        Self::Top,
+        Self::Try,
+        Self::Ttd,
+        Self::Tvd,
+        Self::Twd,
+        Self::Tzs,
+        Self::Uah,
+        Self::Ugx,
+        Self::Usd,
+        Self::Uyu,
+        Self::Uzs,
+        Self::Vef,
+        Self::Vnd,
+        Self::Vuv,
+        Self::Wst,
+        Self::Xaf,
+        Self::Xcd,
+        Self::Xdr,
+        Self::Xof,
+        Self::Xpf,
+        Self::Yer,
+        Self::Zar,
+        Self::Zmw,
+        Self::Zwd,
+        Self::Btc,
+    ];
+
+    /// ISO 4217 alphabetic code, e.g. `"USD"`
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Aed => "AED",
+            Self::Afn => "AFN",
+            Self::All => "ALL",
+            Self::Amd => "AMD",
+            Self::Ang => "ANG",
+            Self::Aoa => "AOA",
+            Self::Ars => "ARS",
+            Self::Aud => "AUD",
+            Self::Awg => "AWG",
+            Self::Azn => "AZN",
+            Self::Bam => "BAM",
+            Self::Bbd => "BBD",
+            Self::Bdt => "BDT",
+            Self::Bgn => "BGN",
+            Self::Bhd => "BHD",
+            Self::Bif => "BIF",
+            Self::Bmd => "BMD",
+            Self::Bnd => "BND",
+            Self::Bob => "BOB",
+            Self::Brl => "BRL",
+            Self::Bsd => "BSD",
+            Self::Btn => "BTN",
+            Self::Bwp => "BWP",
+            Self::Byr => "BYR",
+            Self::Bzd => "BZD",
+            Self::Cad => "CAD",
+            Self::Cdf => "CDF",
+            Self::Chf => "CHF",
+            Self::Clp => "CLP",
+            Self::Cny => "CNY",
+            Self::Cop => "COP",
+            Self::Crc => "CRC",
+            Self::Cuc => "CUC",
+            Self::Cup => "CUP",
+            Self::Cve => "CVE",
+            Self::Czk => "CZK",
+            Self::Djf => "DJF",
+            Self::Dkk => "DKK",
+            Self::Dop => "DOP",
+            Self::Dzd => "DZD",
+            Self::Egp => "EGP",
+            Self::Ern => "ERN",
+            Self::Etb => "ETB",
+            Self::Eur => "EUR",
+            Self::Fjd => "FJD",
+            Self::Fkp => "FKP",
+            Self::Gbp => "GBP",
+            Self::Gel => "GEL",
+            Self::Ggp => "GGP",
+            Self::Ghs => "GHS",
+            Self::Gip => "GIP",
+            Self::Gmd => "GMD",
+            Self::Gnf => "GNF",
+            Self::Gtq => "GTQ",
+            Self::Gyd => "GYD",
+            Self::Hkd => "HKD",
+            Self::Hnl => "HNL",
+            Self::Hrk => "HRK",
+            Self::Htg => "HTG",
+            Self::Huf => "HUF",
+            Self::Idr => "IDR",
+            Self::Ils => "ILS",
+            Self::Imp => "IMP",
+            Self::Inr => "INR",
+            Self::Iqd => "IQD",
+            Self::Irr => "IRR",
+            Self::Isk => "ISK",
+            Self::Jep => "JEP",
+            Self::Jmd => "JMD",
+            Self::Jod => "JOD",
+            Self::Jpy => "JPY",
+            Self::Kes => "KES",
+            Self::Kgs => "KGS",
+            Self::Khr => "KHR",
+            Self::Kmf => "KMF",
+            Self::Kpw => "KPW",
+            Self::Krw => "KRW",
+            Self::Kwd => "KWD",
+            Self::Kyd => "KYD",
+            Self::Kzt => "KZT",
+            Self::Lak => "LAK",
+            Self::Lbp => "LBP",
+            Self::Lkr => "LKR",
+            Self::Lrd => "LRD",
+            Self::Lsl => "LSL",
+            Self::Ltl => "LTL",
+            Self::Lvl => "LVL",
+            Self::Lyd => "LYD",
+            Self::Mad => "MAD",
+            Self::Mdl => "MDL",
+            Self::Mga => "MGA",
+            Self::Mkd => "MKD",
+            Self::Mmk => "MMK",
+            Self::Mnt => "MNT",
+            Self::Mop => "MOP",
+            Self::Mro => "MRO",
+            Self::Mur => "MUR",
+            Self::Mvr => "MVR",
+            Self::Mwk => "MWK",
+            Self::Mxn => "MXN",
+            Self::Myr => "MYR",
+            Self::Mzn => "MZN",
+            Self::Nad => "NAD",
+            Self::Ngn => "NGN",
+            Self::Nio => "NIO",
+            Self::Nok => "NOK",
+            Self::Npr => "NPR",
+            Self::Nzd => "NZD",
+            Self::Omr => "OMR",
+            Self::Pab => "PAB",
+            Self::Pen => "PEN",
+            Self::Pgk => "PGK",
+            Self::Php => "PHP",
+            Self::Pkr => "PKR",
+            Self::Pln => "PLN",
+            Self::Pyg => "PYG",
+            Self::Qar => "QAR",
+            Self::Ron => "RON",
+            Self::Rsd => "RSD",
+            Self::Rub => "RUB",
+            Self::Rwf => "RWF",
+            Self::Sar => "SAR",
+            Self::Sbd => "SBD",
+            Self::Scr => "SCR",
+            Self::Sdg => "SDG",
+            Self::Sek => "SEK",
+            Self::Sgd => "SGD",
+            Self::Shp => "SHP",
+            Self::Sll => "SLL",
+            Self::Sos => "SOS",
+            Self::Spl => "SPL",
+            Self::Srd => "SRD",
+            Self::Std => "STD",
+            Self::Svc => "SVC",
+            Self::Syp => "SYP",
+            Self::Szl => "SZL",
+            Self::Thb => "THB",
+            Self::Tjs => "TJS",
+            Self::Tmt => "TMT",
+            Self::Tnd => "TND",
+            Self::Top => "TOP",
+            Self::Try => "TRY",
+            Self::Ttd => "TTD",
+            Self::Tvd => "TVD",
+            Self::Twd => "TWD",
+            Self::Tzs => "TZS",
+            Self::Uah => "UAH",
+            Self::Ugx => "UGX",
+            Self::Usd => "USD",
+            Self::Uyu => "UYU",
+            Self::Uzs => "UZS",
+            Self::Vef => "VEF",
+            Self::Vnd => "VND",
+            Self::Vuv => "VUV",
+            Self::Wst => "WST",
+            Self::Xaf => "XAF",
+            Self::Xcd => "XCD",
+            Self::Xdr => "XDR",
+            Self::Xof => "XOF",
+            Self::Xpf => "XPF",
+            Self::Yer => "YER",
+            Self::Zar => "ZAR",
+            Self::Zmw => "ZMW",
+            Self::Zwd => "ZWD",
+            Self::Btc => "BTC",
+        }
+    }
+
+    /// Number of digits after the decimal separator, e.g. 2 for USD and 0 for JPY
+    pub fn minor_units(self) -> u32 {
+        match self {
+            Self::Bif
+            | Self::Byr
+            | Self::Clp
+            | Self::Djf
+            | Self::Gnf
+            | Self::Isk
+            | Self::Jpy
+            | Self::Kmf
+            | Self::Krw
+            | Self::Pyg
+            | Self::Rwf
+            | Self::Ugx
+            | Self::Vnd
+            | Self::Vuv
+            | Self::Xaf
+            | Self::Xof
+            | Self::Xpf => 0,
+            Self::Bhd
+            | Self::Iqd
+            | Self::Jod
+            | Self::Kwd
+            | Self::Lyd
+            | Self::Omr
+            | Self::Tnd => 3,
+            Self::Btc => 8,
+            _ => 2,
+        }
+    }
+
+    /// Usual symbol, e.g. `"$"` for USD, or the ISO code when there is no unambiguous one
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Aud => "A$",
+            Self::Azn => "₼",
+            Self::Bdt => "৳",
+            Self::Brl => "R$",
+            Self::Cad => "CA$",
+            Self::Cny => "CN¥",
+            Self::Crc => "₡",
+            Self::Czk => "Kč",
+            Self::Eur => "€",
+            Self::Gbp => "£",
+            Self::Gel => "₾",
+            Self::Hkd => "HK$",
+            Self::Huf => "Ft",
+            Self::Idr => "Rp",
+            Self::Ils => "₪",
+            Self::Inr => "₹",
+            Self::Jpy => "¥",
+            Self::Khr => "៛",
+            Self::Krw => "₩",
+            Self::Kzt => "₸",
+            Self::Lak => "₭",
+            Self::Mnt => "₮",
+            Self::Mxn => "MX$",
+            Self::Myr => "RM",
+            Self::Ngn => "₦",
+            Self::Nzd => "NZ$",
+            Self::Php => "₱",
+            Self::Pln => "zł",
+            Self::Pyg => "₲",
+            Self::Rub => "₽",
+            Self::Sgd => "S$",
+            Self::Thb => "฿",
+            Self::Try => "₺",
+            Self::Twd => "NT$",
+            Self::Uah => "₴",
+            Self::Usd => "$",
+            Self::Vnd => "₫",
+            Self::Zar => "R",
+            Self::Btc => "₿",
+            _ => self.code(),
+        }
+    }
+
+    /// Format an amount with the currency symbol, rounded half away from zero to
+    /// its minor units
+    ///
+    /// `format_amount(-5, USD)` gives `"-$5.00"`; currencies without a symbol are
+    /// suffixed with their code instead, as in `"12.50 CHF"`.
+    pub fn format_amount(self, amount: models::Decimal) -> String {
+        let precision = self.minor_units() as usize;
+        let amount = round(amount, self.minor_units());
+        let sign = if amount < models::Decimal::default() { "-" } else { "" };
+        let amount = amount.abs();
+
+        if self.symbol() == self.code() {
+            format!("{sign}{amount:.precision$} {}", self.code())
+        } else {
+            format!("{sign}{}{amount:.precision$}", self.symbol())
+        }
+    }
+}
+
+/// Round half away from zero to `decimals` decimal places
+fn round(amount: models::Decimal, decimals: u32) -> models::Decimal {
+    amount.round_dp_with_strategy(decimals, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Error returned when parsing an unknown currency code
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown currency code: {0}")]
+pub struct UnknownCurrency(pub String);
+
+impl FromStr for Currency {
+    type Err = UnknownCurrency;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|currency| currency.code().eq_ignore_ascii_case(code))
+            .ok_or_else(|| UnknownCurrency(code.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ Account, InvoiceItem };
+
+    #[test]
+    fn test_currency_is_shared_across_models() {
+        let account: Account = serde_json::from_str(r#"{"currency":"EUR"}"#).unwrap();
+        let item = InvoiceItem {
+            currency: account.currency,
+            ..Default::default()
+        };
+
+        assert_eq!(item.currency, Some(Currency::Eur));
+        assert_eq!(serde_json::to_value(item.currency).unwrap(), "EUR");
+    }
+
+    #[test]
+    fn test_metadata() {
+        assert_eq!(Currency::Usd.minor_units(), 2);
+        assert_eq!(Currency::Jpy.minor_units(), 0);
+        assert_eq!(Currency::Kwd.minor_units(), 3);
+        assert_eq!("usd".parse::<Currency>(), Ok(Currency::Usd));
+        assert!("XYZ".parse::<Currency>().is_err());
+
+        for currency in Currency::ALL {
+            assert_eq!(currency.code().parse::<Currency>(), Ok(currency));
+        }
+    }
+
+    #[test]
+    fn test_format_amount() {
+        let amount = |value: &str| value.parse::<models::Decimal>().unwrap();
+
+        assert_eq!(Currency::Usd.format_amount(amount("19.99")), "$19.99");
+        assert_eq!(Currency::Usd.format_amount(amount("-5")), "-$5.00");
+        assert_eq!(Currency::Jpy.format_amount(amount("1200")), "¥1200");
+        assert_eq!(Currency::Chf.format_amount(amount("12.5")), "12.50 CHF");
+        assert_eq!(Currency::Kwd.format_amount(amount("1.5")), "1.500 KWD");
+        assert_eq!(Currency::Usd.format_amount(amount("19.999")), "$20.00");
+        assert_eq!(Currency::Usd.format_amount(amount("0.125")), "$0.13");
+        assert_eq!(Currency::Usd.format_amount(amount("-0.004")), "$0.00");
+        assert_eq!(Currency::Jpy.format_amount(amount("-1199.5")), "-¥1200");
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Point of a [`TimeSeries`](models::TimeSeries), `x` being the day
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataPoint {
+    #[serde(rename = "x", skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    #[serde(rename = "y", skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub y: Option<models::Decimal>,
+}
+
+impl DataPoint {
+    pub fn new() -> DataPoint {
+        DataPoint {
+            x: None,
+            y: None,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+//! Date types used by the models
+//!
+//! `LocalDate` fields (e.g. `2024-01-31`) map to [`chrono::NaiveDate`] and
+//! `DateTime` fields (e.g. `2024-01-31T10:00:00.000Z`) to
+//! [`chrono::DateTime<Utc>`](chrono::DateTime).
+
+/// A Kill Bill `LocalDate`, without time nor time zone
+pub type Date = chrono::NaiveDate;
+
+/// A Kill Bill `DateTime`, always in UTC
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+/// A value accepted by date query parameters such as `requestedDate`
+///
+/// Kill Bill accepts either a `LocalDate` or a full `DateTime` for these.
+pub trait DateParam {
+    fn into_param(self) -> String;
+}
+
+impl DateParam for String {
+    fn into_param(self) -> String {
+        self
+    }
+}
+
+impl DateParam for &str {
+    fn into_param(self) -> String {
+        self.to_owned()
+    }
+}
+
+impl DateParam for &String {
+    fn into_param(self) -> String {
+        self.clone()
+    }
+}
+
+impl DateParam for chrono::NaiveDate {
+    fn into_param(self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+impl DateParam for chrono::DateTime<chrono::Utc> {
+    fn into_param(self) -> String {
+        self.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ AuditLog, Invoice };
+
+    #[test]
+    fn test_deserializes_dates() {
+        let invoice: Invoice = serde_json::from_str(
+            r#"{"invoiceDate":"2024-01-31","targetDate":"2024-02-29"}"#
+        ).unwrap();
+        let audit_log: AuditLog = serde_json::from_str(
+            r#"{"changeDate":"2024-01-31T10:00:00.000Z"}"#
+        ).unwrap();
+
+        let days = invoice.target_date.unwrap() - invoice.invoice_date.unwrap();
+        assert_eq!(days.num_days(), 29);
+        assert_eq!(
+            audit_log.change_date.unwrap().date_naive(),
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_date_params() {
+        use chrono::TimeZone;
+
+        assert_eq!("2024-01-31".into_param(), "2024-01-31");
+
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert_eq!(date.into_param(), "2024-01-31");
+
+        let date_time = chrono::Utc.with_ymd_and_hms(2024, 1, 31, 10, 0, 0).unwrap();
+        assert_eq!(date_time.into_param(), "2024-01-31T10:00:00.000Z");
+    }
+}
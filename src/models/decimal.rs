@@ -0,0 +1,58 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+//! Decimal type used for amounts, prices and quantities
+//!
+//! Kill Bill models these as `BigDecimal`, mapped to [`rust_decimal::Decimal`].
+//! The model fields read and write them as JSON numbers through serde_json's
+//! `arbitrary_precision`, parsing the digits of each number as sent, so neither
+//! `19.99` nor an amount with more digits than an `f64` holds goes through a
+//! float.
+
+/// A Kill Bill `BigDecimal`
+pub type Decimal = rust_decimal::Decimal;
+
+#[cfg(test)]
+mod tests {
+    use crate::models::Invoice;
+
+    #[test]
+    fn test_amounts_round_trip() {
+        let json = r#"{"amount":19.99,"balance":0.1}"#;
+        let invoice: Invoice = serde_json::from_str(json).unwrap();
+
+        assert_eq!(invoice.amount, Some("19.99".parse().unwrap()));
+        assert_eq!(invoice.amount.unwrap() + invoice.balance.unwrap(), "20.09".parse().unwrap());
+
+        // Only the model fields are written as numbers, `Decimal` keeps its own format
+        assert_eq!(serde_json::to_string(&invoice.amount).unwrap(), r#""19.99""#);
+
+        let value = serde_json::to_value(&invoice).unwrap();
+        assert_eq!(value["amount"].to_string(), "19.99");
+        assert_eq!(value["balance"].to_string(), "0.1");
+    }
+
+    #[test]
+    fn test_amounts_keep_all_their_digits() {
+        let json = r#"{"amount":12345678901234567.89,"balance":0.1234567890123456789}"#;
+        let invoice: Invoice = serde_json::from_str(json).unwrap();
+
+        assert_eq!(invoice.amount, Some("12345678901234567.89".parse().unwrap()));
+        assert_eq!(invoice.balance, Some("0.1234567890123456789".parse().unwrap()));
+
+        let value = serde_json::to_value(&invoice).unwrap();
+        assert_eq!(value["amount"].to_string(), "12345678901234567.89");
+        assert_eq!(value["balance"].to_string(), "0.1234567890123456789");
+
+        let written = serde_json::to_string(&invoice).unwrap();
+        assert!(written.contains(r#""amount":12345678901234567.89"#));
+        assert!(written.contains(r#""balance":0.1234567890123456789"#));
+    }
+}
@@ -18,9 +18,9 @@ pub struct EventSubscription {
     #[serde(rename = "billingPeriod", skip_serializing_if = "Option::is_none")]
     pub billing_period: Option<BillingPeriod>,
     #[serde(rename = "effectiveDate", skip_serializing_if = "Option::is_none")]
-    pub effective_date: Option<String>,
+    pub effective_date: Option<models::DateTime>,
     #[serde(rename = "catalogEffectiveDate", skip_serializing_if = "Option::is_none")]
-    pub catalog_effective_date: Option<String>,
+    pub catalog_effective_date: Option<models::DateTime>,
     #[serde(rename = "plan", skip_serializing_if = "Option::is_none")]
     pub plan: Option<String>,
     #[serde(rename = "product", skip_serializing_if = "Option::is_none")]
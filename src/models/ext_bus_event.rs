@@ -0,0 +1,162 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use serde::{Deserialize, Serialize};
+
+/// Event Kill Bill POSTs to the push-notification callbacks of a tenant
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExtBusEvent {
+    #[serde(rename = "eventType", skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<ExtBusEventType>,
+    #[serde(rename = "objectType", skip_serializing_if = "Option::is_none")]
+    pub object_type: Option<ExtBusObjectType>,
+    #[serde(rename = "objectId", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<uuid::Uuid>,
+    #[serde(rename = "accountId", skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<uuid::Uuid>,
+    /// JSON document whose content depends on the event type, see [`ExtBusEvent::parse_meta_data`]
+    #[serde(rename = "metaData", skip_serializing_if = "Option::is_none")]
+    pub meta_data: Option<String>,
+}
+
+impl ExtBusEvent {
+    pub fn new() -> ExtBusEvent {
+        ExtBusEvent {
+            event_type: None,
+            object_type: None,
+            object_id: None,
+            account_id: None,
+            meta_data: None,
+        }
+    }
+
+    /// Parse the metadata, e.g. the payment transaction of `PAYMENT_SUCCESS` events
+    pub fn parse_meta_data<T: serde::de::DeserializeOwned>(&self) -> Option<serde_json::Result<T>> {
+        self.meta_data.as_deref().map(serde_json::from_str)
+    }
+}
+
+/// Type of an [`ExtBusEvent`]
+///
+/// Types added by newer Kill Bill versions are read as `Unknown`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ExtBusEventType {
+    #[default]
+    #[serde(rename = "ACCOUNT_CREATION")]
+    AccountCreation,
+    #[serde(rename = "ACCOUNT_CHANGE")]
+    AccountChange,
+    #[serde(rename = "BLOCKING_STATE")]
+    BlockingState,
+    #[serde(rename = "BROADCAST_SERVICE")]
+    BroadcastService,
+    #[serde(rename = "SUBSCRIPTION_CREATION")]
+    SubscriptionCreation,
+    #[serde(rename = "SUBSCRIPTION_PHASE")]
+    SubscriptionPhase,
+    #[serde(rename = "SUBSCRIPTION_CHANGE")]
+    SubscriptionChange,
+    #[serde(rename = "SUBSCRIPTION_CANCEL")]
+    SubscriptionCancel,
+    #[serde(rename = "SUBSCRIPTION_UNCANCEL")]
+    SubscriptionUncancel,
+    #[serde(rename = "SUBSCRIPTION_BCD_CHANGE")]
+    SubscriptionBcdChange,
+    #[serde(rename = "SUBSCRIPTION_EXPIRED")]
+    SubscriptionExpired,
+    #[serde(rename = "ENTITLEMENT_CREATION")]
+    EntitlementCreation,
+    #[serde(rename = "ENTITLEMENT_CANCEL")]
+    EntitlementCancel,
+    #[serde(rename = "BUNDLE_PAUSE")]
+    BundlePause,
+    #[serde(rename = "BUNDLE_RESUME")]
+    BundleResume,
+    #[serde(rename = "OVERDUE_CHANGE")]
+    OverdueChange,
+    #[serde(rename = "INVOICE_CREATION")]
+    InvoiceCreation,
+    #[serde(rename = "INVOICE_ADJUSTMENT")]
+    InvoiceAdjustment,
+    #[serde(rename = "INVOICE_NOTIFICATION")]
+    InvoiceNotification,
+    #[serde(rename = "INVOICE_PAYMENT_SUCCESS")]
+    InvoicePaymentSuccess,
+    #[serde(rename = "INVOICE_PAYMENT_FAILED")]
+    InvoicePaymentFailed,
+    #[serde(rename = "PAYMENT_SUCCESS")]
+    PaymentSuccess,
+    #[serde(rename = "PAYMENT_FAILED")]
+    PaymentFailed,
+    #[serde(rename = "PAYMENT_PLUGIN_ERROR")]
+    PaymentPluginError,
+    #[serde(rename = "TAG_CREATION")]
+    TagCreation,
+    #[serde(rename = "TAG_DELETION")]
+    TagDeletion,
+    #[serde(rename = "CUSTOM_FIELD_CREATION")]
+    CustomFieldCreation,
+    #[serde(rename = "CUSTOM_FIELD_DELETION")]
+    CustomFieldDeletion,
+    #[serde(rename = "TENANT_CONFIG_CHANGE")]
+    TenantConfigChange,
+    #[serde(rename = "TENANT_CONFIG_DELETION")]
+    TenantConfigDeletion,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Type of the object an [`ExtBusEvent`] is about
+///
+/// Types added by newer Kill Bill versions are read as `Unknown`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ExtBusObjectType {
+    #[default]
+    #[serde(rename = "ACCOUNT")]
+    Account,
+    #[serde(rename = "ACCOUNT_EMAIL")]
+    AccountEmail,
+    #[serde(rename = "BLOCKING_STATES")]
+    BlockingStates,
+    #[serde(rename = "BUNDLE")]
+    Bundle,
+    #[serde(rename = "CUSTOM_FIELD")]
+    CustomField,
+    #[serde(rename = "INVOICE")]
+    Invoice,
+    #[serde(rename = "PAYMENT")]
+    Payment,
+    #[serde(rename = "TRANSACTION")]
+    Transaction,
+    #[serde(rename = "INVOICE_ITEM")]
+    InvoiceItem,
+    #[serde(rename = "INVOICE_PAYMENT")]
+    InvoicePayment,
+    #[serde(rename = "SUBSCRIPTION")]
+    Subscription,
+    #[serde(rename = "SUBSCRIPTION_EVENT")]
+    SubscriptionEvent,
+    #[serde(rename = "SERVICE_BROADCAST")]
+    ServiceBroadcast,
+    #[serde(rename = "PAYMENT_ATTEMPT")]
+    PaymentAttempt,
+    #[serde(rename = "PAYMENT_METHOD")]
+    PaymentMethod,
+    #[serde(rename = "TAG")]
+    Tag,
+    #[serde(rename = "TAG_DEFINITION")]
+    TagDefinition,
+    #[serde(rename = "TENANT")]
+    Tenant,
+    #[serde(rename = "TENANT_KVS")]
+    TenantKvs,
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,63 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use uuid::Uuid;
+
+/// A value accepted by id parameters such as `account_id`
+///
+/// Either a [`Uuid`], as found on the models, or a string holding one.
+/// Strings are parsed when set and rejected by the request builder if they
+/// are not valid UUIDs.
+pub trait IdParam {
+    fn into_id(self) -> Result<Uuid, InvalidId>;
+}
+
+/// String given as an id that is not a valid UUID
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{value:?} is not a valid UUID")]
+pub struct InvalidId {
+    pub value: String,
+}
+
+impl InvalidId {
+    fn parse(value: &str) -> Result<Uuid, Self> {
+        Uuid::parse_str(value).map_err(|_| Self { value: value.to_owned() })
+    }
+}
+
+impl IdParam for Uuid {
+    fn into_id(self) -> Result<Uuid, InvalidId> {
+        Ok(self)
+    }
+}
+
+impl IdParam for &Uuid {
+    fn into_id(self) -> Result<Uuid, InvalidId> {
+        Ok(*self)
+    }
+}
+
+impl IdParam for &str {
+    fn into_id(self) -> Result<Uuid, InvalidId> {
+        InvalidId::parse(self)
+    }
+}
+
+impl IdParam for String {
+    fn into_id(self) -> Result<Uuid, InvalidId> {
+        InvalidId::parse(&self)
+    }
+}
+
+impl IdParam for &String {
+    fn into_id(self) -> Result<Uuid, InvalidId> {
+        InvalidId::parse(self)
+    }
+}
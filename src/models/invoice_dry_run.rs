@@ -32,7 +32,7 @@ pub struct InvoiceDryRun {
     #[serde(rename = "bundleId", skip_serializing_if = "Option::is_none")]
     pub bundle_id: Option<uuid::Uuid>,
     #[serde(rename = "effectiveDate", skip_serializing_if = "Option::is_none")]
-    pub effective_date: Option<String>,
+    pub effective_date: Option<models::Date>,
     #[serde(rename = "billingPolicy", skip_serializing_if = "Option::is_none")]
     pub billing_policy: Option<BillingPolicy>,
     #[serde(rename = "priceOverrides", skip_serializing_if = "Option::is_none")]
@@ -24,17 +24,22 @@ pub struct InvoicePayment {
     #[serde(rename = "paymentExternalKey", skip_serializing_if = "Option::is_none")]
     pub payment_external_key: Option<String>,
     #[serde(rename = "authAmount", skip_serializing_if = "Option::is_none")]
-    pub auth_amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub auth_amount: Option<models::Decimal>,
     #[serde(rename = "capturedAmount", skip_serializing_if = "Option::is_none")]
-    pub captured_amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub captured_amount: Option<models::Decimal>,
     #[serde(rename = "purchasedAmount", skip_serializing_if = "Option::is_none")]
-    pub purchased_amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub purchased_amount: Option<models::Decimal>,
     #[serde(rename = "refundedAmount", skip_serializing_if = "Option::is_none")]
-    pub refunded_amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub refunded_amount: Option<models::Decimal>,
     #[serde(rename = "creditedAmount", skip_serializing_if = "Option::is_none")]
-    pub credited_amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub credited_amount: Option<models::Decimal>,
     #[serde(rename = "currency", skip_serializing_if = "Option::is_none")]
-    pub currency: Option<Currency>,
+    pub currency: Option<models::Currency>,
     #[serde(rename = "paymentMethodId", skip_serializing_if = "Option::is_none")]
     pub payment_method_id: Option<uuid::Uuid>,
     #[serde(rename = "transactions", skip_serializing_if = "Option::is_none")]
@@ -66,344 +71,3 @@ impl InvoicePayment {
         }
     }
 }
-/// 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
-pub enum Currency {
-    #[serde(rename = "AED")]
-    Aed,
-    #[serde(rename = "AFN")]
-    Afn,
-    #[serde(rename = "ALL")]
-    All,
-    #[serde(rename = "AMD")]
-    Amd,
-    #[serde(rename = "ANG")]
-    Ang,
-    #[serde(rename = "AOA")]
-    Aoa,
-    #[serde(rename = "ARS")]
-    Ars,
-    #[serde(rename = "AUD")]
-    Aud,
-    #[serde(rename = "AWG")]
-    Awg,
-    #[serde(rename = "AZN")]
-    Azn,
-    #[serde(rename = "BAM")]
-    Bam,
-    #[serde(rename = "BBD")]
-    Bbd,
-    #[serde(rename = "BDT")]
-    Bdt,
-    #[serde(rename = "BGN")]
-    Bgn,
-    #[serde(rename = "BHD")]
-    Bhd,
-    #[serde(rename = "BIF")]
-    Bif,
-    #[serde(rename = "BMD")]
-    Bmd,
-    #[serde(rename = "BND")]
-    Bnd,
-    #[serde(rename = "BOB")]
-    Bob,
-    #[serde(rename = "BRL")]
-    Brl,
-    #[serde(rename = "BSD")]
-    Bsd,
-    #[serde(rename = "BTN")]
-    Btn,
-    #[serde(rename = "BWP")]
-    Bwp,
-    #[serde(rename = "BYR")]
-    Byr,
-    #[serde(rename = "BZD")]
-    Bzd,
-    #[serde(rename = "CAD")]
-    Cad,
-    #[serde(rename = "CDF")]
-    Cdf,
-    #[serde(rename = "CHF")]
-    Chf,
-    #[serde(rename = "CLP")]
-    Clp,
-    #[serde(rename = "CNY")]
-    Cny,
-    #[serde(rename = "COP")]
-    Cop,
-    #[serde(rename = "CRC")]
-    Crc,
-    #[serde(rename = "CUC")]
-    Cuc,
-    #[serde(rename = "CUP")]
-    Cup,
-    #[serde(rename = "CVE")]
-    Cve,
-    #[serde(rename = "CZK")]
-    Czk,
-    #[serde(rename = "DJF")]
-    Djf,
-    #[serde(rename = "DKK")]
-    Dkk,
-    #[serde(rename = "DOP")]
-    Dop,
-    #[serde(rename = "DZD")]
-    Dzd,
-    #[serde(rename = "EGP")]
-    Egp,
-    #[serde(rename = "ERN")]
-    Ern,
-    #[serde(rename = "ETB")]
-    Etb,
-    #[serde(rename = "EUR")]
-    Eur,
-    #[serde(rename = "FJD")]
-    Fjd,
-    #[serde(rename = "FKP")]
-    Fkp,
-    #[serde(rename = "GBP")]
-    Gbp,
-    #[serde(rename = "GEL")]
-    Gel,
-    #[serde(rename = "GGP")]
-    Ggp,
-    #[serde(rename = "GHS")]
-    Ghs,
-    #[serde(rename = "GIP")]
-    Gip,
-    #[serde(rename = "GMD")]
-    Gmd,
-    #[serde(rename = "GNF")]
-    Gnf,
-    #[serde(rename = "GTQ")]
-    Gtq,
-    #[serde(rename = "GYD")]
-    Gyd,
-    #[serde(rename = "HKD")]
-    Hkd,
-    #[serde(rename = "HNL")]
-    Hnl,
-    #[serde(rename = "HRK")]
-    Hrk,
-    #[serde(rename = "HTG")]
-    Htg,
-    #[serde(rename = "HUF")]
-    Huf,
-    #[serde(rename = "IDR")]
-    Idr,
-    #[serde(rename = "ILS")]
-    Ils,
-    #[serde(rename = "IMP")]
-    Imp,
-    #[serde(rename = "INR")]
-    Inr,
-    #[serde(rename = "IQD")]
-    Iqd,
-    #[serde(rename = "IRR")]
-    Irr,
-    #[serde(rename = "ISK")]
-    Isk,
-    #[serde(rename = "JEP")]
-    Jep,
-    #[serde(rename = "JMD")]
-    Jmd,
-    #[serde(rename = "JOD")]
-    Jod,
-    #[serde(rename = "JPY")]
-    Jpy,
-    #[serde(rename = "KES")]
-    Kes,
-    #[serde(rename = "KGS")]
-    Kgs,
-    #[serde(rename = "KHR")]
-    Khr,
-    #[serde(rename = "KMF")]
-    Kmf,
-    #[serde(rename = "KPW")]
-    Kpw,
-    #[serde(rename = "KRW")]
-    Krw,
-    #[serde(rename = "KWD")]
-    Kwd,
-    #[serde(rename = "KYD")]
-    Kyd,
-    #[serde(rename = "KZT")]
-    Kzt,
-    #[serde(rename = "LAK")]
-    Lak,
-    #[serde(rename = "LBP")]
-    Lbp,
-    #[serde(rename = "LKR")]
-    Lkr,
-    #[serde(rename = "LRD")]
-    Lrd,
-    #[serde(rename = "LSL")]
-    Lsl,
-    #[serde(rename = "LTL")]
-    Ltl,
-    #[serde(rename = "LVL")]
-    Lvl,
-    #[serde(rename = "LYD")]
-    Lyd,
-    #[serde(rename = "MAD")]
-    Mad,
-    #[serde(rename = "MDL")]
-    Mdl,
-    #[serde(rename = "MGA")]
-    Mga,
-    #[serde(rename = "MKD")]
-    Mkd,
-    #[serde(rename = "MMK")]
-    Mmk,
-    #[serde(rename = "MNT")]
-    Mnt,
-    #[serde(rename = "MOP")]
-    Mop,
-    #[serde(rename = "MRO")]
-    Mro,
-    #[serde(rename = "MUR")]
-    Mur,
-    #[serde(rename = "MVR")]
-    Mvr,
-    #[serde(rename = "MWK")]
-    Mwk,
-    #[serde(rename = "MXN")]
-    Mxn,
-    #[serde(rename = "MYR")]
-    Myr,
-    #[serde(rename = "MZN")]
-    Mzn,
-    #[serde(rename = "NAD")]
-    Nad,
-    #[serde(rename = "NGN")]
-    Ngn,
-    #[serde(rename = "NIO")]
-    Nio,
-    #[serde(rename = "NOK")]
-    Nok,
-    #[serde(rename = "NPR")]
-    Npr,
-    #[serde(rename = "NZD")]
-    Nzd,
-    #[serde(rename = "OMR")]
-    Omr,
-    #[serde(rename = "PAB")]
-    Pab,
-    #[serde(rename = "PEN")]
-    Pen,
-    #[serde(rename = "PGK")]
-    Pgk,
-    #[serde(rename = "PHP")]
-    Php,
-    #[serde(rename = "PKR")]
-    Pkr,
-    #[serde(rename = "PLN")]
-    Pln,
-    #[serde(rename = "PYG")]
-    Pyg,
-    #[serde(rename = "QAR")]
-    Qar,
-    #[serde(rename = "RON")]
-    Ron,
-    #[serde(rename = "RSD")]
-    Rsd,
-    #[serde(rename = "RUB")]
-    Rub,
-    #[serde(rename = "RWF")]
-    Rwf,
-    #[serde(rename = "SAR")]
-    Sar,
-    #[serde(rename = "SBD")]
-    Sbd,
-    #[serde(rename = "SCR")]
-    Scr,
-    #[serde(rename = "SDG")]
-    Sdg,
-    #[serde(rename = "SEK")]
-    Sek,
-    #[serde(rename = "SGD")]
-    Sgd,
-    #[serde(rename = "SHP")]
-    Shp,
-    #[serde(rename = "SLL")]
-    Sll,
-    #[serde(rename = "SOS")]
-    Sos,
-    #[serde(rename = "SPL")]
-    Spl,
-    #[serde(rename = "SRD")]
-    Srd,
-    #[serde(rename = "STD")]
-    Std,
-    #[serde(rename = "SVC")]
-    Svc,
-    #[serde(rename = "SYP")]
-    Syp,
-    #[serde(rename = "SZL")]
-    Szl,
-    #[serde(rename = "THB")]
-    Thb,
-    #[serde(rename = "TJS")]
-    Tjs,
-    #[serde(rename = "TMT")]
-    Tmt,
-    #[serde(rename = "TND")]
-    Tnd,
-    #[serde(rename = "TOP")]
-    Top,
-    #[serde(rename = "TRY")]
-    Try,
-    #[serde(rename = "TTD")]
-    Ttd,
-    #[serde(rename = "TVD")]
-    Tvd,
-    #[serde(rename = "TWD")]
-    Twd,
-    #[serde(rename = "TZS")]
-    Tzs,
-    #[serde(rename = "UAH")]
-    Uah,
-    #[serde(rename = "UGX")]
-    Ugx,
-    #[serde(rename = "USD")]
-    Usd,
-    #[serde(rename = "UYU")]
-    Uyu,
-    #[serde(rename = "UZS")]
-    Uzs,
-    #[serde(rename = "VEF")]
-    Vef,
-    #[serde(rename = "VND")]
-    Vnd,
-    #[serde(rename = "VUV")]
-    Vuv,
-    #[serde(rename = "WST")]
-    Wst,
-    #[serde(rename = "XAF")]
-    Xaf,
-    #[serde(rename = "XCD")]
-    Xcd,
-    #[serde(rename = "XDR")]
-    Xdr,
-    #[serde(rename = "XOF")]
-    Xof,
-    #[serde(rename = "XPF")]
-    Xpf,
-    #[serde(rename = "YER")]
-    Yer,
-    #[serde(rename = "ZAR")]
-    Zar,
-    #[serde(rename = "ZMW")]
-    Zmw,
-    #[serde(rename = "ZWD")]
-    Zwd,
-    #[serde(rename = "BTC")]
-    Btc,
-}
-
-impl Default for Currency {
-    fn default() -> Currency {
-        Self::Aed
-    }
-}
-
@@ -8,6 +8,8 @@ pub mod admin_payment;
 pub use self::admin_payment::AdminPayment;
 pub mod audit_log;
 pub use self::audit_log::AuditLog;
+pub mod audit_level;
+pub use self::audit_level::AuditLevel;
 pub mod block_price;
 pub use self::block_price::BlockPrice;
 pub mod blocking_state;
@@ -18,28 +20,54 @@ pub mod bundle;
 pub use self::bundle::Bundle;
 pub mod bundle_timeline;
 pub use self::bundle_timeline::BundleTimeline;
+pub mod business_account;
+pub use self::business_account::BusinessAccount;
+pub mod business_invoice;
+pub use self::business_invoice::BusinessInvoice;
+pub mod business_invoice_item;
+pub use self::business_invoice_item::BusinessInvoiceItem;
+pub mod business_snapshot;
+pub use self::business_snapshot::BusinessSnapshot;
+pub mod business_subscription;
+pub use self::business_subscription::BusinessSubscription;
+pub mod business_subscription_transition;
+pub use self::business_subscription_transition::BusinessSubscriptionTransition;
 pub mod catalog;
 pub use self::catalog::Catalog;
 pub mod catalog_validation;
 pub use self::catalog_validation::CatalogValidation;
 pub mod catalog_validation_error;
 pub use self::catalog_validation_error::CatalogValidationError;
+pub mod clock;
+pub use self::clock::Clock;
 pub mod combo_hosted_payment_page;
 pub use self::combo_hosted_payment_page::ComboHostedPaymentPage;
 pub mod combo_payment_transaction;
 pub use self::combo_payment_transaction::ComboPaymentTransaction;
+pub mod currency;
+pub use self::currency::Currency;
 pub mod custom_field;
 pub use self::custom_field::CustomField;
+pub mod data_point;
+pub use self::data_point::DataPoint;
+pub mod date;
+pub use self::date::{ Date, DateParam, DateTime };
+pub mod decimal;
+pub use self::decimal::Decimal;
 pub mod duration;
 pub use self::duration::Duration;
 pub mod entity;
 pub use self::entity::Entity;
 pub mod event_subscription;
 pub use self::event_subscription::EventSubscription;
+pub mod ext_bus_event;
+pub use self::ext_bus_event::{ ExtBusEvent, ExtBusEventType, ExtBusObjectType };
 pub mod hosted_payment_page_fields;
 pub use self::hosted_payment_page_fields::HostedPaymentPageFields;
 pub mod hosted_payment_page_form_descriptor;
 pub use self::hosted_payment_page_form_descriptor::HostedPaymentPageFormDescriptor;
+pub mod id;
+pub use self::id::{ IdParam, InvalidId };
 pub mod invoice;
 pub use self::invoice::Invoice;
 pub mod invoice_dry_run;
@@ -96,6 +124,10 @@ pub mod price_list;
 pub use self::price_list::PriceList;
 pub mod product;
 pub use self::product::Product;
+pub mod report;
+pub use self::report::{ Report, ReportType };
+pub mod report_configuration;
+pub use self::report_configuration::ReportConfiguration;
 pub mod role_definition;
 pub use self::role_definition::RoleDefinition;
 pub mod rolled_up_unit;
@@ -126,6 +158,8 @@ pub mod tier_price;
 pub use self::tier_price::TierPrice;
 pub mod tiered_block;
 pub use self::tiered_block::TieredBlock;
+pub mod time_series;
+pub use self::time_series::TimeSeries;
 pub mod unit;
 pub use self::unit::Unit;
 pub mod unit_usage_record;
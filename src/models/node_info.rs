@@ -16,9 +16,9 @@ pub struct NodeInfo {
     #[serde(rename = "nodeName", skip_serializing_if = "Option::is_none")]
     pub node_name: Option<String>,
     #[serde(rename = "bootTime", skip_serializing_if = "Option::is_none")]
-    pub boot_time: Option<String>,
+    pub boot_time: Option<models::DateTime>,
     #[serde(rename = "lastUpdatedDate", skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<String>,
+    pub last_updated_date: Option<models::DateTime>,
     #[serde(rename = "kbVersion", skip_serializing_if = "Option::is_none")]
     pub kb_version: Option<String>,
     #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
@@ -24,7 +24,8 @@ pub struct OverdueCondition {
     #[serde(rename = "responseForLastFailedPayment", skip_serializing_if = "Option::is_none")]
     pub response_for_last_failed_payment: Option<Vec<ResponseForLastFailedPayment>>,
     #[serde(rename = "totalUnpaidInvoiceBalanceEqualsOrExceeds", skip_serializing_if = "Option::is_none")]
-    pub total_unpaid_invoice_balance_equals_or_exceeds: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub total_unpaid_invoice_balance_equals_or_exceeds: Option<models::Decimal>,
 }
 
 impl OverdueCondition {
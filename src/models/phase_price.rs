@@ -20,9 +20,11 @@ pub struct PhasePrice {
     #[serde(rename = "phaseType", skip_serializing_if = "Option::is_none")]
     pub phase_type: Option<String>,
     #[serde(rename = "fixedPrice", skip_serializing_if = "Option::is_none")]
-    pub fixed_price: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub fixed_price: Option<models::Decimal>,
     #[serde(rename = "recurringPrice", skip_serializing_if = "Option::is_none")]
-    pub recurring_price: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub recurring_price: Option<models::Decimal>,
     #[serde(rename = "usagePrices", skip_serializing_if = "Option::is_none")]
     pub usage_prices: Option<Vec<models::UsagePrice>>,
 }
@@ -0,0 +1,55 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Data of an analytics report over a period
+///
+/// The shape of `data` depends on the report type; timeline reports can be
+/// read with [`Report::time_series`].
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub report_type: Option<ReportType>,
+    #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+impl Report {
+    pub fn new() -> Report {
+        Report {
+            title: None,
+            report_type: None,
+            data: None,
+        }
+    }
+
+    /// The series of a timeline report, `None` for other report types
+    pub fn time_series(&self) -> Option<Vec<models::TimeSeries>> {
+        if self.report_type != Some(ReportType::Timeline) {
+            return None;
+        }
+        self.data.clone().and_then(|data| serde_json::from_value(data).ok())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ReportType {
+    #[default]
+    #[serde(rename = "TIMELINE")]
+    Timeline,
+    #[serde(rename = "COUNTERS")]
+    Counters,
+    #[serde(rename = "TABLE")]
+    Table,
+}
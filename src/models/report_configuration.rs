@@ -0,0 +1,45 @@
+/*
+ * Kill Bill
+ *
+ * Kill Bill is an open-source billing and payments platform
+ *
+ * The version of the OpenAPI document: 0.24.10
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Report registered in the analytics plugin
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReportConfiguration {
+    #[serde(rename = "reportName", skip_serializing_if = "Option::is_none")]
+    pub report_name: Option<String>,
+    #[serde(rename = "reportPrettyName", skip_serializing_if = "Option::is_none")]
+    pub report_pretty_name: Option<String>,
+    #[serde(rename = "reportType", skip_serializing_if = "Option::is_none")]
+    pub report_type: Option<models::ReportType>,
+    #[serde(rename = "sourceTableName", skip_serializing_if = "Option::is_none")]
+    pub source_table_name: Option<String>,
+    #[serde(rename = "refreshProcedureName", skip_serializing_if = "Option::is_none")]
+    pub refresh_procedure_name: Option<String>,
+    #[serde(rename = "refreshFrequency", skip_serializing_if = "Option::is_none")]
+    pub refresh_frequency: Option<String>,
+    #[serde(rename = "refreshHourOfDayGmt", skip_serializing_if = "Option::is_none")]
+    pub refresh_hour_of_day_gmt: Option<i32>,
+}
+
+impl ReportConfiguration {
+    pub fn new() -> ReportConfiguration {
+        ReportConfiguration {
+            report_name: None,
+            report_pretty_name: None,
+            report_type: None,
+            source_table_name: None,
+            refresh_procedure_name: None,
+            refresh_frequency: None,
+            refresh_hour_of_day_gmt: None,
+        }
+    }
+}
@@ -16,7 +16,8 @@ pub struct RolledUpUnit {
     #[serde(rename = "unitType", skip_serializing_if = "Option::is_none")]
     pub unit_type: Option<String>,
     #[serde(rename = "amount", skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub amount: Option<models::Decimal>,
 }
 
 impl RolledUpUnit {
@@ -16,9 +16,9 @@ pub struct RolledUpUsage {
     #[serde(rename = "subscriptionId", skip_serializing_if = "Option::is_none")]
     pub subscription_id: Option<uuid::Uuid>,
     #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<String>,
+    pub start_date: Option<models::DateTime>,
     #[serde(rename = "endDate", skip_serializing_if = "Option::is_none")]
-    pub end_date: Option<String>,
+    pub end_date: Option<models::DateTime>,
     #[serde(rename = "rolledUpUnits", skip_serializing_if = "Option::is_none")]
     pub rolled_up_units: Option<Vec<models::RolledUpUnit>>,
 }
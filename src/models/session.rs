@@ -16,9 +16,9 @@ pub struct Session {
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<String>,
+    pub start_date: Option<models::DateTime>,
     #[serde(rename = "lastAccessDate", skip_serializing_if = "Option::is_none")]
-    pub last_access_date: Option<String>,
+    pub last_access_date: Option<models::DateTime>,
     #[serde(rename = "timeout", skip_serializing_if = "Option::is_none")]
     pub timeout: Option<i64>,
     #[serde(rename = "host", skip_serializing_if = "Option::is_none")]
@@ -24,7 +24,7 @@ pub struct Subscription {
     #[serde(rename = "externalKey", skip_serializing_if = "Option::is_none")]
     pub external_key: Option<String>,
     #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<String>,
+    pub start_date: Option<models::DateTime>,
     #[serde(rename = "productName")]
     pub product_name: String,
     #[serde(rename = "productCategory", skip_serializing_if = "Option::is_none")]
@@ -42,13 +42,13 @@ pub struct Subscription {
     #[serde(rename = "sourceType", skip_serializing_if = "Option::is_none")]
     pub source_type: Option<SourceType>,
     #[serde(rename = "cancelledDate", skip_serializing_if = "Option::is_none")]
-    pub cancelled_date: Option<String>,
+    pub cancelled_date: Option<models::DateTime>,
     #[serde(rename = "chargedThroughDate", skip_serializing_if = "Option::is_none")]
-    pub charged_through_date: Option<String>,
+    pub charged_through_date: Option<models::Date>,
     #[serde(rename = "billingStartDate", skip_serializing_if = "Option::is_none")]
-    pub billing_start_date: Option<String>,
+    pub billing_start_date: Option<models::DateTime>,
     #[serde(rename = "billingEndDate", skip_serializing_if = "Option::is_none")]
-    pub billing_end_date: Option<String>,
+    pub billing_end_date: Option<models::DateTime>,
     #[serde(rename = "billCycleDayLocal", skip_serializing_if = "Option::is_none")]
     pub bill_cycle_day_local: Option<i32>,
     #[serde(rename = "quantity", skip_serializing_if = "Option::is_none")]
@@ -14,9 +14,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UsageRecord {
     #[serde(rename = "recordDate", skip_serializing_if = "Option::is_none")]
-    pub record_date: Option<String>,
+    pub record_date: Option<models::DateTime>,
     #[serde(rename = "amount", skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::arbitrary_precision_option")]
+    pub amount: Option<models::Decimal>,
 }
 
 impl UsageRecord {
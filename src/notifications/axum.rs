@@ -0,0 +1,71 @@
+//! Axum handler for push notifications
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use killbill_client::models::ExtBusEventType;
+//! use killbill_client::notifications::{ self, Dispatcher };
+//!
+//! let dispatcher = Dispatcher::new()
+//!     .on(ExtBusEventType::PaymentFailed, |event| async move {
+//!         println!("payment failed for account {:?}", event.account_id);
+//!         Ok(())
+//!     });
+//!
+//! let app: axum::Router = axum::Router::new()
+//!     .nest("/killbill/callback", notifications::axum::router(Arc::new(dispatcher)));
+//! ```
+
+use std::sync::Arc;
+
+use ::axum::{ body::Bytes, extract::State, http::StatusCode, routing::post, Router };
+
+use super::{ Dispatcher, NotificationError };
+
+/// Parse and dispatch the notification in the request body
+///
+/// Answers `200 OK` once every handler succeeded, `400 Bad Request` for an
+/// invalid payload and `500 Internal Server Error` when a handler failed, in
+/// which case Kill Bill retries the notification.
+pub async fn handle(State(dispatcher): State<Arc<Dispatcher>>, body: Bytes) -> StatusCode {
+    match dispatcher.handle(&body).await {
+        Ok(_) => StatusCode::OK,
+        Err(NotificationError::InvalidPayload(_)) => StatusCode::BAD_REQUEST,
+        Err(NotificationError::HandlerFailed(_)) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Router accepting notifications with a `POST` on `/`
+pub fn router<S>(dispatcher: Arc<Dispatcher>) -> Router<S> {
+    Router::new().route("/", post(handle)).with_state(dispatcher)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::axum::{ body::Body, http::Request };
+    use tower::ServiceExt;
+
+    use crate::models::ExtBusEventType;
+
+    #[tokio::test]
+    async fn test_axum_handler() {
+        let dispatcher = Dispatcher::new().on(ExtBusEventType::PaymentFailed, |_| async {
+            Err("payment gateway unavailable".into())
+        });
+        let app: Router = router(Arc::new(dispatcher));
+
+        let request = |body: &'static str| {
+            Request::post("/").header("content-type", "application/json").body(Body::from(body)).unwrap()
+        };
+
+        let response = app.clone().oneshot(request(r#"{"eventType":"ACCOUNT_CREATION"}"#)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app.clone().oneshot(request(r#"{"eventType":"PAYMENT_FAILED"}"#)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let response = app.oneshot(request("{")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,180 @@
+//! Receiving the push notifications Kill Bill sends to tenant callbacks
+//!
+//! Callbacks are registered with
+//! [`TenantApi::register_push_notification_callback`](crate::apis::tenant_api::TenantApi::register_push_notification_callback).
+//! Kill Bill then POSTs an [`ExtBusEvent`] to them for every billing event
+//! of the tenant, and retries the delivery when the callback does not answer
+//! with a `2xx`.
+//!
+//! [`Dispatcher`] routes parsed events to async handlers and does not depend
+//! on any web framework; the `axum` feature adds a ready-made handler.
+//!
+//! ```
+//! # async fn run(body: &[u8]) -> Result<(), killbill_client::notifications::NotificationError> {
+//! use killbill_client::models::ExtBusEventType;
+//! use killbill_client::notifications::Dispatcher;
+//!
+//! let dispatcher = Dispatcher::new()
+//!     .on(ExtBusEventType::InvoiceCreation, |event| async move {
+//!         println!("invoice {:?} created", event.object_id);
+//!         Ok(())
+//!     });
+//!
+//! dispatcher.handle(body).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+use std::fmt;
+use std::future::Future;
+
+use futures::future::BoxFuture;
+use thiserror::Error;
+
+use crate::models::{ ExtBusEvent, ExtBusEventType };
+
+/// Error returned by a notification handler
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+type Handler = Box<dyn (Fn(ExtBusEvent) -> BoxFuture<'static, Result<(), HandlerError>>) + Send + Sync>;
+
+#[derive(Debug, Error)]
+pub enum NotificationError {
+    #[error("Invalid notification payload: {0}")] InvalidPayload(#[from] serde_json::Error),
+
+    #[error("Notification handler failed: {0}")] HandlerFailed(HandlerError),
+}
+
+/// Parse the body of a push notification
+pub fn parse(body: &[u8]) -> Result<ExtBusEvent, NotificationError> {
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// Routes events to the handlers registered for their type
+///
+/// Handlers run one after the other, in registration order. The first error
+/// stops the dispatch so that the callback can fail and Kill Bill retry it.
+#[derive(Default)]
+pub struct Dispatcher {
+    handlers: Vec<(Option<ExtBusEventType>, Handler)>,
+}
+
+impl Dispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a handler for one event type
+    pub fn on<F, Fut>(self, event_type: ExtBusEventType, handler: F) -> Self
+        where
+            F: Fn(ExtBusEvent) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = Result<(), HandlerError>> + Send + 'static
+    {
+        self.register(Some(event_type), handler)
+    }
+
+    /// Register a handler for every event
+    pub fn on_any<F, Fut>(self, handler: F) -> Self
+        where
+            F: Fn(ExtBusEvent) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = Result<(), HandlerError>> + Send + 'static
+    {
+        self.register(None, handler)
+    }
+
+    fn register<F, Fut>(mut self, event_type: Option<ExtBusEventType>, handler: F) -> Self
+        where
+            F: Fn(ExtBusEvent) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = Result<(), HandlerError>> + Send + 'static
+    {
+        self.handlers.push((event_type, Box::new(move |event| Box::pin(handler(event)))));
+        self
+    }
+
+    /// Run the handlers matching the event, returning how many ran
+    pub async fn dispatch(&self, event: &ExtBusEvent) -> Result<usize, NotificationError> {
+        let mut count = 0;
+        for (event_type, handler) in &self.handlers {
+            if event_type.is_some() && *event_type != event.event_type {
+                continue;
+            }
+            handler(event.clone()).await.map_err(NotificationError::HandlerFailed)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Parse a notification body and dispatch it
+    pub async fn handle(&self, body: &[u8]) -> Result<ExtBusEvent, NotificationError> {
+        let event = parse(body)?;
+        self.dispatch(&event).await?;
+        Ok(event)
+    }
+}
+
+impl fmt::Debug for Dispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatcher").field("handlers", &self.handlers.len()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ExtBusObjectType;
+    use std::sync::{ Arc, Mutex };
+
+    const PAYMENT_SUCCESS: &str =
+        r#"{"eventType":"PAYMENT_SUCCESS","objectType":"PAYMENT","objectId":"3e5d6f0a-52d1-5c4b-8a2e-7b9c0d1e2f30","accountId":"7f5c6a4e-2f4e-5b8a-9c61-3a0d1e6b2c11","metaData":"{\"paymentTransactionId\":\"5a579cae-a171-5ce4-956e-720acfa7bc4c\"}"}"#;
+
+    #[test]
+    fn test_parse_notification() {
+        let event = parse(PAYMENT_SUCCESS.as_bytes()).unwrap();
+        assert_eq!(event.event_type, Some(ExtBusEventType::PaymentSuccess));
+        assert_eq!(event.object_type, Some(ExtBusObjectType::Payment));
+
+        let meta_data: serde_json::Value = event.parse_meta_data().unwrap().unwrap();
+        assert_eq!(meta_data["paymentTransactionId"], "5a579cae-a171-5ce4-956e-720acfa7bc4c");
+
+        let event = parse(br#"{"eventType":"SOMETHING_NEW","objectType":"SOMETHING_ELSE"}"#).unwrap();
+        assert_eq!(event.event_type, Some(ExtBusEventType::Unknown));
+        assert_eq!(event.object_type, Some(ExtBusObjectType::Unknown));
+        assert!(parse(b"not json").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_event_type() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let payments = seen.clone();
+        let all = seen.clone();
+        let dispatcher = Dispatcher::new()
+            .on(ExtBusEventType::PaymentSuccess, move |event| {
+                let payments = payments.clone();
+                async move {
+                    payments.lock().unwrap().push(format!("payment {}", event.object_id.unwrap()));
+                    Ok(())
+                }
+            })
+            .on(ExtBusEventType::InvoiceCreation, |_| async { Err("unexpected".into()) })
+            .on_any(move |event| {
+                let all = all.clone();
+                async move {
+                    all.lock().unwrap().push(format!("{:?}", event.event_type.unwrap()));
+                    Ok(())
+                }
+            });
+
+        dispatcher.handle(PAYMENT_SUCCESS.as_bytes()).await.unwrap();
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec!["payment 3e5d6f0a-52d1-5c4b-8a2e-7b9c0d1e2f30".to_string(), "PaymentSuccess".to_string()]
+        );
+
+        let result = dispatcher.handle(br#"{"eventType":"INVOICE_CREATION"}"#).await;
+        assert!(matches!(result, Err(NotificationError::HandlerFailed(_))));
+    }
+}
@@ -0,0 +1,390 @@
+//! In-memory fake Kill Bill server for tests
+//!
+//! [`FakeKillBill`] listens on a random local port and keeps accounts,
+//! bundles, subscriptions, invoices, payments, tags and custom fields in
+//! memory, serving the `/1.0/kb/...` routes the API structs call for them:
+//!
+//! - accounts: create, get (by id or external key, with balance), list,
+//!   update, close, and their bundles, invoices, payments and invoice payments
+//! - subscriptions: create (with a new bundle unless `bundleId` is set), get,
+//!   change plan, cancel and uncancel; bundles: get by id or external key
+//! - invoices: external charges, get, pay (`POST /invoices/{invoiceId}/payments`)
+//!   and get the invoice payment
+//! - payments: process from an account, get
+//! - tags and custom fields of accounts, bundles, subscriptions, invoices
+//!   and payments
+//!
+//! There is no catalog, billing or payment plugin behind it: subscriptions
+//! are not invoiced and every payment succeeds. Creations answer `201
+//! Created` with an empty body and a `Location` header, like Kill Bill, and
+//! unknown ids a `404` carrying a Kill Bill error body. Other routes answer
+//! `404` with an empty body.
+//!
+//! ```
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use killbill_client::apis::account_api::CreateAccountRequest;
+//! use killbill_client::models;
+//! use killbill_client::testing::FakeKillBill;
+//!
+//! let server = FakeKillBill::start();
+//! let client = server.client();
+//!
+//! let account = models::Account {
+//!     name: Some("John Doe".to_owned()),
+//!     currency: Some(models::Currency::Usd),
+//!     ..models::Account::new()
+//! };
+//! let request = CreateAccountRequest::builder()
+//!     .body(account)
+//!     .x_killbill_created_by("test")
+//!     .build()?;
+//! let account = client.accounts().create_account(request).await?;
+//!
+//! assert_eq!(server.accounts()[0].account_id, account.account_id);
+//! # Ok(())
+//! # }
+//! ```
+
+mod routes;
+
+use std::sync::{ Arc, Mutex };
+use std::thread;
+
+use tokio::sync::oneshot;
+
+use crate::{ apis::configuration::Configuration, models, KillBillClient };
+
+use self::routes::{ Shared, Store };
+
+/// Fake Kill Bill server, stopped when dropped
+///
+/// The server runs on its own thread and runtime, so it can be used from
+/// async tests as well as with the blocking client.
+pub struct FakeKillBill {
+    base_path: String,
+    store: Shared,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl FakeKillBill {
+    /// Start a server on a random port of `127.0.0.1`
+    ///
+    /// # Panics
+    ///
+    /// Panics if no local port can be bound.
+    pub fn start() -> Self {
+        let listener = std::net::TcpListener
+            ::bind("127.0.0.1:0")
+            .expect("failed to bind the fake Kill Bill server");
+        listener.set_nonblocking(true).expect("failed to configure the fake Kill Bill server");
+        let base_path = format!("http://{}", listener.local_addr().unwrap());
+
+        let store = Arc::new(Mutex::new(Store::default()));
+        let app = routes::router(store.clone());
+        let (shutdown, stopped) = oneshot::channel::<()>();
+
+        thread::spawn(move || {
+            let runtime = tokio::runtime::Builder
+                ::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to start the fake Kill Bill runtime");
+            runtime.block_on(async move {
+                let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                let _ = axum
+                    ::serve(listener, app)
+                    .with_graceful_shutdown(async move {
+                        let _ = stopped.await;
+                    }).await;
+            });
+        });
+
+        Self {
+            base_path,
+            store,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// URL of the server, e.g. `http://127.0.0.1:41234`
+    pub fn base_path(&self) -> &str {
+        &self.base_path
+    }
+
+    /// Configuration pointing at the server
+    pub fn config(&self) -> Configuration {
+        Configuration::builder()
+            .base_path(self.base_path.clone())
+            .basic_auth("admin", Some("password"))
+            .tenant("bob", "lazar")
+            .build()
+            .expect("the fake Kill Bill configuration is valid")
+    }
+
+    pub fn client(&self) -> KillBillClient {
+        KillBillClient::new(self.config())
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn blocking_client(&self) -> crate::blocking::KillBillClient {
+        crate::blocking::KillBillClient
+            ::new(self.config())
+            .expect("failed to start the blocking client runtime")
+    }
+
+    pub fn accounts(&self) -> Vec<models::Account> {
+        self.store().accounts.clone()
+    }
+
+    /// Bundles, without their subscriptions
+    pub fn bundles(&self) -> Vec<models::Bundle> {
+        self.store().bundles.clone()
+    }
+
+    pub fn subscriptions(&self) -> Vec<models::Subscription> {
+        self.store().subscriptions.clone()
+    }
+
+    pub fn invoices(&self) -> Vec<models::Invoice> {
+        self.store().invoices.clone()
+    }
+
+    pub fn payments(&self) -> Vec<models::Payment> {
+        self.store().payments.clone()
+    }
+
+    pub fn tags(&self) -> Vec<models::Tag> {
+        self.store().tags.clone()
+    }
+
+    pub fn custom_fields(&self) -> Vec<models::CustomField> {
+        self.store().custom_fields.clone()
+    }
+
+    /// Forget everything, e.g. between the steps of a test
+    pub fn reset(&self) {
+        *self.store() = Store::default();
+    }
+
+    fn store(&self) -> std::sync::MutexGuard<'_, Store> {
+        self.store.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for FakeKillBill {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+impl std::fmt::Debug for FakeKillBill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FakeKillBill").field("base_path", &self.base_path).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apis::{
+        account_api::{
+            AccountApiError,
+            CreateAccountRequest,
+            CreateAccountTagsRequest,
+            GetAccountByKeyRequest,
+            GetAccountRequest,
+            GetAccountTagsRequest,
+            GetAccountsRequest,
+        },
+        error::{ ErrorCode, KillBillError },
+        invoice_api::{ CreateExternalChargesRequest, CreateInstantPaymentRequest },
+        subscription_api::{
+            CancelSubscriptionPlanRequest,
+            CreateSubscriptionRequest,
+            GetSubscriptionRequest,
+        },
+    };
+    use reqwest::{ header::LOCATION, StatusCode };
+
+    fn account(external_key: &str) -> models::Account {
+        models::Account {
+            name: Some("John Doe".to_owned()),
+            external_key: Some(external_key.to_owned()),
+            currency: Some(models::Currency::Usd),
+            ..models::Account::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscription_workflow() {
+        let server = FakeKillBill::start();
+        let client = server.client();
+
+        let request = CreateAccountRequest::builder()
+            .body(account("john"))
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        let account = client.accounts().create_account(request).await.unwrap();
+        let account_id = account.account_id.unwrap();
+
+        let subscription = models::Subscription {
+            account_id: Some(account_id),
+            ..models::Subscription::new(
+                "Sports".to_owned(),
+                models::subscription::BillingPeriod::Monthly,
+                "DEFAULT".to_owned(),
+                "sports-monthly".to_owned()
+            )
+        };
+        let request = CreateSubscriptionRequest::builder()
+            .body(subscription)
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        let subscription = client.subscriptions().create_subscription(request).await.unwrap();
+        assert_eq!(subscription.state, Some(models::subscription::State::Active));
+
+        let subscription_id = subscription.subscription_id.unwrap();
+        let request = CancelSubscriptionPlanRequest::builder()
+            .subscription_id(subscription_id)
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        client.subscriptions().cancel_subscription_plan(request).await.unwrap();
+
+        let request = GetSubscriptionRequest::builder().subscription_id(subscription_id).build().unwrap();
+        let subscription = client.subscriptions().get_subscription(request).await.unwrap();
+        assert_eq!(subscription.state, Some(models::subscription::State::Cancelled));
+        assert_eq!(server.bundles().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invoice_payment_workflow() {
+        let server = FakeKillBill::start();
+        let client = server.client();
+
+        let request = CreateAccountRequest::builder()
+            .body(account("jane"))
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        let account_id = client.accounts().create_account(request).await.unwrap().account_id.unwrap();
+
+        let amount: models::Decimal = serde_json::from_str("12.5").unwrap();
+        let charge = models::InvoiceItem {
+            amount: Some(amount),
+            description: Some("Setup fee".to_owned()),
+            ..models::InvoiceItem::new(uuid::Uuid::nil(), account_id)
+        };
+        let request = CreateExternalChargesRequest::builder()
+            .account_id(account_id)
+            .body(vec![charge])
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        let items = client.invoices().create_external_charges(request).await.unwrap();
+        let invoice_id = items[0].invoice_id.unwrap();
+
+        let balance = |account: models::Account| account.account_balance.unwrap();
+        let request = GetAccountRequest::builder()
+            .account_id(account_id)
+            .account_with_balance(true)
+            .build()
+            .unwrap();
+        let account = client.accounts().get_account(request.clone()).await.unwrap();
+        assert_eq!(balance(account), amount);
+
+        let request_payment = CreateInstantPaymentRequest::builder()
+            .invoice_id(invoice_id)
+            .body(models::InvoicePayment { account_id: Some(account_id), ..models::InvoicePayment::new() })
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        client.invoices().create_instant_payment(request_payment).await.unwrap();
+
+        let account = client.accounts().get_account(request).await.unwrap();
+        assert_eq!(balance(account), models::Decimal::default());
+        assert_eq!(server.payments().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tags_pagination_and_errors() {
+        let server = FakeKillBill::start();
+        let client = server.client();
+
+        let mut account_id = None;
+        for key in ["a", "b", "c"] {
+            let request = CreateAccountRequest::builder()
+                .body(account(key))
+                .x_killbill_created_by("test")
+                .build()
+                .unwrap();
+            account_id = client.accounts().create_account(request).await.unwrap().account_id;
+        }
+        let account_id = account_id.unwrap();
+
+        let request = GetAccountsRequest::builder().limit(2).build().unwrap();
+        let page = client.accounts().get_accounts(request).await.unwrap();
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.next_offset, Some(2));
+
+        let auto_pay_off = uuid::Uuid::from_u128(1);
+        let request = CreateAccountTagsRequest::builder()
+            .account_id(account_id)
+            .body(vec![auto_pay_off])
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        client.accounts().create_account_tags(request).await.unwrap();
+
+        let request = GetAccountTagsRequest::builder().account_id(account_id).build().unwrap();
+        let tags = client.accounts().get_account_tags(request).await.unwrap();
+        assert_eq!(tags[0].tag_definition_name.as_deref(), Some("AUTO_PAY_OFF"));
+
+        let request = GetAccountRequest::builder().account_id(uuid::Uuid::nil()).build().unwrap();
+        let result = client.accounts().get_account(request).await;
+        assert!(
+            matches!(result, Err(AccountApiError::ApiError { status, .. }) if status == StatusCode::NOT_FOUND)
+        );
+
+        let request = CreateAccountRequest::builder()
+            .body(account("a"))
+            .x_killbill_created_by("test")
+            .build()
+            .unwrap();
+        assert!(client.accounts().create_account(request).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_creations_answer_location() {
+        let server = FakeKillBill::start();
+
+        let response = reqwest::Client
+            ::new()
+            .post(format!("{}/1.0/kb/accounts", server.base_path()))
+            .json(&account("john"))
+            .send().await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.content_length(), Some(0));
+        let account_id = server.accounts()[0].account_id.unwrap();
+        assert_eq!(response.headers()[LOCATION], format!("/1.0/kb/accounts/{}", account_id));
+    }
+
+    #[tokio::test]
+    async fn test_account_not_found_error_codes() {
+        let server = FakeKillBill::start();
+        let client = server.client();
+
+        let request = GetAccountRequest::builder().account_id(uuid::Uuid::nil()).build().unwrap();
+        let error = KillBillError::from(client.accounts().get_account(request).await.unwrap_err());
+        assert_eq!(error.error_code(), Some(ErrorCode::AccountDoesNotExistForId));
+
+        let request = GetAccountByKeyRequest::builder().external_key("missing").build().unwrap();
+        let error = KillBillError::from(client.accounts().get_account_by_key(request).await.unwrap_err());
+        assert_eq!(error.error_code(), Some(ErrorCode::AccountDoesNotExistForKey));
+    }
+}
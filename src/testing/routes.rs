@@ -0,0 +1,918 @@
+use std::sync::{ Arc, Mutex, MutexGuard };
+
+use axum::{
+    extract::{ Path, RawQuery, State },
+    http::{ header::LOCATION, HeaderMap, HeaderValue, StatusCode },
+    response::{ IntoResponse, Response },
+    routing::{ get, post, put },
+    Json,
+    Router,
+};
+use serde::{ de::DeserializeOwned, Serialize };
+use uuid::Uuid;
+
+use crate::{ apis::error::BillingException, models };
+
+pub(crate) type Shared = Arc<Mutex<Store>>;
+
+/// Everything the fake server knows about, in creation order
+#[derive(Debug, Default)]
+pub(crate) struct Store {
+    pub(crate) accounts: Vec<models::Account>,
+    pub(crate) bundles: Vec<models::Bundle>,
+    pub(crate) subscriptions: Vec<models::Subscription>,
+    pub(crate) invoices: Vec<models::Invoice>,
+    pub(crate) payments: Vec<models::Payment>,
+    pub(crate) invoice_payments: Vec<models::InvoicePayment>,
+    pub(crate) tags: Vec<models::Tag>,
+    pub(crate) custom_fields: Vec<models::CustomField>,
+}
+
+impl Store {
+    fn account(&self, account_id: Uuid) -> Result<&models::Account, Missing> {
+        self.accounts
+            .iter()
+            .find(|account| account.account_id == Some(account_id))
+            .ok_or(Missing::Account(account_id))
+    }
+
+    fn subscription_mut(
+        &mut self,
+        subscription_id: Uuid
+    ) -> Result<&mut models::Subscription, Missing> {
+        self.subscriptions
+            .iter_mut()
+            .find(|subscription| subscription.subscription_id == Some(subscription_id))
+            .ok_or(Missing::Subscription(subscription_id))
+    }
+
+    fn invoice_mut(&mut self, invoice_id: Uuid) -> Result<&mut models::Invoice, Missing> {
+        self.invoices
+            .iter_mut()
+            .find(|invoice| invoice.invoice_id == Some(invoice_id))
+            .ok_or(Missing::Invoice(invoice_id))
+    }
+
+    /// The bundle with its subscriptions, as returned by the bundle routes
+    fn bundle_view(&self, bundle: &models::Bundle) -> models::Bundle {
+        let subscriptions = self.subscriptions
+            .iter()
+            .filter(|subscription| subscription.bundle_id == bundle.bundle_id)
+            .cloned()
+            .collect();
+        models::Bundle { subscriptions: Some(subscriptions), ..bundle.clone() }
+    }
+
+    fn account_balance(&self, account_id: Uuid) -> models::Decimal {
+        self.invoices
+            .iter()
+            .filter(|invoice| invoice.account_id == Some(account_id))
+            .filter(|invoice| invoice.status == Some(models::invoice::Status::Committed))
+            .filter_map(|invoice| invoice.balance)
+            .fold(models::Decimal::default(), |total, balance| total + balance)
+    }
+}
+
+/// Resource a [`Store`] lookup did not find
+#[derive(Debug)]
+enum Missing {
+    Account(Uuid),
+    Subscription(Uuid),
+    Invoice(Uuid),
+}
+
+impl IntoResponse for Missing {
+    fn into_response(self) -> Response {
+        match self {
+            Missing::Account(account_id) => account_not_found(account_id),
+            Missing::Subscription(subscription_id) => subscription_not_found(subscription_id),
+            Missing::Invoice(invoice_id) => invoice_not_found(invoice_id),
+        }
+    }
+}
+
+pub(crate) fn router(store: Shared) -> Router {
+    let router = Router::new()
+        .route("/1.0/kb/accounts", post(create_account).get(get_account_by_key))
+        .route("/1.0/kb/accounts/pagination", get(get_accounts))
+        .route(
+            "/1.0/kb/accounts/{accountId}",
+            get(get_account).put(update_account).delete(close_account)
+        )
+        .route("/1.0/kb/accounts/{accountId}/bundles", get(get_account_bundles))
+        .route("/1.0/kb/accounts/{accountId}/invoices", get(get_account_invoices))
+        .route(
+            "/1.0/kb/accounts/{accountId}/payments",
+            get(get_account_payments).post(process_payment)
+        )
+        .route("/1.0/kb/accounts/{accountId}/invoicePayments", get(get_account_invoice_payments))
+        .route("/1.0/kb/bundles", get(get_bundle_by_key))
+        .route("/1.0/kb/bundles/{bundleId}", get(get_bundle))
+        .route("/1.0/kb/subscriptions", post(create_subscription))
+        .route(
+            "/1.0/kb/subscriptions/{subscriptionId}",
+            get(get_subscription).put(change_subscription_plan).delete(cancel_subscription_plan)
+        )
+        .route("/1.0/kb/subscriptions/{subscriptionId}/uncancel", put(uncancel_subscription_plan))
+        .route("/1.0/kb/invoices/charges/{accountId}", post(create_external_charges))
+        .route("/1.0/kb/invoices/{invoiceId}", get(get_invoice))
+        .route(
+            "/1.0/kb/invoices/{invoiceId}/payments",
+            get(get_invoice_payments).post(create_instant_payment)
+        )
+        .route("/1.0/kb/invoicePayments/{paymentId}", get(get_invoice_payment))
+        .route("/1.0/kb/payments/{paymentId}", get(get_payment));
+
+    let objects = [
+        ("accounts", models::tag::ObjectType::Account, models::custom_field::ObjectType::Account),
+        ("bundles", models::tag::ObjectType::Bundle, models::custom_field::ObjectType::Bundle),
+        (
+            "subscriptions",
+            models::tag::ObjectType::Subscription,
+            models::custom_field::ObjectType::Subscription,
+        ),
+        ("invoices", models::tag::ObjectType::Invoice, models::custom_field::ObjectType::Invoice),
+        ("payments", models::tag::ObjectType::Payment, models::custom_field::ObjectType::Payment),
+    ];
+    objects
+        .into_iter()
+        .fold(router, |router, (resource, tag_object, field_object)| {
+            router
+                .route(
+                    &format!("/1.0/kb/{}/{{objectId}}/tags", resource),
+                    get(get_tags)
+                        .post(move |state, path, body| {
+                            create_tags(state, path, body, resource, tag_object)
+                        })
+                        .delete(delete_tags)
+                )
+                .route(
+                    &format!("/1.0/kb/{}/{{objectId}}/customFields", resource),
+                    get(get_custom_fields)
+                        .post(move |state, path, body| {
+                            create_custom_fields(state, path, body, resource, field_object)
+                        })
+                        .put(modify_custom_fields)
+                        .delete(delete_custom_fields)
+                )
+        })
+        .with_state(store)
+}
+
+fn lock(store: &Shared) -> MutexGuard<'_, Store> {
+    store.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn query(raw: Option<String>) -> Vec<(String, String)> {
+    url::form_urlencoded
+        ::parse(raw.unwrap_or_default().as_bytes())
+        .into_owned()
+        .collect()
+}
+
+fn param<'a>(query: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    query
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn params<'a>(query: &'a [(String, String)], name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    query
+        .iter()
+        .filter(move |(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn json<T: Serialize>(status: StatusCode, value: &T) -> Response {
+    (status, Json(value)).into_response()
+}
+
+/// Empty `201 Created` pointing at the created resource, as Kill Bill answers
+/// creations; the client follows the `Location` to read it
+fn created(location: String) -> Response {
+    (StatusCode::CREATED, [(LOCATION, location)]).into_response()
+}
+
+/// Convert between models sharing the same JSON fields
+fn convert<T: Serialize, U: DeserializeOwned>(value: &T) -> U {
+    serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
+}
+
+/// Overwrite the fields of `base` that are set in `patch`
+fn merge<T: Serialize + DeserializeOwned>(base: &T, patch: &T) -> T {
+    let mut base = serde_json::to_value(base).unwrap();
+    if let (Some(base), serde_json::Value::Object(patch)) = (base.as_object_mut(), serde_json::to_value(patch).unwrap()) {
+        base.extend(patch);
+    }
+    serde_json::from_value(base).unwrap()
+}
+
+fn error(status: StatusCode, class_name: &str, code: i32, message: String) -> Response {
+    let exception = BillingException {
+        class_name: Some(class_name.to_owned()),
+        code: Some(code),
+        message: Some(message),
+        ..Default::default()
+    };
+    json(status, &exception)
+}
+
+fn account_not_found(account_id: Uuid) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "org.killbill.billing.account.api.AccountApiException",
+        3002,
+        format!("Account does not exist for id {}", account_id)
+    )
+}
+
+fn subscription_not_found(subscription_id: Uuid) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "org.killbill.billing.entitlement.api.EntitlementApiException",
+        1019,
+        format!("Unknown subscription {}", subscription_id)
+    )
+}
+
+fn invoice_not_found(invoice_id: Uuid) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "org.killbill.billing.invoice.api.InvoiceApiException",
+        4012,
+        format!("No invoice could be found for id {}", invoice_id)
+    )
+}
+
+fn not_found(what: &str, id: impl std::fmt::Display) -> Response {
+    error(
+        StatusCode::NOT_FOUND,
+        "org.killbill.billing.ObjectDoesNotExist",
+        0,
+        format!("{} does not exist for {}", what, id)
+    )
+}
+
+async fn create_account(State(store): State<Shared>, Json(body): Json<models::Account>) -> Response {
+    let mut store = lock(&store);
+
+    let account_id = body.account_id.unwrap_or_else(Uuid::new_v4);
+    let external_key = body.external_key.clone().unwrap_or_else(|| account_id.to_string());
+    if store.accounts.iter().any(|account| account.external_key.as_ref() == Some(&external_key)) {
+        return error(
+            StatusCode::CONFLICT,
+            "org.killbill.billing.account.api.AccountApiException",
+            3000,
+            format!("Account already exists for key {}", external_key)
+        );
+    }
+
+    let account = models::Account {
+        account_id: Some(account_id),
+        external_key: Some(external_key),
+        ..body
+    };
+    store.accounts.push(account);
+    created(format!("/1.0/kb/accounts/{}", account_id))
+}
+
+async fn get_account_by_key(State(store): State<Shared>, RawQuery(raw): RawQuery) -> Response {
+    let store = lock(&store);
+    let query = query(raw);
+    let external_key = param(&query, "externalKey").unwrap_or_default();
+
+    match store.accounts.iter().find(|account| account.external_key.as_deref() == Some(external_key)) {
+        Some(account) => json(StatusCode::OK, account),
+        None =>
+            error(
+                StatusCode::NOT_FOUND,
+                "org.killbill.billing.account.api.AccountApiException",
+                3003,
+                format!("Account does not exist for key {}", external_key)
+            ),
+    }
+}
+
+async fn get_accounts(State(store): State<Shared>, RawQuery(raw): RawQuery) -> Response {
+    let store = lock(&store);
+    let query = query(raw);
+    let offset: usize = param(&query, "offset").and_then(|o| o.parse().ok()).unwrap_or(0);
+    let limit: usize = param(&query, "limit").and_then(|l| l.parse().ok()).unwrap_or(100);
+
+    let total = store.accounts.len();
+    let page: Vec<_> = store.accounts.iter().skip(offset).take(limit).cloned().collect();
+
+    let mut headers = HeaderMap::new();
+    let number = |n: usize| HeaderValue::from(n as u64);
+    headers.insert("X-Killbill-Pagination-CurrentOffset", number(offset));
+    headers.insert("X-Killbill-Pagination-TotalNbRecords", number(total));
+    headers.insert("X-Killbill-Pagination-MaxNbRecords", number(total));
+    let next = offset + page.len();
+    if !page.is_empty() && next < total {
+        headers.insert("X-Killbill-Pagination-NextOffset", number(next));
+        let uri = format!("/1.0/kb/accounts/pagination?offset={}&limit={}", next, limit);
+        headers.insert("X-Killbill-Pagination-NextPageUri", HeaderValue::try_from(uri).unwrap());
+    }
+
+    (headers, json(StatusCode::OK, &page)).into_response()
+}
+
+async fn get_account(
+    State(store): State<Shared>,
+    Path(account_id): Path<Uuid>,
+    RawQuery(raw): RawQuery
+) -> Response {
+    let store = lock(&store);
+    let query = query(raw);
+    let mut account = match store.account(account_id) {
+        Ok(account) => account.clone(),
+        Err(missing) => {
+            return missing.into_response();
+        }
+    };
+
+    let with_balance = |name| param(&query, name) == Some("true");
+    if with_balance("accountWithBalance") || with_balance("accountWithBalanceAndCBA") {
+        account.account_balance = Some(store.account_balance(account_id));
+    }
+    if with_balance("accountWithBalanceAndCBA") {
+        account.account_cba = Some(models::Decimal::default());
+    }
+    json(StatusCode::OK, &account)
+}
+
+async fn update_account(
+    State(store): State<Shared>,
+    Path(account_id): Path<Uuid>,
+    Json(body): Json<models::Account>
+) -> Response {
+    let mut store = lock(&store);
+    let Some(account) = store.accounts.iter_mut().find(|account| account.account_id == Some(account_id)) else {
+        return account_not_found(account_id);
+    };
+
+    *account = models::Account {
+        account_id: Some(account_id),
+        external_key: account.external_key.clone(),
+        ..merge(account, &body)
+    };
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn close_account(State(store): State<Shared>, Path(account_id): Path<Uuid>) -> Response {
+    let mut store = lock(&store);
+    if let Err(missing) = store.account(account_id) {
+        return missing.into_response();
+    }
+
+    for subscription in &mut store.subscriptions {
+        if subscription.account_id == Some(account_id) {
+            subscription.state = Some(models::subscription::State::Cancelled);
+        }
+    }
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn get_account_bundles(State(store): State<Shared>, Path(account_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    if let Err(missing) = store.account(account_id) {
+        return missing.into_response();
+    }
+
+    let bundles: Vec<_> = store.bundles
+        .iter()
+        .filter(|bundle| bundle.account_id == account_id)
+        .map(|bundle| store.bundle_view(bundle))
+        .collect();
+    json(StatusCode::OK, &bundles)
+}
+
+async fn get_account_invoices(State(store): State<Shared>, Path(account_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    if let Err(missing) = store.account(account_id) {
+        return missing.into_response();
+    }
+
+    let invoices: Vec<_> = store.invoices
+        .iter()
+        .filter(|invoice| invoice.account_id == Some(account_id))
+        .collect();
+    json(StatusCode::OK, &invoices)
+}
+
+async fn get_account_payments(State(store): State<Shared>, Path(account_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    if let Err(missing) = store.account(account_id) {
+        return missing.into_response();
+    }
+
+    let payments: Vec<_> = store.payments
+        .iter()
+        .filter(|payment| payment.account_id == Some(account_id))
+        .collect();
+    json(StatusCode::OK, &payments)
+}
+
+async fn get_account_invoice_payments(
+    State(store): State<Shared>,
+    Path(account_id): Path<Uuid>
+) -> Response {
+    let store = lock(&store);
+    if let Err(missing) = store.account(account_id) {
+        return missing.into_response();
+    }
+
+    let payments: Vec<_> = store.invoice_payments
+        .iter()
+        .filter(|payment| payment.account_id == Some(account_id))
+        .collect();
+    json(StatusCode::OK, &payments)
+}
+
+/// Record a successful payment, or return the one already made with the same
+/// transaction external key
+fn record_payment(
+    store: &mut Store,
+    account_id: Uuid,
+    transaction: &models::PaymentTransaction
+) -> models::Payment {
+    let key = transaction.transaction_external_key.as_ref();
+    let existing = store.payments.iter().find(|payment| {
+        key.is_some() &&
+            payment.transactions
+                .iter()
+                .flatten()
+                .any(|t| t.transaction_external_key.as_ref() == key)
+    });
+    if let Some(payment) = existing {
+        return payment.clone();
+    }
+
+    let payment_id = Uuid::new_v4();
+    let transaction_type = transaction.transaction_type.unwrap_or(
+        models::payment_transaction::TransactionType::Purchase
+    );
+    let amount = transaction.amount.unwrap_or_default();
+    let currency = transaction.currency.or_else(|| {
+        store
+            .account(account_id)
+            .ok()
+            .and_then(|account| account.currency)
+    });
+
+    let transaction = models::PaymentTransaction {
+        transaction_id: Some(Uuid::new_v4()),
+        transaction_external_key: Some(
+            transaction.transaction_external_key.clone().unwrap_or_else(|| Uuid::new_v4().to_string())
+        ),
+        payment_id: Some(payment_id),
+        transaction_type: Some(transaction_type),
+        amount: Some(amount),
+        currency,
+        processed_amount: Some(amount),
+        processed_currency: currency,
+        status: Some(models::payment_transaction::Status::Success),
+        ..transaction.clone()
+    };
+
+    use models::payment_transaction::TransactionType;
+    let zero = models::Decimal::default();
+    let amount_for = |t: TransactionType| Some(if transaction_type == t { amount } else { zero });
+    let payment = models::Payment {
+        account_id: Some(account_id),
+        payment_id: Some(payment_id),
+        payment_number: Some((store.payments.len() + 1).to_string()),
+        payment_external_key: Some(
+            transaction.payment_external_key.clone().unwrap_or_else(|| payment_id.to_string())
+        ),
+        auth_amount: amount_for(TransactionType::Authorize),
+        captured_amount: Some(zero),
+        purchased_amount: amount_for(TransactionType::Purchase),
+        refunded_amount: Some(zero),
+        credited_amount: amount_for(TransactionType::Credit),
+        currency,
+        payment_method_id: None,
+        transactions: Some(vec![transaction]),
+        payment_attempts: None,
+        audit_logs: None,
+    };
+    store.payments.push(payment.clone());
+    payment
+}
+
+async fn process_payment(
+    State(store): State<Shared>,
+    Path(account_id): Path<Uuid>,
+    Json(body): Json<models::PaymentTransaction>
+) -> Response {
+    let mut store = lock(&store);
+    if let Err(missing) = store.account(account_id) {
+        return missing.into_response();
+    }
+
+    let payment = record_payment(&mut store, account_id, &body);
+    created(format!("/1.0/kb/payments/{}", payment.payment_id.unwrap_or_default()))
+}
+
+async fn get_bundle_by_key(State(store): State<Shared>, RawQuery(raw): RawQuery) -> Response {
+    let store = lock(&store);
+    let query = query(raw);
+    let external_key = param(&query, "externalKey");
+
+    let bundles: Vec<_> = store.bundles
+        .iter()
+        .filter(|bundle| bundle.external_key.as_deref() == external_key)
+        .map(|bundle| store.bundle_view(bundle))
+        .collect();
+    if bundles.is_empty() {
+        return not_found("Bundle", external_key.unwrap_or_default());
+    }
+    json(StatusCode::OK, &bundles)
+}
+
+async fn get_bundle(State(store): State<Shared>, Path(bundle_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    match store.bundles.iter().find(|bundle| bundle.bundle_id == Some(bundle_id)) {
+        Some(bundle) => json(StatusCode::OK, &store.bundle_view(bundle)),
+        None => not_found("Bundle", bundle_id),
+    }
+}
+
+async fn create_subscription(
+    State(store): State<Shared>,
+    Json(body): Json<models::Subscription>
+) -> Response {
+    let mut store = lock(&store);
+
+    let bundle = match body.bundle_id {
+        Some(bundle_id) =>
+            match store.bundles.iter().find(|bundle| bundle.bundle_id == Some(bundle_id)) {
+                Some(bundle) => bundle.clone(),
+                None => {
+                    return not_found("Bundle", bundle_id);
+                }
+            }
+        None => {
+            let Some(account_id) = body.account_id else {
+                return error(
+                    StatusCode::BAD_REQUEST,
+                    "java.lang.IllegalArgumentException",
+                    0,
+                    "SubscriptionJson accountId needs to be set".to_owned()
+                );
+            };
+            if let Err(missing) = store.account(account_id) {
+                return missing.into_response();
+            }
+
+            let bundle_id = Uuid::new_v4();
+            let bundle = models::Bundle {
+                account_id,
+                bundle_id: Some(bundle_id),
+                external_key: Some(
+                    body.bundle_external_key.clone().unwrap_or_else(|| bundle_id.to_string())
+                ),
+                subscriptions: None,
+                timeline: None,
+                audit_logs: None,
+            };
+            store.bundles.push(bundle.clone());
+            bundle
+        }
+    };
+
+    let subscription_id = Uuid::new_v4();
+    let subscription = models::Subscription {
+        account_id: Some(bundle.account_id),
+        bundle_id: bundle.bundle_id,
+        bundle_external_key: bundle.external_key.clone(),
+        subscription_id: Some(subscription_id),
+        external_key: Some(body.external_key.clone().unwrap_or_else(|| subscription_id.to_string())),
+        product_category: Some(body.product_category.unwrap_or_default()),
+        phase_type: Some(body.phase_type.unwrap_or(models::subscription::PhaseType::Evergreen)),
+        state: Some(models::subscription::State::Active),
+        source_type: Some(models::subscription::SourceType::Native),
+        quantity: Some(body.quantity.unwrap_or(1)),
+        ..body
+    };
+    store.subscriptions.push(subscription);
+    created(format!("/1.0/kb/subscriptions/{}", subscription_id))
+}
+
+async fn get_subscription(State(store): State<Shared>, Path(subscription_id): Path<Uuid>) -> Response {
+    let mut store = lock(&store);
+    match store.subscription_mut(subscription_id) {
+        Ok(subscription) => json(StatusCode::OK, subscription),
+        Err(missing) => missing.into_response(),
+    }
+}
+
+async fn change_subscription_plan(
+    State(store): State<Shared>,
+    Path(subscription_id): Path<Uuid>,
+    Json(body): Json<models::Subscription>
+) -> Response {
+    let mut store = lock(&store);
+    match store.subscription_mut(subscription_id) {
+        Ok(subscription) => {
+            subscription.product_name = body.product_name;
+            subscription.billing_period = body.billing_period;
+            subscription.price_list = body.price_list;
+            subscription.plan_name = body.plan_name;
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(missing) => missing.into_response(),
+    }
+}
+
+async fn cancel_subscription_plan(
+    State(store): State<Shared>,
+    Path(subscription_id): Path<Uuid>
+) -> Response {
+    let mut store = lock(&store);
+    match store.subscription_mut(subscription_id) {
+        Ok(subscription) => {
+            subscription.state = Some(models::subscription::State::Cancelled);
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(missing) => missing.into_response(),
+    }
+}
+
+async fn uncancel_subscription_plan(
+    State(store): State<Shared>,
+    Path(subscription_id): Path<Uuid>
+) -> Response {
+    let mut store = lock(&store);
+    match store.subscription_mut(subscription_id) {
+        Ok(subscription) => {
+            subscription.state = Some(models::subscription::State::Active);
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(missing) => missing.into_response(),
+    }
+}
+
+async fn create_external_charges(
+    State(store): State<Shared>,
+    Path(account_id): Path<Uuid>,
+    Json(body): Json<Vec<models::InvoiceItem>>
+) -> Response {
+    let mut store = lock(&store);
+    let currency = match store.account(account_id) {
+        Ok(account) => account.currency,
+        Err(missing) => {
+            return missing.into_response();
+        }
+    };
+
+    let invoice_id = Uuid::new_v4();
+    let items: Vec<_> = body
+        .into_iter()
+        .map(|item| models::InvoiceItem {
+            invoice_item_id: Uuid::new_v4(),
+            invoice_id: Some(invoice_id),
+            account_id,
+            item_type: Some(models::invoice_item::ItemType::ExternalCharge),
+            currency: item.currency.or(currency),
+            ..item
+        })
+        .collect();
+    let amount = items
+        .iter()
+        .filter_map(|item| item.amount)
+        .fold(models::Decimal::default(), |total, amount| total + amount);
+
+    let invoice = models::Invoice {
+        amount: Some(amount),
+        currency,
+        status: Some(models::invoice::Status::Committed),
+        credit_adj: Some(models::Decimal::default()),
+        refund_adj: Some(models::Decimal::default()),
+        invoice_id: Some(invoice_id),
+        invoice_number: Some((store.invoices.len() + 1).to_string()),
+        balance: Some(amount),
+        account_id: Some(account_id),
+        items: Some(items.clone()),
+        ..models::Invoice::new()
+    };
+    store.invoices.push(invoice);
+    // Unlike other creations, Kill Bill answers with the created items
+    json(StatusCode::OK, &items)
+}
+
+async fn get_invoice(State(store): State<Shared>, Path(invoice_id): Path<Uuid>) -> Response {
+    let mut store = lock(&store);
+    match store.invoice_mut(invoice_id) {
+        Ok(invoice) => json(StatusCode::OK, invoice),
+        Err(missing) => missing.into_response(),
+    }
+}
+
+async fn get_invoice_payments(State(store): State<Shared>, Path(invoice_id): Path<Uuid>) -> Response {
+    let mut store = lock(&store);
+    if let Err(missing) = store.invoice_mut(invoice_id) {
+        return missing.into_response();
+    }
+
+    let payments: Vec<_> = store.invoice_payments
+        .iter()
+        .filter(|payment| payment.target_invoice_id == Some(invoice_id))
+        .collect();
+    json(StatusCode::OK, &payments)
+}
+
+async fn create_instant_payment(
+    State(store): State<Shared>,
+    Path(invoice_id): Path<Uuid>,
+    Json(body): Json<models::InvoicePayment>
+) -> Response {
+    let mut store = lock(&store);
+    let (account_id, balance) = match store.invoice_mut(invoice_id) {
+        Ok(invoice) => (invoice.account_id.unwrap_or_default(), invoice.balance.unwrap_or_default()),
+        Err(missing) => {
+            return missing.into_response();
+        }
+    };
+
+    let amount = body.purchased_amount.unwrap_or(balance);
+    let transaction = models::PaymentTransaction {
+        amount: Some(amount),
+        currency: body.currency,
+        payment_external_key: body.payment_external_key.clone(),
+        ..models::PaymentTransaction::new()
+    };
+    let payment = record_payment(&mut store, account_id, &transaction);
+    if let Ok(invoice) = store.invoice_mut(invoice_id) {
+        invoice.balance = Some(balance - amount);
+    }
+
+    let invoice_payment = models::InvoicePayment {
+        target_invoice_id: Some(invoice_id),
+        ..convert(&payment)
+    };
+    store.invoice_payments.push(invoice_payment);
+    created(format!("/1.0/kb/invoicePayments/{}", payment.payment_id.unwrap_or_default()))
+}
+
+async fn get_invoice_payment(State(store): State<Shared>, Path(payment_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    match store.invoice_payments.iter().find(|payment| payment.payment_id == Some(payment_id)) {
+        Some(payment) => json(StatusCode::OK, payment),
+        None => not_found("Invoice payment", payment_id),
+    }
+}
+
+async fn get_payment(State(store): State<Shared>, Path(payment_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    match store.payments.iter().find(|payment| payment.payment_id == Some(payment_id)) {
+        Some(payment) => json(StatusCode::OK, payment),
+        None =>
+            error(
+                StatusCode::NOT_FOUND,
+                "org.killbill.billing.payment.api.PaymentApiException",
+                7018,
+                format!("Payment {} does not exist", payment_id)
+            ),
+    }
+}
+
+/// Name of the Kill Bill control tags, the only definitions the fake knows
+fn control_tag_name(tag_definition_id: Uuid) -> Option<&'static str> {
+    const NAMES: [&str; 9] = [
+        "AUTO_PAY_OFF",
+        "AUTO_INVOICING_OFF",
+        "OVERDUE_ENFORCEMENT_OFF",
+        "WRITTEN_OFF",
+        "MANUAL_PAY",
+        "TEST",
+        "PARTNER",
+        "AUTO_INVOICING_DRAFT",
+        "AUTO_INVOICING_REUSE_DRAFT",
+    ];
+    match tag_definition_id.as_u128() {
+        n @ 1..=9 => Some(NAMES[(n - 1) as usize]),
+        _ => None,
+    }
+}
+
+async fn get_tags(State(store): State<Shared>, Path(object_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    let tags: Vec<_> = store.tags
+        .iter()
+        .filter(|tag| tag.object_id == Some(object_id))
+        .collect();
+    json(StatusCode::OK, &tags)
+}
+
+async fn create_tags(
+    State(store): State<Shared>,
+    Path(object_id): Path<Uuid>,
+    Json(body): Json<Vec<Uuid>>,
+    resource: &'static str,
+    object_type: models::tag::ObjectType
+) -> Response {
+    let mut store = lock(&store);
+    for tag_definition_id in body {
+        let exists = store.tags
+            .iter()
+            .any(|tag| tag.object_id == Some(object_id) && tag.tag_definition_id == Some(tag_definition_id));
+        if !exists {
+            store.tags.push(models::Tag {
+                tag_id: Some(Uuid::new_v4()),
+                object_type: Some(object_type),
+                object_id: Some(object_id),
+                tag_definition_id: Some(tag_definition_id),
+                tag_definition_name: control_tag_name(tag_definition_id).map(str::to_owned),
+                audit_logs: None,
+            });
+        }
+    }
+
+    created(format!("/1.0/kb/{}/{}/tags", resource, object_id))
+}
+
+async fn delete_tags(
+    State(store): State<Shared>,
+    Path(object_id): Path<Uuid>,
+    RawQuery(raw): RawQuery
+) -> Response {
+    let mut store = lock(&store);
+    let query = query(raw);
+    let tag_definitions: Vec<_> = params(&query, "tagDef").collect();
+
+    store.tags.retain(|tag| {
+        tag.object_id != Some(object_id) ||
+            !tag.tag_definition_id.is_some_and(|id| tag_definitions.contains(&id.to_string().as_str()))
+    });
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn get_custom_fields(State(store): State<Shared>, Path(object_id): Path<Uuid>) -> Response {
+    let store = lock(&store);
+    let fields: Vec<_> = store.custom_fields
+        .iter()
+        .filter(|field| field.object_id == Some(object_id))
+        .collect();
+    json(StatusCode::OK, &fields)
+}
+
+async fn create_custom_fields(
+    State(store): State<Shared>,
+    Path(object_id): Path<Uuid>,
+    Json(body): Json<Vec<models::CustomField>>,
+    resource: &'static str,
+    object_type: models::custom_field::ObjectType
+) -> Response {
+    let mut store = lock(&store);
+    let fields = body
+        .into_iter()
+        .map(|field| models::CustomField {
+            custom_field_id: Some(Uuid::new_v4()),
+            object_id: Some(object_id),
+            object_type: Some(object_type),
+            ..field
+        });
+    store.custom_fields.extend(fields);
+    created(format!("/1.0/kb/{}/{}/customFields", resource, object_id))
+}
+
+async fn modify_custom_fields(
+    State(store): State<Shared>,
+    Path(object_id): Path<Uuid>,
+    Json(body): Json<Vec<models::CustomField>>
+) -> Response {
+    let mut store = lock(&store);
+    for update in body {
+        let field = store.custom_fields
+            .iter_mut()
+            .find(|field| field.object_id == Some(object_id) && field.custom_field_id == update.custom_field_id);
+        match field {
+            Some(field) => {
+                field.value = update.value;
+            }
+            None => {
+                return not_found("Custom field", update.custom_field_id.unwrap_or_default());
+            }
+        }
+    }
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn delete_custom_fields(
+    State(store): State<Shared>,
+    Path(object_id): Path<Uuid>,
+    RawQuery(raw): RawQuery
+) -> Response {
+    let mut store = lock(&store);
+    let query = query(raw);
+    let ids: Vec<_> = params(&query, "customField").collect();
+
+    store.custom_fields.retain(|field| {
+        field.object_id != Some(object_id) ||
+            (!ids.is_empty() &&
+                !field.custom_field_id.is_some_and(|id| ids.contains(&id.to_string().as_str())))
+    });
+    StatusCode::NO_CONTENT.into_response()
+}